@@ -1,13 +1,44 @@
 //! Queries a BAM file with a given region.
 //!
-//! The input BAM must have an index in the same directory.
+//! The input BAM must have an index in the same directory. A CSI (`<src>.csi`) is used if it
+//! exists; otherwise, a BAI (`<src>.bai`).
 //!
 //! The result matches the output of `samtools view <src> <region>`.
 
-use std::{env, fs::File, path::PathBuf};
+use std::{
+    env,
+    fs::File,
+    io::{self, Read, Seek},
+    path::PathBuf,
+};
 
 use noodles_bam::{self as bam, bai};
-use noodles_sam as sam;
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::{self as csi, binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam::{self as sam, header::ReferenceSequences};
+
+fn print_query<R, I, RS>(
+    reader: &mut bam::Reader<bgzf::Reader<R>>,
+    reference_sequences: &ReferenceSequences,
+    index: &I,
+    region: &Region,
+) -> io::Result<()>
+where
+    R: Read + Seek,
+    I: BinningIndex<RS>,
+    RS: ReferenceSequenceExt,
+{
+    let query = reader.query(reference_sequences, index, region)?;
+
+    for result in query {
+        let record = result?;
+        let sam_record = record.try_into_sam_record(reference_sequences)?;
+        println!("{}", sam_record);
+    }
+
+    Ok(())
+}
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut args = env::args();
@@ -20,14 +51,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let header: sam::Header = reader.read_header()?.parse()?;
     let reference_sequences = header.reference_sequences();
 
-    let index = bai::read(src.with_extension("bam.bai"))?;
+    let csi_src = src.with_extension("bam.csi");
 
-    let query = reader.query(reference_sequences, &index, &region)?;
-
-    for result in query {
-        let record = result?;
-        let sam_record = record.try_into_sam_record(reference_sequences)?;
-        println!("{}", sam_record);
+    if csi_src.exists() {
+        let index = csi::read(csi_src)?;
+        print_query(&mut reader, reference_sequences, &index, &region)?;
+    } else {
+        let index = bai::read(src.with_extension("bam.bai"))?;
+        print_query(&mut reader, reference_sequences, &index, &region)?;
     }
 
     Ok(())
@@ -1,6 +1,7 @@
 //! Queries a BAM file with a given region.
 //!
-//! The input BAM must have an index in the same directory.
+//! The input BAM must have an index in the same directory. A CSI (`<src>.csi`) is used if it
+//! exists; otherwise, a BAI (`<src>.bai`).
 //!
 //! The result matches the output of `samtools view <src> <region>`.
 
@@ -8,8 +9,35 @@ use std::{env, path::PathBuf};
 
 use futures::TryStreamExt;
 use noodles_bam::{self as bam, bai};
-use noodles_sam as sam;
-use tokio::fs::File;
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::{self as csi, binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam::{self as sam, header::ReferenceSequences};
+use tokio::{
+    fs::File,
+    io::{AsyncRead, AsyncSeek},
+};
+
+async fn print_query<R, I, RS>(
+    reader: &mut bam::AsyncReader<bgzf::AsyncReader<R>>,
+    reference_sequences: &ReferenceSequences,
+    index: &I,
+    region: &Region,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+    I: BinningIndex<RS>,
+    RS: ReferenceSequenceExt,
+{
+    let mut query = reader.query(reference_sequences, index, region)?;
+
+    while let Some(record) = query.try_next().await? {
+        let sam_record = record.try_into_sam_record(reference_sequences)?;
+        println!("{}", sam_record);
+    }
+
+    Ok(())
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -23,13 +51,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let header: sam::Header = reader.read_header().await?.parse()?;
     let reference_sequences = header.reference_sequences();
 
-    let index = bai::r#async::read(src.with_extension("bam.bai")).await?;
-
-    let mut query = reader.query(reference_sequences, &index, &region)?;
+    let csi_src = src.with_extension("bam.csi");
 
-    while let Some(record) = query.try_next().await? {
-        let sam_record = record.try_into_sam_record(reference_sequences)?;
-        println!("{}", sam_record);
+    if csi_src.exists() {
+        let index = csi::r#async::read(csi_src).await?;
+        print_query(&mut reader, reference_sequences, &index, &region).await?;
+    } else {
+        let index = bai::r#async::read(src.with_extension("bam.bai")).await?;
+        print_query(&mut reader, reference_sequences, &index, &region).await?;
     }
 
     Ok(())
@@ -0,0 +1,64 @@
+//! Times a BAM query with and without BGZF block decompression on worker threads.
+//!
+//! The input BAM must have an index in the same directory. The worker count defaults to 4.
+//!
+//! This prints the number of records in the region and the time taken to read them for each
+//! reader, e.g., `bam_query_workers sample.bam sq0 8`.
+
+use std::{
+    env,
+    fs::File,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use noodles_bam::{self as bam, bai};
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_sam as sam;
+
+fn time_query(
+    src: &Path,
+    index: &bai::Index,
+    region: &Region,
+    worker_count: usize,
+) -> Result<(usize, Duration), Box<dyn std::error::Error>> {
+    let file = File::open(src)?;
+    let inner = bgzf::Reader::builder(file)
+        .set_worker_count(worker_count)
+        .build();
+    let mut reader = bam::Reader::from(inner);
+
+    let header: sam::Header = reader.read_header()?.parse()?;
+    let reference_sequences = header.reference_sequences();
+
+    let start = Instant::now();
+    let mut n = 0;
+
+    for result in reader.query(reference_sequences, index, region)? {
+        result?;
+        n += 1;
+    }
+
+    Ok((n, start.elapsed()))
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args();
+
+    let src = args.nth(1).map(PathBuf::from).expect("missing src");
+    let region = args.next().expect("missing region").parse()?;
+    let worker_count = args.next().map(|s| s.parse()).transpose()?.unwrap_or(4);
+
+    let index = bai::read(src.with_extension("bam.bai"))?;
+
+    for n in [0, worker_count] {
+        let (record_count, elapsed) = time_query(&src, &index, &region, n)?;
+        println!(
+            "workers={}\trecords={}\telapsed={:?}",
+            n, record_count, elapsed
+        );
+    }
+
+    Ok(())
+}
@@ -4,66 +4,37 @@
 //! `out_<index>.bam` and contains records from a single read group. Records without a read group
 //! are discarded.
 //!
+//! Each output is written with [`bam::write`], which takes a single stream of records, so the
+//! input is read once per read group.
+//!
 //! This is similar to the outputs of `samtools split <src>`.
 
 use noodles_bam as bam;
-use noodles_bgzf as bgzf;
 use noodles_sam::{self as sam, AlignmentRecord};
 
-use std::{collections::HashMap, env, fs::File, io};
-
-type Writers = HashMap<String, bam::Writer<bgzf::Writer<File>>>;
-
-fn build_writers(read_groups: &sam::header::ReadGroups) -> io::Result<Writers> {
-    read_groups
-        .values()
-        .enumerate()
-        .map(|(i, rg)| {
-            let dst = format!("out_{}.bam", i);
-            File::create(dst).map(|f| (rg.id().into(), bam::Writer::new(f)))
-        })
-        .collect::<Result<_, _>>()
-}
+use std::{env, fs::File, io};
 
-fn write_headers(writers: &mut Writers, header: &sam::Header) -> io::Result<()> {
-    for read_group in header.read_groups().values() {
-        let id = read_group.id();
+fn find_read_group(data: &sam::record::Data) -> io::Result<Option<&str>> {
+    use sam::record::data::field::{value::Type, Tag};
 
-        let writer = writers.get_mut(id).ok_or_else(|| {
+    match data.get(Tag::ReadGroup) {
+        Some(field) => field.value().as_str().map(Some).ok_or_else(|| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
-                format!("invalid read group: {}", id),
+                format!("expected {:?}, got {:?}", Type::String, field.value()),
             )
-        })?;
-
-        let mut modified_header = header.clone();
-
-        let read_groups = modified_header.read_groups_mut();
-        read_groups.clear();
-        read_groups.insert(id.into(), read_group.clone());
-
-        writer.write_header(&modified_header)?;
-        writer.write_reference_sequences(modified_header.reference_sequences())?;
+        }),
+        None => Ok(None),
     }
-
-    Ok(())
 }
 
-fn find_read_group(data: &sam::record::Data) -> io::Result<Option<String>> {
-    use sam::record::data::field::{value::Type, Tag};
-
-    match data.get(Tag::ReadGroup) {
-        Some(field) => field
-            .value()
-            .as_str()
-            .map(|s| Some(s.into()))
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("expected {:?}, got {:?}", Type::String, field.value()),
-                )
-            }),
-        None => Ok(None),
+fn is_in_read_group(header: &sam::Header, record: &bam::Record, id: &str) -> io::Result<bool> {
+    match find_read_group(record.data())? {
+        Some(rg) if !header.read_groups().contains_key(rg) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid read group: {}", rg),
+        )),
+        rg => Ok(rg == Some(id)),
     }
 }
 
@@ -71,26 +42,33 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut args = env::args().skip(1);
     let src = args.next().expect("missing src");
 
-    let mut reader = File::open(src).map(bam::Reader::new)?;
+    let mut reader = File::open(&src).map(bam::Reader::new)?;
     let header: sam::Header = reader.read_header()?.parse()?;
-    reader.read_reference_sequences()?;
 
-    let mut writers = build_writers(header.read_groups())?;
-    write_headers(&mut writers, &header)?;
+    for (i, read_group) in header.read_groups().values().enumerate() {
+        let id = read_group.id();
 
-    for result in reader.records() {
-        let record = result?;
+        let mut modified_header = header.clone();
 
-        if let Some(rg) = find_read_group(record.data())? {
-            let writer = writers.get_mut(&rg).ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("invalid read group: {}", rg),
-                )
-            })?;
+        let read_groups = modified_header.read_groups_mut();
+        read_groups.clear();
+        read_groups.insert(id.into(), read_group.clone());
 
-            writer.write_record(&record)?;
-        }
+        let mut reader = File::open(&src).map(bam::Reader::new)?;
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let records = reader.records().filter_map(|result| {
+            result
+                .and_then(|record| {
+                    is_in_read_group(&header, &record, id)
+                        .map(|is_in_read_group| Some(record).filter(|_| is_in_read_group))
+                })
+                .transpose()
+        });
+
+        let dst = format!("out_{}.bam", i);
+        bam::write(dst, &modified_header, records)?;
     }
 
     Ok(())
@@ -13,7 +13,7 @@ use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
 use self::{query::query, record::read_record};
 use crate::{
-    reader::{bytes_with_nul_to_string, resolve_region},
+    reader::{bytes_with_nul_to_string, header_text_len, resolve_region, truncated_header_error},
     Record, MAGIC_NUMBER,
 };
 
@@ -102,6 +102,12 @@ where
     /// This returns the raw SAM header as a [`String`]. It can subsequently be parsed as a
     /// [`noodles_sam::Header`].
     ///
+    /// # Errors
+    ///
+    /// The header text is read into a buffer of its stated length (`l_text`). To bound this
+    /// allocation, this returns an error if the length is greater than 1 GiB. This also returns
+    /// an error if the stream ends before the end of the header text.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -394,12 +400,16 @@ async fn read_header<R>(reader: &mut R) -> io::Result<String>
 where
     R: AsyncRead + Unpin,
 {
-    let l_text = reader.read_u32_le().await.and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let l_text = reader.read_u32_le().await.and_then(header_text_len)?;
 
     let mut text = vec![0; l_text];
-    reader.read_exact(&mut text).await?;
+    reader
+        .read_exact(&mut text)
+        .await
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::UnexpectedEof => truncated_header_error(l_text),
+            _ => e,
+        })?;
 
     // § 4.2 The BAM format (2021-06-03): "Plain header text in SAM; not necessarily
     // NUL-terminated".
@@ -493,6 +503,36 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_header_with_truncated_text() {
+        let data = [
+            0x0b, 0x00, 0x00, 0x00, // l_text = 11
+            b'@', b'H', b'D', // text = "@HD"
+        ];
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            read_header(&mut reader).await,
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_header_with_l_text_greater_than_max() {
+        let data = [
+            0xff, 0xff, 0xff, 0xff, // l_text = 4294967295
+            b'@', b'H', b'D', // text = "@HD"
+        ];
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            read_header(&mut reader).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
     #[tokio::test]
     async fn test_read_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
         use sam::header::reference_sequence;
@@ -7,10 +7,7 @@ use noodles_csi::index::reference_sequence::bin::Chunk;
 use tokio::io::{self, AsyncRead, AsyncSeek};
 
 use super::Reader;
-use crate::{
-    reader::query::{intersects, next_chunk},
-    Record,
-};
+use crate::{reader::query::intersects, Record};
 
 enum State {
     Seek,
@@ -86,6 +83,12 @@ where
     }))
 }
 
+fn next_chunk(chunks: &[Chunk], i: &mut usize) -> Option<Chunk> {
+    let chunk = chunks.get(*i).copied();
+    *i += 1;
+    chunk
+}
+
 async fn next_record<R>(reader: &mut Reader<bgzf::AsyncReader<R>>) -> io::Result<Option<Record>>
 where
     R: AsyncRead + AsyncSeek + Unpin,
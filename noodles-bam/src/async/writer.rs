@@ -4,12 +4,16 @@ pub use self::builder::Builder;
 
 use std::ffi::CString;
 
+use futures::{Stream, TryStreamExt};
 use noodles_bgzf as bgzf;
 use noodles_sam as sam;
 use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
 use crate::{
-    writer::{alignment_record::encode_alignment_record, record::encode_record},
+    writer::{
+        alignment_record::{encode_alignment_record, encode_sam_record, ReferenceSequenceIdCache},
+        record::encode_record,
+    },
     Record,
 };
 
@@ -17,6 +21,7 @@ use crate::{
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    reference_sequence_id_cache: ReferenceSequenceIdCache,
 }
 
 impl<W> Writer<W>
@@ -163,6 +168,45 @@ where
         Ok(())
     }
 
+    /// Writes a stream of BAM records.
+    ///
+    /// Records are pulled from the stream one at a time, and the next record is not polled until
+    /// the previous one is written. A slow writer therefore applies backpressure to the upstream
+    /// stream, e.g., the receiver of a bounded channel.
+    ///
+    /// The first error, either from the stream or from writing, is returned, and no further
+    /// records are read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use futures::{stream, StreamExt};
+    /// use noodles_bam as bam;
+    ///
+    /// let records = stream::iter(vec![bam::Record::default(), bam::Record::default()]).map(Ok);
+    ///
+    /// let mut writer = bam::AsyncWriter::new(Vec::new());
+    /// writer.write_records(records).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_records<S>(&mut self, records: S) -> io::Result<()>
+    where
+        S: Stream<Item = io::Result<Record>>,
+    {
+        futures::pin_mut!(records);
+
+        while let Some(record) = records.try_next().await? {
+            self.write_record(&record).await?;
+        }
+
+        Ok(())
+    }
+
     /// Writes a SAM record.
     ///
     /// # Examples
@@ -192,7 +236,12 @@ where
         reference_sequences: &sam::header::ReferenceSequences,
         record: &sam::Record,
     ) -> io::Result<()> {
-        encode_alignment_record(&mut self.buf, reference_sequences, record)?;
+        encode_sam_record(
+            &mut self.buf,
+            reference_sequences,
+            &mut self.reference_sequence_id_cache,
+            record,
+        )?;
 
         let block_size = u32::try_from(self.buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -230,7 +279,12 @@ where
         header: &sam::Header,
         record: &dyn sam::AlignmentRecord,
     ) -> io::Result<()> {
-        encode_alignment_record(&mut self.buf, header.reference_sequences(), record)?;
+        encode_alignment_record(
+            &mut self.buf,
+            header.reference_sequences(),
+            &mut self.reference_sequence_id_cache,
+            record,
+        )?;
 
         let block_size = u32::try_from(self.buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -279,6 +333,7 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             buf: Vec::new(),
+            reference_sequence_id_cache: ReferenceSequenceIdCache::default(),
         }
     }
 }
@@ -362,4 +417,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_write_records() -> io::Result<()> {
+        use futures::stream;
+
+        let records = stream::iter(vec![Ok(Record::default()), Ok(Record::default())]);
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_records(records).await?;
+
+        let mut expected = Writer::from(Vec::new());
+        expected.write_record(&Record::default()).await?;
+        expected.write_record(&Record::default()).await?;
+
+        assert_eq!(writer.get_ref(), expected.get_ref());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_records_with_stream_error() -> io::Result<()> {
+        use futures::stream;
+
+        let records = stream::iter(vec![
+            Ok(Record::default()),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Ok(Record::default()),
+        ]);
+
+        let mut writer = Writer::from(Vec::new());
+
+        assert!(matches!(
+            writer.write_records(records).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut expected = Writer::from(Vec::new());
+        expected.write_record(&Record::default()).await?;
+        assert_eq!(writer.get_ref(), expected.get_ref());
+
+        Ok(())
+    }
 }
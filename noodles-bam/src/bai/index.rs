@@ -10,13 +10,18 @@ use std::{
     ops::{Bound, RangeBounds},
 };
 
+use noodles_bgzf as bgzf;
 use noodles_core::Position;
 use noodles_csi::{
-    binning_index::optimize_chunks, index::reference_sequence::bin::Chunk, BinningIndex,
+    binning_index::{optimize_chunks, MergedChunks},
+    index::reference_sequence::bin::Chunk,
+    BinningIndex,
 };
 
-const MIN_SHIFT: u8 = 14;
-const DEPTH: u8 = 5;
+use self::reference_sequence::Bin;
+
+pub(crate) const MIN_SHIFT: u8 = 14;
+pub(crate) const DEPTH: u8 = 5;
 
 const MAX_POSITION: Position = match Position::new((1 << (MIN_SHIFT + 3 * DEPTH)) - 1) {
     Some(position) => position,
@@ -118,6 +123,42 @@ impl BinningIndex<ReferenceSequence> for Index {
     }
 
     fn query<B>(&self, reference_sequence_id: usize, interval: B) -> io::Result<Vec<Chunk>>
+    where
+        B: RangeBounds<Position> + Clone,
+    {
+        let (query_bins, min_offset) = self.query_bins(reference_sequence_id, interval)?;
+
+        let chunks: Vec<_> = query_bins
+            .iter()
+            .flat_map(|bin| bin.chunks())
+            .copied()
+            .collect();
+
+        let merged_chunks = optimize_chunks(&chunks, min_offset);
+
+        Ok(merged_chunks)
+    }
+
+    fn query_merged_chunks<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<MergedChunks<'_>>
+    where
+        B: RangeBounds<Position> + Clone,
+    {
+        let (query_bins, min_offset) = self.query_bins(reference_sequence_id, interval)?;
+        let bins = query_bins.into_iter().map(|bin| bin.chunks());
+        Ok(MergedChunks::new(bins, min_offset))
+    }
+}
+
+impl Index {
+    fn query_bins<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<(Vec<&Bin>, bgzf::VirtualPosition)>
     where
         B: RangeBounds<Position> + Clone,
     {
@@ -135,17 +176,10 @@ impl BinningIndex<ReferenceSequence> for Index {
             .query(interval.clone())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        let chunks: Vec<_> = query_bins
-            .iter()
-            .flat_map(|bin| bin.chunks())
-            .copied()
-            .collect();
-
         let (start, _) = resolve_interval(interval)?;
         let min_offset = reference_sequence.min_offset(start);
-        let merged_chunks = optimize_chunks(&chunks, min_offset);
 
-        Ok(merged_chunks)
+        Ok((query_bins, min_offset))
     }
 }
 
@@ -6,7 +6,7 @@ pub(crate) use self::builder::Builder;
 
 use noodles_csi::index::reference_sequence::bin::Chunk;
 
-use crate::bai::index::DEPTH;
+use crate::bai::index::{DEPTH, MIN_SHIFT};
 
 pub(crate) const MAX_ID: u32 = ((1 << (3 * (DEPTH + 1))) - 1) / 7;
 
@@ -68,4 +68,38 @@ impl Bin {
     pub fn chunks(&self) -> &[Chunk] {
         &self.chunks
     }
+
+    /// Returns the 0-based start position of the interval covered by this bin.
+    pub(crate) fn start(&self) -> usize {
+        let mut level_offset = 0;
+
+        for level in 0..=DEPTH {
+            let next_level_offset = ((1 << (3 * (level + 1))) - 1) / 7;
+
+            if self.id < next_level_offset {
+                let shift = MIN_SHIFT + 3 * (DEPTH - level);
+                return ((self.id - level_offset) as usize) << shift;
+            }
+
+            level_offset = next_level_offset;
+        }
+
+        // The metadata pseudo-bin does not cover an interval.
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start() {
+        assert_eq!(Bin::new(0, Vec::new()).start(), 0);
+        assert_eq!(Bin::new(2, Vec::new()).start(), 1 << 26);
+        assert_eq!(Bin::new(4681, Vec::new()).start(), 0);
+        assert_eq!(Bin::new(4682, Vec::new()).start(), 1 << 14);
+        assert_eq!(Bin::new(MAX_ID - 1, Vec::new()).start(), 32767 << 14);
+        assert_eq!(Bin::new(METADATA_ID, Vec::new()).start(), 0);
+    }
 }
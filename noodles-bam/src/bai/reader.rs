@@ -249,6 +249,28 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_index() -> io::Result<()> {
+        use noodles_csi::BinningIndex;
+
+        let data = [
+            0x00, 0x00, 0x00, 0x00, // n_ref = 0
+        ];
+        let mut reader = Reader::new(&data[..]);
+        let index = reader.read_index()?;
+        assert!(index.unplaced_unmapped_record_count().is_none());
+
+        let data = [
+            0x00, 0x00, 0x00, 0x00, // n_ref = 0
+            0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // n_no_coor = 8
+        ];
+        let mut reader = Reader::new(&data[..]);
+        let index = reader.read_index()?;
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(8));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic() {
         let data = b"BAI\x01";
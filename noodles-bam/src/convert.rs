@@ -0,0 +1,321 @@
+use std::io::{self, BufRead, Read, Write};
+
+use noodles_sam::{
+    self as sam,
+    record::{
+        cigar::op::Kind,
+        data::{
+            field::{Tag, Value},
+            Field,
+        },
+    },
+    AlignmentReader, AlignmentRecord, AlignmentWriter, RecordExt,
+};
+
+use super::{Reader, Writer};
+
+/// SAM to BAM conversion options.
+///
+/// See [`convert_from_sam_with_options`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConvertFromSamOptions {
+    compute_edit_distance: bool,
+}
+
+impl ConvertFromSamOptions {
+    /// Sets whether to compute the edit distance (`NM`) of records that do not have one.
+    ///
+    /// The edit distance is the number of inserted (`I`), deleted (`D`), and mismatched bases. The
+    /// mismatched bases are counted from the sequence mismatch (`X`) CIGAR operations or, if the
+    /// CIGAR has alignment matches (`M`), from the mismatched positions string (`MD`). The
+    /// reference sequence is not used, so records with alignment matches and no `MD` field are
+    /// left without an edit distance.
+    ///
+    /// By default, the edit distance is not computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let options = bam::ConvertFromSamOptions::default().set_compute_edit_distance(true);
+    /// ```
+    pub fn set_compute_edit_distance(mut self, compute_edit_distance: bool) -> Self {
+        self.compute_edit_distance = compute_edit_distance;
+        self
+    }
+}
+
+/// Converts a SAM stream to a BAM stream.
+///
+/// This reads the SAM header and writes it along with the binary reference sequences. Each SAM
+/// record is then converted to and written as a BAM record.
+///
+/// The BAM writer is not finished. It is finished when dropped or by calling
+/// [`Writer::try_finish`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let data = b"@HD\tVN:1.6
+/// @SQ\tSN:sq0\tLN:8
+/// r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+/// ";
+///
+/// let mut reader = sam::Reader::new(&data[..]);
+/// let mut writer = bam::Writer::new(Vec::new());
+/// bam::convert_from_sam(&mut reader, &mut writer)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn convert_from_sam<R, W>(reader: &mut sam::Reader<R>, writer: &mut Writer<W>) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    convert_from_sam_with_options(reader, writer, ConvertFromSamOptions::default())
+}
+
+/// Converts a SAM stream to a BAM stream using the given options.
+///
+/// This is the same as [`convert_from_sam`] but allows records to be modified during conversion.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam::{self as sam, AlignmentRecord};
+///
+/// let data = b"@HD\tVN:1.6
+/// @SQ\tSN:sq0\tLN:8
+/// r0\t0\tsq0\t1\t60\t2=1X1=\t*\t0\t0\tACGT\tNDLS
+/// ";
+///
+/// let mut reader = sam::Reader::new(&data[..]);
+/// let mut writer = bam::Writer::new(Vec::new());
+/// let options = bam::ConvertFromSamOptions::default().set_compute_edit_distance(true);
+/// bam::convert_from_sam_with_options(&mut reader, &mut writer, options)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn convert_from_sam_with_options<R, W>(
+    reader: &mut sam::Reader<R>,
+    writer: &mut Writer<W>,
+    options: ConvertFromSamOptions,
+) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let header = reader.read_alignment_header()?;
+    writer.write_alignment_header(&header)?;
+
+    for result in reader.records() {
+        let mut record = result?;
+
+        if options.compute_edit_distance {
+            add_edit_distance(&mut record);
+        }
+
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    Ok(())
+}
+
+fn add_edit_distance(record: &mut sam::Record) {
+    if record.data().get(Tag::EditDistance).is_some() {
+        return;
+    }
+
+    if let Some(edit_distance) = calculate_edit_distance(record) {
+        let field = Field::new(Tag::EditDistance, Value::from(edit_distance));
+        record.data_mut().insert(field);
+    }
+}
+
+// Calculates the edit distance of a record without a reference sequence.
+//
+// This returns `None` if the record has no CIGAR or if it has alignment matches (`M`) and no
+// mismatched positions string (`MD`).
+fn calculate_edit_distance(record: &sam::Record) -> Option<u32> {
+    let cigar = record.cigar();
+
+    if cigar.is_empty() {
+        return None;
+    }
+
+    let mut indel_len = 0;
+    let mut sequence_mismatch_len = 0;
+    let mut has_alignment_matches = false;
+
+    for op in cigar.iter() {
+        match op.kind() {
+            Kind::Insertion | Kind::Deletion => indel_len += op.len(),
+            Kind::SequenceMismatch => sequence_mismatch_len += op.len(),
+            Kind::Match => has_alignment_matches = true,
+            _ => {}
+        }
+    }
+
+    let mismatch_len = if has_alignment_matches {
+        record
+            .mismatch_string()
+            .ok()
+            .map(count_mismatched_positions)?
+    } else {
+        sequence_mismatch_len
+    };
+
+    u32::try_from(indel_len + mismatch_len).ok()
+}
+
+// Counts the number of mismatched bases in a mismatched positions string (`MD`), excluding
+// deleted bases.
+fn count_mismatched_positions(s: &str) -> usize {
+    const DELETION_PREFIX: char = '^';
+
+    let mut n = 0;
+    let mut is_deletion = false;
+
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            is_deletion = false;
+        } else if c == DELETION_PREFIX {
+            is_deletion = true;
+        } else if !is_deletion {
+            n += 1;
+        }
+    }
+
+    n
+}
+
+/// Converts a BAM stream to a SAM stream.
+///
+/// This reads the BAM header and binary reference sequences and writes the SAM header. Each BAM
+/// record is then converted to and written as a SAM record.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let mut writer = bam::Writer::new(Vec::new());
+/// let header = sam::Header::default();
+/// writer.write_header(&header)?;
+/// writer.write_reference_sequences(header.reference_sequences())?;
+/// writer.write_record(&bam::Record::default())?;
+/// let data = writer.into_inner().finish()?;
+///
+/// let mut reader = bam::Reader::new(&data[..]);
+/// let mut writer = sam::Writer::new(Vec::new());
+/// bam::convert_to_sam(&mut reader, &mut writer)?;
+///
+/// assert_eq!(writer.get_ref(), b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n");
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn convert_to_sam<R, W>(reader: &mut Reader<R>, writer: &mut sam::Writer<W>) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let header = reader.read_alignment_header()?;
+    writer.write_alignment_header(&header)?;
+
+    for result in reader.records() {
+        let record = result?;
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_from_sam_and_convert_to_sam() -> io::Result<()> {
+        let data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+r0\t99\tsq0\t1\t60\t4M\t=\t5\t8\tACGT\tNDLS\tNH:i:1\tCO:Z:noodles
+r1\t147\tsq0\t5\t60\t2S2M\t=\t1\t-8\tTTGA\tNDLS
+r2\t0\tsq1\t3\t13\t1M1I2M\t*\t0\t0\tACGT\t*\tRG:Z:rg0
+r3\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+        let mut reader = sam::Reader::new(&data[..]);
+        let mut writer = Writer::new(Vec::new());
+        convert_from_sam(&mut reader, &mut writer)?;
+        let bam_data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&bam_data[..]);
+        let mut writer = sam::Writer::new(Vec::new());
+        convert_to_sam(&mut reader, &mut writer)?;
+
+        let mut reader = sam::Reader::new(&data[..]);
+        let expected_header = reader.read_alignment_header()?;
+        let expected_records = reader.records().collect::<io::Result<Vec<_>>>()?;
+
+        let mut reader = sam::Reader::new(writer.get_ref().as_slice());
+        let actual_header = reader.read_alignment_header()?;
+        let actual_records = reader.records().collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(actual_header, expected_header);
+        assert_eq!(actual_records, expected_records);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_from_sam_with_options() -> io::Result<()> {
+        let data = b"@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:13
+r0\t0\tsq0\t1\t60\t2=1X1=1I2=1D2=\t*\t0\t0\tACGTACGTA\tNDLSNDLSN
+r1\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS\tMD:Z:1A0C1
+r2\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r3\t0\tsq0\t1\t60\t4=\t*\t0\t0\tACGT\tNDLS\tNM:i:8
+r4\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+        let mut reader = sam::Reader::new(&data[..]);
+        let mut writer = Writer::new(Vec::new());
+        let options = ConvertFromSamOptions::default().set_compute_edit_distance(true);
+        convert_from_sam_with_options(&mut reader, &mut writer, options)?;
+        let bam_data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&bam_data[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let actual: Vec<_> = reader
+            .records()
+            .map(|result| {
+                result.map(|record| {
+                    record
+                        .data()
+                        .get(Tag::EditDistance)
+                        .and_then(|field| field.value().as_int())
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, [Some(3), Some(2), None, Some(8), None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_mismatched_positions() {
+        assert_eq!(count_mismatched_positions("8"), 0);
+        assert_eq!(count_mismatched_positions("1A0C1"), 2);
+        assert_eq!(count_mismatched_positions("4^AC0G3"), 1);
+        assert_eq!(count_mismatched_positions("2^ACG4T0"), 1);
+    }
+}
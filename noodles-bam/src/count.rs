@@ -0,0 +1,319 @@
+//! BAM record counts by reference sequence.
+
+use std::io::{self, Read};
+
+use noodles_sam::{self as sam, AlignmentRecord};
+
+use super::{Reader, Record};
+
+/// Record counts of a reference sequence.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceSequenceRecordCounts {
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+}
+
+impl ReferenceSequenceRecordCounts {
+    /// Returns the number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of unmapped records with a position.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+}
+
+/// Record counts by reference sequence.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RecordCounts {
+    reference_sequences: Vec<ReferenceSequenceRecordCounts>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl RecordCounts {
+    /// Returns the record counts of each reference sequence.
+    ///
+    /// This list is parallel to the reference sequences defined in the associated header.
+    pub fn reference_sequences(&self) -> &[ReferenceSequenceRecordCounts] {
+        &self.reference_sequences
+    }
+
+    /// Returns the number of unplaced, unmapped records.
+    pub fn unplaced_unmapped_record_count(&self) -> u64 {
+        self.unplaced_unmapped_record_count
+    }
+
+    fn new(reference_sequence_count: usize) -> Self {
+        Self {
+            reference_sequences: vec![
+                ReferenceSequenceRecordCounts::default();
+                reference_sequence_count
+            ],
+            unplaced_unmapped_record_count: 0,
+        }
+    }
+
+    fn add<R>(&mut self, reference_sequence_id: Option<usize>, record: &R) -> io::Result<()>
+    where
+        R: AlignmentRecord,
+    {
+        let reference_sequence_id = match (
+            reference_sequence_id,
+            record.alignment_start(),
+            record.alignment_end(),
+        ) {
+            (Some(reference_sequence_id), Some(_), Some(_)) => reference_sequence_id,
+            _ => {
+                self.unplaced_unmapped_record_count += 1;
+                return Ok(());
+            }
+        };
+
+        let reference_sequence_counts = self
+            .reference_sequences
+            .get_mut(reference_sequence_id)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid reference sequence ID: {}", reference_sequence_id),
+                )
+            })?;
+
+        if record.flags().is_unmapped() {
+            reference_sequence_counts.unmapped_record_count += 1;
+        } else {
+            reference_sequence_counts.mapped_record_count += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// Counts mapped and unmapped records by reference sequence by reading all records.
+///
+/// This is the equivalent of the counts in a BAM index (e.g., as printed by `samtools idxstats`)
+/// for files without an index. The stream is expected to be directly after the reference
+/// sequences or at the start of another record.
+///
+/// A record with a reference sequence ID and an alignment span is counted with its reference
+/// sequence as mapped or, if it has the unmapped flag set, as unmapped. Any other record is
+/// counted as unplaced and unmapped.
+///
+/// # Errors
+///
+/// This returns an error if a record fails to read or if a reference sequence ID is not in the
+/// header.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let header: sam::Header = reader.read_header()?.parse().map_err(|e| {
+///     io::Error::new(io::ErrorKind::InvalidData, e)
+/// })?;
+/// reader.read_reference_sequences()?;
+///
+/// let counts = bam::count_by_reference(&mut reader, &header)?;
+///
+/// for (reference_sequence, counts) in header
+///     .reference_sequences()
+///     .values()
+///     .zip(counts.reference_sequences())
+/// {
+///     println!(
+///         "{}\t{}\t{}\t{}",
+///         reference_sequence.name(),
+///         reference_sequence.len(),
+///         counts.mapped_record_count(),
+///         counts.unmapped_record_count(),
+///     );
+/// }
+///
+/// println!("*\t0\t0\t{}", counts.unplaced_unmapped_record_count());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn count_by_reference<R>(
+    reader: &mut Reader<R>,
+    header: &sam::Header,
+) -> io::Result<RecordCounts>
+where
+    R: Read,
+{
+    let mut counts = RecordCounts::new(header.reference_sequences().len());
+    let mut record = Record::default();
+
+    while reader.read_record(&mut record)? != 0 {
+        counts.add(record.reference_sequence_id(), &record)?;
+    }
+
+    Ok(counts)
+}
+
+/// Counts mapped and unmapped records by reference sequence from an iterator of records.
+///
+/// This is the same as [`count_by_reference`], but the records can be of any [`AlignmentRecord`]
+/// type, e.g., SAM, BAM, or CRAM records. The reference sequence of a record is resolved using the
+/// reference sequences in the given header.
+///
+/// # Errors
+///
+/// This returns an error if a record fails to read or if a reference sequence is not in the
+/// header.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_sam::{self as sam, record::Flags};
+///
+/// let header = sam::Header::default();
+/// let records = vec![Ok(sam::Record::builder().set_flags(Flags::UNMAPPED).build())];
+///
+/// let counts = bam::count_records(&header, records)?;
+///
+/// assert!(counts.reference_sequences().is_empty());
+/// assert_eq!(counts.unplaced_unmapped_record_count(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn count_records<I, R>(header: &sam::Header, records: I) -> io::Result<RecordCounts>
+where
+    I: IntoIterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    let reference_sequences = header.reference_sequences();
+    let mut counts = RecordCounts::new(reference_sequences.len());
+
+    for result in records {
+        let record = result?;
+
+        let reference_sequence_id = record
+            .reference_sequence(reference_sequences)
+            .transpose()?
+            .and_then(|reference_sequence| {
+                reference_sequences.get_index_of(reference_sequence.name().as_str())
+            });
+
+        counts.add(reference_sequence_id, &record)?;
+    }
+
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+
+    use super::*;
+    use crate::test_helpers::build_bam_and_index;
+
+    #[test]
+    fn test_count_by_reference() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+@SQ\tSN:sq2\tLN:21
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t4\tsq0\t3\t0\t*\t*\t0\t0\tACGT\tNDLS
+r3\t0\tsq2\t5\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r4\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+r5\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+        let (bam_data, index) = build_bam_and_index(data)?;
+
+        let mut reader = Reader::new(&bam_data[..]);
+        let header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+        let actual = count_by_reference(&mut reader, &header)?;
+
+        let expected = RecordCounts {
+            reference_sequences: index
+                .reference_sequences()
+                .iter()
+                .map(|reference_sequence| {
+                    let (mapped_record_count, unmapped_record_count) = reference_sequence
+                        .metadata()
+                        .map(|m| (m.mapped_record_count(), m.unmapped_record_count()))
+                        .unwrap_or_default();
+
+                    ReferenceSequenceRecordCounts {
+                        mapped_record_count,
+                        unmapped_record_count,
+                    }
+                })
+                .collect(),
+            unplaced_unmapped_record_count: index
+                .unplaced_unmapped_record_count()
+                .unwrap_or_default(),
+        };
+
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            actual.reference_sequences(),
+            [
+                ReferenceSequenceRecordCounts {
+                    mapped_record_count: 2,
+                    unmapped_record_count: 1,
+                },
+                ReferenceSequenceRecordCounts::default(),
+                ReferenceSequenceRecordCounts {
+                    mapped_record_count: 1,
+                    unmapped_record_count: 0,
+                },
+            ]
+        );
+        assert_eq!(actual.unplaced_unmapped_record_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_records() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t4\tsq0\t3\t0\t*\t*\t0\t0\tACGT\tNDLS
+r2\t0\tsq1\t5\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r3\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+        let mut reader = sam::Reader::new(&data[..]);
+        let header: sam::Header = reader.read_header()?.parse()?;
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+
+        let actual = count_records(&header, records.into_iter().map(Ok))?;
+
+        let (bam_data, _) = build_bam_and_index(data)?;
+        let mut reader = Reader::new(&bam_data[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+        let expected = count_by_reference(&mut reader, &header)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual.reference_sequences(),
+            [
+                ReferenceSequenceRecordCounts {
+                    mapped_record_count: 1,
+                    unmapped_record_count: 1,
+                },
+                ReferenceSequenceRecordCounts {
+                    mapped_record_count: 1,
+                    unmapped_record_count: 0,
+                },
+            ]
+        );
+        assert_eq!(actual.unplaced_unmapped_record_count(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,549 @@
+//! BAM record coverage.
+
+mod mask;
+
+pub(crate) use self::mask::Mask;
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt, io,
+};
+
+use noodles_core::{Position, Region};
+use noodles_sam::{self as sam, record::cigar::op::Kind, AlignmentRecord};
+
+/// A bedGraph record, i.e., a run of reference positions with the same depth.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BedGraphRecord {
+    reference_sequence_name: String,
+    start: Position,
+    end: Position,
+    depth: u64,
+}
+
+impl BedGraphRecord {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the start position.
+    ///
+    /// This is 1-based. Note that bedGraph start positions are 0-based, which the [`fmt::Display`]
+    /// implementation accounts for.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    ///
+    /// This is 1-based and inclusive.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the number of records covering each position in the run.
+    pub fn depth(&self) -> u64 {
+        self.depth
+    }
+}
+
+impl fmt::Display for BedGraphRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.reference_sequence_name,
+            usize::from(self.start) - 1,
+            self.end,
+            self.depth
+        )
+    }
+}
+
+/// An iterator over coverage runs of coordinate-sorted alignment records.
+///
+/// This is created by calling [`coverage_bedgraph`].
+pub struct CoverageBedGraph<'h, I> {
+    records: I,
+    reference_sequences: &'h sam::header::ReferenceSequences,
+    include_zero_coverage: bool,
+    mask: Mask,
+    reference_sequence_id: usize,
+    last_alignment_start: Option<Position>,
+    // Depth changes keyed by 1-based position.
+    deltas: BTreeMap<usize, i64>,
+    position: usize,
+    depth: i64,
+    run: Option<(usize, usize, i64)>,
+    queue: VecDeque<BedGraphRecord>,
+    is_done: bool,
+}
+
+impl<'h, I, R> CoverageBedGraph<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    fn add_record(&mut self, record: &R) -> io::Result<()> {
+        let reference_sequence = record
+            .reference_sequence(self.reference_sequences)
+            .transpose()?;
+
+        let (reference_sequence, alignment_start) =
+            match (reference_sequence, record.alignment_start()) {
+                (Some(reference_sequence), Some(start)) => (reference_sequence, start),
+                _ => {
+                    // Unplaced records are last in coordinate-sorted input.
+                    self.finish(self.reference_sequences.len());
+                    self.is_done = true;
+                    return Ok(());
+                }
+            };
+
+        let reference_sequence_id = self
+            .reference_sequences
+            .get_index_of(reference_sequence.name().as_str())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "invalid reference sequence name: {}",
+                        reference_sequence.name()
+                    ),
+                )
+            })?;
+
+        if reference_sequence_id < self.reference_sequence_id
+            || (reference_sequence_id == self.reference_sequence_id
+                && Some(alignment_start) < self.last_alignment_start)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "records are not coordinate-sorted",
+            ));
+        }
+
+        self.finish(reference_sequence_id);
+        self.last_alignment_start = Some(alignment_start);
+
+        if record.flags().is_unmapped() {
+            return Ok(());
+        }
+
+        // No following record can change the depth of positions before this record.
+        self.advance(usize::from(alignment_start));
+
+        let mut position = usize::from(alignment_start);
+
+        for op in record.cigar().iter() {
+            match op.kind() {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch | Kind::Deletion => {
+                    *self.deltas.entry(position).or_default() += 1;
+                    position += op.len();
+                    *self.deltas.entry(position).or_default() -= 1;
+                }
+                Kind::Skip => position += op.len(),
+                Kind::Insertion | Kind::SoftClip | Kind::HardClip | Kind::Pad => {}
+                Kind::Back => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "unsupported CIGAR operation: B",
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Finishes all reference sequences before the given reference sequence ID.
+    fn finish(&mut self, reference_sequence_id: usize) {
+        while self.reference_sequence_id < reference_sequence_id {
+            let len = self.reference_sequence_len();
+            self.advance(len + 1);
+            self.flush();
+
+            self.reference_sequence_id += 1;
+            self.last_alignment_start = None;
+            self.deltas.clear();
+            self.position = 1;
+            self.depth = 0;
+        }
+    }
+
+    // Adds runs for the positions before the given (1-based) position.
+    fn advance(&mut self, end: usize) {
+        while let Some((&position, &delta)) = self.deltas.iter().next() {
+            if position >= end {
+                break;
+            }
+
+            self.deltas.remove(&position);
+            self.push_run(self.position, position, self.depth);
+            self.depth += delta;
+            self.position = position;
+        }
+
+        self.push_run(self.position, end, self.depth);
+        self.position = self.position.max(end);
+    }
+
+    fn reference_sequence_len(&self) -> usize {
+        self.reference_sequences
+            .get_index(self.reference_sequence_id)
+            .map(|(_, rs)| usize::try_from(rs.len()).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    // Adds runs for the half-open interval [start, end), with a depth of 0 in masked positions.
+    //
+    // Runs are clamped to the reference sequence length, i.e., positions of records that extend
+    // past the end of the reference sequence are not emitted.
+    fn push_run(&mut self, start: usize, end: usize, depth: i64) {
+        let end = end.min(self.reference_sequence_len() + 1);
+
+        if start >= end {
+            return;
+        }
+
+        for (piece_start, piece_end, is_masked) in
+            self.mask.split(self.reference_sequence_id, start, end)
+        {
+            let piece_depth = if is_masked { 0 } else { depth };
+            self.push_unmasked_run(piece_start, piece_end, piece_depth);
+        }
+    }
+
+    // Adds a run for the half-open interval [start, end), merging it with the previous run if they
+    // are adjacent and have the same depth.
+    fn push_unmasked_run(&mut self, start: usize, end: usize, depth: i64) {
+        if start >= end || (depth == 0 && !self.include_zero_coverage) {
+            return;
+        }
+
+        let end = end - 1;
+
+        if let Some(run) = self.run.as_mut() {
+            if run.1 + 1 == start && run.2 == depth {
+                run.1 = end;
+                return;
+            }
+        }
+
+        self.flush();
+        self.run = Some((start, end, depth));
+    }
+
+    fn flush(&mut self) {
+        if let Some((start, end, depth)) = self.run.take() {
+            let name = self
+                .reference_sequences
+                .get_index(self.reference_sequence_id)
+                .map(|(name, _)| name.clone())
+                .unwrap_or_default();
+
+            // SAFETY: Positions are > 0.
+            self.queue.push_back(BedGraphRecord {
+                reference_sequence_name: name,
+                start: Position::new(start).unwrap(),
+                end: Position::new(end).unwrap(),
+                depth: depth as u64,
+            });
+        }
+    }
+}
+
+impl<'h, I, R> Iterator for CoverageBedGraph<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<BedGraphRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.queue.pop_front() {
+                return Some(Ok(record));
+            }
+
+            if self.is_done {
+                return None;
+            }
+
+            match self.records.next() {
+                Some(Ok(record)) => {
+                    if let Err(e) = self.add_record(&record) {
+                        self.queue.clear();
+                        self.is_done = true;
+                        return Some(Err(e));
+                    }
+                }
+                Some(Err(e)) => {
+                    self.queue.clear();
+                    self.is_done = true;
+                    return Some(Err(e));
+                }
+                None => {
+                    self.finish(self.reference_sequences.len());
+                    self.is_done = true;
+                }
+            }
+        }
+    }
+}
+
+/// Returns an iterator over the coverage of coordinate-sorted alignment records as bedGraph
+/// records.
+///
+/// The records can be of any [`AlignmentRecord`] type, e.g., SAM, BAM, or CRAM records. Their
+/// reference sequences are resolved using the given reference sequences.
+///
+/// The depth of a reference position is the number of mapped records with an alignment match
+/// (`M`), sequence match (`=`), sequence mismatch (`X`), or deletion (`D`) over it. Reference skips
+/// (`N`) are not counted. Adjacent positions with the same depth are merged into a single run,
+/// like `bedtools genomecov -bg -split`. No records are filtered besides unmapped records, e.g.,
+/// secondary and duplicate records are counted.
+///
+/// If `include_zero_coverage` is set, runs of positions without coverage are also returned,
+/// including whole reference sequences without records (like `bedtools genomecov -bga -split`).
+///
+/// Positions in `mask_regions` (e.g., a blacklist read from an exclude BED) have a depth of 0.
+/// Mask regions on reference sequences not in the given reference sequences are ignored.
+///
+/// Runs are returned in the order of the given reference sequences, which is expected to be the
+/// order of the input. Records are read until the first unplaced record.
+///
+/// The iterator returns an [`io::ErrorKind::InvalidData`] error if the records are not
+/// coordinate-sorted, after which it stops.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+/// use noodles_sam::{self as sam, header::ReferenceSequence};
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+///     .build();
+///
+/// let records = vec![
+///     Ok(bam::Record::builder()
+///         .set_flags(sam::record::Flags::empty())
+///         .set_reference_sequence_id(0)
+///         .set_position(Position::try_from(2)?)
+///         .set_cigar("4M".parse()?)
+///         .build()),
+/// ];
+///
+/// let lines: Vec<_> = bam::coverage_bedgraph(records, header.reference_sequences(), true, &[])
+///     .map(|result| result.map(|record| record.to_string()))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(lines, ["sq0\t0\t1\t0", "sq0\t1\t5\t1", "sq0\t5\t8\t0"]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn coverage_bedgraph<'h, I, R>(
+    records: I,
+    reference_sequences: &'h sam::header::ReferenceSequences,
+    include_zero_coverage: bool,
+    mask_regions: &[Region],
+) -> CoverageBedGraph<'h, I::IntoIter>
+where
+    I: IntoIterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    CoverageBedGraph {
+        records: records.into_iter(),
+        reference_sequences,
+        include_zero_coverage,
+        mask: Mask::new(reference_sequences, mask_regions),
+        reference_sequence_id: 0,
+        last_alignment_start: None,
+        deltas: BTreeMap::new(),
+        position: 1,
+        depth: 0,
+        run: None,
+        queue: VecDeque::new(),
+        is_done: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sam::header::ReferenceSequence;
+
+    use super::*;
+    use crate::{test_helpers::build_record, Record};
+
+    fn collect<I>(
+        records: I,
+        reference_sequences: &sam::header::ReferenceSequences,
+        include_zero_coverage: bool,
+        mask_regions: &[Region],
+    ) -> io::Result<Vec<String>>
+    where
+        I: IntoIterator<Item = io::Result<Record>>,
+    {
+        coverage_bedgraph(
+            records,
+            reference_sequences,
+            include_zero_coverage,
+            mask_regions,
+        )
+        .map(|result| result.map(|record| record.to_string()))
+        .collect()
+    }
+
+    #[test]
+    fn test_coverage_bedgraph() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 20)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq2".parse()?, 13)?)
+            .build();
+
+        let records = vec![
+            build_record(0, 1, "4M")?,
+            build_record(0, 3, "2M1D2M")?,
+            build_record(0, 5, "2S2M2I2M")?,
+            build_record(0, 10, "2M4N2M")?,
+            build_record(2, 9, "4M")?,
+        ];
+
+        // sq0: 1-2: 1, 3-7: 2, 8: 1, 10-11: 1, 16-17: 1
+        // sq2: 9-12: 1
+        let actual = collect(
+            records.iter().cloned().map(Ok),
+            header.reference_sequences(),
+            false,
+            &[],
+        )?;
+        let expected = [
+            "sq0\t0\t2\t1",
+            "sq0\t2\t7\t2",
+            "sq0\t7\t8\t1",
+            "sq0\t9\t11\t1",
+            "sq0\t15\t17\t1",
+            "sq2\t8\t12\t1",
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = collect(
+            records.into_iter().map(Ok),
+            header.reference_sequences(),
+            true,
+            &[],
+        )?;
+        let expected = [
+            "sq0\t0\t2\t1",
+            "sq0\t2\t7\t2",
+            "sq0\t7\t8\t1",
+            "sq0\t8\t9\t0",
+            "sq0\t9\t11\t1",
+            "sq0\t11\t15\t0",
+            "sq0\t15\t17\t1",
+            "sq0\t17\t20\t0",
+            "sq1\t0\t8\t0",
+            "sq2\t0\t8\t0",
+            "sq2\t8\t12\t1",
+            "sq2\t12\t13\t0",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coverage_bedgraph_with_mask_regions() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 12)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 8)?)
+            .build();
+
+        let records = vec![
+            build_record(0, 1, "8M")?,
+            build_record(0, 3, "4M")?,
+            build_record(1, 1, "8M")?,
+        ];
+
+        let mask_regions = [
+            Region::new("sq0", Position::try_from(4)?..=Position::try_from(9)?),
+            Region::new("sq2", ..),
+        ];
+
+        // sq0: 1-2: 1, 3: 2, 4-9: masked, 10-12: 0
+        // sq1: 1-8: 1
+        let actual = collect(
+            records.iter().cloned().map(Ok),
+            header.reference_sequences(),
+            false,
+            &mask_regions,
+        )?;
+        let expected = ["sq0\t0\t2\t1", "sq0\t2\t3\t2", "sq1\t0\t8\t1"];
+        assert_eq!(actual, expected);
+
+        let actual = collect(
+            records.into_iter().map(Ok),
+            header.reference_sequences(),
+            true,
+            &mask_regions,
+        )?;
+        let expected = [
+            "sq0\t0\t2\t1",
+            "sq0\t2\t3\t2",
+            "sq0\t3\t12\t0",
+            "sq1\t0\t8\t1",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coverage_bedgraph_with_records_past_reference_sequence_end(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 8)?)
+            .build();
+
+        let records = vec![build_record(0, 5, "8M")?, build_record(1, 7, "4M")?];
+
+        let actual = collect(
+            records.into_iter().map(Ok),
+            header.reference_sequences(),
+            true,
+            &[],
+        )?;
+        let expected = [
+            "sq0\t0\t4\t0",
+            "sq0\t4\t8\t1",
+            "sq1\t0\t6\t0",
+            "sq1\t6\t8\t1",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coverage_bedgraph_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 20)?)
+            .build();
+
+        let records = vec![Ok(build_record(0, 8, "4M")?), Ok(build_record(0, 1, "4M")?)];
+
+        let mut iter = coverage_bedgraph(records, header.reference_sequences(), false, &[]);
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use std::ops::Bound;
+
+use noodles_core::Region;
+use noodles_sam::header::ReferenceSequences;
+
+/// Intervals excluded from coverage.
+///
+/// The intervals of each reference sequence are 1-based, closed, sorted, and merged.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Mask(Vec<Vec<(usize, usize)>>);
+
+impl Mask {
+    /// Builds a mask from a list of regions.
+    ///
+    /// Regions with reference sequence names not in the given reference sequences are ignored.
+    pub(crate) fn new(reference_sequences: &ReferenceSequences, regions: &[Region]) -> Self {
+        let mut intervals = vec![Vec::new(); reference_sequences.len()];
+
+        for region in regions {
+            let i = match reference_sequences.get_index_of(region.name()) {
+                Some(i) => i,
+                None => continue,
+            };
+
+            let start = match region.start() {
+                Bound::Included(position) => usize::from(position),
+                Bound::Excluded(position) => usize::from(position) + 1,
+                Bound::Unbounded => 1,
+            };
+
+            let end = match region.end() {
+                Bound::Included(position) => usize::from(position),
+                Bound::Excluded(position) => usize::from(position) - 1,
+                Bound::Unbounded => usize::MAX,
+            };
+
+            if start <= end {
+                intervals[i].push((start, end));
+            }
+        }
+
+        for list in &mut intervals {
+            list.sort_unstable();
+            *list = merge(list);
+        }
+
+        Self(intervals)
+    }
+
+    /// Splits the half-open interval [start, end) into pieces that are either entirely masked or
+    /// entirely unmasked.
+    ///
+    /// Each piece is a half-open interval with whether it is masked.
+    pub(crate) fn split(
+        &self,
+        reference_sequence_id: usize,
+        start: usize,
+        end: usize,
+    ) -> Vec<(usize, usize, bool)> {
+        let intervals = self
+            .0
+            .get(reference_sequence_id)
+            .map(|list| list.as_slice())
+            .unwrap_or_default();
+
+        let i = intervals.partition_point(|&(_, mask_end)| mask_end < start);
+
+        let mut pieces = Vec::new();
+        let mut position = start;
+
+        for &(mask_start, mask_end) in &intervals[i..] {
+            if mask_start >= end {
+                break;
+            }
+
+            if position < mask_start {
+                pieces.push((position, mask_start, false));
+            }
+
+            let masked_end = mask_end.saturating_add(1).min(end);
+            pieces.push((position.max(mask_start), masked_end, true));
+            position = masked_end;
+        }
+
+        if position < end {
+            pieces.push((position, end, false));
+        }
+
+        pieces
+    }
+
+    /// Sets the depths of masked positions to 0.
+    ///
+    /// The depths are of consecutive positions starting at the given (1-based) position.
+    pub(crate) fn apply(&self, reference_sequence_id: usize, start: usize, depths: &mut [u64]) {
+        let end = start + depths.len();
+
+        for (piece_start, piece_end, is_masked) in self.split(reference_sequence_id, start, end) {
+            if is_masked {
+                depths[piece_start - start..piece_end - start].fill(0);
+            }
+        }
+    }
+}
+
+// Merges overlapping and adjacent sorted intervals.
+fn merge(intervals: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(intervals.len());
+
+    for &(start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::{self as sam, header::ReferenceSequence};
+
+    use super::*;
+
+    #[test]
+    fn test_split() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 20)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 8)?)
+            .build();
+
+        let regions = [
+            Region::new("sq0", Position::try_from(8)?..=Position::try_from(9)?),
+            Region::new("sq0", Position::try_from(3)?..=Position::try_from(4)?),
+            Region::new("sq0", Position::try_from(5)?..=Position::try_from(5)?),
+            Region::new("sq1", ..),
+            Region::new("sq2", ..),
+        ];
+
+        let mask = Mask::new(header.reference_sequences(), &regions);
+
+        assert_eq!(
+            mask.split(0, 1, 11),
+            [
+                (1, 3, false),
+                (3, 6, true),
+                (6, 8, false),
+                (8, 10, true),
+                (10, 11, false)
+            ]
+        );
+        assert_eq!(
+            mask.split(0, 4, 9),
+            [(4, 6, true), (6, 8, false), (8, 9, true)]
+        );
+        assert_eq!(mask.split(0, 11, 21), [(11, 21, false)]);
+        assert_eq!(mask.split(1, 1, 9), [(1, 9, true)]);
+        assert_eq!(mask.split(2, 1, 9), [(1, 9, false)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 20)?)
+            .build();
+
+        let regions = [Region::new(
+            "sq0",
+            Position::try_from(3)?..=Position::try_from(5)?,
+        )];
+
+        let mask = Mask::new(header.reference_sequences(), &regions);
+
+        let mut depths = [1, 2, 3, 4, 5, 6];
+        mask.apply(0, 2, &mut depths);
+        assert_eq!(depths, [1, 0, 0, 0, 5, 6]);
+
+        Ok(())
+    }
+}
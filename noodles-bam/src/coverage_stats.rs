@@ -0,0 +1,337 @@
+//! BAM region coverage statistics.
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Seek},
+    ops::Bound,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam::{self as sam, header::ReferenceSequences, AlignmentRecord};
+
+use super::{coverage_bedgraph, reader::resolve_region, Reader};
+
+/// Depth of coverage statistics over a region.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CoverageStats {
+    // Number of bases keyed by depth.
+    histogram: BTreeMap<u64, u64>,
+}
+
+impl CoverageStats {
+    /// Returns the number of bases in the region.
+    pub fn base_count(&self) -> u64 {
+        self.histogram.values().sum()
+    }
+
+    /// Returns the number of bases keyed by depth.
+    pub fn depth_histogram(&self) -> &BTreeMap<u64, u64> {
+        &self.histogram
+    }
+
+    /// Returns the mean depth.
+    ///
+    /// This is 0 if the region is empty.
+    pub fn mean_depth(&self) -> f64 {
+        let base_count = self.base_count();
+
+        if base_count == 0 {
+            return 0.0;
+        }
+
+        let depth_sum: u64 = self
+            .histogram
+            .iter()
+            .map(|(depth, count)| depth * count)
+            .sum();
+
+        depth_sum as f64 / base_count as f64
+    }
+
+    /// Returns the median depth.
+    ///
+    /// For an even number of bases, this is the lower of the two middle depths. This is 0 if the
+    /// region is empty.
+    pub fn median_depth(&self) -> u64 {
+        let base_count = self.base_count();
+
+        if base_count == 0 {
+            return 0;
+        }
+
+        let i = (base_count - 1) / 2;
+        let mut n = 0;
+
+        for (&depth, &count) in &self.histogram {
+            n += count;
+
+            if n > i {
+                return depth;
+            }
+        }
+
+        unreachable!();
+    }
+
+    /// Returns the fraction of bases with at least the given depth.
+    ///
+    /// This is 0 if the region is empty.
+    pub fn fraction_at_least(&self, min_depth: u64) -> f64 {
+        let base_count = self.base_count();
+
+        if base_count == 0 {
+            return 0.0;
+        }
+
+        let n: u64 = self
+            .histogram
+            .range(min_depth..)
+            .map(|(_, count)| count)
+            .sum();
+        n as f64 / base_count as f64
+    }
+
+    fn add(&mut self, depth: u64, len: u64) {
+        if len > 0 {
+            *self.histogram.entry(depth).or_default() += len;
+        }
+    }
+}
+
+/// Calculates depth of coverage statistics over a region.
+///
+/// The records overlapping the region are queried using the given index. Depth is calculated the
+/// same as [`coverage_bedgraph`], except unmapped, secondary, QC fail, and duplicate records are
+/// excluded (i.e., the default `mosdepth` and `samtools depth` flag filter). Overlapping mates are
+/// each counted.
+///
+/// Deletions are counted as covered, like `mosdepth` and `samtools depth -J`. This differs from
+/// the default of `samtools depth`, which does not count deletions.
+///
+/// Positions in `mask_regions` (e.g., a blacklist read from an exclude BED) have a depth of 0 but
+/// are still counted as bases in the region.
+///
+/// The region is clamped to the length of its reference sequence.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::fs::File;
+/// use noodles_bam::{self as bam, bai};
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let header: sam::Header = reader.read_header()?.parse()?;
+/// reader.read_reference_sequences()?;
+///
+/// let reference_sequences = header.reference_sequences();
+/// let index = bai::read("sample.bam.bai")?;
+/// let region = "sq0:8-13".parse()?;
+/// let stats = bam::region_coverage_stats(&mut reader, reference_sequences, &index, &region, &[])?;
+///
+/// println!("mean depth: {}", stats.mean_depth());
+/// println!(">= 30x: {}", stats.fraction_at_least(30));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn region_coverage_stats<R, I, RS>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    reference_sequences: &ReferenceSequences,
+    index: &I,
+    region: &Region,
+    mask_regions: &[Region],
+) -> io::Result<CoverageStats>
+where
+    R: Read + Seek,
+    I: BinningIndex<RS>,
+    RS: ReferenceSequenceExt,
+{
+    let (_, start, end) = resolve_region_interval(reference_sequences, region)?;
+
+    let mut stats = CoverageStats::default();
+
+    if start > end {
+        return Ok(stats);
+    }
+
+    let records = reader
+        .query(reference_sequences, index, region)?
+        .filter(|result| {
+            result
+                .as_ref()
+                .map(|record| is_counted(record.flags()))
+                .unwrap_or(true)
+        });
+
+    for result in coverage_bedgraph(records, reference_sequences, true, mask_regions) {
+        let run = result?;
+
+        if run.reference_sequence_name() != region.name() {
+            continue;
+        }
+
+        let run_start = usize::from(run.start()).max(start);
+        let run_end = usize::from(run.end()).min(end);
+
+        if run_start > end {
+            break;
+        } else if run_start <= run_end {
+            stats.add(run.depth(), (run_end - run_start + 1) as u64);
+        }
+    }
+
+    Ok(stats)
+}
+
+// Returns whether a record with the given flags is counted in the depth of coverage.
+//
+// Unmapped, secondary, QC fail, and duplicate records are excluded.
+pub(crate) fn is_counted(flags: sam::record::Flags) -> bool {
+    use sam::record::Flags;
+
+    !flags.intersects(Flags::UNMAPPED | Flags::SECONDARY | Flags::QC_FAIL | Flags::DUPLICATE)
+}
+
+// Returns the depths from depth changes, excluding the last change.
+pub(crate) fn accumulate(deltas: &[i64]) -> Vec<u64> {
+    let mut depth = 0;
+
+    deltas[..deltas.len() - 1]
+        .iter()
+        .map(|delta| {
+            depth += delta;
+            depth as u64
+        })
+        .collect()
+}
+
+// Returns the reference sequence ID of the region and its 1-based, closed interval, clamped to the
+// length of the reference sequence.
+pub(crate) fn resolve_region_interval(
+    reference_sequences: &ReferenceSequences,
+    region: &Region,
+) -> io::Result<(usize, usize, usize)> {
+    let reference_sequence_id = resolve_region(reference_sequences, region)?;
+
+    // SAFETY: `reference_sequence_id` was resolved from `reference_sequences`.
+    let (_, reference_sequence) = reference_sequences
+        .get_index(reference_sequence_id)
+        .unwrap();
+
+    let len = usize::try_from(reference_sequence.len()).unwrap_or_default();
+    let (start, end) = resolve_interval(region, len);
+
+    Ok((reference_sequence_id, start, end))
+}
+
+// Returns the 1-based, closed interval of the region, clamped to [1, len].
+fn resolve_interval(region: &Region, len: usize) -> (usize, usize) {
+    let start = match region.start() {
+        Bound::Included(position) => usize::from(position),
+        Bound::Excluded(position) => usize::from(position) + 1,
+        Bound::Unbounded => 1,
+    };
+
+    let end = match region.end() {
+        Bound::Included(position) => usize::from(position).min(len),
+        Bound::Excluded(position) => (usize::from(position) - 1).min(len),
+        Bound::Unbounded => len,
+    };
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::test_helpers::build_bam_and_index;
+
+    #[test]
+    fn test_region_coverage_stats() -> Result<(), Box<dyn std::error::Error>> {
+        let sam_data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:10
+@SQ\tSN:sq1\tLN:8
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t1024\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r3\t0\tsq0\t5\t60\t1M2D1M\t*\t0\t0\tAC\tND
+r4\t0\tsq1\t1\t60\t8M\t*\t0\t0\tACGTACGT\tNDLSNDLS
+";
+
+        let (data, index) = build_bam_and_index(sam_data)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header: sam::Header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+        let reference_sequences = header.reference_sequences();
+
+        // sq0 depths: 1 1 2 2 2 2 1 1 0 0
+        let region = Region::new("sq0", ..);
+        let stats = region_coverage_stats(&mut reader, reference_sequences, &index, &region, &[])?;
+
+        assert_eq!(stats.base_count(), 10);
+        assert_eq!(
+            stats.depth_histogram(),
+            &[(0, 2), (1, 4), (2, 4)].into_iter().collect()
+        );
+        assert_eq!(stats.mean_depth(), 1.2);
+        assert_eq!(stats.median_depth(), 1);
+        assert_eq!(stats.fraction_at_least(1), 0.8);
+        assert_eq!(stats.fraction_at_least(2), 0.4);
+        assert_eq!(stats.fraction_at_least(3), 0.0);
+
+        let region = Region::new("sq0", Position::try_from(3)?..=Position::try_from(6)?);
+        let stats = region_coverage_stats(&mut reader, reference_sequences, &index, &region, &[])?;
+        assert_eq!(stats.base_count(), 4);
+        assert_eq!(stats.mean_depth(), 2.0);
+
+        let mask_regions = [Region::new(
+            "sq0",
+            Position::try_from(5)?..=Position::try_from(8)?,
+        )];
+        let stats = region_coverage_stats(
+            &mut reader,
+            reference_sequences,
+            &index,
+            &region,
+            &mask_regions,
+        )?;
+        assert_eq!(stats.base_count(), 4);
+        assert_eq!(
+            stats.depth_histogram(),
+            &[(0, 2), (2, 2)].into_iter().collect()
+        );
+
+        let region = Region::new("sq1", Position::try_from(5)?..=Position::try_from(13)?);
+        let stats = region_coverage_stats(&mut reader, reference_sequences, &index, &region, &[])?;
+        assert_eq!(stats.base_count(), 4);
+        assert_eq!(stats.fraction_at_least(1), 1.0);
+
+        let region = Region::new("sq2", ..);
+        assert!(matches!(
+            region_coverage_stats(&mut reader, reference_sequences, &index, &region, &[]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_median_depth() {
+        let mut stats = CoverageStats::default();
+        assert_eq!(stats.median_depth(), 0);
+
+        stats.add(3, 1);
+        stats.add(1, 2);
+        stats.add(8, 1);
+        assert_eq!(stats.median_depth(), 1);
+
+        stats.add(5, 1);
+        assert_eq!(stats.median_depth(), 3);
+    }
+}
@@ -0,0 +1,241 @@
+//! BAM record filtering.
+
+use std::{
+    collections::HashSet,
+    io::{self, Read},
+    mem, str,
+};
+
+use super::{
+    reader::record::{decode_record, read_block},
+    Reader, Record,
+};
+
+// The offset of `l_read_name` in a record, i.e., after the reference sequence ID (`i32`) and
+// position (`i32`).
+const L_READ_NAME_OFFSET: usize = mem::size_of::<i32>() + mem::size_of::<i32>();
+
+// The offset of `read_name` in a record, i.e., the size of the fixed-length fields.
+const READ_NAME_OFFSET: usize = 32;
+
+/// An iterator over records filtered by read name.
+///
+/// This is created by calling [`filter_by_names`].
+pub struct FilterByNames<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+    names: HashSet<String>,
+    keep: bool,
+    buf: Vec<u8>,
+}
+
+impl<'a, R> FilterByNames<'a, R>
+where
+    R: Read,
+{
+    fn is_match(&self) -> io::Result<bool> {
+        let is_match = get_read_name(&self.buf)?
+            .and_then(|buf| str::from_utf8(buf).ok())
+            .map(|name| self.names.contains(name))
+            .unwrap_or(false);
+
+        Ok(is_match)
+    }
+}
+
+impl<'a, R> Iterator for FilterByNames<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match read_block(self.reader.get_mut(), &mut self.buf) {
+                Ok(Some(_)) => {}
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+
+            match self.is_match() {
+                Ok(is_match) if is_match == self.keep => {
+                    let mut record = Record::default();
+
+                    return match decode_record(&self.buf[..], &mut record) {
+                        Ok(()) => Some(Ok(record)),
+                        Err(e) => Some(Err(e)),
+                    };
+                }
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Filters records by read name.
+///
+/// If `keep` is true, only records with a read name in `names` are returned; otherwise, only
+/// records with a read name not in `names` are returned. Records with a missing read name are
+/// never in `names`.
+///
+/// Only the read name of each record is read to test for a match. Records that are filtered out
+/// are not fully decoded.
+///
+/// The stream is expected to be directly after the reference sequences or at the start of
+/// another record.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{collections::HashSet, fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// reader.read_header()?;
+/// reader.read_reference_sequences()?;
+///
+/// let names: HashSet<_> = ["r0", "r1"].iter().map(|name| name.to_string()).collect();
+///
+/// for result in bam::filter_by_names(&mut reader, names, true) {
+///     let record = result?;
+///     println!("{:?}", record);
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn filter_by_names<R>(
+    reader: &mut Reader<R>,
+    names: HashSet<String>,
+    keep: bool,
+) -> FilterByNames<'_, R>
+where
+    R: Read,
+{
+    FilterByNames {
+        reader,
+        names,
+        keep,
+        buf: Vec::new(),
+    }
+}
+
+// Returns the raw read name of an encoded record, without the NUL terminator.
+//
+// This returns `None` if the read name is missing.
+fn get_read_name(src: &[u8]) -> io::Result<Option<&[u8]>> {
+    const NUL: u8 = 0x00;
+    const MISSING: &[u8] = b"*";
+
+    let l_read_name = src
+        .get(L_READ_NAME_OFFSET)
+        .map(|&n| usize::from(n))
+        .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+    if l_read_name == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid l_read_name",
+        ));
+    }
+
+    let buf = src
+        .get(READ_NAME_OFFSET..READ_NAME_OFFSET + l_read_name)
+        .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+    match buf.split_last() {
+        Some((&NUL, name)) if name.is_empty() || name == MISSING => Ok(None),
+        Some((&NUL, name)) => Ok(Some(name)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid read name terminator",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::AlignmentRecord;
+
+    use super::*;
+    use crate::test_helpers::build_bam;
+
+    fn read_names(
+        data: &[u8],
+        names: &[&str],
+        keep: bool,
+    ) -> Result<Vec<Option<String>>, Box<dyn std::error::Error>> {
+        let mut reader = Reader::new(data);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let names = names.iter().map(|name| name.to_string()).collect();
+
+        let names = filter_by_names(&mut reader, names, keep)
+            .map(|result| result.map(|record| record.read_name().map(|name| name.to_string())))
+            .collect::<io::Result<_>>()?;
+
+        Ok(names)
+    }
+
+    #[test]
+    fn test_filter_by_names() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_bam(
+            b"@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:8
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t0\tsq0\t5\t60\t4M\t*\t0\t0\tACGT\tNDLS
+*\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+",
+        )?;
+
+        assert_eq!(
+            read_names(&data, &["r0", "r2", "r3"], true)?,
+            [Some(String::from("r0")), Some(String::from("r2"))]
+        );
+
+        assert_eq!(
+            read_names(&data, &["r0", "r2", "r3"], false)?,
+            [Some(String::from("r1")), None]
+        );
+
+        assert!(read_names(&data, &["*"], true)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_read_name() -> io::Result<()> {
+        fn build_record(read_name: &[u8]) -> Vec<u8> {
+            let mut buf = vec![0; READ_NAME_OFFSET];
+            buf[L_READ_NAME_OFFSET] = read_name.len() as u8;
+            buf.extend_from_slice(read_name);
+            buf
+        }
+
+        assert_eq!(get_read_name(&build_record(b"r0\x00"))?, Some(&b"r0"[..]));
+        assert_eq!(get_read_name(&build_record(b"*\x00"))?, None);
+        assert_eq!(get_read_name(&build_record(b"\x00"))?, None);
+
+        assert!(matches!(
+            get_read_name(&build_record(b"r0")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            get_read_name(&build_record(b"")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut buf = build_record(b"r0\x00");
+        buf.truncate(READ_NAME_OFFSET + 1);
+        assert!(matches!(
+            get_read_name(&buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+//! BAM fragment coverage.
+
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::{Position, Region};
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam::{header::ReferenceSequences, AlignmentRecord};
+
+use super::{
+    coverage::Mask,
+    coverage_stats::{accumulate, is_counted, resolve_region_interval},
+    Reader, Record,
+};
+
+/// Calculates the fragment depth of coverage of each position in a region.
+///
+/// Unlike read coverage, each properly paired fragment covers its full span once, i.e., the
+/// positions between the outer coordinates of its mates, including the gap between them. The
+/// fragment span is given by the leftmost mate's alignment start and the template length (`TLEN`),
+/// the same as the fragment spans of `bedtools bamtobed -bedpe`. Only the mate with a positive
+/// template length is counted. A properly paired mate with a template length of 0 and records that
+/// are not properly paired (e.g., singletons) cover their own alignment span.
+///
+/// Unmapped, secondary, supplementary, QC fail, and duplicate records are excluded.
+///
+/// Records are queried using the given index starting `max_fragment_len` bases before the start of
+/// the region, so that fragments spanning the start of the region are counted.
+///
+/// Positions in `mask_regions` (e.g., a blacklist read from an exclude BED) have a depth of 0.
+///
+/// The region is clamped to the length of its reference sequence. The returned depths start at the
+/// start of the region.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::fs::File;
+/// use noodles_bam::{self as bam, bai};
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let header: sam::Header = reader.read_header()?.parse()?;
+/// reader.read_reference_sequences()?;
+///
+/// let reference_sequences = header.reference_sequences();
+/// let index = bai::read("sample.bam.bai")?;
+/// let region = "sq0:8-13".parse()?;
+/// let depths =
+///     bam::fragment_coverage(&mut reader, reference_sequences, &index, &region, 1000, &[])?;
+///
+/// for depth in depths {
+///     println!("{}", depth);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn fragment_coverage<R, I, RS>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    reference_sequences: &ReferenceSequences,
+    index: &I,
+    region: &Region,
+    max_fragment_len: usize,
+    mask_regions: &[Region],
+) -> io::Result<Vec<u64>>
+where
+    R: Read + Seek,
+    I: BinningIndex<RS>,
+    RS: ReferenceSequenceExt,
+{
+    let (reference_sequence_id, start, end) = resolve_region_interval(reference_sequences, region)?;
+
+    if start > end {
+        return Ok(Vec::new());
+    }
+
+    // SAFETY: `start` is > 0.
+    let query_start = Position::new(start.saturating_sub(max_fragment_len).max(1)).unwrap();
+    let query_end = Position::new(end).unwrap();
+    let query_region = Region::new(region.name(), query_start..=query_end);
+
+    // Depth changes keyed by offset from the region start.
+    let mut deltas = vec![0i64; end - start + 2];
+
+    for result in reader.query(reference_sequences, index, &query_region)? {
+        let record = result?;
+
+        let flags = record.flags();
+
+        if !is_counted(flags) || flags.is_supplementary() {
+            continue;
+        }
+
+        let (span_start, span_end) = match fragment_span(&record) {
+            Some(span) => span,
+            None => continue,
+        };
+
+        if span_end < start || span_start > end {
+            continue;
+        }
+
+        deltas[span_start.max(start) - start] += 1;
+        deltas[span_end.min(end) - start + 1] -= 1;
+    }
+
+    let mut depths = accumulate(&deltas);
+
+    let mask = Mask::new(reference_sequences, mask_regions);
+    mask.apply(reference_sequence_id, start, &mut depths);
+
+    Ok(depths)
+}
+
+// Returns the 1-based, closed interval covered by the record.
+fn fragment_span(record: &Record) -> Option<(usize, usize)> {
+    let flags = record.flags();
+    let alignment_start = record.alignment_start().map(usize::from)?;
+
+    let is_fragment = flags.is_properly_aligned()
+        && !flags.is_mate_unmapped()
+        && record.mate_reference_sequence_id() == record.reference_sequence_id();
+
+    let template_length = record.template_length();
+
+    if is_fragment && template_length != 0 {
+        if template_length < 0 {
+            // The fragment is counted by the leftmost mate.
+            return None;
+        }
+
+        let len = usize::try_from(template_length).ok()?;
+        Some((alignment_start, alignment_start + len - 1))
+    } else {
+        let span = record.alignment_span();
+
+        if span == 0 {
+            None
+        } else {
+            Some((alignment_start, alignment_start + span - 1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_sam as sam;
+
+    use super::*;
+    use crate::test_helpers::build_bam_and_index;
+
+    #[test]
+    fn test_fragment_coverage() -> Result<(), Box<dyn std::error::Error>> {
+        let sam_data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:20
+r0\t99\tsq0\t1\t60\t4M\t=\t9\t12\tACGT\tNDLS
+r1\t0\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t1123\tsq0\t3\t60\t4M\t=\t13\t14\tACGT\tNDLS
+r0\t147\tsq0\t9\t60\t4M\t=\t1\t-12\tACGT\tNDLS
+r3\t65\tsq0\t11\t60\t4M\t=\t17\t10\tACGT\tNDLS
+r3\t129\tsq0\t17\t60\t4M\t=\t11\t-10\tACGT\tNDLS
+";
+
+        let (data, index) = build_bam_and_index(sam_data)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header: sam::Header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+        let reference_sequences = header.reference_sequences();
+
+        // r0 covers 1-12 (fragment), r1 covers 3-6 (singleton), r2 is a duplicate, and r3 is not
+        // properly paired (11-14, 17-20).
+        let region = Region::new("sq0", ..);
+        let depths = fragment_coverage(&mut reader, reference_sequences, &index, &region, 0, &[])?;
+        assert_eq!(
+            depths,
+            [1, 1, 2, 2, 2, 2, 1, 1, 1, 1, 2, 2, 1, 1, 0, 0, 1, 1, 1, 1]
+        );
+
+        let mask_regions = [
+            Region::new("sq0", Position::try_from(5)?..=Position::try_from(9)?),
+            Region::new("sq0", Position::try_from(19)?..),
+        ];
+        let depths = fragment_coverage(
+            &mut reader,
+            reference_sequences,
+            &index,
+            &region,
+            0,
+            &mask_regions,
+        )?;
+        assert_eq!(
+            depths,
+            [1, 1, 2, 2, 0, 0, 0, 0, 0, 1, 2, 2, 1, 1, 0, 0, 1, 1, 0, 0]
+        );
+
+        // The gap between the mates of r0 is covered, but only if the lookback reaches r0.
+        let region = Region::new("sq0", Position::try_from(7)?..=Position::try_from(8)?);
+        let depths = fragment_coverage(&mut reader, reference_sequences, &index, &region, 0, &[])?;
+        assert_eq!(depths, [0, 0]);
+        let depths =
+            fragment_coverage(&mut reader, reference_sequences, &index, &region, 500, &[])?;
+        assert_eq!(depths, [1, 1]);
+
+        let region = Region::new("sq1", ..);
+        assert!(matches!(
+            fragment_coverage(&mut reader, reference_sequences, &index, &region, 0, &[]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+//! BAM GC bias.
+
+use std::{io, num::NonZeroUsize};
+
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use super::{coverage_bedgraph, Record};
+
+const MAX_GC_PERCENT: usize = 100;
+
+/// Coverage of windows with the same GC content.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GcBin {
+    gc_percent: u8,
+    window_count: u64,
+    base_count: u64,
+    depth_sum: u64,
+}
+
+impl GcBin {
+    /// Returns the GC content of the windows in this bin as a rounded percentage.
+    pub fn gc_percent(&self) -> u8 {
+        self.gc_percent
+    }
+
+    /// Returns the number of windows in this bin.
+    pub fn window_count(&self) -> u64 {
+        self.window_count
+    }
+
+    /// Returns the mean depth of the positions in the windows in this bin.
+    pub fn mean_coverage(&self) -> f64 {
+        self.depth_sum as f64 / self.base_count as f64
+    }
+}
+
+/// Calculates the mean coverage of windows binned by GC content.
+///
+/// Each reference sequence is split into consecutive, nonoverlapping windows of `window_size`
+/// bases. The GC content of a window is the percentage of `G` and `C` bases, rounded to the
+/// nearest integer. Windows that contain a base other than `A`, `C`, `G`, or `T` (e.g., `N`) and
+/// a trailing window shorter than `window_size` are skipped.
+///
+/// Depth is calculated the same as [`coverage_bedgraph`]. The records are expected to be
+/// coordinate-sorted, and all reference sequences must be in the given repository.
+///
+/// This returns only bins with at least one window, ordered by GC content.
+///
+/// # Examples
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+/// use noodles_fasta as fasta;
+/// use noodles_sam::{self as sam, header::ReferenceSequence};
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     fasta::record::Definition::new("sq0", None),
+///     fasta::record::Sequence::from(b"GGCCATAT".to_vec()),
+/// )]);
+///
+/// let records = vec![Ok(bam::Record::builder()
+///     .set_flags(sam::record::Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_position(Position::try_from(1)?)
+///     .set_cigar("4M".parse()?)
+///     .build())];
+///
+/// let window_size = NonZeroUsize::new(4).unwrap();
+/// let bins = bam::gc_bias(records, header.reference_sequences(), &repository, window_size)?;
+///
+/// assert_eq!(bins.len(), 2);
+/// assert_eq!((bins[0].gc_percent(), bins[0].mean_coverage()), (0, 0.0));
+/// assert_eq!((bins[1].gc_percent(), bins[1].mean_coverage()), (100, 1.0));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn gc_bias<I>(
+    records: I,
+    reference_sequences: &sam::header::ReferenceSequences,
+    reference_sequence_repository: &fasta::Repository,
+    window_size: NonZeroUsize,
+) -> io::Result<Vec<GcBin>>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let window_size = window_size.get();
+
+    let mut bins: Vec<_> = (0..=MAX_GC_PERCENT)
+        .map(|gc_percent| GcBin {
+            gc_percent: gc_percent as u8,
+            window_count: 0,
+            base_count: 0,
+            depth_sum: 0,
+        })
+        .collect();
+
+    let mut reference_sequence_name: Option<String> = None;
+    let mut window_depth_sums = Vec::new();
+
+    for result in coverage_bedgraph(records, reference_sequences, true, &[]) {
+        let run = result?;
+
+        if reference_sequence_name.as_deref() != Some(run.reference_sequence_name()) {
+            if let Some(name) = reference_sequence_name.take() {
+                add_windows(
+                    &mut bins,
+                    reference_sequence_repository,
+                    &name,
+                    window_size,
+                    &window_depth_sums,
+                )?;
+            }
+
+            reference_sequence_name = Some(run.reference_sequence_name().into());
+            window_depth_sums.clear();
+        }
+
+        // 0-based, half-open
+        let mut start = usize::from(run.start()) - 1;
+        let end = usize::from(run.end());
+
+        while start < end {
+            let i = start / window_size;
+            let window_end = ((i + 1) * window_size).min(end);
+
+            if window_depth_sums.len() <= i {
+                window_depth_sums.resize(i + 1, 0);
+            }
+
+            window_depth_sums[i] += (window_end - start) as u64 * run.depth();
+            start = window_end;
+        }
+    }
+
+    if let Some(name) = reference_sequence_name {
+        add_windows(
+            &mut bins,
+            reference_sequence_repository,
+            &name,
+            window_size,
+            &window_depth_sums,
+        )?;
+    }
+
+    Ok(bins
+        .into_iter()
+        .filter(|bin| bin.window_count > 0)
+        .collect())
+}
+
+fn add_windows(
+    bins: &mut [GcBin],
+    reference_sequence_repository: &fasta::Repository,
+    reference_sequence_name: &str,
+    window_size: usize,
+    window_depth_sums: &[u64],
+) -> io::Result<()> {
+    let sequence = reference_sequence_repository
+        .get(reference_sequence_name)
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {}", reference_sequence_name),
+            )
+        })?;
+
+    for (window, &depth_sum) in sequence
+        .as_ref()
+        .chunks_exact(window_size)
+        .zip(window_depth_sums)
+    {
+        if let Some(gc_percent) = gc_percent(window) {
+            let bin = &mut bins[usize::from(gc_percent)];
+            bin.window_count += 1;
+            bin.base_count += window.len() as u64;
+            bin.depth_sum += depth_sum;
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the rounded percentage of G and C bases.
+//
+// This returns `None` if the window is empty or has a base other than A, C, G, or T.
+fn gc_percent(window: &[u8]) -> Option<u8> {
+    if window.is_empty() {
+        return None;
+    }
+
+    let mut gc_count = 0;
+
+    for base in window {
+        match base.to_ascii_uppercase() {
+            b'G' | b'C' => gc_count += 1,
+            b'A' | b'T' => {}
+            _ => return None,
+        }
+    }
+
+    let gc_percent = (gc_count * MAX_GC_PERCENT) as f64 / window.len() as f64;
+    Some(gc_percent.round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use sam::header::ReferenceSequence;
+
+    use super::*;
+    use crate::test_helpers::build_record;
+
+    fn build_fasta_record(name: &str, sequence: &[u8]) -> fasta::Record {
+        fasta::Record::new(
+            fasta::record::Definition::new(name, None),
+            fasta::record::Sequence::from(sequence.to_vec()),
+        )
+    }
+
+    #[test]
+    fn test_gc_bias() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 14)?)
+            .build();
+
+        let repository = fasta::Repository::new(vec![
+            build_fasta_record("sq0", b"GGCCATAT"),
+            build_fasta_record("sq1", b"ACGTNNNNacgtAC"),
+        ]);
+
+        let records = vec![
+            Ok(build_record(0, 1, "4M")?),
+            Ok(build_record(0, 3, "4M")?),
+            Ok(build_record(1, 9, "2M")?),
+        ];
+
+        let window_size = NonZeroUsize::new(4).unwrap();
+        let bins = gc_bias(
+            records,
+            header.reference_sequences(),
+            &repository,
+            window_size,
+        )?;
+
+        let actual: Vec<_> = bins
+            .iter()
+            .map(|bin| (bin.gc_percent(), bin.window_count(), bin.mean_coverage()))
+            .collect();
+
+        // sq0: GGCC (depth 6), ATAT (depth 2)
+        // sq1: ACGT (depth 0), acgt (depth 2)
+        assert_eq!(actual, [(0, 1, 0.5), (50, 2, 0.25), (100, 1, 1.5)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gc_bias_with_missing_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build();
+
+        let records: Vec<io::Result<Record>> = Vec::new();
+        let repository = fasta::Repository::default();
+        let window_size = NonZeroUsize::new(4).unwrap();
+
+        assert!(matches!(
+            gc_bias(records, header.reference_sequences(), &repository, window_size),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gc_percent() {
+        assert_eq!(gc_percent(b"ACGT"), Some(50));
+        assert_eq!(gc_percent(b"gcGC"), Some(100));
+        assert_eq!(gc_percent(b"AAT"), Some(0));
+        assert_eq!(gc_percent(b"CAT"), Some(33));
+        assert_eq!(gc_percent(b"CGT"), Some(67));
+        assert_eq!(gc_percent(b"CAAAAAAA"), Some(13));
+        assert_eq!(gc_percent(b"ACNT"), None);
+        assert_eq!(gc_percent(b""), None);
+    }
+}
@@ -53,11 +53,56 @@
 mod r#async;
 
 pub mod bai;
+mod convert;
+pub mod count;
+pub mod coverage;
+pub mod coverage_stats;
+pub mod filter;
+pub mod fragment_coverage;
+pub mod gc_bias;
+pub mod mate_consistency;
+pub mod merge;
+pub mod mismatch_profile;
 pub mod reader;
 pub mod record;
+#[cfg(feature = "arrow")]
+pub mod record_batch;
+pub mod sanitize;
+pub mod sort;
+pub mod stats;
+pub mod stranded_coverage;
+pub mod streams;
+#[cfg(test)]
+mod test_helpers;
+mod validate;
 pub mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    convert::{
+        convert_from_sam, convert_from_sam_with_options, convert_to_sam, ConvertFromSamOptions,
+    },
+    count::{count_by_reference, count_records},
+    coverage::coverage_bedgraph,
+    coverage_stats::region_coverage_stats,
+    filter::filter_by_names,
+    fragment_coverage::fragment_coverage,
+    gc_bias::gc_bias,
+    mate_consistency::{validate_mate_consistency, MateError},
+    merge::merge,
+    mismatch_profile::mismatch_profile,
+    reader::Reader,
+    record::Record,
+    sanitize::sanitize,
+    sort::sort_by_name,
+    stats::flagstat,
+    stranded_coverage::stranded_coverage,
+    streams::stream_offsets,
+    validate::validate_index,
+    writer::{write, write_empty, Writer},
+};
+
+#[cfg(feature = "arrow")]
+pub use self::record_batch::to_record_batch;
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -0,0 +1,306 @@
+//! BAM record mate consistency.
+
+use std::{error, fmt};
+
+use noodles_core::Position;
+use noodles_sam::AlignmentRecord;
+
+use super::Record;
+
+/// A segment of a pair.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Segment {
+    /// The first record given, i.e., read 1.
+    Read1,
+    /// The second record given, i.e., read 2.
+    Read2,
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read1 => f.write_str("read 1"),
+            Self::Read2 => f.write_str("read 2"),
+        }
+    }
+}
+
+/// An error returned when the mate fields of a pair are inconsistent.
+///
+/// Each mate field error is reported for the segment whose mate field is inconsistent with its
+/// mate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MateError {
+    /// A record is not paired (0x01).
+    NotPaired(Segment),
+    /// The read names do not match.
+    ReadNameMismatch,
+    /// The mate reference sequence ID does not match the reference sequence ID of the mate.
+    MateReferenceSequenceIdMismatch {
+        /// The segment with the invalid field.
+        segment: Segment,
+        /// The reference sequence ID of the mate.
+        expected: Option<usize>,
+        /// The mate reference sequence ID.
+        actual: Option<usize>,
+    },
+    /// The mate position does not match the position of the mate.
+    MatePositionMismatch {
+        /// The segment with the invalid field.
+        segment: Segment,
+        /// The position of the mate.
+        expected: Option<Position>,
+        /// The mate position.
+        actual: Option<Position>,
+    },
+    /// The mate unmapped flag (0x08) does not match the unmapped flag (0x04) of the mate.
+    MateUnmappedFlagMismatch(Segment),
+    /// The mate reverse complemented flag (0x20) does not match the reverse complemented flag
+    /// (0x10) of the mate.
+    MateReverseComplementedFlagMismatch(Segment),
+    /// The template lengths are not the negation of each other.
+    TemplateLengthMismatch(i32, i32),
+}
+
+impl error::Error for MateError {}
+
+impl fmt::Display for MateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotPaired(segment) => write!(f, "{} is not paired", segment),
+            Self::ReadNameMismatch => f.write_str("read name mismatch"),
+            Self::MateReferenceSequenceIdMismatch {
+                segment,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} mate reference sequence ID mismatch: expected {:?}, got {:?}",
+                segment, expected, actual
+            ),
+            Self::MatePositionMismatch {
+                segment,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} mate position mismatch: expected {:?}, got {:?}",
+                segment, expected, actual
+            ),
+            Self::MateUnmappedFlagMismatch(segment) => {
+                write!(f, "{} mate unmapped flag mismatch", segment)
+            }
+            Self::MateReverseComplementedFlagMismatch(segment) => {
+                write!(f, "{} mate reverse complemented flag mismatch", segment)
+            }
+            Self::TemplateLengthMismatch(a, b) => {
+                write!(f, "template length mismatch: {} and {}", a, b)
+            }
+        }
+    }
+}
+
+/// Validates that the mate fields of a pair are consistent.
+///
+/// Both records must be paired and have the same read name (if set). The mate reference sequence
+/// ID, mate position, mate unmapped flag, and mate reverse complemented flag of each record must
+/// match the reference sequence ID, position, unmapped flag, and reverse complemented flag of the
+/// other, and the template lengths must be the negation of each other. These are the fields that
+/// `samtools fixmate` repairs.
+///
+/// # Errors
+///
+/// This returns the first inconsistency found.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::{self as bam, mate_consistency::{MateError, Segment}};
+/// use noodles_core::Position;
+/// use noodles_sam::record::Flags;
+///
+/// let read1 = bam::Record::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::MATE_REVERSE_COMPLEMENTED | Flags::FIRST_SEGMENT)
+///     .set_reference_sequence_id(0)
+///     .set_position(Position::try_from(8)?)
+///     .set_mate_reference_sequence_id(0)
+///     .set_mate_position(Position::try_from(13)?)
+///     .set_template_length(9)
+///     .build();
+///
+/// let read2 = bam::Record::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED | Flags::LAST_SEGMENT)
+///     .set_reference_sequence_id(0)
+///     .set_position(Position::try_from(13)?)
+///     .set_mate_reference_sequence_id(0)
+///     .set_mate_position(Position::try_from(8)?)
+///     .set_template_length(-9)
+///     .build();
+///
+/// assert!(bam::validate_mate_consistency(&read1, &read2).is_ok());
+///
+/// let mut read2 = read2;
+/// *read2.mate_position_mut() = Position::new(5);
+///
+/// assert_eq!(
+///     bam::validate_mate_consistency(&read1, &read2),
+///     Err(MateError::MatePositionMismatch {
+///         segment: Segment::Read2,
+///         expected: Position::new(8),
+///         actual: Position::new(5),
+///     })
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate_mate_consistency(read1: &Record, read2: &Record) -> Result<(), MateError> {
+    for (segment, record) in [(Segment::Read1, read1), (Segment::Read2, read2)] {
+        if !record.flags().is_segmented() {
+            return Err(MateError::NotPaired(segment));
+        }
+    }
+
+    if let (Some(a), Some(b)) = (read1.read_name(), read2.read_name()) {
+        if a != b {
+            return Err(MateError::ReadNameMismatch);
+        }
+    }
+
+    validate_mate_fields(Segment::Read1, read1, read2)?;
+    validate_mate_fields(Segment::Read2, read2, read1)?;
+
+    let (a, b) = (read1.template_length(), read2.template_length());
+
+    if i64::from(a) != -i64::from(b) {
+        return Err(MateError::TemplateLengthMismatch(a, b));
+    }
+
+    Ok(())
+}
+
+fn validate_mate_fields(segment: Segment, record: &Record, mate: &Record) -> Result<(), MateError> {
+    if record.mate_reference_sequence_id() != mate.reference_sequence_id() {
+        return Err(MateError::MateReferenceSequenceIdMismatch {
+            segment,
+            expected: mate.reference_sequence_id(),
+            actual: record.mate_reference_sequence_id(),
+        });
+    }
+
+    if record.mate_position() != mate.position() {
+        return Err(MateError::MatePositionMismatch {
+            segment,
+            expected: mate.position(),
+            actual: record.mate_position(),
+        });
+    }
+
+    let (flags, mate_flags) = (record.flags(), mate.flags());
+
+    if flags.is_mate_unmapped() != mate_flags.is_unmapped() {
+        return Err(MateError::MateUnmappedFlagMismatch(segment));
+    }
+
+    if flags.is_mate_reverse_complemented() != mate_flags.is_reverse_complemented() {
+        return Err(MateError::MateReverseComplementedFlagMismatch(segment));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::Flags;
+
+    use super::*;
+
+    fn build_pair() -> Result<(Record, Record), Box<dyn std::error::Error>> {
+        let read1 = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(Flags::SEGMENTED | Flags::MATE_REVERSE_COMPLEMENTED | Flags::FIRST_SEGMENT)
+            .set_reference_sequence_id(0)
+            .set_position(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_position(Position::try_from(13)?)
+            .set_template_length(9)
+            .build();
+
+        let read2 = Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED | Flags::LAST_SEGMENT)
+            .set_reference_sequence_id(0)
+            .set_position(Position::try_from(13)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_position(Position::try_from(8)?)
+            .set_template_length(-9)
+            .build();
+
+        Ok((read1, read2))
+    }
+
+    #[test]
+    fn test_validate_mate_consistency() -> Result<(), Box<dyn std::error::Error>> {
+        let (read1, read2) = build_pair()?;
+        assert_eq!(validate_mate_consistency(&read1, &read2), Ok(()));
+
+        let (mut read1, read2) = build_pair()?;
+        *read1.flags_mut() = Flags::empty();
+        assert_eq!(
+            validate_mate_consistency(&read1, &read2),
+            Err(MateError::NotPaired(Segment::Read1))
+        );
+
+        let (mut read1, read2) = build_pair()?;
+        *read1.read_name_mut() = Some("r1".parse()?);
+        assert_eq!(
+            validate_mate_consistency(&read1, &read2),
+            Err(MateError::ReadNameMismatch)
+        );
+
+        let (mut read1, read2) = build_pair()?;
+        *read1.mate_reference_sequence_id_mut() = Some(1);
+        assert_eq!(
+            validate_mate_consistency(&read1, &read2),
+            Err(MateError::MateReferenceSequenceIdMismatch {
+                segment: Segment::Read1,
+                expected: Some(0),
+                actual: Some(1),
+            })
+        );
+
+        let (read1, mut read2) = build_pair()?;
+        *read2.mate_position_mut() = None;
+        assert_eq!(
+            validate_mate_consistency(&read1, &read2),
+            Err(MateError::MatePositionMismatch {
+                segment: Segment::Read2,
+                expected: Position::new(8),
+                actual: None,
+            })
+        );
+
+        let (read1, mut read2) = build_pair()?;
+        read2.flags_mut().insert(Flags::UNMAPPED);
+        assert_eq!(
+            validate_mate_consistency(&read1, &read2),
+            Err(MateError::MateUnmappedFlagMismatch(Segment::Read1))
+        );
+
+        let (read1, mut read2) = build_pair()?;
+        read2.flags_mut().remove(Flags::REVERSE_COMPLEMENTED);
+        assert_eq!(
+            validate_mate_consistency(&read1, &read2),
+            Err(MateError::MateReverseComplementedFlagMismatch(
+                Segment::Read1
+            ))
+        );
+
+        let (read1, mut read2) = build_pair()?;
+        *read2.template_length_mut() = 9;
+        assert_eq!(
+            validate_mate_consistency(&read1, &read2),
+            Err(MateError::TemplateLengthMismatch(9, 9))
+        );
+
+        Ok(())
+    }
+}
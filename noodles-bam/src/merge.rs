@@ -0,0 +1,377 @@
+//! BAM coordinate-sorted record merging.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    io::{self, Read},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_sam::{self as sam, AlignmentRecord};
+
+use super::{Reader, Record};
+
+/// An iterator over merged coordinate-sorted records.
+///
+/// This is created by calling [`merge`].
+pub struct Merge<R> {
+    readers: Vec<Reader<bgzf::Reader<R>>>,
+    reference_sequence_id_maps: Vec<Vec<usize>>,
+    heap: BinaryHeap<Reverse<Entry>>,
+    pending_error: Option<io::Error>,
+    is_done: bool,
+}
+
+impl<R> Merge<R>
+where
+    R: Read,
+{
+    // Reads the next record of the given input and adds it to the heap.
+    fn push_next(&mut self, i: usize, prev_key: Option<Key>) -> io::Result<()> {
+        let mut record = Record::default();
+
+        if self.readers[i].read_record(&mut record)? == 0 {
+            return Ok(());
+        }
+
+        remap_reference_sequence_ids(&self.reference_sequence_id_maps[i], &mut record)?;
+
+        let key = Key::new(&record, i);
+
+        if let Some(prev_key) = prev_key {
+            if key.position_key() < prev_key.position_key() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("input {} is not coordinate-sorted", i),
+                ));
+            }
+        }
+
+        self.heap.push(Reverse(Entry { key, record }));
+
+        Ok(())
+    }
+}
+
+impl<R> Iterator for Merge<R>
+where
+    R: Read,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        // An error reading the next record of an input is returned after the record that preceded
+        // it, and it ends the iteration.
+        if let Some(e) = self.pending_error.take() {
+            self.is_done = true;
+            return Some(Err(e));
+        }
+
+        let Reverse(entry) = self.heap.pop()?;
+
+        if let Err(e) = self.push_next(entry.key.input_index, Some(entry.key)) {
+            self.pending_error = Some(e);
+        }
+
+        Some(Ok(entry.record))
+    }
+}
+
+// A merge sort key. This follows the order of `samtools merge`: records are ordered by reference
+// sequence ID, with unplaced records last; position; strand, forward before reverse; and, lastly,
+// input order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Key {
+    reference_sequence_id: Option<usize>,
+    position: Option<Position>,
+    is_reverse_complemented: bool,
+    input_index: usize,
+}
+
+impl Key {
+    fn new(record: &Record, input_index: usize) -> Self {
+        Self {
+            reference_sequence_id: record.reference_sequence_id(),
+            position: record.position(),
+            is_reverse_complemented: record.flags().is_reverse_complemented(),
+            input_index,
+        }
+    }
+
+    fn position_key(&self) -> (bool, Option<usize>, Option<Position>) {
+        (
+            self.reference_sequence_id.is_none(),
+            self.reference_sequence_id,
+            self.position,
+        )
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.position_key()
+            .cmp(&other.position_key())
+            .then_with(|| {
+                self.is_reverse_complemented
+                    .cmp(&other.is_reverse_complemented)
+            })
+            .then_with(|| self.input_index.cmp(&other.input_index))
+    }
+}
+
+struct Entry {
+    key: Key,
+    record: Record,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Merges coordinate-sorted BAM records from multiple readers.
+///
+/// The readers are expected to be at the start of their streams. The header and reference
+/// sequences of each input are read, and the reference sequence IDs of each record (including
+/// its mate) are remapped to the reference sequences of the given merged header by name.
+///
+/// Records are ordered by reference sequence ID, position, and strand, like `samtools merge`.
+/// Records that compare equal are returned in input order.
+///
+/// # Errors
+///
+/// This returns an error if a reference sequence of an input is not in the merged header. The
+/// iterator returns an error if a record fails to be read or if an input is not
+/// coordinate-sorted, after which it stops.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let readers = ["sample.1.bam", "sample.2.bam"]
+///     .iter()
+///     .map(|src| File::open(src).map(bam::Reader::new))
+///     .collect::<io::Result<_>>()?;
+///
+/// let header: sam::Header = File::open("sample.1.bam")
+///     .map(bam::Reader::new)?
+///     .read_header()?
+///     .parse()
+///     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+///
+/// for result in bam::merge(readers, &header)? {
+///     let record = result?;
+///     println!("{:?}", record);
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn merge<R>(
+    mut readers: Vec<Reader<bgzf::Reader<R>>>,
+    header: &sam::Header,
+) -> io::Result<Merge<R>>
+where
+    R: Read,
+{
+    let merged_reference_sequences = header.reference_sequences();
+    let mut reference_sequence_id_maps = Vec::with_capacity(readers.len());
+
+    for reader in &mut readers {
+        reader.read_header()?;
+        let reference_sequences = reader.read_reference_sequences()?;
+
+        let id_map = reference_sequences
+            .keys()
+            .map(|name| {
+                merged_reference_sequences
+                    .get_index_of(name)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("reference sequence not in merged header: {}", name),
+                        )
+                    })
+            })
+            .collect::<io::Result<_>>()?;
+
+        reference_sequence_id_maps.push(id_map);
+    }
+
+    let mut merge = Merge {
+        heap: BinaryHeap::with_capacity(readers.len()),
+        readers,
+        reference_sequence_id_maps,
+        pending_error: None,
+        is_done: false,
+    };
+
+    for i in 0..merge.readers.len() {
+        merge.push_next(i, None)?;
+    }
+
+    Ok(merge)
+}
+
+fn remap_reference_sequence_ids(id_map: &[usize], record: &mut Record) -> io::Result<()> {
+    fn remap(id_map: &[usize], id: &mut Option<usize>) -> io::Result<()> {
+        if let Some(id) = id {
+            match id_map.get(*id) {
+                Some(&merged_id) => *id = merged_id,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid reference sequence ID: {}", id),
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    remap(id_map, record.reference_sequence_id_mut())?;
+    remap(id_map, record.mate_reference_sequence_id_mut())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::build_bam;
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let inputs = [
+            build_bam(
+                b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+a0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+a1\t16\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+a2\t0\tsq1\t5\t60\t4M\tsq0\t1\t0\tACGT\tNDLS
+",
+            )?,
+            build_bam(
+                b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq1\tLN:13
+b0\t0\tsq1\t2\t60\t4M\t*\t0\t0\tACGT\tNDLS
+b1\t0\tsq1\t5\t60\t4M\t*\t0\t0\tACGT\tNDLS
+b2\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+",
+            )?,
+            build_bam(
+                b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+c0\t0\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+c1\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+",
+            )?,
+        ];
+
+        let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+"
+        .parse()?;
+
+        let readers = inputs.iter().map(|data| Reader::new(&data[..])).collect();
+        let records: Vec<_> = merge(readers, &header)?.collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| {
+                (
+                    record.read_name().map(|name| name.to_string()),
+                    record.reference_sequence_id(),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (Some(String::from("a0")), Some(0)),
+            (Some(String::from("c0")), Some(0)),
+            (Some(String::from("a1")), Some(0)),
+            (Some(String::from("b0")), Some(1)),
+            (Some(String::from("a2")), Some(1)),
+            (Some(String::from("b1")), Some(1)),
+            (Some(String::from("b2")), None),
+            (Some(String::from("c1")), None),
+        ];
+
+        assert_eq!(actual, expected);
+        assert_eq!(records[4].mate_reference_sequence_id(), Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_unsorted_input() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_bam(
+            b"@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:8
+r0\t0\tsq0\t5\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+",
+        )?;
+
+        let header: sam::Header = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n".parse()?;
+
+        let mut records = merge(vec![Reader::new(&data[..])], &header)?;
+
+        let record = records.next().transpose()?;
+        assert_eq!(
+            record.and_then(|record| record.position()),
+            Position::new(5)
+        );
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_missing_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_bam(b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n")?;
+        let header = sam::Header::default();
+
+        assert!(matches!(
+            merge(vec![Reader::new(&data[..])], &header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,401 @@
+//! BAM per-cycle mismatch profile.
+
+use std::io;
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    record::{cigar::op::Kind, sequence::Base},
+    AlignmentRecord,
+};
+
+use super::Record;
+
+const BASES: [u8; 4] = [b'A', b'C', b'G', b'T'];
+
+/// Match and mismatch counts of a read cycle.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CycleCounts {
+    match_count: u64,
+    mismatch_count: u64,
+}
+
+impl CycleCounts {
+    /// Returns the number of aligned bases that match the reference.
+    pub fn match_count(&self) -> u64 {
+        self.match_count
+    }
+
+    /// Returns the number of aligned bases that do not match the reference.
+    pub fn mismatch_count(&self) -> u64 {
+        self.mismatch_count
+    }
+
+    /// Returns the fraction of aligned bases that do not match the reference.
+    ///
+    /// This is NaN if there are no aligned bases.
+    pub fn mismatch_rate(&self) -> f64 {
+        self.mismatch_count as f64 / (self.match_count + self.mismatch_count) as f64
+    }
+}
+
+/// A per-read-cycle mismatch profile.
+///
+/// A read cycle is a 0-based position in a read in sequencing order, i.e., reverse complemented
+/// reads are counted from the end of the stored sequence.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MismatchProfile {
+    cycles: Vec<CycleCounts>,
+    // reference base (ACGT) x read base (ACGT)
+    substitution_counts: [[u64; 4]; 4],
+}
+
+impl MismatchProfile {
+    /// Returns the match and mismatch counts of each read cycle.
+    pub fn cycles(&self) -> &[CycleCounts] {
+        &self.cycles
+    }
+
+    /// Returns the mismatch rate of each read cycle.
+    pub fn mismatch_rates(&self) -> Vec<f64> {
+        self.cycles.iter().map(|c| c.mismatch_rate()).collect()
+    }
+
+    /// Returns the number of substitutions of a reference base with a read base.
+    ///
+    /// Bases are case-insensitive. Like read cycles, substitutions are in sequencing order, i.e.,
+    /// both bases of a reverse complemented read are complemented. For example, an `A>C`
+    /// substitution in a reverse complemented read is counted as `T>G`.
+    ///
+    /// This returns 0 for a base that is not `A`, `C`, `G`, or `T`.
+    pub fn substitution_count(&self, reference_base: u8, read_base: u8) -> u64 {
+        match (base_index(reference_base), base_index(read_base)) {
+            (Some(i), Some(j)) => self.substitution_counts[i][j],
+            _ => 0,
+        }
+    }
+
+    fn add(&mut self, reference_sequence: &[u8], record: &Record) -> io::Result<()> {
+        let read_len = record.sequence().len();
+        let is_reverse_complemented = record.flags().is_reverse_complemented();
+
+        // The caller checks that the record has a position.
+        let mut reference_position = record.position().map(usize::from).unwrap_or(1) - 1;
+        let mut read_position = 0;
+
+        if self.cycles.len() < read_len {
+            self.cycles.resize(read_len, CycleCounts::default());
+        }
+
+        for op in record.cigar().iter() {
+            let len = op.len();
+
+            match op.kind() {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                    let reference_bases = reference_sequence
+                        .get(reference_position..reference_position + len)
+                        .ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                "alignment is out of reference sequence bounds",
+                            )
+                        })?;
+
+                    let read_bases = record
+                        .sequence()
+                        .as_ref()
+                        .get(read_position..read_position + len)
+                        .ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                "CIGAR read length does not match sequence length",
+                            )
+                        })?;
+
+                    for (k, (&reference_base, &read_base)) in
+                        reference_bases.iter().zip(read_bases).enumerate()
+                    {
+                        let i = read_position + k;
+
+                        let reference_base = match Base::try_from(reference_base) {
+                            Ok(base) => base,
+                            Err(_) => continue,
+                        };
+
+                        let (cycle, reference_base, read_base) = if is_reverse_complemented {
+                            (
+                                read_len - 1 - i,
+                                reference_base.complement(),
+                                read_base.complement(),
+                            )
+                        } else {
+                            (i, reference_base, read_base)
+                        };
+
+                        let (reference_index, read_index) = match (
+                            base_index(u8::from(reference_base)),
+                            base_index(u8::from(read_base)),
+                        ) {
+                            (Some(a), Some(b)) => (a, b),
+                            _ => continue,
+                        };
+
+                        let counts = &mut self.cycles[cycle];
+
+                        if reference_index == read_index {
+                            counts.match_count += 1;
+                        } else {
+                            counts.mismatch_count += 1;
+                            self.substitution_counts[reference_index][read_index] += 1;
+                        }
+                    }
+
+                    reference_position += len;
+                    read_position += len;
+                }
+                Kind::Insertion | Kind::SoftClip => read_position += len,
+                Kind::Deletion | Kind::Skip => reference_position += len,
+                Kind::HardClip | Kind::Pad => {}
+                Kind::Back => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "unsupported CIGAR operation: B",
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Calculates the per-read-cycle mismatch profile of records against their reference sequences.
+///
+/// Aligned bases (`M`, `=`, and `X` CIGAR operations) are compared to the reference sequence.
+/// Bases are case-insensitive, and a pair of bases where either is not `A`, `C`, `G`, or `T` is
+/// skipped. Unmapped, secondary, and supplementary records and records without a sequence are
+/// skipped.
+///
+/// # Errors
+///
+/// This returns an error if a record fails to read, if a reference sequence is missing from the
+/// repository, if an alignment is out of the bounds of its reference sequence, or if a CIGAR has a
+/// back (`B`) operation.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+/// use noodles_fasta as fasta;
+/// use noodles_sam::{self as sam, header::ReferenceSequence};
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     fasta::record::Definition::new("sq0", None),
+///     fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+/// )]);
+///
+/// let records = vec![Ok(bam::Record::builder()
+///     .set_flags(sam::record::Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_position(Position::try_from(1)?)
+///     .set_cigar("4M".parse()?)
+///     .set_sequence("ACTT".parse()?)
+///     .build())];
+///
+/// let profile = bam::mismatch_profile(records, header.reference_sequences(), &repository)?;
+///
+/// assert_eq!(profile.mismatch_rates(), [0.0, 0.0, 1.0, 0.0]);
+/// assert_eq!(profile.substitution_count(b'G', b'T'), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn mismatch_profile<I>(
+    records: I,
+    reference_sequences: &sam::header::ReferenceSequences,
+    reference_sequence_repository: &fasta::Repository,
+) -> io::Result<MismatchProfile>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut profile = MismatchProfile::default();
+    let mut reference_sequence: Option<(usize, fasta::record::Sequence)> = None;
+
+    for result in records {
+        let record = result?;
+        let flags = record.flags();
+
+        if flags.is_unmapped()
+            || flags.is_secondary()
+            || flags.is_supplementary()
+            || record.sequence().is_empty()
+            || record.position().is_none()
+        {
+            continue;
+        }
+
+        let reference_sequence_id = match record.reference_sequence_id() {
+            Some(id) => id,
+            None => continue,
+        };
+
+        let is_cached = matches!(reference_sequence, Some((id, _)) if id == reference_sequence_id);
+
+        if !is_cached {
+            let name = reference_sequences
+                .get_index(reference_sequence_id)
+                .map(|(name, _)| name)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid reference sequence ID: {}", reference_sequence_id),
+                    )
+                })?;
+
+            let sequence = reference_sequence_repository
+                .get(name)
+                .transpose()?
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("missing reference sequence: {}", name),
+                    )
+                })?;
+
+            reference_sequence = Some((reference_sequence_id, sequence));
+        }
+
+        if let Some((_, sequence)) = &reference_sequence {
+            profile.add(sequence.as_ref(), &record)?;
+        }
+    }
+
+    Ok(profile)
+}
+
+fn base_index(base: u8) -> Option<usize> {
+    let base = base.to_ascii_uppercase();
+    BASES.iter().position(|&b| b == base)
+}
+
+#[cfg(test)]
+mod tests {
+    use sam::header::ReferenceSequence;
+
+    use super::*;
+    use crate::test_helpers;
+
+    fn build_record(
+        flags: sam::record::Flags,
+        position: usize,
+        cigar: &str,
+        sequence: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        let mut record = test_helpers::build_record(0, position, cigar)?;
+        *record.flags_mut() = flags;
+        *record.sequence_mut() = sequence.parse()?;
+        Ok(record)
+    }
+
+    #[test]
+    fn test_mismatch_profile() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::Flags;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 12)?)
+            .build();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTacgtNNAA".to_vec()),
+        )]);
+
+        let records = vec![
+            // ref ACGT, read AGGT: C>G at cycle 1
+            Ok(build_record(Flags::empty(), 1, "4M", "AGGT")?),
+            // soft clip and deletion: ref C|GTa (2S1M1D2M), read NNCTA
+            //   cycle 2: C=C, cycle 3: T=T, cycle 4: A=a
+            Ok(build_record(Flags::empty(), 2, "2S1M1D2M", "NNCTA")?),
+            // reverse complemented, ref gtNN, read GANN
+            //   cycle 3: g=G, cycle 2: t>A (complemented, A>T)
+            Ok(build_record(Flags::REVERSE_COMPLEMENTED, 7, "4M", "GANN")?),
+            // skipped
+            Ok(build_record(Flags::SECONDARY, 1, "4M", "TTTT")?),
+            Ok(Record::default()),
+        ];
+
+        let profile = mismatch_profile(records, header.reference_sequences(), &repository)?;
+
+        let cycles: Vec<_> = profile
+            .cycles()
+            .iter()
+            .map(|c| (c.match_count(), c.mismatch_count()))
+            .collect();
+
+        assert_eq!(cycles, [(1, 0), (0, 1), (2, 1), (3, 0), (1, 0)]);
+
+        assert_eq!(profile.substitution_count(b'C', b'G'), 1);
+        assert_eq!(profile.substitution_count(b'a', b't'), 1);
+        assert_eq!(profile.substitution_count(b'T', b'A'), 0);
+        assert_eq!(profile.substitution_count(b'N', b'A'), 0);
+
+        let rates = profile.mismatch_rates();
+        assert_eq!(rates[1], 1.0);
+        assert!((rates[2] - 1.0 / 3.0).abs() < f64::EPSILON);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mismatch_profile_with_missing_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build();
+
+        let repository = fasta::Repository::default();
+
+        let records = vec![Ok(build_record(
+            sam::record::Flags::empty(),
+            1,
+            "4M",
+            "ACGT",
+        )?)];
+
+        assert!(matches!(
+            mismatch_profile(records, header.reference_sequences(), &repository),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mismatch_profile_with_back_operation() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let records = vec![Ok(build_record(
+            sam::record::Flags::empty(),
+            1,
+            "2M1B2M",
+            "ACGT",
+        )?)];
+
+        assert!(matches!(
+            mismatch_profile(records, header.reference_sequences(), &repository),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -14,7 +14,7 @@ use std::{
 
 use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_bgzf as bgzf;
-use noodles_core::{region::Interval, Region};
+use noodles_core::{region::Interval, Position, Region};
 use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
 use noodles_fasta as fasta;
 use noodles_sam::{
@@ -22,8 +22,8 @@ use noodles_sam::{
     header::{ReferenceSequence, ReferenceSequences},
 };
 
-use self::record::read_record;
-use super::{bai, Record, MAGIC_NUMBER};
+use self::record::{read_block, read_record, read_record_diagnostic};
+use super::{Record, MAGIC_NUMBER};
 
 /// A BAM reader.
 ///
@@ -114,6 +114,12 @@ where
     /// This returns the raw SAM header as a [`String`]. It can subsequently be parsed as a
     /// [`noodles_sam::Header`].
     ///
+    /// # Errors
+    ///
+    /// The header text is read into a buffer of its stated length (`l_text`). To bound this
+    /// allocation, this returns an error if the length is greater than 1 GiB. This also returns
+    /// an error if the stream ends before the end of the header text.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -187,6 +193,42 @@ where
         read_record(&mut self.inner, &mut self.buf, record)
     }
 
+    /// Reads a single record, reporting which field fails to decode.
+    ///
+    /// This is a diagnostic alternative to [`Self::read_record`] for corrupt files. The given
+    /// record is reset, and its fields are decoded in order. If a field fails to decode, the
+    /// record is left with the fields decoded so far, and a [`record::DecodeError`] naming the
+    /// field is returned. A record block that is shorter than its block size, e.g., at the end of
+    /// a truncated stream, is still decoded as far as possible.
+    ///
+    /// The outer result is an error reading the stream. The inner result is either the record
+    /// block size, where 0 means the stream reached EOF, or the decode error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// reader.read_header()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// let mut record = bam::Record::default();
+    ///
+    /// if let Err(e) = reader.read_record_diagnostic(&mut record)? {
+    ///     eprintln!("invalid field {}: {}", e.field(), e);
+    ///     eprintln!("partial record: {:?}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_record_diagnostic(
+        &mut self,
+        record: &mut Record,
+    ) -> io::Result<Result<usize, record::DecodeError>> {
+        read_record_diagnostic(&mut self.inner, &mut self.buf, record)
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be directly after the reference sequences or at the start of
@@ -211,6 +253,46 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
+
+    /// Skips the given number of records.
+    ///
+    /// The stream is expected to be directly after the reference sequences or at the start of
+    /// another record.
+    ///
+    /// Skipped records are read but not decoded. Combined with [`Iterator::take`], this can be
+    /// used to read a range of records by ordinal, e.g., for sharding by record count.
+    ///
+    /// If successful, the number of records skipped is returned. This is less than `n` if the
+    /// stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// reader.read_header()?;
+    /// reader.read_reference_sequences()?;
+    ///
+    /// // Reads records [1000, 1100).
+    /// reader.skip_records(1000)?;
+    ///
+    /// for result in reader.records().take(100) {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn skip_records(&mut self, n: u64) -> io::Result<u64> {
+        for i in 0..n {
+            if read_block(&mut self.inner, &mut self.buf)?.is_none() {
+                return Ok(i);
+            }
+        }
+
+        Ok(n)
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -287,6 +369,11 @@ where
 
     /// Returns an iterator over records that intersect the given region.
     ///
+    /// Chunks of a region query often span many BGZF blocks. To decompress upcoming blocks on
+    /// worker threads while records are being read, wrap a BGZF reader built with
+    /// [`bgzf::reader::Builder::set_worker_count`] (see the second example). The same records are
+    /// returned either way.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -309,19 +396,43 @@ where
     /// }
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn query<I, RS>(
-        &mut self,
+    ///
+    /// Decompressing blocks on worker threads:
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.bam")
+    ///     .map(|file| bgzf::Reader::builder(file).set_worker_count(4).build())
+    ///     .map(bam::Reader::from)?;
+    /// let header: sam::Header = reader.read_header()?.parse()?;
+    ///
+    /// let reference_sequences = header.reference_sequences();
+    /// let index = bai::read("sample.bam.bai")?;
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query(reference_sequences, &index, &region)? {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query<'a, I, RS>(
+        &'a mut self,
         reference_sequences: &ReferenceSequences,
-        index: &I,
+        index: &'a I,
         region: &Region,
-    ) -> io::Result<Query<'_, R, Interval>>
+    ) -> io::Result<Query<'a, R, Interval>>
     where
         I: BinningIndex<RS>,
         RS: ReferenceSequenceExt,
     {
         let reference_sequence_id = resolve_region(reference_sequences, region)?;
 
-        let chunks = index.query(reference_sequence_id, region.interval())?;
+        let chunks = index.query_merged_chunks(reference_sequence_id, region.interval())?;
 
         Ok(Query::new(
             self,
@@ -331,6 +442,133 @@ where
         ))
     }
 
+    /// Returns an iterator over records that overlap the given position.
+    ///
+    /// This is a single-base query, i.e., the same as [`Self::query`] with the region
+    /// `<reference_sequence_name>:<position>-<position>`. Only records with an alignment span, as
+    /// calculated from the CIGAR, that includes the position are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    /// use noodles_core::Position;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// let header: sam::Header = reader.read_header()?.parse()?;
+    ///
+    /// let reference_sequences = header.reference_sequences();
+    /// let index = bai::read("sample.bam.bai")?;
+    /// let position = Position::try_from(8)?;
+    /// let query = reader.query_position(reference_sequences, &index, "sq0", position)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_position<'a, I, RS>(
+        &'a mut self,
+        reference_sequences: &ReferenceSequences,
+        index: &'a I,
+        reference_sequence_name: &str,
+        position: Position,
+    ) -> io::Result<Query<'a, R, Interval>>
+    where
+        I: BinningIndex<RS>,
+        RS: ReferenceSequenceExt,
+    {
+        let region = Region::new(reference_sequence_name, position..=position);
+        self.query(reference_sequences, index, &region)
+    }
+
+    /// Returns the mate of the given record.
+    ///
+    /// The mate is found by a single-base query at the mate reference sequence and mate position
+    /// of the record (see [`Self::query_position`]), so mates on other reference sequences are
+    /// also found. A mate has the same read name, points back to the position of the record, is
+    /// the other segment of the template, and is neither secondary nor supplementary.
+    ///
+    /// This returns `None` if the record has no mate reference sequence ID or mate position, or if
+    /// no mate is found.
+    ///
+    /// This moves the position of the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    /// use noodles_sam as sam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+    /// let header: sam::Header = reader.read_header()?.parse()?;
+    ///
+    /// let reference_sequences = header.reference_sequences();
+    /// let index = bai::read("sample.bam.bai")?;
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// let records: Vec<_> = reader
+    ///     .query(reference_sequences, &index, &region)?
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// for record in &records {
+    ///     if let Some(mate) = reader.query_mate(reference_sequences, &index, record)? {
+    ///         println!("{:?}\t{:?}", record, mate);
+    ///     }
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_mate<I, RS>(
+        &mut self,
+        reference_sequences: &ReferenceSequences,
+        index: &I,
+        record: &Record,
+    ) -> io::Result<Option<Record>>
+    where
+        I: BinningIndex<RS>,
+        RS: ReferenceSequenceExt,
+    {
+        let (mate_reference_sequence_id, mate_position) =
+            match (record.mate_reference_sequence_id(), record.mate_position()) {
+                (Some(id), Some(position)) => (id, position),
+                _ => return Ok(None),
+            };
+
+        let mate_reference_sequence_name = reference_sequences
+            .get_index(mate_reference_sequence_id)
+            .map(|(name, _)| name.to_string())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "invalid mate reference sequence ID: {}",
+                        mate_reference_sequence_id
+                    ),
+                )
+            })?;
+
+        let query = self.query_position(
+            reference_sequences,
+            index,
+            &mate_reference_sequence_name,
+            mate_position,
+        )?;
+
+        for result in query {
+            let candidate = result?;
+
+            if is_mate(record, &candidate) {
+                return Ok(Some(candidate));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
     /// # Examples
@@ -349,7 +587,11 @@ where
     /// }
     /// # Ok::<(), io::Error>(())
     /// ```
-    pub fn query_unmapped(&mut self, index: &bai::Index) -> io::Result<UnmappedRecords<'_, R>> {
+    pub fn query_unmapped<I, RS>(&mut self, index: &I) -> io::Result<UnmappedRecords<'_, R>>
+    where
+        I: BinningIndex<RS>,
+        RS: ReferenceSequenceExt,
+    {
         if let Some(pos) = index.first_record_in_last_linear_bin_start_position() {
             self.seek(pos)?;
         } else {
@@ -399,6 +641,21 @@ where
     }
 }
 
+fn is_mate(record: &Record, candidate: &Record) -> bool {
+    use sam::{record::Flags, AlignmentRecord};
+
+    let flags = record.flags();
+    let candidate_flags = candidate.flags();
+
+    !candidate_flags.intersects(Flags::SECONDARY | Flags::SUPPLEMENTARY)
+        && candidate.read_name() == record.read_name()
+        && candidate.reference_sequence_id() == record.mate_reference_sequence_id()
+        && candidate.position() == record.mate_position()
+        && candidate.mate_reference_sequence_id() == record.reference_sequence_id()
+        && candidate.mate_position() == record.position()
+        && candidate_flags.is_first_segment() != flags.is_first_segment()
+}
+
 fn read_magic<R>(reader: &mut R) -> io::Result<()>
 where
     R: Read,
@@ -420,12 +677,15 @@ fn read_header<R>(reader: &mut R) -> io::Result<String>
 where
     R: Read,
 {
-    let l_text = reader.read_u32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let l_text = reader
+        .read_u32::<LittleEndian>()
+        .and_then(header_text_len)?;
 
     let mut text = vec![0; l_text];
-    reader.read_exact(&mut text)?;
+    reader.read_exact(&mut text).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => truncated_header_error(l_text),
+        _ => e,
+    })?;
 
     // § 4.2 The BAM format (2021-06-03): "Plain header text in SAM; not necessarily
     // NUL-terminated".
@@ -434,6 +694,35 @@ where
     })
 }
 
+// The maximum length of the header text (`l_text`).
+//
+// l_text is read from the stream, and the text buffer is allocated from it before the text is read.
+// This bounds that allocation for a corrupt or malicious length.
+const MAX_HEADER_TEXT_LEN: usize = 1 << 30;
+
+pub(crate) fn header_text_len(n: u32) -> io::Result<usize> {
+    let l_text = usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if l_text > MAX_HEADER_TEXT_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid header text length (l_text): expected <= {}, got {}",
+                MAX_HEADER_TEXT_LEN, l_text
+            ),
+        ));
+    }
+
+    Ok(l_text)
+}
+
+pub(crate) fn truncated_header_error(l_text: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        format!("truncated header: expected {} bytes (l_text)", l_text),
+    )
+}
+
 fn read_reference_sequences<R>(reader: &mut R) -> io::Result<ReferenceSequences>
 where
     R: Read,
@@ -509,6 +798,7 @@ mod tests {
     use noodles_sam as sam;
 
     use super::*;
+    use crate::bai;
 
     #[test]
     fn test_read_magic() -> io::Result<()> {
@@ -549,6 +839,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_header_with_truncated_text() {
+        let data = [
+            0x0b, 0x00, 0x00, 0x00, // l_text = 11
+            b'@', b'H', b'D', // text = "@HD"
+        ];
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            read_header(&mut reader),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[test]
+    fn test_read_header_with_large_text() -> io::Result<()> {
+        let mut expected = String::from("@HD\tVN:1.6\n");
+
+        for i in 0..(1 << 16) {
+            expected.push_str(&format!("@CO\t{}\n", i));
+        }
+
+        let data_len = expected.len() as u32;
+        let mut data = data_len.to_le_bytes().to_vec();
+        data.extend(expected.as_bytes());
+
+        let mut reader = &data[..];
+        let actual = read_header(&mut reader)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_l_text_greater_than_max() {
+        let data = [
+            0xff, 0xff, 0xff, 0xff, // l_text = 4294967295
+            b'@', b'H', b'D', // text = "@HD"
+        ];
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            read_header(&mut reader),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
     #[test]
     fn test_read_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
         use sam::header::reference_sequence;
@@ -575,4 +915,444 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_skip_records() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{AlignmentRecord, AlignmentWriter};
+
+        use crate::Writer;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+
+        for i in 0..8 {
+            let record = sam::Record::builder()
+                .set_read_name(format!("r{}", i).parse()?)
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let read_names = |start, count| -> io::Result<Vec<String>> {
+            let mut reader = Reader::new(&data[..]);
+            reader.read_header()?;
+            reader.read_reference_sequences()?;
+
+            reader.skip_records(start)?;
+
+            reader
+                .records()
+                .take(count)
+                .map(|result| {
+                    result.map(|record| {
+                        record
+                            .read_name()
+                            .map(|name| name.to_string())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect()
+        };
+
+        assert_eq!(read_names(0, 2)?, ["r0", "r1"]);
+        assert_eq!(read_names(3, 2)?, ["r3", "r4"]);
+        assert_eq!(read_names(6, 4)?, ["r6", "r7"]);
+        assert!(read_names(10, 2)?.is_empty());
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+        assert_eq!(reader.skip_records(5)?, 5);
+        assert_eq!(reader.skip_records(5)?, 3);
+        assert_eq!(reader.skip_records(5)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_workers() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{fmt::Write as _, io::Cursor};
+
+        use noodles_csi::index::reference_sequence::bin::Chunk;
+        use noodles_sam::AlignmentWriter;
+
+        use crate::Writer;
+
+        fn query<R>(
+            mut reader: Reader<bgzf::Reader<R>>,
+            index: &bai::Index,
+            region: &Region,
+        ) -> Result<Vec<Record>, Box<dyn std::error::Error>>
+        where
+            R: Read + Seek,
+        {
+            let header: sam::Header = reader.read_header()?.parse()?;
+            reader.read_reference_sequences()?;
+
+            let records = reader
+                .query(header.reference_sequences(), index, region)?
+                .collect::<io::Result<_>>()?;
+
+            Ok(records)
+        }
+
+        let mut sam_data = String::from("@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8192\n");
+        let sequence = "ACGT".repeat(25);
+        let quality_scores = "NDLS".repeat(25);
+
+        // The records span many blocks so that the queried chunks are read by the workers.
+        for i in 0..4096 {
+            writeln!(
+                sam_data,
+                "r{}\t0\tsq0\t{}\t60\t100M\t*\t0\t0\t{}\t{}",
+                i,
+                i + 1,
+                sequence,
+                quality_scores
+            )?;
+        }
+
+        let mut reader = sam::Reader::new(sam_data.as_bytes());
+        let header: sam::Header = reader.read_header()?.parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+
+        for result in reader.records() {
+            let record = result?;
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let index = {
+            let mut reader = Reader::new(&data[..]);
+            reader.read_header()?;
+            reader.read_reference_sequences()?;
+
+            let mut builder = bai::Index::builder();
+            let mut record = Record::default();
+            let mut start_position = reader.virtual_position();
+
+            while reader.read_record(&mut record)? != 0 {
+                let end_position = reader.virtual_position();
+                builder.add_record(&record, Chunk::new(start_position, end_position))?;
+                start_position = end_position;
+            }
+
+            builder.build(header.reference_sequences().len())
+        };
+
+        let region = "sq0:1024-3072".parse()?;
+
+        let expected = query(Reader::new(Cursor::new(data.clone())), &index, &region)?;
+        assert_eq!(expected.len(), 2148);
+
+        let reader = Reader::from(
+            bgzf::Reader::builder(Cursor::new(data))
+                .set_worker_count(2)
+                .build(),
+        );
+        let actual = query(reader, &index, &region)?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_position() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use sam::AlignmentRecord;
+
+        use crate::test_helpers::build_bam_and_index;
+
+        fn read_names<R>(
+            reader: &mut Reader<bgzf::Reader<R>>,
+            reference_sequences: &ReferenceSequences,
+            index: &bai::Index,
+            position: usize,
+        ) -> Result<Vec<String>, Box<dyn std::error::Error>>
+        where
+            R: Read + Seek,
+        {
+            let position = Position::try_from(position)?;
+
+            let names = reader
+                .query_position(reference_sequences, index, "sq0", position)?
+                .map(|result| {
+                    result.map(|record| {
+                        record
+                            .read_name()
+                            .map(|name| name.to_string())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            Ok(names)
+        }
+
+        let sam_data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:34
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t2\t60\t1M5D1M\t*\t0\t0\tAC\tND
+r2\t0\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r3\t0\tsq0\t5\t60\t2M10N2M\t*\t0\t0\tACGT\tNDLS
+r4\t0\tsq0\t8\t60\t4M\t*\t0\t0\tACGT\tNDLS
+";
+
+        let (data, index) = build_bam_and_index(sam_data)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header: sam::Header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+        let reference_sequences = header.reference_sequences();
+
+        assert_eq!(
+            read_names(&mut reader, reference_sequences, &index, 1)?,
+            ["r0"]
+        );
+        assert_eq!(
+            read_names(&mut reader, reference_sequences, &index, 5)?,
+            ["r1", "r2", "r3"]
+        );
+        assert_eq!(
+            read_names(&mut reader, reference_sequences, &index, 7)?,
+            ["r1", "r3"]
+        );
+        assert_eq!(
+            read_names(&mut reader, reference_sequences, &index, 12)?,
+            ["r3"]
+        );
+        assert!(read_names(&mut reader, reference_sequences, &index, 20)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_mate() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use crate::test_helpers::build_bam_and_index;
+
+        let sam_data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:20
+@SQ\tSN:sq1\tLN:20
+r0\t99\tsq0\t1\t60\t4M\t=\t9\t12\tACGT\tNDLS
+r1\t65\tsq0\t5\t60\t4M\tsq1\t3\t0\tACGT\tNDLS
+r0\t403\tsq0\t9\t60\t4M\t=\t1\t-12\tACGT\tNDLS
+r2\t0\tsq0\t9\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r0\t147\tsq0\t9\t60\t4M\t=\t1\t-12\tACGT\tNDLS
+r1\t129\tsq1\t3\t60\t4M\tsq0\t5\t0\tACGT\tNDLS
+";
+
+        let (data, index) = build_bam_and_index(sam_data)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header: sam::Header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+        let reference_sequences = header.reference_sequences();
+
+        let mate = reader.query_mate(reference_sequences, &index, &records[0])?;
+        assert_eq!(mate.as_ref(), Some(&records[4]));
+
+        let mate = reader.query_mate(reference_sequences, &index, &records[4])?;
+        assert_eq!(mate.as_ref(), Some(&records[0]));
+
+        // The mate is on another reference sequence.
+        let mate = reader.query_mate(reference_sequences, &index, &records[1])?;
+        assert_eq!(mate.as_ref(), Some(&records[5]));
+        assert_eq!(mate.and_then(|r| r.reference_sequence_id()), Some(1));
+
+        let mate = reader.query_mate(reference_sequences, &index, &records[5])?;
+        assert_eq!(mate.as_ref(), Some(&records[1]));
+
+        assert!(reader
+            .query_mate(reference_sequences, &index, &records[3])?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_custom_index() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, ops::RangeBounds};
+
+        use noodles_csi::index::reference_sequence::{bin::Chunk, Metadata};
+        use sam::AlignmentRecord;
+
+        use crate::test_helpers::build_bam;
+
+        struct ReferenceSequence;
+
+        impl ReferenceSequenceExt for ReferenceSequence {
+            fn metadata(&self) -> Option<&Metadata> {
+                None
+            }
+
+            fn first_record_in_last_linear_bin_start_position(
+                &self,
+            ) -> Option<bgzf::VirtualPosition> {
+                None
+            }
+        }
+
+        // An index with a single chunk for all records.
+        struct Index {
+            reference_sequences: Vec<ReferenceSequence>,
+            chunk: Chunk,
+        }
+
+        impl BinningIndex<ReferenceSequence> for Index {
+            fn reference_sequences(&self) -> &[ReferenceSequence] {
+                &self.reference_sequences
+            }
+
+            fn unplaced_unmapped_record_count(&self) -> Option<u64> {
+                None
+            }
+
+            fn query<B>(&self, _: usize, _: B) -> io::Result<Vec<Chunk>>
+            where
+                B: RangeBounds<Position> + Clone,
+            {
+                Ok(vec![self.chunk])
+            }
+        }
+
+        let sam_data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@SQ\tSN:sq1\tLN:13
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t5\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t0\tsq1\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+";
+
+        let data = build_bam(sam_data)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header: sam::Header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+
+        let start = reader.virtual_position();
+        let mut record = Record::default();
+        while reader.read_record(&mut record)? != 0 {}
+        let end = reader.virtual_position();
+
+        let index = Index {
+            reference_sequences: vec![ReferenceSequence, ReferenceSequence],
+            chunk: Chunk::new(start, end),
+        };
+
+        let region = "sq0:3-6".parse()?;
+        let names: Vec<_> = reader
+            .query(header.reference_sequences(), &index, &region)?
+            .map(|result| result.map(|record| record.read_name().map(|name| name.to_string())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, [Some(String::from("r0")), Some(String::from("r1"))]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_csi_and_bai() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{collections::BTreeMap, io::Cursor};
+
+        use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+        use sam::AlignmentRecord;
+
+        use crate::{test_helpers::build_bam_and_index, writer::record::calculate_bin};
+
+        fn read_names<R, I, RS>(
+            reader: &mut Reader<bgzf::Reader<R>>,
+            reference_sequences: &ReferenceSequences,
+            index: &I,
+            region: &Region,
+        ) -> Result<Vec<String>, Box<dyn std::error::Error>>
+        where
+            R: Read + Seek,
+            I: BinningIndex<RS>,
+            RS: ReferenceSequenceExt,
+        {
+            let names = reader
+                .query(reference_sequences, index, region)?
+                .map(|result| {
+                    result.map(|record| {
+                        record
+                            .read_name()
+                            .map(|name| name.to_string())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            Ok(names)
+        }
+
+        let sam_data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:34
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq0\t2\t60\t1M5D1M\t*\t0\t0\tAC\tND
+r2\t0\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r3\t0\tsq0\t5\t60\t2M10N2M\t*\t0\t0\tACGT\tNDLS
+r4\t0\tsq0\t8\t60\t4M\t*\t0\t0\tACGT\tNDLS
+";
+
+        let (data, bai_index) = build_bam_and_index(sam_data)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header: sam::Header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+
+        // A BAI bin is the same as a CSI bin with a min shift of 14 and a depth of 5.
+        let mut csi_bins = BTreeMap::new();
+
+        let mut record = Record::default();
+        let mut start_position = reader.virtual_position();
+
+        while reader.read_record(&mut record)? != 0 {
+            let end_position = reader.virtual_position();
+            let chunk = Chunk::new(start_position, end_position);
+
+            let bin_id = calculate_bin(record.alignment_start(), record.alignment_end())?;
+            let (_, chunks) = csi_bins
+                .entry(u32::from(bin_id))
+                .or_insert_with(|| (start_position, Vec::new()));
+            chunks.push(chunk);
+
+            start_position = end_position;
+        }
+
+        let bins = csi_bins
+            .into_iter()
+            .map(|(id, (loffset, chunks))| {
+                csi::index::reference_sequence::Bin::new(id, loffset, chunks)
+            })
+            .collect();
+
+        let csi_index = csi::Index::builder()
+            .set_min_shift(14)
+            .set_depth(5)
+            .set_reference_sequences(vec![csi::index::ReferenceSequence::new(bins, None)])
+            .build();
+
+        let reference_sequences = header.reference_sequences();
+        let region = "sq0:5-7".parse()?;
+
+        let bai_names = read_names(&mut reader, reference_sequences, &bai_index, &region)?;
+        assert_eq!(bai_names, ["r1", "r2", "r3"]);
+
+        let csi_names = read_names(&mut reader, reference_sequences, &csi_index, &region)?;
+        assert_eq!(csi_names, bai_names);
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use std::{
 
 use noodles_bgzf::{self as bgzf, VirtualPosition};
 use noodles_core::Position;
-use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_csi::binning_index::MergedChunks;
 use noodles_sam::AlignmentRecord;
 
 use crate::Record;
@@ -28,8 +28,7 @@ where
 {
     reader: &'a mut Reader<bgzf::Reader<R>>,
 
-    chunks: Vec<Chunk>,
-    i: usize,
+    chunks: MergedChunks<'a>,
 
     reference_sequence_id: usize,
     interval: B,
@@ -45,7 +44,7 @@ where
 {
     pub(super) fn new(
         reader: &'a mut Reader<bgzf::Reader<R>>,
-        chunks: Vec<Chunk>,
+        chunks: MergedChunks<'a>,
         reference_sequence_id: usize,
         interval: B,
     ) -> Self {
@@ -53,7 +52,6 @@ where
             reader,
 
             chunks,
-            i: 0,
 
             reference_sequence_id,
             interval,
@@ -82,7 +80,7 @@ where
         loop {
             match self.state {
                 State::Seek => {
-                    self.state = match next_chunk(&self.chunks, &mut self.i) {
+                    self.state = match self.chunks.next() {
                         Some(chunk) => {
                             if let Err(e) = self.reader.seek(chunk.start()) {
                                 return Some(Err(e));
@@ -112,12 +110,6 @@ where
     }
 }
 
-pub(crate) fn next_chunk(chunks: &[Chunk], i: &mut usize) -> Option<Chunk> {
-    let chunk = chunks.get(*i).copied();
-    *i += 1;
-    chunk
-}
-
 pub(crate) fn intersects<B>(record: &Record, reference_sequence_id: usize, interval: B) -> bool
 where
     B: RangeBounds<Position>,
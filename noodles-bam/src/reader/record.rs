@@ -7,6 +7,7 @@ mod read_name;
 mod sequence;
 
 use std::{
+    error, fmt,
     io::{self, Read},
     mem,
     num::NonZeroUsize,
@@ -19,29 +20,186 @@ use noodles_sam as sam;
 
 use crate::Record;
 
+pub(crate) use self::{
+    cigar::decode_op, quality_scores::is_missing_quality_scores, sequence::decode_base,
+};
+
 pub(crate) fn read_record<R>(
     reader: &mut R,
     buf: &mut Vec<u8>,
     record: &mut Record,
 ) -> io::Result<usize>
+where
+    R: Read,
+{
+    match read_block(reader, buf)? {
+        Some(block_size) => {
+            decode_record(&buf[..], record)?;
+            Ok(block_size)
+        }
+        None => Ok(0),
+    }
+}
+
+pub(crate) fn read_record_diagnostic<R>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    record: &mut Record,
+) -> io::Result<Result<usize, DecodeError>>
+where
+    R: Read,
+{
+    let block_size = match reader.read_u32::<LittleEndian>() {
+        Ok(bs) => usize::try_from(bs).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(Ok(0)),
+        Err(e) => return Err(e),
+    };
+
+    buf.clear();
+    reader.take(block_size as u64).read_to_end(buf)?;
+
+    *record = Record::default();
+
+    if let Err(e) = try_decode_record(&buf[..], record) {
+        return Ok(Err(e));
+    }
+
+    if buf.len() < block_size {
+        // The record decoded, but the block is truncated, so the data is incomplete.
+        return Ok(Err(DecodeError::new(
+            Field::Data,
+            io::Error::from(io::ErrorKind::UnexpectedEof),
+        )));
+    }
+
+    Ok(Ok(block_size))
+}
+
+// Reads the raw data of a record into the given buffer.
+//
+// If successful, the block size is returned. `None` means the stream reached EOF.
+pub(crate) fn read_block<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<usize>>
 where
     R: Read,
 {
     let block_size = match reader.read_u32::<LittleEndian>() {
         Ok(bs) => usize::try_from(bs).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
         Err(e) => return Err(e),
     };
 
     buf.resize(block_size, Default::default());
     reader.read_exact(buf)?;
 
-    decode_record(&buf[..], record)?;
+    Ok(Some(block_size))
+}
+
+pub(crate) fn decode_record<B>(src: B, record: &mut Record) -> io::Result<()>
+where
+    B: Buf,
+{
+    try_decode_record(src, record).map_err(|e| e.source)
+}
+
+/// A BAM record field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Field {
+    /// Reference sequence ID (`ref_id`).
+    ReferenceSequenceId,
+    /// Position (`pos`).
+    Position,
+    /// Length of the read name (`l_read_name`).
+    ReadNameLength,
+    /// Mapping quality (`mapq`).
+    MappingQuality,
+    /// BAI index bin (`bin`).
+    Bin,
+    /// Number of CIGAR operations (`n_cigar_op`).
+    CigarOpCount,
+    /// Flags (`flag`).
+    Flags,
+    /// Length of the sequence (`l_seq`).
+    SequenceLength,
+    /// Mate reference sequence ID (`next_refID`).
+    MateReferenceSequenceId,
+    /// Mate position (`next_pos`).
+    MatePosition,
+    /// Template length (`tlen`).
+    TemplateLength,
+    /// Read name (`read_name`).
+    ReadName,
+    /// CIGAR operations (`cigar`).
+    Cigar,
+    /// Sequence (`seq`).
+    Sequence,
+    /// Quality scores (`qual`).
+    QualityScores,
+    /// Data.
+    Data,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReferenceSequenceId => f.write_str("ref_id"),
+            Self::Position => f.write_str("pos"),
+            Self::ReadNameLength => f.write_str("l_read_name"),
+            Self::MappingQuality => f.write_str("mapq"),
+            Self::Bin => f.write_str("bin"),
+            Self::CigarOpCount => f.write_str("n_cigar_op"),
+            Self::Flags => f.write_str("flag"),
+            Self::SequenceLength => f.write_str("l_seq"),
+            Self::MateReferenceSequenceId => f.write_str("next_refID"),
+            Self::MatePosition => f.write_str("next_pos"),
+            Self::TemplateLength => f.write_str("tlen"),
+            Self::ReadName => f.write_str("read_name"),
+            Self::Cigar => f.write_str("cigar"),
+            Self::Sequence => f.write_str("seq"),
+            Self::QualityScores => f.write_str("qual"),
+            Self::Data => f.write_str("data"),
+        }
+    }
+}
 
-    Ok(block_size)
+/// An error returned when a BAM record fails to decode.
+///
+/// This names the field that failed to decode. Fields are decoded in order, so all fields before
+/// it were successfully decoded.
+#[derive(Debug)]
+pub struct DecodeError {
+    field: Field,
+    source: io::Error,
 }
 
-pub(crate) fn decode_record<B>(mut src: B, record: &mut Record) -> io::Result<()>
+impl DecodeError {
+    fn new(field: Field, source: io::Error) -> Self {
+        Self { field, source }
+    }
+
+    /// Returns the field that failed to decode.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// Returns the I/O error of the failed field.
+    pub fn get_ref(&self) -> &io::Error {
+        &self.source
+    }
+}
+
+impl error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {}", self.field, self.source)
+    }
+}
+
+pub(crate) fn try_decode_record<B>(mut src: B, record: &mut Record) -> Result<(), DecodeError>
 where
     B: Buf,
 {
@@ -50,40 +208,109 @@ where
         read_name::get_read_name, sequence::get_sequence,
     };
 
-    *record.reference_sequence_id_mut() = get_reference_sequence_id(&mut src)?;
-    *record.position_mut() = get_position(&mut src)?;
+    *record.reference_sequence_id_mut() = get_reference_sequence_id(&mut src)
+        .map_err(|e| DecodeError::new(Field::ReferenceSequenceId, e))?;
 
-    let l_read_name = NonZeroUsize::new(usize::from(src.get_u8()))
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid l_read_name"))?;
+    *record.position_mut() =
+        get_position(&mut src).map_err(|e| DecodeError::new(Field::Position, e))?;
 
-    *record.mapping_quality_mut() = get_mapping_quality(&mut src)?;
+    let l_read_name = get_u8(&mut src)
+        .and_then(|n| {
+            NonZeroUsize::new(usize::from(n))
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid l_read_name"))
+        })
+        .map_err(|e| DecodeError::new(Field::ReadNameLength, e))?;
+
+    *record.mapping_quality_mut() =
+        get_mapping_quality(&mut src).map_err(|e| DecodeError::new(Field::MappingQuality, e))?;
 
     // Discard bin.
-    src.advance(mem::size_of::<u16>());
+    get_u16_le(&mut src).map_err(|e| DecodeError::new(Field::Bin, e))?;
+
+    let n_cigar_op = get_u16_le(&mut src)
+        .map(usize::from)
+        .map_err(|e| DecodeError::new(Field::CigarOpCount, e))?;
+
+    *record.flags_mut() = get_flags(&mut src).map_err(|e| DecodeError::new(Field::Flags, e))?;
+
+    let l_seq = get_u32_le(&mut src)
+        .and_then(|n| usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+        .map_err(|e| DecodeError::new(Field::SequenceLength, e))?;
+
+    *record.mate_reference_sequence_id_mut() = get_reference_sequence_id(&mut src)
+        .map_err(|e| DecodeError::new(Field::MateReferenceSequenceId, e))?;
+
+    *record.mate_position_mut() =
+        get_position(&mut src).map_err(|e| DecodeError::new(Field::MatePosition, e))?;
 
-    let n_cigar_op = usize::from(src.get_u16_le());
+    *record.template_length_mut() =
+        get_i32_le(&mut src).map_err(|e| DecodeError::new(Field::TemplateLength, e))?;
 
-    *record.flags_mut() = get_flags(&mut src)?;
+    get_read_name(&mut src, record.read_name_mut(), l_read_name)
+        .map_err(|e| DecodeError::new(Field::ReadName, e))?;
 
-    let l_seq = usize::try_from(src.get_u32_le())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    // An empty read name is read as missing but is kept to be written back as it was read.
+    *record.is_read_name_empty_mut() = l_read_name.get() == 1;
 
-    *record.mate_reference_sequence_id_mut() = get_reference_sequence_id(&mut src)?;
-    *record.mate_position_mut() = get_position(&mut src)?;
+    get_cigar(&mut src, record.cigar_mut(), n_cigar_op)
+        .map_err(|e| DecodeError::new(Field::Cigar, e))?;
 
-    *record.template_length_mut() = src.get_i32_le();
+    get_sequence(&mut src, record.sequence_mut(), l_seq)
+        .map_err(|e| DecodeError::new(Field::Sequence, e))?;
 
-    get_read_name(&mut src, record.read_name_mut(), l_read_name)?;
-    get_cigar(&mut src, record.cigar_mut(), n_cigar_op)?;
-    get_sequence(&mut src, record.sequence_mut(), l_seq)?;
-    get_quality_scores(&mut src, record.quality_scores_mut(), l_seq)?;
+    get_quality_scores(&mut src, record.quality_scores_mut(), l_seq)
+        .map_err(|e| DecodeError::new(Field::QualityScores, e))?;
 
-    get_data(&mut src, record.data_mut())?;
+    get_data(&mut src, record.data_mut()).map_err(|e| DecodeError::new(Field::Data, e))?;
 
     Ok(())
 }
 
-fn get_reference_sequence_id<B>(src: &mut B) -> io::Result<Option<usize>>
+fn get_u8<B>(src: &mut B) -> io::Result<u8>
+where
+    B: Buf,
+{
+    if src.remaining() < mem::size_of::<u8>() {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    Ok(src.get_u8())
+}
+
+fn get_u16_le<B>(src: &mut B) -> io::Result<u16>
+where
+    B: Buf,
+{
+    if src.remaining() < mem::size_of::<u16>() {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    Ok(src.get_u16_le())
+}
+
+fn get_u32_le<B>(src: &mut B) -> io::Result<u32>
+where
+    B: Buf,
+{
+    if src.remaining() < mem::size_of::<u32>() {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    Ok(src.get_u32_le())
+}
+
+fn get_i32_le<B>(src: &mut B) -> io::Result<i32>
+where
+    B: Buf,
+{
+    if src.remaining() < mem::size_of::<i32>() {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    Ok(src.get_i32_le())
+}
+
+pub(crate) fn get_reference_sequence_id<B>(src: &mut B) -> io::Result<Option<usize>>
 where
     B: Buf,
 {
@@ -101,7 +328,7 @@ where
     }
 }
 
-fn get_position<B>(src: &mut B) -> io::Result<Option<Position>>
+pub(crate) fn get_position<B>(src: &mut B) -> io::Result<Option<Position>>
 where
     B: Buf,
 {
@@ -119,7 +346,7 @@ where
     }
 }
 
-fn get_mapping_quality<B>(src: &mut B) -> io::Result<Option<sam::record::MappingQuality>>
+pub(crate) fn get_mapping_quality<B>(src: &mut B) -> io::Result<Option<sam::record::MappingQuality>>
 where
     B: Buf,
 {
@@ -135,7 +362,7 @@ where
     }
 }
 
-fn get_flags<B>(src: &mut B) -> io::Result<sam::record::Flags>
+pub(crate) fn get_flags<B>(src: &mut B) -> io::Result<sam::record::Flags>
 where
     B: Buf,
 {
@@ -178,6 +405,106 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_diagnostic() -> io::Result<()> {
+        let data = [
+            0x22, 0x00, 0x00, 0x00, // block_size = 34
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x02, // l_read_name = 2
+            0xff, // mapq = 255
+            0x48, 0x12, // bin = 4680
+            0x00, 0x00, // n_cigar_op = 0
+            0x04, 0x00, // flag = 4
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            0x2a, 0x00, // read_name = "*\x00"
+        ];
+
+        let mut buf = Vec::new();
+        let mut record = Record::default();
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record_diagnostic(&mut reader, &mut buf, &mut record)?,
+            Ok(34)
+        ));
+        assert!(matches!(
+            read_record_diagnostic(&mut reader, &mut buf, &mut record)?,
+            Ok(0)
+        ));
+
+        // The stream ends in the middle of the read name.
+        let mut reader = &data[..data.len() - 1];
+        assert!(matches!(
+            read_record_diagnostic(&mut reader, &mut buf, &mut record)?,
+            Err(e) if e.field() == Field::ReadName
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_decode_record_with_invalid_n_cigar_op() {
+        use sam::AlignmentRecord;
+
+        let data = [
+            0x00, 0x00, 0x00, 0x00, // ref_id = 0
+            0x07, 0x00, 0x00, 0x00, // pos = 7
+            0x03, // l_read_name = 3
+            0x0d, // mapq = 13
+            0x49, 0x12, // bin = 4681
+            0x08, 0x00, // n_cigar_op = 8 (actual = 1)
+            0x00, 0x00, // flag = 0
+            0x04, 0x00, 0x00, 0x00, // l_seq = 4
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'r', b'0', 0x00, // read_name = "r0\x00"
+            0x40, 0x00, 0x00, 0x00, // cigar = 4M
+            0x12, 0x48, // seq = ACGT
+            0x2d, 0x23, 0x2b, 0x32, // qual = NDLS
+        ];
+
+        let mut record = Record::default();
+
+        match try_decode_record(&data[..], &mut record) {
+            Err(e) => {
+                assert_eq!(e.field(), Field::Cigar);
+                assert_eq!(e.get_ref().kind(), io::ErrorKind::UnexpectedEof);
+            }
+            Ok(()) => panic!("expected an error"),
+        }
+
+        assert_eq!(record.reference_sequence_id(), Some(0));
+        assert_eq!(record.position(), Position::new(8));
+        assert_eq!(
+            record.read_name().map(|n| n.to_string()),
+            Some(String::from("r0"))
+        );
+        assert!(record.cigar().is_empty());
+    }
+
+    #[test]
+    fn test_try_decode_record_with_truncated_fixed_fields() {
+        let data = [
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x02, // l_read_name = 2
+            0xff, // mapq = 255
+            0x48, // bin (truncated)
+        ];
+
+        let mut record = Record::default();
+
+        assert!(matches!(
+            try_decode_record(&data[..], &mut record),
+            Err(e) if e.field() == Field::Bin
+        ));
+    }
+
     #[test]
     fn test_decode_record_with_invalid_l_read_name() {
         let data = [
@@ -28,7 +28,7 @@ where
     Ok(())
 }
 
-fn decode_op(n: u32) -> io::Result<Op> {
+pub(crate) fn decode_op(n: u32) -> io::Result<Op> {
     let kind = decode_kind(n)?;
     let len = usize::try_from(n >> 4).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     Ok(Op::new(kind, len))
@@ -45,6 +45,7 @@ fn decode_kind(n: u32) -> io::Result<Kind> {
         6 => Ok(Kind::Pad),
         7 => Ok(Kind::SequenceMatch),
         8 => Ok(Kind::SequenceMismatch),
+        9 => Ok(Kind::Back),
         _ => Err(io::Error::new(
             io::ErrorKind::InvalidData,
             "invalid CIGAR op kind",
@@ -88,7 +89,7 @@ mod tests {
         assert_eq!(decode_op(0x10)?, Op::new(Kind::Match, 1));
 
         assert!(matches!(
-            decode_op(0x19),
+            decode_op(0x1a),
             Err(e) if e.kind() == io::ErrorKind::InvalidData
         ));
 
@@ -106,9 +107,10 @@ mod tests {
         assert_eq!(decode_kind(0x06)?, Kind::Pad);
         assert_eq!(decode_kind(0x07)?, Kind::SequenceMatch);
         assert_eq!(decode_kind(0x08)?, Kind::SequenceMismatch);
+        assert_eq!(decode_kind(0x09)?, Kind::Back);
 
         assert!(matches!(
-            decode_op(0x09),
+            decode_op(0x0a),
             Err(e) if e.kind() == io::ErrorKind::InvalidData
         ));
 
@@ -16,14 +16,56 @@ where
 {
     use self::tag::get_tag;
 
-    let tag = match get_tag(src) {
-        Ok(t) => t,
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
-        Err(e) => return Err(e),
-    };
+    if !src.has_remaining() {
+        return Ok(None);
+    }
 
-    let ty = value::get_type(src)?;
-    let value = get_value(src, ty)?;
+    let tag = get_tag(src)?;
+
+    let value = value::get_type(src)
+        .and_then(|ty| get_value(src, ty))
+        .map_err(|e| io::Error::new(e.kind(), format!("invalid field {}: {}", tag, e)))?;
 
     Ok(Some(Field::new(tag, value)))
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::data::field::{Tag, Value};
+
+    use super::*;
+
+    #[test]
+    fn test_get_field() -> io::Result<()> {
+        let data = [b'N', b'H', b'C', 0x01];
+        let mut src = &data[..];
+        assert_eq!(
+            get_field(&mut src)?,
+            Some(Field::new(Tag::AlignmentHitCount, Value::UInt8(1)))
+        );
+        assert!(get_field(&mut src)?.is_none());
+
+        let data = [b'N'];
+        let mut src = &data[..];
+        assert!(matches!(
+            get_field(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let data = [b'N', b'H', b'?', 0x01];
+        let mut src = &data[..];
+        assert!(matches!(
+            get_field(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData && e.to_string().starts_with("invalid field NH: ")
+        ));
+
+        let data = [b'N', b'H', b'i', 0x01];
+        let mut src = &data[..];
+        assert!(matches!(
+            get_field(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof && e.to_string().starts_with("invalid field NH: ")
+        ));
+
+        Ok(())
+    }
+}
@@ -12,7 +12,13 @@ where
     }
 
     let buf = [src.get_u8(), src.get_u8()];
-    Tag::try_from(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+
+    Tag::try_from(buf).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid tag {:02x?}: {}", buf, e),
+        )
+    })
 }
 
 #[cfg(test)]
@@ -30,6 +36,13 @@ mod tests {
 
         assert_eq!(actual, expected);
 
+        let data = [b'N', 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            get_tag(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData && e.to_string().starts_with("invalid tag [4e, 00]: ")
+        ));
+
         Ok(())
     }
 }
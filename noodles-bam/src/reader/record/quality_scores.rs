@@ -37,7 +37,7 @@ where
     Ok(())
 }
 
-fn is_missing_quality_scores(src: &[u8]) -> bool {
+pub(crate) fn is_missing_quality_scores(src: &[u8]) -> bool {
     use crate::writer::alignment_record::NULL_QUALITY_SCORE;
 
     src.iter().all(|&b| b == NULL_QUALITY_SCORE)
@@ -20,8 +20,13 @@ where
         return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
     }
 
-    *read_name = if src.take(len).chunk() == MISSING {
-        src.advance(MISSING.len());
+    // Some writers use an empty read name (a lone NUL terminator) for a missing read name. This
+    // is out of spec but is read as if it were `*`. The record keeps track of it so that it can
+    // be written back as it was read.
+    let is_missing = matches!(src.take(len).chunk(), c if c == MISSING || c == [NUL]);
+
+    *read_name = if is_missing {
+        src.advance(len);
         None
     } else {
         let mut read_name_buf = read_name.take().map(Vec::from).unwrap_or_default();
@@ -66,6 +71,7 @@ mod tests {
         }
 
         t(&[b'*', 0x00], None)?;
+        t(&[0x00], None)?;
         t(&[b'r', 0x00], "r".parse().map(Some)?)?;
         t(&[b'r', b'1', 0x00], "r1".parse().map(Some)?)?;
 
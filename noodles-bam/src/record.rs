@@ -2,12 +2,17 @@
 
 pub mod builder;
 mod convert;
+mod record_ref;
 pub mod reference_sequence_id;
 
-pub use self::builder::Builder;
+pub use self::{
+    builder::Builder,
+    record_ref::{RecordRef, RecordRefs},
+};
 
-use std::io;
+use std::{io, mem};
 
+use byteorder::{ByteOrder, LittleEndian};
 use noodles_core::Position;
 use noodles_sam::{
     self as sam,
@@ -37,7 +42,7 @@ pub(crate) const UNMAPPED_POSITION: i32 = -1;
 ///
 /// A `bam::Record` and its fields store raw values and care should be taken when manipulating
 /// them.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Record {
     reference_sequence_id: Option<usize>,
     position: Option<Position>,
@@ -47,6 +52,9 @@ pub struct Record {
     mate_position: Option<Position>,
     template_length: i32,
     read_name: Option<sam::record::ReadName>,
+    // Whether a missing read name was read as an empty read name (a lone NUL terminator) rather
+    // than `*`. This is only used to write the read name back as it was read.
+    is_read_name_empty: bool,
     cigar: sam::record::Cigar,
     sequence: sam::record::Sequence,
     quality_scores: sam::record::QualityScores,
@@ -67,6 +75,142 @@ impl Record {
         Builder::default()
     }
 
+    /// Decodes a BAM record from raw bytes.
+    ///
+    /// The input must start with the block size (`block_size`) of the record, i.e., as it is
+    /// stored in the uncompressed BAM stream. Any data after the record is ignored.
+    ///
+    /// This returns the decoded record and the number of bytes consumed, which includes the block
+    /// size.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the input is too short or if the record is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    ///
+    /// let data = [
+    ///     0x22, 0x00, 0x00, 0x00, // block_size = 34
+    ///     0xff, 0xff, 0xff, 0xff, // ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // pos = -1
+    ///     0x02, // l_read_name = 2
+    ///     0xff, // mapq = 255
+    ///     0x48, 0x12, // bin = 4680
+    ///     0x00, 0x00, // n_cigar_op = 0
+    ///     0x04, 0x00, // flag = 4
+    ///     0x00, 0x00, 0x00, 0x00, // l_seq = 0
+    ///     0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // next_pos = -1
+    ///     0x00, 0x00, 0x00, 0x00, // tlen = 0
+    ///     0x2a, 0x00, // read_name = "*\x00"
+    /// ];
+    ///
+    /// let (record, len) = bam::Record::try_from_bytes(&data)?;
+    /// assert_eq!(record, bam::Record::default());
+    /// assert_eq!(len, data.len());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn try_from_bytes(src: &[u8]) -> io::Result<(Self, usize)> {
+        use crate::reader::record::decode_record;
+
+        const BLOCK_SIZE_LEN: usize = mem::size_of::<u32>();
+
+        if src.len() < BLOCK_SIZE_LEN {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        let block_size = usize::try_from(LittleEndian::read_u32(&src[..BLOCK_SIZE_LEN]))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let end = BLOCK_SIZE_LEN
+            .checked_add(block_size)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid block size"))?;
+
+        let buf = src
+            .get(BLOCK_SIZE_LEN..end)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+        let mut record = Self::default();
+        decode_record(buf, &mut record)?;
+
+        Ok((record, end))
+    }
+
+    /// Validates the stored bin of an encoded record.
+    ///
+    /// The bin (`bin`) of a BAM record is not kept in a [`Record`]. It is discarded when a record
+    /// is decoded and recomputed from the alignment start and end when a record is encoded. This
+    /// checks the stored bin of the encoded record in the given buffer against the bin computed
+    /// from its position and CIGAR, which catches records written by tools with bin calculation
+    /// bugs.
+    ///
+    /// Like [`Self::try_from_bytes`], the buffer is expected to start with the block size of the
+    /// record.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`io::ErrorKind::InvalidData`] error with the expected and actual bins if
+    /// the stored bin is not the computed bin or an error if the record fails to decode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    ///
+    /// let mut data = vec![
+    ///     0x22, 0x00, 0x00, 0x00, // block_size = 34
+    ///     0xff, 0xff, 0xff, 0xff, // ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // pos = -1
+    ///     0x02, // l_read_name = 2
+    ///     0xff, // mapq = 255
+    ///     0x48, 0x12, // bin = 4680
+    ///     0x00, 0x00, // n_cigar_op = 0
+    ///     0x04, 0x00, // flag = 4
+    ///     0x00, 0x00, 0x00, 0x00, // l_seq = 0
+    ///     0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // next_pos = -1
+    ///     0x00, 0x00, 0x00, 0x00, // tlen = 0
+    ///     0x2a, 0x00, // read_name = "*\x00"
+    /// ];
+    ///
+    /// assert!(bam::Record::validate_bin(&data).is_ok());
+    ///
+    /// data[14] = 0x00; // bin = 4608
+    /// assert!(bam::Record::validate_bin(&data).is_err());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn validate_bin(src: &[u8]) -> io::Result<()> {
+        use sam::AlignmentRecord;
+
+        use crate::writer::record::calculate_bin;
+
+        // block_size (4) + ref_id (4) + pos (4) + l_read_name (1) + mapq (1)
+        const BIN_OFFSET: usize = 14;
+
+        let (record, _) = Self::try_from_bytes(src)?;
+
+        let actual = src
+            .get(BIN_OFFSET..BIN_OFFSET + mem::size_of::<u16>())
+            .map(LittleEndian::read_u16)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+        let expected = calculate_bin(record.alignment_start(), record.alignment_end())?;
+
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid bin: expected {}, got {}", expected, actual),
+            ))
+        }
+    }
+
     /// Returns the reference sequence ID of this record.
     ///
     /// The reference sequence ID is the index of the associated reference sequence in the SAM
@@ -264,6 +408,16 @@ impl Record {
         &mut self.read_name
     }
 
+    /// Returns whether the read name is stored as an empty read name, i.e., a lone NUL
+    /// terminator.
+    pub(crate) fn is_read_name_empty(&self) -> bool {
+        self.read_name.is_none() && self.is_read_name_empty
+    }
+
+    pub(crate) fn is_read_name_empty_mut(&mut self) -> &mut bool {
+        &mut self.is_read_name_empty
+    }
+
     /// Returns a mutable reference to the CIGAR.
     ///
     /// # Examples
@@ -473,10 +627,64 @@ impl Default for Record {
     }
 }
 
+// `is_read_name_empty` is an encoding detail and does not change the record, so it is not
+// compared.
+impl PartialEq for Record {
+    fn eq(&self, other: &Self) -> bool {
+        self.reference_sequence_id == other.reference_sequence_id
+            && self.position == other.position
+            && self.mapping_quality == other.mapping_quality
+            && self.flags == other.flags
+            && self.mate_reference_sequence_id == other.mate_reference_sequence_id
+            && self.mate_position == other.mate_position
+            && self.template_length == other.template_length
+            && self.read_name == other.read_name
+            && self.cigar == other.cigar
+            && self.sequence == other.sequence
+            && self.quality_scores == other.quality_scores
+            && self.data == other.data
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_bin() -> io::Result<()> {
+        let mut data = [
+            0x27, 0x00, 0x00, 0x00, // block_size = 39
+            0x00, 0x00, 0x00, 0x00, // ref_id = 0
+            0x07, 0x00, 0x00, 0x00, // pos = 7
+            0x03, // l_read_name = 3
+            0x0d, // mapq = 13
+            0x49, 0x12, // bin = 4681
+            0x01, 0x00, // n_cigar_op = 1
+            0x00, 0x00, // flag = 0
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'r', b'0', 0x00, // read_name = "r0\x00"
+            0x40, 0x00, 0x00, 0x00, // cigar = 4M
+        ];
+
+        Record::validate_bin(&data)?;
+
+        // bin = 4680
+        data[14] = 0x48;
+
+        match Record::validate_bin(&data) {
+            Err(e) => {
+                assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                assert_eq!(e.to_string(), "invalid bin: expected 4681, got 4680");
+            }
+            Ok(()) => panic!("expected an error"),
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_default() {
         let record = Record::default();
@@ -494,4 +702,68 @@ mod tests {
         assert!(record.quality_scores.is_empty());
         assert!(record.data.is_empty());
     }
+
+    #[test]
+    fn test_eq_ignores_empty_read_name_encoding() {
+        let mut record = Record::default();
+        *record.is_read_name_empty_mut() = true;
+        assert_eq!(record, Record::default());
+    }
+
+    #[test]
+    fn test_try_from_bytes() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::{Flags, MappingQuality};
+
+        use crate::Reader;
+
+        let data = [
+            0x2d, 0x00, 0x00, 0x00, // block_size = 45
+            0x00, 0x00, 0x00, 0x00, // ref_id = 0
+            0x07, 0x00, 0x00, 0x00, // pos = 7
+            0x03, // l_read_name = 3
+            0x0d, // mapq = 13
+            0x49, 0x12, // bin = 4681
+            0x01, 0x00, // n_cigar_op = 1
+            0x00, 0x00, // flag = 0
+            0x04, 0x00, 0x00, 0x00, // l_seq = 4
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'r', b'0', 0x00, // read_name = "r0\x00"
+            0x40, 0x00, 0x00, 0x00, // cigar = 4M
+            0x12, 0x48, // seq = ACGT
+            0x2d, 0x23, 0x2b, 0x32, // qual = NDLS
+            0xde, 0xad, 0xbe, 0xef, // (trailing data)
+        ];
+
+        let (record, len) = Record::try_from_bytes(&data)?;
+
+        assert_eq!(len, 49);
+        assert_eq!(record.reference_sequence_id(), Some(0));
+        assert_eq!(record.position(), Position::new(8));
+        assert_eq!(record.mapping_quality, MappingQuality::new(13));
+        assert_eq!(record.flags, Flags::empty());
+        assert_eq!(record.cigar.to_string(), "4M");
+        assert_eq!(record.sequence.to_string(), "ACGT");
+        assert_eq!(record.quality_scores.to_string(), "NDLS");
+
+        let mut reader = Reader::from(&data[..]);
+        let mut expected = Record::default();
+        let block_size = reader.read_record(&mut expected)?;
+
+        assert_eq!(record, expected);
+        assert_eq!(len, block_size + 4);
+
+        assert!(matches!(
+            Record::try_from_bytes(&data[..2]),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        assert!(matches!(
+            Record::try_from_bytes(&data[..40]),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
@@ -283,6 +283,7 @@ impl Builder {
             mate_position: self.mate_position,
             template_length: self.template_length,
             read_name: self.read_name,
+            is_read_name_empty: false,
             cigar: self.cigar,
             sequence: self.sequence,
             quality_scores: self.quality_scores,
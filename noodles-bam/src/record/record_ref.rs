@@ -0,0 +1,494 @@
+//! Borrowed BAM record.
+
+use std::{io, mem};
+
+use byteorder::{ByteOrder, LittleEndian};
+use bytes::Buf;
+use noodles_core::Position;
+use noodles_sam::{
+    self as sam,
+    record::{cigar::Op, sequence::Base},
+};
+
+use crate::{reader::record::decode_record, Record};
+
+const BLOCK_SIZE_LEN: usize = mem::size_of::<u32>();
+
+/// A borrowed BAM record.
+///
+/// Unlike [`Record`], a `RecordRef` does not own its variable-length fields. The read name, CIGAR,
+/// sequence, quality scores, and data reference slices of the backing buffer, e.g., a
+/// memory-mapped decompressed BAM record stream. Creating a `RecordRef` does not allocate.
+///
+/// The fixed-length fields are decoded and the read name, CIGAR, and quality scores are validated
+/// when the record is created, so the accessors do not fail. The data is not decoded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RecordRef<'a> {
+    src: &'a [u8],
+    reference_sequence_id: Option<usize>,
+    position: Option<Position>,
+    mapping_quality: Option<sam::record::MappingQuality>,
+    flags: sam::record::Flags,
+    mate_reference_sequence_id: Option<usize>,
+    mate_position: Option<Position>,
+    template_length: i32,
+    read_name: Option<&'a [u8]>,
+    cigar: &'a [u8],
+    sequence: &'a [u8],
+    sequence_len: usize,
+    quality_scores: &'a [u8],
+    data: &'a [u8],
+}
+
+impl<'a> RecordRef<'a> {
+    /// Creates a borrowed BAM record from raw bytes.
+    ///
+    /// Like [`Record::try_from_bytes`], the input must start with the block size (`block_size`)
+    /// of the record, and any data after the record is ignored.
+    ///
+    /// This returns the borrowed record and the number of bytes consumed, which includes the
+    /// block size.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the input is too short or if the record is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::record::RecordRef;
+    ///
+    /// let data = [
+    ///     0x22, 0x00, 0x00, 0x00, // block_size = 34
+    ///     0xff, 0xff, 0xff, 0xff, // ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // pos = -1
+    ///     0x02, // l_read_name = 2
+    ///     0xff, // mapq = 255
+    ///     0x48, 0x12, // bin = 4680
+    ///     0x00, 0x00, // n_cigar_op = 0
+    ///     0x04, 0x00, // flag = 4
+    ///     0x00, 0x00, 0x00, 0x00, // l_seq = 0
+    ///     0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // next_pos = -1
+    ///     0x00, 0x00, 0x00, 0x00, // tlen = 0
+    ///     0x2a, 0x00, // read_name = "*\x00"
+    /// ];
+    ///
+    /// let (record, len) = RecordRef::try_from_bytes(&data)?;
+    /// assert!(record.reference_sequence_id().is_none());
+    /// assert!(record.read_name().is_none());
+    /// assert_eq!(len, data.len());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn try_from_bytes(src: &'a [u8]) -> io::Result<(Self, usize)> {
+        if src.len() < BLOCK_SIZE_LEN {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        let block_size = usize::try_from(LittleEndian::read_u32(&src[..BLOCK_SIZE_LEN]))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let end = BLOCK_SIZE_LEN
+            .checked_add(block_size)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid block size"))?;
+
+        let buf = src
+            .get(BLOCK_SIZE_LEN..end)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+        let record = Self::decode(buf)?;
+
+        Ok((record, end))
+    }
+
+    fn decode(src: &'a [u8]) -> io::Result<Self> {
+        use crate::reader::record::{
+            decode_op, get_flags, get_mapping_quality, get_position, get_reference_sequence_id,
+            is_missing_quality_scores,
+        };
+
+        const NUL: u8 = 0x00;
+
+        // ref_id (4) + pos (4) + l_read_name (1) + mapq (1) + bin (2) + n_cigar_op (2) + flag (2)
+        // + l_seq (4) + next_ref_id (4) + next_pos (4) + tlen (4)
+        const FIXED_LEN: usize = 32;
+
+        if src.len() < FIXED_LEN {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        let mut buf = src;
+
+        let reference_sequence_id = get_reference_sequence_id(&mut buf)?;
+        let position = get_position(&mut buf)?;
+
+        let l_read_name = usize::from(buf.get_u8());
+
+        if l_read_name == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid l_read_name",
+            ));
+        }
+
+        let mapping_quality = get_mapping_quality(&mut buf)?;
+
+        // Discard bin.
+        buf.advance(mem::size_of::<u16>());
+
+        let n_cigar_op = usize::from(buf.get_u16_le());
+        let flags = get_flags(&mut buf)?;
+
+        let l_seq = usize::try_from(buf.get_u32_le())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mate_reference_sequence_id = get_reference_sequence_id(&mut buf)?;
+        let mate_position = get_position(&mut buf)?;
+        let template_length = buf.get_i32_le();
+
+        let raw_read_name = split_off(&mut buf, l_read_name)?;
+
+        let read_name = match raw_read_name {
+            [b'*', NUL] | [NUL] => None,
+            [name @ .., NUL] => Some(name),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid read name terminator",
+                ))
+            }
+        };
+
+        let cigar = split_off(&mut buf, mem::size_of::<u32>() * n_cigar_op)?;
+
+        for chunk in cigar.chunks_exact(mem::size_of::<u32>()) {
+            decode_op(LittleEndian::read_u32(chunk))?;
+        }
+
+        let sequence = split_off(&mut buf, (l_seq + 1) / 2)?;
+        let mut quality_scores = split_off(&mut buf, l_seq)?;
+
+        if is_missing_quality_scores(quality_scores) {
+            quality_scores = &[];
+        } else if quality_scores
+            .iter()
+            .any(|&n| sam::record::quality_scores::Score::try_from(n).is_err())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid quality score",
+            ));
+        }
+
+        Ok(Self {
+            src,
+            reference_sequence_id,
+            position,
+            mapping_quality,
+            flags,
+            mate_reference_sequence_id,
+            mate_position,
+            template_length,
+            read_name,
+            cigar,
+            sequence,
+            sequence_len: l_seq,
+            quality_scores,
+            data: buf,
+        })
+    }
+
+    /// Returns an iterator over the borrowed records in the given buffer.
+    ///
+    /// The buffer is a decompressed stream of records, i.e., as stored in a BAM file after the
+    /// header and reference sequences. Each record is prefixed with its block size.
+    ///
+    /// The iterator stops after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::record::RecordRef;
+    ///
+    /// let data = [
+    ///     0x22, 0x00, 0x00, 0x00, // block_size = 34
+    ///     0xff, 0xff, 0xff, 0xff, // ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // pos = -1
+    ///     0x02, // l_read_name = 2
+    ///     0xff, // mapq = 255
+    ///     0x48, 0x12, // bin = 4680
+    ///     0x00, 0x00, // n_cigar_op = 0
+    ///     0x04, 0x00, // flag = 4
+    ///     0x00, 0x00, 0x00, 0x00, // l_seq = 0
+    ///     0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+    ///     0xff, 0xff, 0xff, 0xff, // next_pos = -1
+    ///     0x00, 0x00, 0x00, 0x00, // tlen = 0
+    ///     0x2a, 0x00, // read_name = "*\x00"
+    /// ];
+    ///
+    /// let records: Vec<_> = RecordRef::iter(&data).collect::<io::Result<_>>()?;
+    /// assert_eq!(records.len(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn iter(src: &'a [u8]) -> RecordRefs<'a> {
+        RecordRefs { src }
+    }
+
+    /// Returns the reference sequence ID of this record.
+    pub fn reference_sequence_id(&self) -> Option<usize> {
+        self.reference_sequence_id
+    }
+
+    /// Returns the start position of this record.
+    ///
+    /// Like [`Record::position`], this is normalized as a 1-based position.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Returns the mapping quality of this record.
+    pub fn mapping_quality(&self) -> Option<sam::record::MappingQuality> {
+        self.mapping_quality
+    }
+
+    /// Returns the flags of this record.
+    pub fn flags(&self) -> sam::record::Flags {
+        self.flags
+    }
+
+    /// Returns the reference sequence ID of the mate of this record.
+    pub fn mate_reference_sequence_id(&self) -> Option<usize> {
+        self.mate_reference_sequence_id
+    }
+
+    /// Returns the start position of the mate of this record.
+    pub fn mate_position(&self) -> Option<Position> {
+        self.mate_position
+    }
+
+    /// Returns the template length of this record.
+    pub fn template_length(&self) -> i32 {
+        self.template_length
+    }
+
+    /// Returns the read name of this record.
+    ///
+    /// The read name does not include the NUL terminator. A missing read name (`*`) is `None`.
+    pub fn read_name(&self) -> Option<&'a [u8]> {
+        self.read_name
+    }
+
+    /// Returns the number of CIGAR operations in this record.
+    pub fn cigar_len(&self) -> usize {
+        self.cigar.len() / mem::size_of::<u32>()
+    }
+
+    /// Returns an iterator over the CIGAR operations of this record.
+    pub fn cigar(&self) -> impl Iterator<Item = Op> + 'a {
+        use crate::reader::record::decode_op;
+
+        self.cigar
+            .chunks_exact(mem::size_of::<u32>())
+            // All ops are validated when the record is created.
+            .filter_map(|chunk| decode_op(LittleEndian::read_u32(chunk)).ok())
+    }
+
+    /// Returns the number of bases in the sequence of this record.
+    pub fn sequence_len(&self) -> usize {
+        self.sequence_len
+    }
+
+    /// Returns an iterator over the bases of the sequence of this record.
+    pub fn sequence(&self) -> impl Iterator<Item = Base> + 'a {
+        use crate::reader::record::decode_base;
+
+        self.sequence
+            .iter()
+            .flat_map(|&b| [decode_base(b >> 4), decode_base(b)])
+            .take(self.sequence_len)
+    }
+
+    /// Returns the raw quality scores of this record.
+    ///
+    /// Each score is a Phred quality score, i.e., it is not offset by 33. Missing quality scores
+    /// are empty.
+    pub fn quality_scores(&self) -> &'a [u8] {
+        self.quality_scores
+    }
+
+    /// Returns the raw data of this record.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl<'a> TryFrom<RecordRef<'a>> for Record {
+    type Error = io::Error;
+
+    fn try_from(record_ref: RecordRef<'a>) -> Result<Self, Self::Error> {
+        let mut record = Self::default();
+        decode_record(record_ref.src, &mut record)?;
+        Ok(record)
+    }
+}
+
+/// An iterator over borrowed records of a decompressed BAM record stream.
+///
+/// This is created by calling [`RecordRef::iter`].
+pub struct RecordRefs<'a> {
+    src: &'a [u8],
+}
+
+impl<'a> Iterator for RecordRefs<'a> {
+    type Item = io::Result<RecordRef<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.src.is_empty() {
+            return None;
+        }
+
+        match RecordRef::try_from_bytes(self.src) {
+            Ok((record, len)) => {
+                self.src = &self.src[len..];
+                Some(Ok(record))
+            }
+            Err(e) => {
+                self.src = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+fn split_off<'a>(src: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    let (buf, rest) = src.split_at(len);
+    *src = rest;
+
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DATA: [u8; 87] = [
+        0x2d, 0x00, 0x00, 0x00, // block_size = 45
+        0x00, 0x00, 0x00, 0x00, // ref_id = 0
+        0x07, 0x00, 0x00, 0x00, // pos = 7
+        0x03, // l_read_name = 3
+        0x0d, // mapq = 13
+        0x49, 0x12, // bin = 4681
+        0x01, 0x00, // n_cigar_op = 1
+        0x00, 0x00, // flag = 0
+        0x04, 0x00, 0x00, 0x00, // l_seq = 4
+        0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+        0xff, 0xff, 0xff, 0xff, // next_pos = -1
+        0x00, 0x00, 0x00, 0x00, // tlen = 0
+        b'r', b'0', 0x00, // read_name = "r0\x00"
+        0x40, 0x00, 0x00, 0x00, // cigar = 4M
+        0x12, 0x48, // seq = ACGT
+        0x2d, 0x23, 0x2b, 0x32, // qual = NDLS
+        0x22, 0x00, 0x00, 0x00, // block_size = 34
+        0xff, 0xff, 0xff, 0xff, // ref_id = -1
+        0xff, 0xff, 0xff, 0xff, // pos = -1
+        0x02, // l_read_name = 2
+        0xff, // mapq = 255
+        0x48, 0x12, // bin = 4680
+        0x00, 0x00, // n_cigar_op = 0
+        0x04, 0x00, // flag = 4
+        0x00, 0x00, 0x00, 0x00, // l_seq = 0
+        0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+        0xff, 0xff, 0xff, 0xff, // next_pos = -1
+        0x00, 0x00, 0x00, 0x00, // tlen = 0
+        0x2a, 0x00, // read_name = "*\x00"
+    ];
+
+    #[test]
+    fn test_try_from_bytes() -> io::Result<()> {
+        use sam::record::{cigar::op::Kind, Flags, MappingQuality};
+
+        let (record, len) = RecordRef::try_from_bytes(&DATA)?;
+
+        assert_eq!(len, 49);
+        assert_eq!(record.reference_sequence_id(), Some(0));
+        assert_eq!(record.position(), Position::new(8));
+        assert_eq!(record.mapping_quality(), MappingQuality::new(13));
+        assert_eq!(record.flags(), Flags::empty());
+        assert!(record.mate_reference_sequence_id().is_none());
+        assert!(record.mate_position().is_none());
+        assert_eq!(record.template_length(), 0);
+        assert_eq!(record.read_name(), Some(&b"r0"[..]));
+        assert_eq!(record.cigar_len(), 1);
+        assert!(record.cigar().eq([Op::new(Kind::Match, 4)]));
+        assert_eq!(record.sequence_len(), 4);
+        assert!(record.sequence().eq([Base::A, Base::C, Base::G, Base::T]));
+        assert_eq!(record.quality_scores(), [45, 35, 43, 50]);
+        assert!(record.data().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_bytes_with_invalid_read_name_terminator() {
+        let mut data = DATA;
+        data[38] = b'!';
+
+        assert!(matches!(
+            RecordRef::try_from_bytes(&data),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_iter() -> io::Result<()> {
+        use sam::AlignmentRecord;
+
+        let mut src = &DATA[..];
+        let mut records = RecordRef::iter(&DATA);
+
+        loop {
+            let (expected, len) = match Record::try_from_bytes(src) {
+                Ok(value) => value,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e),
+            };
+
+            src = &src[len..];
+
+            let actual = records.next().transpose()?.expect("missing record");
+
+            assert_eq!(
+                actual.reference_sequence_id(),
+                expected.reference_sequence_id()
+            );
+            assert_eq!(actual.position(), expected.position());
+            assert_eq!(actual.mapping_quality(), expected.mapping_quality());
+            assert_eq!(actual.flags(), expected.flags());
+            assert_eq!(
+                actual.read_name(),
+                expected.read_name().map(AsRef::<[u8]>::as_ref)
+            );
+            assert!(actual.cigar().eq(expected.cigar().iter().copied()));
+            assert!(actual
+                .sequence()
+                .eq(expected.sequence().as_ref().iter().copied()));
+            assert!(actual.quality_scores().iter().copied().eq(expected
+                .quality_scores()
+                .as_ref()
+                .iter()
+                .map(|&s| u8::from(s))));
+
+            assert_eq!(Record::try_from(actual)?, expected);
+        }
+
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+}
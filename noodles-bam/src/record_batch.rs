@@ -0,0 +1,285 @@
+//! BAM records as Arrow record batches.
+//!
+//! This requires the `arrow` feature, which has a minimum supported Rust version of 1.70.
+
+use std::sync::Arc;
+
+use arrow::{
+    array::{ArrayRef, Int32Array, StringArray, UInt16Array, UInt8Array},
+    datatypes::{DataType, Field, Schema, SchemaRef},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use noodles_sam::AlignmentRecord;
+
+use super::Record;
+
+const NAME: &str = "name";
+const FLAGS: &str = "flags";
+const REFERENCE_SEQUENCE_ID: &str = "ref_id";
+const POSITION: &str = "pos";
+const MAPPING_QUALITY: &str = "mapq";
+const CIGAR: &str = "cigar";
+const SEQUENCE: &str = "seq";
+const QUALITY_SCORES: &str = "qual";
+
+/// Returns the schema of all the columns supported by [`to_record_batch`].
+///
+/// The columns are
+///
+///   * `name` (utf8): the read name,
+///   * `flags` (uint16): the flags,
+///   * `ref_id` (int32): the reference sequence ID,
+///   * `pos` (int32): the 1-based position,
+///   * `mapq` (uint8): the mapping quality,
+///   * `cigar` (utf8): the CIGAR string,
+///   * `seq` (utf8): the sequence, and
+///   * `qual` (utf8): the quality scores, encoded as in SAM (Phred+33).
+///
+/// All columns except `flags` are nullable. A missing value is null.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::record_batch;
+/// let schema = record_batch::schema();
+/// assert_eq!(schema.fields().len(), 8);
+/// ```
+pub fn schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new(NAME, DataType::Utf8, true),
+        Field::new(FLAGS, DataType::UInt16, false),
+        Field::new(REFERENCE_SEQUENCE_ID, DataType::Int32, true),
+        Field::new(POSITION, DataType::Int32, true),
+        Field::new(MAPPING_QUALITY, DataType::UInt8, true),
+        Field::new(CIGAR, DataType::Utf8, true),
+        Field::new(SEQUENCE, DataType::Utf8, true),
+        Field::new(QUALITY_SCORES, DataType::Utf8, true),
+    ]))
+}
+
+/// Converts BAM records to an Arrow record batch.
+///
+/// Each row of the record batch is a record, and each column is a record field. The given schema
+/// selects the columns and their order. It must be [`schema`] or a projection of it.
+///
+/// # Errors
+///
+/// This returns an [`ArrowError::InvalidArgumentError`] if the schema has an unknown column or a
+/// column with an unexpected data type.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::{self as bam, record_batch};
+///
+/// let records = [bam::Record::default(), bam::Record::default()];
+/// let batch = bam::to_record_batch(&records, record_batch::schema())?;
+///
+/// assert_eq!(batch.num_rows(), 2);
+/// assert_eq!(batch.num_columns(), 8);
+/// # Ok::<_, arrow::error::ArrowError>(())
+/// ```
+pub fn to_record_batch<'a, I>(records: I, schema: SchemaRef) -> Result<RecordBatch, ArrowError>
+where
+    I: IntoIterator<Item = &'a Record>,
+{
+    let records: Vec<_> = records.into_iter().collect();
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| build_column(&records, field.name()))
+        .collect::<Result<_, _>>()?;
+
+    RecordBatch::try_new(schema, columns)
+}
+
+fn build_column(records: &[&Record], name: &str) -> Result<ArrayRef, ArrowError> {
+    let column: ArrayRef = match name {
+        NAME => Arc::new(
+            records
+                .iter()
+                .map(|record| record.read_name().map(|name| name.to_string()))
+                .collect::<StringArray>(),
+        ),
+        FLAGS => Arc::new(
+            records
+                .iter()
+                .map(|record| Some(u16::from(record.flags())))
+                .collect::<UInt16Array>(),
+        ),
+        REFERENCE_SEQUENCE_ID => Arc::new(
+            records
+                .iter()
+                .map(|record| {
+                    record
+                        .reference_sequence_id()
+                        .map(|id| i32::try_from(id).map_err(invalid_value))
+                        .transpose()
+                })
+                .collect::<Result<Int32Array, _>>()?,
+        ),
+        POSITION => Arc::new(
+            records
+                .iter()
+                .map(|record| {
+                    record
+                        .position()
+                        .map(|position| i32::try_from(usize::from(position)).map_err(invalid_value))
+                        .transpose()
+                })
+                .collect::<Result<Int32Array, _>>()?,
+        ),
+        MAPPING_QUALITY => Arc::new(
+            records
+                .iter()
+                .map(|record| record.mapping_quality().map(u8::from))
+                .collect::<UInt8Array>(),
+        ),
+        CIGAR => Arc::new(
+            records
+                .iter()
+                .map(|record| {
+                    let cigar = record.cigar();
+                    (!cigar.is_empty()).then(|| cigar.to_string())
+                })
+                .collect::<StringArray>(),
+        ),
+        SEQUENCE => Arc::new(
+            records
+                .iter()
+                .map(|record| {
+                    let sequence = record.sequence();
+                    (!sequence.is_empty()).then(|| sequence.to_string())
+                })
+                .collect::<StringArray>(),
+        ),
+        QUALITY_SCORES => Arc::new(
+            records
+                .iter()
+                .map(|record| {
+                    let quality_scores = record.quality_scores();
+                    (!quality_scores.is_empty()).then(|| quality_scores.to_string())
+                })
+                .collect::<StringArray>(),
+        ),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "invalid column: {}",
+                name
+            )))
+        }
+    };
+
+    Ok(column)
+}
+
+fn invalid_value<E>(e: E) -> ArrowError
+where
+    E: std::fmt::Display,
+{
+    ArrowError::InvalidArgumentError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use noodles_core::Position;
+    use noodles_sam::record::{Flags, MappingQuality};
+
+    use super::*;
+
+    #[test]
+    fn test_to_record_batch() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_position(Position::try_from(8)?)
+                .set_mapping_quality(MappingQuality::try_from(13)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            Record::default(),
+        ];
+
+        let batch = to_record_batch(&records, schema())?;
+
+        assert_eq!(batch.num_rows(), 2);
+
+        let column = |name| {
+            let (i, _) = batch.schema().column_with_name(name).unwrap();
+            batch.column(i).clone()
+        };
+
+        let names = column(NAME);
+        let names = names.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(names.value(0), "r0");
+        assert!(names.is_null(1));
+
+        let flags = column(FLAGS);
+        let flags = flags.as_any().downcast_ref::<UInt16Array>().unwrap();
+        assert_eq!(flags.value(0), 0);
+        assert_eq!(flags.value(1), 4);
+
+        let reference_sequence_ids = column(REFERENCE_SEQUENCE_ID);
+        let reference_sequence_ids = reference_sequence_ids
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(reference_sequence_ids.value(0), 1);
+        assert!(reference_sequence_ids.is_null(1));
+
+        let positions = column(POSITION);
+        let positions = positions.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(positions.value(0), 8);
+        assert!(positions.is_null(1));
+
+        let mapping_qualities = column(MAPPING_QUALITY);
+        let mapping_qualities = mapping_qualities
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .unwrap();
+        assert_eq!(mapping_qualities.value(0), 13);
+        assert!(mapping_qualities.is_null(1));
+
+        for (name, expected) in [(CIGAR, "4M"), (SEQUENCE, "ACGT"), (QUALITY_SCORES, "NDLS")] {
+            let values = column(name);
+            let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+            assert_eq!(values.value(0), expected);
+            assert!(values.is_null(1));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_record_batch_with_projection() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [Record::default()];
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(POSITION, DataType::Int32, true),
+            Field::new(FLAGS, DataType::UInt16, false),
+        ]));
+
+        let batch = to_record_batch(&records, schema)?;
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema().field(0).name(), POSITION);
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "noodles",
+            DataType::Utf8,
+            true,
+        )]));
+
+        assert!(matches!(
+            to_record_batch(&records, schema),
+            Err(ArrowError::InvalidArgumentError(_))
+        ));
+
+        Ok(())
+    }
+}
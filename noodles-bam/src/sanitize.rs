@@ -0,0 +1,253 @@
+//! BAM record sanitization.
+
+use std::{cmp::Ordering, fmt, io};
+
+use noodles_core::Position;
+
+use super::Record;
+
+/// An iterator adapter that validates the coordinate order of BAM records.
+///
+/// This is created by calling [`sanitize`].
+pub struct Sanitize<I> {
+    records: I,
+    prev_key: Option<Key>,
+    record_count: u64,
+    is_done: bool,
+}
+
+impl<I> Iterator for Sanitize<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(e) => {
+                self.is_done = true;
+                return Some(Err(e));
+            }
+        };
+
+        let key = Key::from(&record);
+
+        if let Some(prev_key) = self.prev_key {
+            if key < prev_key {
+                self.is_done = true;
+
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "record {} ({}) is out of order: expected >= record {} ({})",
+                        self.record_count,
+                        key,
+                        self.record_count - 1,
+                        prev_key
+                    ),
+                )));
+            }
+        }
+
+        self.prev_key = Some(key);
+        self.record_count += 1;
+
+        Some(Ok(record))
+    }
+}
+
+// A coordinate sort key. Unplaced records (`None`) sort after all placed records.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Key {
+    reference_sequence_id: Option<usize>,
+    position: Option<Position>,
+}
+
+impl Key {
+    fn sort_key(&self) -> (bool, Option<usize>, Option<Position>) {
+        (
+            self.reference_sequence_id.is_none(),
+            self.reference_sequence_id,
+            self.position,
+        )
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl From<&Record> for Key {
+    fn from(record: &Record) -> Self {
+        Self {
+            reference_sequence_id: record.reference_sequence_id(),
+            position: record.position(),
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.reference_sequence_id, self.position) {
+            (Some(id), Some(position)) => write!(f, "{}:{}", id, position),
+            (Some(id), None) => write!(f, "{}:*", id),
+            (None, _) => f.write_str("*"),
+        }
+    }
+}
+
+/// Returns an iterator that validates that BAM records are coordinate-sorted.
+///
+/// Records are expected to be ordered by reference sequence ID and then position, with unplaced
+/// records last. The iterator returns an [`io::ErrorKind::InvalidData`] error at the first record
+/// that is before its predecessor, after which it stops.
+///
+/// The BAM index bin (`bin`) of a record is not stored in a [`Record`]. It is discarded when a
+/// record is read and recomputed from the alignment start and end when the record is written.
+/// Writing the sanitized records (e.g., with [`crate::Writer::write_record`]) therefore repairs
+/// any stale bins of the input.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+///
+/// let records = vec![
+///     Ok(bam::Record::builder()
+///         .set_reference_sequence_id(0)
+///         .set_position(Position::try_from(8)?)
+///         .build()),
+///     Ok(bam::Record::builder()
+///         .set_reference_sequence_id(0)
+///         .set_position(Position::try_from(5)?)
+///         .build()),
+/// ];
+///
+/// let mut records = bam::sanitize(records.into_iter());
+/// assert!(records.next().transpose()?.is_some());
+/// assert!(records.next().transpose().is_err());
+/// assert!(records.next().is_none());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn sanitize<I>(records: I) -> Sanitize<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    Sanitize {
+        records,
+        prev_key: None,
+        record_count: 0,
+        is_done: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::record::encode_record;
+
+    fn build_record(reference_sequence_id: Option<usize>, position: Option<usize>) -> Record {
+        let mut record = Record::default();
+        *record.reference_sequence_id_mut() = reference_sequence_id;
+        *record.position_mut() = position.and_then(Position::new);
+        record
+    }
+
+    #[test]
+    fn test_sanitize() -> io::Result<()> {
+        let records = vec![
+            Ok(build_record(Some(0), Some(8))),
+            Ok(build_record(Some(0), Some(8))),
+            Ok(build_record(Some(1), Some(5))),
+            Ok(build_record(None, None)),
+        ];
+
+        let expected: Vec<_> = records.iter().flatten().cloned().collect();
+        let actual: Vec<_> = sanitize(records.into_iter()).collect::<io::Result<_>>()?;
+        assert_eq!(actual, expected);
+
+        let records = vec![
+            Ok(build_record(Some(0), Some(8))),
+            Ok(build_record(Some(1), Some(13))),
+            Ok(build_record(Some(1), Some(5))),
+            Ok(build_record(Some(2), Some(1))),
+        ];
+
+        let mut iter = sanitize(records.into_iter());
+        assert!(iter.next().transpose()?.is_some());
+        assert!(iter.next().transpose()?.is_some());
+
+        match iter.next() {
+            Some(Err(e)) => {
+                assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                assert_eq!(
+                    e.to_string(),
+                    "record 2 (1:5) is out of order: expected >= record 1 (1:13)"
+                );
+            }
+            _ => panic!("expected an error"),
+        }
+
+        assert!(iter.next().is_none());
+
+        let records = vec![
+            Ok(build_record(None, None)),
+            Ok(build_record(Some(0), Some(8))),
+        ];
+
+        let mut iter = sanitize(records.into_iter());
+        assert!(iter.next().transpose()?.is_some());
+        assert!(matches!(iter.next(), Some(Err(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sanitize_repairs_bins() -> io::Result<()> {
+        let data = [
+            0x27, 0x00, 0x00, 0x00, // block_size = 39
+            0x00, 0x00, 0x00, 0x00, // ref_id = 0
+            0x07, 0x00, 0x00, 0x00, // pos = 7
+            0x03, // l_read_name = 3
+            0x0d, // mapq = 13
+            0x00, 0x00, // bin = 0 (stale)
+            0x01, 0x00, // n_cigar_op = 1
+            0x00, 0x00, // flag = 0
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'r', b'0', 0x00, // read_name = "r0\x00"
+            0x40, 0x00, 0x00, 0x00, // cigar = 4M
+        ];
+
+        let (record, _) = Record::try_from_bytes(&data)?;
+
+        let mut records = sanitize(std::iter::once(Ok(record)));
+        let record = records.next().transpose()?.expect("missing record");
+
+        let mut buf = Vec::new();
+        encode_record(&mut buf, &record)?;
+
+        // bin = reg2bin(7, 11) = 4681
+        assert_eq!(&buf[10..12], [0x49, 0x12]);
+        assert_eq!(&buf[12..], &data[16..]);
+
+        Ok(())
+    }
+}
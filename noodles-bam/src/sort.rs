@@ -0,0 +1,230 @@
+//! BAM record sorting.
+
+use std::{cmp::Ordering, io};
+
+use noodles_sam::{record::Flags, AlignmentRecord};
+
+use super::Record;
+
+const MISSING_READ_NAME: &[u8] = b"*";
+
+/// Sorts records by read name (queryname order).
+///
+/// This consumes all records and sorts them in memory. The order is the same as `samtools sort
+/// -n`: records are compared by read name using a natural comparison (see [`compare_names`]),
+/// then by segment (first before last), and then by alignment type (primary before secondary
+/// before supplementary). Records that compare equal keep their input order.
+///
+/// # Errors
+///
+/// This returns the first error of the given records.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam::AlignmentRecord;
+///
+/// let records: Vec<io::Result<bam::Record>> = ["r10", "r2", "r1"]
+///     .iter()
+///     .map(|name| Ok(bam::Record::builder().set_read_name(name.parse().unwrap()).build()))
+///     .collect();
+///
+/// let records = bam::sort_by_name(records)?;
+///
+/// let names: Vec<_> = records
+///     .iter()
+///     .filter_map(|record| record.read_name().map(|name| name.to_string()))
+///     .collect();
+///
+/// assert_eq!(names, ["r1", "r2", "r10"]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn sort_by_name<I>(records: I) -> io::Result<Vec<Record>>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut records: Vec<_> = records.into_iter().collect::<io::Result<_>>()?;
+    records.sort_by(compare_records_by_name);
+    Ok(records)
+}
+
+/// Compares two read names using a natural order.
+///
+/// This is the comparison used by `samtools sort -n`. Names are compared byte by byte, except
+/// that runs of digits are compared by numeric value, ignoring leading zeros. For example, `r2`
+/// is ordered before `r10`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use noodles_bam::sort::compare_names;
+///
+/// assert_eq!(compare_names(b"r2", b"r10"), Ordering::Less);
+/// assert_eq!(compare_names(b"r10", b"r010"), Ordering::Equal);
+/// assert_eq!(compare_names(b"r1a", b"r1b"), Ordering::Less);
+/// ```
+pub fn compare_names(a: &[u8], b: &[u8]) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        if !a[i].is_ascii_digit() || !b[j].is_ascii_digit() {
+            if a[i] != b[j] {
+                return a[i].cmp(&b[j]);
+            }
+
+            i += 1;
+            j += 1;
+        } else {
+            while a.get(i) == Some(&b'0') {
+                i += 1;
+            }
+
+            while b.get(j) == Some(&b'0') {
+                j += 1;
+            }
+
+            while i < a.len() && j < b.len() && a[i].is_ascii_digit() && a[i] == b[j] {
+                i += 1;
+                j += 1;
+            }
+
+            // The first mismatch decides the order of numbers with the same number of digits.
+            let diff = a.get(i).cmp(&b.get(j));
+
+            while i < a.len() && j < b.len() && a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+                i += 1;
+                j += 1;
+            }
+
+            let a_has_digit = a.get(i).map(u8::is_ascii_digit).unwrap_or(false);
+            let b_has_digit = b.get(j).map(u8::is_ascii_digit).unwrap_or(false);
+
+            match (a_has_digit, b_has_digit) {
+                (true, _) => return Ordering::Greater,
+                (_, true) => return Ordering::Less,
+                _ => {
+                    if diff != Ordering::Equal {
+                        return diff;
+                    }
+                }
+            }
+        }
+    }
+
+    (a.len() - i).cmp(&(b.len() - j))
+}
+
+fn compare_records_by_name(a: &Record, b: &Record) -> Ordering {
+    compare_names(read_name(a), read_name(b))
+        .then_with(|| segment_key(a.flags()).cmp(&segment_key(b.flags())))
+        .then_with(|| alignment_type_key(a.flags()).cmp(&alignment_type_key(b.flags())))
+}
+
+fn read_name(record: &Record) -> &[u8] {
+    match record.read_name() {
+        Some(read_name) => read_name.as_ref(),
+        None => MISSING_READ_NAME,
+    }
+}
+
+fn segment_key(flags: Flags) -> u16 {
+    u16::from(flags & (Flags::FIRST_SEGMENT | Flags::LAST_SEGMENT))
+}
+
+fn alignment_type_key(flags: Flags) -> u16 {
+    u16::from(flags & (Flags::SECONDARY | Flags::SUPPLEMENTARY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_names() {
+        let mut names = vec![
+            "r10", "r9", "r1", "r01", "r2", "a", "r", "r1b", "r1a", "r1.10", "r1.9", "r0", "r00",
+            "r100", "s1", "R1",
+        ];
+
+        names.sort_by(|a, b| compare_names(a.as_bytes(), b.as_bytes()));
+
+        assert_eq!(
+            names,
+            [
+                "R1", "a", "r", "r0", "r00", "r1", "r01", "r1.9", "r1.10", "r1a", "r1b", "r2",
+                "r9", "r10", "r100", "s1",
+            ]
+        );
+
+        assert_eq!(compare_names(b"r19", b"r20"), Ordering::Less);
+        assert_eq!(compare_names(b"r29", b"r30"), Ordering::Less);
+        assert_eq!(compare_names(b"r007", b"r7"), Ordering::Equal);
+        assert_eq!(compare_names(b"r7", b"r7x"), Ordering::Less);
+        assert_eq!(compare_names(b"", b""), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_sort_by_name() -> Result<(), Box<dyn std::error::Error>> {
+        let first = Flags::SEGMENTED | Flags::FIRST_SEGMENT;
+        let last = Flags::SEGMENTED | Flags::LAST_SEGMENT;
+
+        let records = vec![
+            Record::builder()
+                .set_read_name("r10".parse()?)
+                .set_flags(last)
+                .build(),
+            Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(last | Flags::SUPPLEMENTARY)
+                .build(),
+            Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(last)
+                .build(),
+            Record::builder()
+                .set_read_name("r10".parse()?)
+                .set_flags(first)
+                .build(),
+            Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(first)
+                .build(),
+            Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(first | Flags::SECONDARY)
+                .build(),
+        ];
+
+        let actual: Vec<_> = sort_by_name(records.into_iter().map(Ok))?
+            .iter()
+            .map(|record| {
+                (
+                    record.read_name().map(|name| name.to_string()),
+                    record.flags(),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (Some(String::from("r2")), first),
+            (Some(String::from("r2")), first | Flags::SECONDARY),
+            (Some(String::from("r2")), last),
+            (Some(String::from("r2")), last | Flags::SUPPLEMENTARY),
+            (Some(String::from("r10")), first),
+            (Some(String::from("r10")), last),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let records = vec![
+            Ok(Record::default()),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+        ];
+        assert!(sort_by_name(records).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,643 @@
+//! BAM record statistics.
+
+use std::io;
+
+use noodles_sam::{self as sam, AlignmentRecord};
+
+/// Flag statistics counts of either QC-passed or QC-failed records.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Counts {
+    read: u64,
+    primary: u64,
+    secondary: u64,
+    supplementary: u64,
+    duplicate: u64,
+    primary_duplicate: u64,
+    mapped: u64,
+    primary_mapped: u64,
+    paired: u64,
+    read_1: u64,
+    read_2: u64,
+    proper_pair: u64,
+    mate_mapped: u64,
+    singleton: u64,
+    mate_reference_sequence_id_mismatch: u64,
+    mate_reference_sequence_id_mismatch_hq: u64,
+}
+
+impl Counts {
+    /// Returns the total number of records.
+    pub fn read(&self) -> u64 {
+        self.read
+    }
+
+    /// Returns the number of primary records.
+    pub fn primary(&self) -> u64 {
+        self.primary
+    }
+
+    /// Returns the number of secondary records.
+    pub fn secondary(&self) -> u64 {
+        self.secondary
+    }
+
+    /// Returns the number of supplementary records.
+    pub fn supplementary(&self) -> u64 {
+        self.supplementary
+    }
+
+    /// Returns the number of duplicate records.
+    pub fn duplicate(&self) -> u64 {
+        self.duplicate
+    }
+
+    /// Returns the number of primary duplicate records.
+    pub fn primary_duplicate(&self) -> u64 {
+        self.primary_duplicate
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped(&self) -> u64 {
+        self.mapped
+    }
+
+    /// Returns the number of primary mapped records.
+    pub fn primary_mapped(&self) -> u64 {
+        self.primary_mapped
+    }
+
+    /// Returns the number of primary records that are paired in sequencing.
+    pub fn paired(&self) -> u64 {
+        self.paired
+    }
+
+    /// Returns the number of primary paired records that are the first segment (read 1).
+    pub fn read_1(&self) -> u64 {
+        self.read_1
+    }
+
+    /// Returns the number of primary paired records that are the last segment (read 2).
+    pub fn read_2(&self) -> u64 {
+        self.read_2
+    }
+
+    /// Returns the number of primary paired records that are mapped and properly paired.
+    pub fn proper_pair(&self) -> u64 {
+        self.proper_pair
+    }
+
+    /// Returns the number of primary paired records where both the record and its mate are
+    /// mapped.
+    pub fn mate_mapped(&self) -> u64 {
+        self.mate_mapped
+    }
+
+    /// Returns the number of primary paired records that are mapped but whose mate is unmapped.
+    pub fn singleton(&self) -> u64 {
+        self.singleton
+    }
+
+    /// Returns the number of primary paired records where both the record and its mate are
+    /// mapped but to different reference sequences.
+    pub fn mate_reference_sequence_id_mismatch(&self) -> u64 {
+        self.mate_reference_sequence_id_mismatch
+    }
+
+    /// Returns the number of primary paired records where both the record and its mate are
+    /// mapped but to different reference sequences and the mapping quality is at least 5.
+    pub fn mate_reference_sequence_id_mismatch_hq(&self) -> u64 {
+        self.mate_reference_sequence_id_mismatch_hq
+    }
+
+    fn add<R>(
+        &mut self,
+        reference_sequences: &sam::header::ReferenceSequences,
+        record: &R,
+    ) -> io::Result<()>
+    where
+        R: AlignmentRecord,
+    {
+        let flags = record.flags();
+
+        self.read += 1;
+
+        if !flags.is_unmapped() {
+            self.mapped += 1;
+        }
+
+        if flags.is_duplicate() {
+            self.duplicate += 1;
+        }
+
+        if flags.is_secondary() {
+            self.secondary += 1;
+        } else if flags.is_supplementary() {
+            self.supplementary += 1;
+        } else {
+            self.primary += 1;
+
+            if !flags.is_unmapped() {
+                self.primary_mapped += 1;
+            }
+
+            if flags.is_duplicate() {
+                self.primary_duplicate += 1;
+            }
+
+            if flags.is_segmented() {
+                self.paired += 1;
+
+                if flags.is_first_segment() {
+                    self.read_1 += 1;
+                }
+
+                if flags.is_last_segment() {
+                    self.read_2 += 1;
+                }
+
+                if !flags.is_unmapped() {
+                    if flags.is_properly_aligned() {
+                        self.proper_pair += 1;
+                    }
+
+                    if flags.is_mate_unmapped() {
+                        self.singleton += 1;
+                    } else {
+                        self.mate_mapped += 1;
+
+                        let reference_sequence_name = record
+                            .reference_sequence(reference_sequences)
+                            .transpose()?
+                            .map(|rs| rs.name());
+
+                        let mate_reference_sequence_name = record
+                            .mate_reference_sequence(reference_sequences)
+                            .transpose()?
+                            .map(|rs| rs.name());
+
+                        if mate_reference_sequence_name != reference_sequence_name {
+                            self.mate_reference_sequence_id_mismatch += 1;
+
+                            let mapq = record
+                                .mapping_quality()
+                                .map(u8::from)
+                                .unwrap_or(sam::record::mapping_quality::MISSING);
+
+                            if mapq >= 5 {
+                                self.mate_reference_sequence_id_mismatch_hq += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Flag statistics of a list of records.
+///
+/// This holds the counts of QC-passed and QC-failed records separately.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FlagStats {
+    qc_pass: Counts,
+    qc_fail: Counts,
+}
+
+impl FlagStats {
+    /// Returns the counts of records that passed quality control.
+    pub fn qc_pass(&self) -> &Counts {
+        &self.qc_pass
+    }
+
+    /// Returns the counts of records that failed quality control (0x200).
+    pub fn qc_fail(&self) -> &Counts {
+        &self.qc_fail
+    }
+
+    /// Adds a record to the statistics.
+    ///
+    /// The reference sequences are used to compare the reference sequence of a record with that
+    /// of its mate.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the record or its mate has an invalid reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::{self as bam, stats::FlagStats};
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let mut stats = FlagStats::default();
+    /// stats.add(header.reference_sequences(), &bam::Record::default())?;
+    /// stats.add(header.reference_sequences(), &sam::Record::default())?;
+    ///
+    /// assert_eq!(stats.qc_pass().read(), 2);
+    /// assert_eq!(stats.qc_fail().read(), 0);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add<R>(
+        &mut self,
+        reference_sequences: &sam::header::ReferenceSequences,
+        record: &R,
+    ) -> io::Result<()>
+    where
+        R: AlignmentRecord,
+    {
+        if record.flags().is_qc_fail() {
+            self.qc_fail.add(reference_sequences, record)
+        } else {
+            self.qc_pass.add(reference_sequences, record)
+        }
+    }
+}
+
+/// Calculates flag statistics of records in a single pass.
+///
+/// The counts match the output of `samtools flagstat`. Records are split into QC-passed and
+/// QC-failed (0x200) counts.
+///
+/// The records can be of any [`AlignmentRecord`] type, e.g., SAM, BAM, or CRAM records. The
+/// reference sequences are used to compare the reference sequence of a record with that of its
+/// mate.
+///
+/// # Errors
+///
+/// This returns an error if a record fails to read or has an invalid reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_sam::{self as sam, record::Flags};
+///
+/// let header = sam::Header::default();
+///
+/// let records = vec![
+///     Ok(bam::Record::builder()
+///         .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_UNMAPPED)
+///         .build()),
+///     Ok(bam::Record::builder()
+///         .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::UNMAPPED)
+///         .build()),
+///     Ok(bam::Record::builder()
+///         .set_flags(Flags::UNMAPPED | Flags::QC_FAIL)
+///         .build()),
+/// ];
+///
+/// let stats = bam::flagstat(header.reference_sequences(), records)?;
+///
+/// assert_eq!(stats.qc_pass().read(), 2);
+/// assert_eq!(stats.qc_pass().paired(), 2);
+/// assert_eq!(stats.qc_pass().singleton(), 1);
+/// assert_eq!(stats.qc_fail().read(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn flagstat<I, R>(
+    reference_sequences: &sam::header::ReferenceSequences,
+    records: I,
+) -> io::Result<FlagStats>
+where
+    I: IntoIterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    let mut stats = FlagStats::default();
+
+    for result in records {
+        let record = result?;
+        stats.add(reference_sequences, &record)?;
+    }
+
+    Ok(stats)
+}
+
+/// Calculates the fraction of paired reads that are properly paired.
+///
+/// This is the same as the "properly paired" percentage of the QC-passed reads in
+/// `samtools flagstat`, as a fraction. Only primary, QC-passed reads are considered. The
+/// denominator is the number of these reads that are paired (0x01), and the numerator is the
+/// number of paired reads that are mapped and properly paired (0x02).
+///
+/// The records can be of any [`AlignmentRecord`] type and are read in a single pass. If there are
+/// no paired reads, this returns NaN.
+///
+/// # Errors
+///
+/// This returns an error if a record fails to read.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_sam::record::Flags;
+///
+/// let records = vec![
+///     Ok(bam::Record::builder()
+///         .set_flags(Flags::SEGMENTED | Flags::PROPERLY_ALIGNED)
+///         .build()),
+///     Ok(bam::Record::builder().set_flags(Flags::SEGMENTED).build()),
+///     Ok(bam::Record::builder().set_flags(Flags::empty()).build()),
+/// ];
+///
+/// assert_eq!(bam::stats::proper_pair_rate(records)?, 0.5);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn proper_pair_rate<I, R>(records: I) -> io::Result<f64>
+where
+    I: IntoIterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    let mut paired_count: u64 = 0;
+    let mut proper_pair_count: u64 = 0;
+
+    for result in records {
+        let record = result?;
+        let flags = record.flags();
+
+        if flags.is_secondary()
+            || flags.is_supplementary()
+            || flags.is_qc_fail()
+            || !flags.is_segmented()
+        {
+            continue;
+        }
+
+        paired_count += 1;
+
+        if !flags.is_unmapped() && flags.is_properly_aligned() {
+            proper_pair_count += 1;
+        }
+    }
+
+    Ok(proper_pair_count as f64 / paired_count as f64)
+}
+
+/// Calculates the histogram of mapping qualities (MAPQ) of records in a single pass.
+///
+/// The records can be of any [`AlignmentRecord`] type, e.g., SAM, BAM, or CRAM records.
+///
+/// The count at index _i_ is the number of records with a mapping quality of _i_. Every record is
+/// counted, e.g., unmapped and secondary records, so the counts sum to the number of records.
+///
+/// A missing mapping quality is stored as 255 (see [`sam::record::mapping_quality::MISSING`]), so
+/// the count at index 255 is the number of records with an unavailable mapping quality rather than
+/// a mapping quality of 255.
+///
+/// # Errors
+///
+/// This returns an error if a record fails to read.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_sam::record::MappingQuality;
+///
+/// let records = vec![
+///     Ok(bam::Record::builder()
+///         .set_mapping_quality(MappingQuality::try_from(60)?)
+///         .build()),
+///     Ok(bam::Record::builder()
+///         .set_mapping_quality(MappingQuality::try_from(60)?)
+///         .build()),
+///     Ok(bam::Record::default()),
+/// ];
+///
+/// let histogram = bam::stats::mapq_histogram(records)?;
+///
+/// assert_eq!(histogram[60], 2);
+/// assert_eq!(histogram[255], 1);
+/// assert_eq!(histogram.iter().sum::<u64>(), 3);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn mapq_histogram<I, R>(records: I) -> io::Result<[u64; 256]>
+where
+    I: IntoIterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    let mut histogram = [0; 256];
+
+    for result in records {
+        let record = result?;
+
+        let mapq = record
+            .mapping_quality()
+            .map(u8::from)
+            .unwrap_or(sam::record::mapping_quality::MISSING);
+
+        histogram[usize::from(mapq)] += 1;
+    }
+
+    Ok(histogram)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::Flags;
+
+    use super::*;
+    use crate::Record;
+
+    #[test]
+    fn test_flagstat() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use sam::{header::ReferenceSequence, record::MappingQuality};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .build();
+
+        fn build_mapped_record(
+            flags: Flags,
+            reference_sequence_id: usize,
+            mate_reference_sequence_id: Option<usize>,
+            mapping_quality: u8,
+        ) -> Result<Record, Box<dyn std::error::Error>> {
+            let mut builder = Record::builder()
+                .set_flags(flags)
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_position(Position::try_from(1)?);
+
+            if let Some(mapping_quality) = MappingQuality::new(mapping_quality) {
+                builder = builder.set_mapping_quality(mapping_quality);
+            }
+
+            if let Some(id) = mate_reference_sequence_id {
+                builder = builder
+                    .set_mate_reference_sequence_id(id)
+                    .set_mate_position(Position::try_from(1)?);
+            }
+
+            Ok(builder.build())
+        }
+
+        let paired = Flags::SEGMENTED | Flags::PROPERLY_ALIGNED;
+
+        let records = vec![
+            // properly paired, both mapped to sq0
+            Ok(build_mapped_record(
+                paired | Flags::FIRST_SEGMENT,
+                0,
+                Some(0),
+                60,
+            )?),
+            Ok(build_mapped_record(
+                paired | Flags::LAST_SEGMENT,
+                0,
+                Some(0),
+                60,
+            )?),
+            // mates mapped to different reference sequences
+            Ok(build_mapped_record(
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT,
+                0,
+                Some(1),
+                3,
+            )?),
+            Ok(build_mapped_record(
+                Flags::SEGMENTED | Flags::LAST_SEGMENT,
+                1,
+                Some(0),
+                30,
+            )?),
+            // singleton and its unmapped mate
+            Ok(build_mapped_record(
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_UNMAPPED,
+                0,
+                None,
+                60,
+            )?),
+            Ok(Record::builder()
+                .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::UNMAPPED)
+                .build()),
+            // secondary, supplementary, and duplicate
+            Ok(build_mapped_record(Flags::SECONDARY, 0, None, 0)?),
+            Ok(build_mapped_record(Flags::SUPPLEMENTARY, 0, None, 0)?),
+            Ok(build_mapped_record(Flags::DUPLICATE, 0, None, 60)?),
+            // QC-failed
+            Ok(Record::builder()
+                .set_flags(Flags::UNMAPPED | Flags::QC_FAIL)
+                .build()),
+            Ok(build_mapped_record(
+                Flags::QC_FAIL | Flags::DUPLICATE,
+                0,
+                None,
+                60,
+            )?),
+        ];
+
+        let stats = flagstat(header.reference_sequences(), records)?;
+
+        let qc_pass = stats.qc_pass();
+        assert_eq!(qc_pass.read(), 9);
+        assert_eq!(qc_pass.primary(), 7);
+        assert_eq!(qc_pass.secondary(), 1);
+        assert_eq!(qc_pass.supplementary(), 1);
+        assert_eq!(qc_pass.duplicate(), 1);
+        assert_eq!(qc_pass.primary_duplicate(), 1);
+        assert_eq!(qc_pass.mapped(), 8);
+        assert_eq!(qc_pass.primary_mapped(), 6);
+        assert_eq!(qc_pass.paired(), 6);
+        assert_eq!(qc_pass.read_1(), 3);
+        assert_eq!(qc_pass.read_2(), 3);
+        assert_eq!(qc_pass.proper_pair(), 2);
+        assert_eq!(qc_pass.mate_mapped(), 4);
+        assert_eq!(qc_pass.singleton(), 1);
+        assert_eq!(qc_pass.mate_reference_sequence_id_mismatch(), 2);
+        assert_eq!(qc_pass.mate_reference_sequence_id_mismatch_hq(), 1);
+
+        let qc_fail = stats.qc_fail();
+        assert_eq!(qc_fail.read(), 2);
+        assert_eq!(qc_fail.primary(), 2);
+        assert_eq!(qc_fail.duplicate(), 1);
+        assert_eq!(qc_fail.primary_duplicate(), 1);
+        assert_eq!(qc_fail.mapped(), 1);
+        assert_eq!(qc_fail.primary_mapped(), 1);
+        assert_eq!(qc_fail.paired(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_proper_pair_rate() -> io::Result<()> {
+        let flags = [
+            // properly paired
+            Flags::SEGMENTED | Flags::PROPERLY_ALIGNED,
+            Flags::SEGMENTED | Flags::PROPERLY_ALIGNED | Flags::FIRST_SEGMENT,
+            // paired
+            Flags::SEGMENTED,
+            Flags::SEGMENTED | Flags::PROPERLY_ALIGNED | Flags::UNMAPPED,
+            // unpaired
+            Flags::empty(),
+            Flags::PROPERLY_ALIGNED,
+            // not primary or QC-failed
+            Flags::SEGMENTED | Flags::PROPERLY_ALIGNED | Flags::SECONDARY,
+            Flags::SEGMENTED | Flags::PROPERLY_ALIGNED | Flags::SUPPLEMENTARY,
+            Flags::SEGMENTED | Flags::QC_FAIL,
+        ];
+
+        let records = flags
+            .iter()
+            .map(|&flags| Ok(Record::builder().set_flags(flags).build()));
+
+        assert_eq!(proper_pair_rate(records)?, 0.5);
+
+        let records = vec![Ok(Record::builder().set_flags(Flags::empty()).build())];
+        assert!(proper_pair_rate(records)?.is_nan());
+
+        let records: Vec<io::Result<Record>> =
+            vec![Err(io::Error::from(io::ErrorKind::InvalidData))];
+        assert!(proper_pair_rate(records).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mapq_histogram() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::MappingQuality;
+
+        let records = vec![
+            Ok(Record::builder()
+                .set_mapping_quality(MappingQuality::try_from(0)?)
+                .build()),
+            Ok(Record::builder()
+                .set_mapping_quality(MappingQuality::try_from(60)?)
+                .build()),
+            Ok(Record::default()),
+            Ok(Record::builder()
+                .set_mapping_quality(MappingQuality::try_from(254)?)
+                .build()),
+            Ok(Record::builder()
+                .set_mapping_quality(MappingQuality::try_from(60)?)
+                .build()),
+            Ok(Record::builder()
+                .set_mapping_quality(MappingQuality::try_from(0)?)
+                .build()),
+            Ok(Record::builder()
+                .set_mapping_quality(MappingQuality::try_from(60)?)
+                .build()),
+        ];
+        let record_count = records.len() as u64;
+
+        let histogram = mapq_histogram(records)?;
+
+        assert_eq!(histogram.iter().sum::<u64>(), record_count);
+        assert_eq!(histogram[0], 2);
+        assert_eq!(histogram[60], 3);
+        assert_eq!(histogram[254], 1);
+        assert_eq!(histogram[255], 1);
+        assert_eq!(histogram.iter().filter(|&&n| n > 0).count(), 4);
+
+        let records: Vec<io::Result<Record>> =
+            vec![Err(io::Error::from(io::ErrorKind::InvalidData))];
+        assert!(mapq_histogram(records).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+//! BAM strand-specific coverage.
+
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+use noodles_sam::{
+    header::ReferenceSequences,
+    record::{cigar::op::Kind, data::field::Tag},
+    AlignmentRecord,
+};
+
+use super::{
+    coverage::Mask,
+    coverage_stats::{accumulate, is_counted, resolve_region_interval},
+    Reader, Record,
+};
+
+/// Depths of coverage split by strand.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StrandedCoverage {
+    forward: Vec<u64>,
+    reverse: Vec<u64>,
+}
+
+impl StrandedCoverage {
+    /// Returns the depths of coverage of records on the forward strand.
+    pub fn forward(&self) -> &[u64] {
+        &self.forward
+    }
+
+    /// Returns the depths of coverage of records on the reverse strand.
+    pub fn reverse(&self) -> &[u64] {
+        &self.reverse
+    }
+
+    /// Returns the total depths of coverage, i.e., the sums of the forward and reverse depths.
+    pub fn total(&self) -> Vec<u64> {
+        self.forward
+            .iter()
+            .zip(&self.reverse)
+            .map(|(f, r)| f + r)
+            .collect()
+    }
+}
+
+/// Calculates the depth of coverage of each position in a region, split by strand.
+///
+/// The strand of a record is taken from, in order of precedence:
+///
+///   1. the `XS` tag (`+` or `-`), the transcript strand of spliced alignments set by, e.g.,
+///      HISAT2 and STAR;
+///   2. the `ts` tag (`+` or `-`), the transcript strand relative to the read set by minimap2,
+///      which is flipped for reverse complemented records; and
+///   3. the reverse complemented flag (`0x10`).
+///
+/// Tags with other values are ignored.
+///
+/// Depth is calculated the same as [`crate::region_coverage_stats`], i.e., unmapped, secondary,
+/// QC fail, and duplicate records are excluded, and deletions are counted as covered. The forward
+/// and reverse depths at each position sum to the total depth.
+///
+/// Positions in `mask_regions` (e.g., a blacklist read from an exclude BED) have a forward and
+/// reverse depth of 0.
+///
+/// The region is clamped to the length of its reference sequence. The returned depths start at the
+/// start of the region.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::fs::File;
+/// use noodles_bam::{self as bam, bai};
+/// use noodles_sam as sam;
+///
+/// let mut reader = File::open("sample.bam").map(bam::Reader::new)?;
+/// let header: sam::Header = reader.read_header()?.parse()?;
+/// reader.read_reference_sequences()?;
+///
+/// let reference_sequences = header.reference_sequences();
+/// let index = bai::read("sample.bam.bai")?;
+/// let region = "sq0:8-13".parse()?;
+/// let coverage = bam::stranded_coverage(&mut reader, reference_sequences, &index, &region, &[])?;
+///
+/// for (forward, reverse) in coverage.forward().iter().zip(coverage.reverse()) {
+///     println!("{}\t{}", forward, reverse);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn stranded_coverage<R, I, RS>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    reference_sequences: &ReferenceSequences,
+    index: &I,
+    region: &Region,
+    mask_regions: &[Region],
+) -> io::Result<StrandedCoverage>
+where
+    R: Read + Seek,
+    I: BinningIndex<RS>,
+    RS: ReferenceSequenceExt,
+{
+    let (reference_sequence_id, start, end) = resolve_region_interval(reference_sequences, region)?;
+
+    if start > end {
+        return Ok(StrandedCoverage::default());
+    }
+
+    // Depth changes keyed by offset from the region start.
+    let mut forward_deltas = vec![0i64; end - start + 2];
+    let mut reverse_deltas = vec![0i64; end - start + 2];
+
+    for result in reader.query(reference_sequences, index, region)? {
+        let record = result?;
+
+        if !is_counted(record.flags()) {
+            continue;
+        }
+
+        let mut position = match record.alignment_start() {
+            Some(alignment_start) => usize::from(alignment_start),
+            None => continue,
+        };
+
+        let deltas = if is_reverse_strand(&record) {
+            &mut reverse_deltas
+        } else {
+            &mut forward_deltas
+        };
+
+        for op in record.cigar().iter() {
+            match op.kind() {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch | Kind::Deletion => {
+                    let op_end = position + op.len() - 1;
+
+                    if position <= end && op_end >= start {
+                        deltas[position.max(start) - start] += 1;
+                        deltas[op_end.min(end) - start + 1] -= 1;
+                    }
+
+                    position += op.len();
+                }
+                Kind::Skip => position += op.len(),
+                Kind::Insertion | Kind::SoftClip | Kind::HardClip | Kind::Pad => {}
+                Kind::Back => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "unsupported CIGAR operation: B",
+                    ))
+                }
+            }
+        }
+    }
+
+    let mut forward = accumulate(&forward_deltas);
+    let mut reverse = accumulate(&reverse_deltas);
+
+    let mask = Mask::new(reference_sequences, mask_regions);
+    mask.apply(reference_sequence_id, start, &mut forward);
+    mask.apply(reference_sequence_id, start, &mut reverse);
+
+    Ok(StrandedCoverage { forward, reverse })
+}
+
+fn is_reverse_strand(record: &Record) -> bool {
+    let is_reverse_complemented = record.flags().is_reverse_complemented();
+
+    // SAFETY: "XS" and "ts" are valid tags.
+    let xs = Tag::try_from(*b"XS").unwrap();
+    let ts = Tag::try_from(*b"ts").unwrap();
+
+    if let Some(is_reverse) = get_strand(record, xs) {
+        is_reverse
+    } else if let Some(is_reverse) = get_strand(record, ts) {
+        is_reverse != is_reverse_complemented
+    } else {
+        is_reverse_complemented
+    }
+}
+
+// Returns whether the strand in the given tag is reverse (`-`).
+fn get_strand(record: &Record, tag: Tag) -> Option<bool> {
+    let field = record.data().get(tag)?;
+
+    match field.value().as_char() {
+        Some('+') => Some(false),
+        Some('-') => Some(true),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+    use noodles_sam as sam;
+
+    use super::*;
+    use crate::test_helpers::build_bam_and_index;
+
+    #[test]
+    fn test_stranded_coverage() -> Result<(), Box<dyn std::error::Error>> {
+        let sam_data = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:12
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t16\tsq0\t3\t60\t4M\t*\t0\t0\tACGT\tNDLS
+r2\t16\tsq0\t3\t60\t2M4N2M\t*\t0\t0\tACGT\tNDLS\tXS:A:+
+r3\t0\tsq0\t5\t60\t2M1D2M\t*\t0\t0\tACGT\tNDLS\tts:A:-
+r4\t16\tsq0\t9\t60\t4M\t*\t0\t0\tACGT\tNDLS\tts:A:-
+r5\t1024\tsq0\t9\t60\t4M\t*\t0\t0\tACGT\tNDLS
+";
+
+        let (data, index) = build_bam_and_index(sam_data)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        let header: sam::Header = reader.read_header()?.parse()?;
+        reader.read_reference_sequences()?;
+        let reference_sequences = header.reference_sequences();
+
+        // r0 (forward) covers 1-4; r1 (reverse) covers 3-6; r2 (forward by XS) covers 3-4, 9-10;
+        // r3 (reverse by ts) covers 5-9; r4 (forward by ts) covers 9-12; and r5 is a duplicate.
+        let region = Region::new("sq0", ..);
+        let coverage = stranded_coverage(&mut reader, reference_sequences, &index, &region, &[])?;
+        assert_eq!(coverage.forward(), [1, 1, 2, 2, 0, 0, 0, 0, 2, 2, 1, 1]);
+        assert_eq!(coverage.reverse(), [0, 0, 1, 1, 2, 2, 1, 1, 1, 0, 0, 0]);
+        assert_eq!(coverage.total(), [1, 1, 3, 3, 2, 2, 1, 1, 3, 2, 1, 1]);
+
+        let mask_regions = [Region::new(
+            "sq0",
+            Position::try_from(4)?..=Position::try_from(9)?,
+        )];
+        let coverage = stranded_coverage(
+            &mut reader,
+            reference_sequences,
+            &index,
+            &region,
+            &mask_regions,
+        )?;
+        assert_eq!(coverage.forward(), [1, 1, 2, 0, 0, 0, 0, 0, 0, 2, 1, 1]);
+        assert_eq!(coverage.reverse(), [0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let region = Region::new("sq0", Position::try_from(4)?..=Position::try_from(6)?);
+        let coverage = stranded_coverage(&mut reader, reference_sequences, &index, &region, &[])?;
+        assert_eq!(coverage.forward(), [2, 0, 0]);
+        assert_eq!(coverage.reverse(), [1, 2, 2]);
+
+        let region = Region::new("sq1", ..);
+        assert!(matches!(
+            stranded_coverage(&mut reader, reference_sequences, &index, &region, &[]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+//! Concatenated BAM streams.
+
+use std::io::{self, BufRead, Read};
+
+use noodles_bgzf as bgzf;
+
+use super::MAGIC_NUMBER;
+
+/// Returns the compressed offsets of each BAM stream in a concatenation of BAM files.
+///
+/// A BAM file ends with a BGZF EOF marker, i.e., an empty block. A stream is detected at the
+/// start of the input and after each empty block when the uncompressed data of the next
+/// nonempty block starts with the BAM magic number (`BAM\x01`).
+///
+/// The given reader must be a raw BGZF stream and be at the start of the input. All blocks are
+/// decompressed, but no records are decoded.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+///
+/// let offsets = File::open("concatenated.bam").and_then(bam::stream_offsets)?;
+///
+/// for offset in offsets {
+///     println!("{}", offset);
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn stream_offsets<R>(reader: R) -> io::Result<Vec<u64>>
+where
+    R: Read,
+{
+    let mut reader = bgzf::Reader::new(reader);
+    let mut offsets = Vec::new();
+    let mut is_at_stream_boundary = true;
+
+    loop {
+        let prev_position = reader.position();
+        let buf = reader.fill_buf()?;
+
+        if buf.is_empty() {
+            // An empty buffer is either an empty block (e.g., an EOF marker) or the end of the
+            // input, in which case no block was read.
+            if reader.position() == prev_position {
+                break;
+            }
+
+            is_at_stream_boundary = true;
+
+            continue;
+        }
+
+        let len = buf.len();
+
+        if is_at_stream_boundary {
+            if buf.starts_with(MAGIC_NUMBER) {
+                offsets.push(reader.virtual_position().compressed());
+            }
+
+            is_at_stream_boundary = false;
+        }
+
+        reader.consume(len);
+    }
+
+    Ok(offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::build_bam;
+
+    #[test]
+    fn test_stream_offsets() -> Result<(), Box<dyn std::error::Error>> {
+        let a = build_bam(
+            b"@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:8
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS
+",
+        )?;
+
+        let b = build_bam(
+            b"@HD\tVN:1.6
+@SQ\tSN:sq1\tLN:13
+r1\t4\t*\t0\t0\t*\t*\t0\t0\tACGT\tNDLS
+",
+        )?;
+
+        assert_eq!(stream_offsets(&a[..])?, [0]);
+
+        let data = [&a[..], &b[..]].concat();
+        assert_eq!(stream_offsets(&data[..])?, [0, a.len() as u64]);
+
+        let data = [&a[..], &b[..], &a[..]].concat();
+        assert_eq!(
+            stream_offsets(&data[..])?,
+            [0, a.len() as u64, (a.len() + b.len()) as u64]
+        );
+
+        assert!(stream_offsets(&[][..])?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_offsets_with_non_bam_data() -> io::Result<()> {
+        use std::io::Write;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?;
+
+        assert!(stream_offsets(&data[..])?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+//! Shared test helpers.
+
+use noodles_core::Position;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_sam::{self as sam, AlignmentWriter};
+
+use crate::{bai, Reader, Record, Writer};
+
+/// Converts a SAM to a BAM.
+pub(crate) fn build_bam(data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut reader = sam::Reader::new(data);
+    let header: sam::Header = reader.read_header()?.parse()?;
+
+    let mut writer = Writer::new(Vec::new());
+    writer.write_alignment_header(&header)?;
+
+    for result in reader.records() {
+        let record = result?;
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    Ok(writer.into_inner().finish()?)
+}
+
+/// Builds a BAM index from the records of a BAM.
+pub(crate) fn build_index(data: &[u8]) -> Result<bai::Index, Box<dyn std::error::Error>> {
+    let mut reader = Reader::new(data);
+    reader.read_header()?;
+    let reference_sequences = reader.read_reference_sequences()?;
+
+    let mut builder = bai::Index::builder();
+    let mut record = Record::default();
+    let mut start_position = reader.virtual_position();
+
+    while reader.read_record(&mut record)? != 0 {
+        let end_position = reader.virtual_position();
+        builder.add_record(&record, Chunk::new(start_position, end_position))?;
+        start_position = end_position;
+    }
+
+    Ok(builder.build(reference_sequences.len()))
+}
+
+/// Converts a coordinate-sorted SAM to a BAM and builds its index.
+pub(crate) fn build_bam_and_index(
+    data: &[u8],
+) -> Result<(Vec<u8>, bai::Index), Box<dyn std::error::Error>> {
+    let bam_data = build_bam(data)?;
+    let index = build_index(&bam_data)?;
+    Ok((bam_data, index))
+}
+
+/// Builds a mapped record with no flags set.
+pub(crate) fn build_record(
+    reference_sequence_id: usize,
+    position: usize,
+    cigar: &str,
+) -> Result<Record, Box<dyn std::error::Error>> {
+    Ok(Record::builder()
+        .set_flags(sam::record::Flags::empty())
+        .set_reference_sequence_id(reference_sequence_id)
+        .set_position(Position::try_from(position)?)
+        .set_cigar(cigar.parse()?)
+        .build())
+}
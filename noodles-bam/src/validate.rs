@@ -0,0 +1,183 @@
+use std::io;
+
+use noodles_csi::BinningIndex;
+use noodles_sam as sam;
+
+use super::bai::{self, index::reference_sequence::bin::Bin};
+
+const WINDOW_SIZE: usize = 1 << bai::index::MIN_SHIFT;
+
+/// Validates that a BAM index is plausibly associated with a SAM header.
+///
+/// The reference sequences in a BAM index are parallel to the reference sequences in the
+/// associated BAM header. This checks that the number of reference sequences in the index and
+/// header match and that no indexed reference sequence has bins or linear index windows past the
+/// end of the corresponding header reference sequence.
+///
+/// This catches stale indexes, e.g., an index built from a BAM with a different set or order of
+/// reference sequences, before a query returns incorrect results.
+///
+/// # Errors
+///
+/// This returns an [`io::ErrorKind::InvalidData`] error if the index is inconsistent with the
+/// header.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam::{self as bam, bai};
+/// use noodles_sam::{self as sam, header::ReferenceSequence};
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+///     .build();
+///
+/// let index = bai::Index::new(vec![bai::index::ReferenceSequence::default()], None);
+/// assert!(bam::validate_index(&header, &index).is_ok());
+///
+/// let index = bai::Index::default();
+/// assert!(bam::validate_index(&header, &index).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate_index(header: &sam::Header, index: &bai::Index) -> io::Result<()> {
+    let header_reference_sequences = header.reference_sequences();
+    let index_reference_sequences = index.reference_sequences();
+
+    if index_reference_sequences.len() != header_reference_sequences.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "reference sequence count mismatch: header has {}, index has {}",
+                header_reference_sequences.len(),
+                index_reference_sequences.len()
+            ),
+        ));
+    }
+
+    for (header_reference_sequence, index_reference_sequence) in header_reference_sequences
+        .values()
+        .zip(index_reference_sequences)
+    {
+        let len = usize::try_from(header_reference_sequence.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let max_window_count = (len + WINDOW_SIZE - 1) / WINDOW_SIZE;
+        let has_invalid_window = index_reference_sequence.intervals().len() > max_window_count;
+
+        let has_invalid_bin = index_reference_sequence
+            .bins()
+            .iter()
+            .map(Bin::start)
+            .any(|start| start >= len);
+
+        if has_invalid_window || has_invalid_bin {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "index for reference sequence {} extends past its length ({})",
+                    header_reference_sequence.name(),
+                    len
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_sam::header::ReferenceSequence;
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 1 << 16)?)
+            .build())
+    }
+
+    #[test]
+    fn test_validate_index() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let index = bai::Index::new(
+            vec![
+                bai::index::ReferenceSequence::new(
+                    vec![Bin::new(4681, Vec::new())],
+                    vec![bgzf::VirtualPosition::default()],
+                    None,
+                ),
+                bai::index::ReferenceSequence::new(
+                    vec![Bin::new(4684, Vec::new())],
+                    vec![bgzf::VirtualPosition::default(); 4],
+                    None,
+                ),
+            ],
+            None,
+        );
+
+        assert!(validate_index(&header, &index).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_index_with_reference_sequence_count_mismatch(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let index = bai::Index::new(vec![bai::index::ReferenceSequence::default()], None);
+
+        assert!(matches!(
+            validate_index(&header, &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_index_with_out_of_range_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let index = bai::Index::new(
+            vec![
+                bai::index::ReferenceSequence::new(
+                    vec![Bin::new(4682, Vec::new())],
+                    Vec::new(),
+                    None,
+                ),
+                bai::index::ReferenceSequence::default(),
+            ],
+            None,
+        );
+
+        assert!(matches!(
+            validate_index(&header, &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let index = bai::Index::new(
+            vec![
+                bai::index::ReferenceSequence::new(
+                    Vec::new(),
+                    vec![bgzf::VirtualPosition::default(); 2],
+                    None,
+                ),
+                bai::index::ReferenceSequence::default(),
+            ],
+            None,
+        );
+
+        assert!(matches!(
+            validate_index(&header, &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
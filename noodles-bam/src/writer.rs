@@ -1,11 +1,17 @@
 //! BAM writer.
 
 pub(crate) mod alignment_record;
+mod indexed_writer;
 pub mod record;
 
+pub use self::indexed_writer::IndexedWriter;
+
 use std::{
     ffi::CString,
-    io::{self, Write},
+    fs::File,
+    io::{self, Read, Seek, Write},
+    iter,
+    path::Path,
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -15,7 +21,10 @@ use noodles_sam::{
     header::{ReferenceSequence, ReferenceSequences},
 };
 
-use self::{alignment_record::encode_alignment_record, record::encode_record};
+use self::{
+    alignment_record::{encode_alignment_record, encode_sam_record, ReferenceSequenceIdCache},
+    record::encode_record,
+};
 use super::Record;
 
 /// A BAM writer.
@@ -43,6 +52,7 @@ use super::Record;
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    reference_sequence_id_cache: ReferenceSequenceIdCache,
 }
 
 impl<W> Writer<W>
@@ -187,7 +197,12 @@ where
         reference_sequences: &ReferenceSequences,
         record: &sam::Record,
     ) -> io::Result<()> {
-        encode_alignment_record(&mut self.buf, reference_sequences, record)?;
+        encode_sam_record(
+            &mut self.buf,
+            reference_sequences,
+            &mut self.reference_sequence_id_cache,
+            record,
+        )?;
 
         let block_size = u32::try_from(self.buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -238,15 +253,143 @@ where
     }
 }
 
+impl<W> Writer<bgzf::Writer<W>>
+where
+    W: Read + Write + Seek,
+{
+    /// Creates a BAM writer that appends records to an existing BAM stream.
+    ///
+    /// The stream must be a complete BAM, i.e., it must end with a BGZF EOF block. The writer is
+    /// positioned at the start of this block, which is then overwritten by new records. A new EOF
+    /// block is written when the writer is finished.
+    ///
+    /// The header and reference sequences must not be written again.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`io::ErrorKind::InvalidData`] error if the stream does not end with a BGZF
+    /// EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let mut writer = bam::Writer::new(Vec::new());
+    /// writer.write_header(&header)?;
+    /// writer.write_reference_sequences(header.reference_sequences())?;
+    /// writer.write_record(&bam::Record::default())?;
+    /// let data = writer.into_inner().finish()?;
+    ///
+    /// let mut writer = bam::Writer::append(Cursor::new(data))?;
+    /// writer.write_record(&bam::Record::default())?;
+    /// let data = writer.into_inner().finish()?.into_inner();
+    ///
+    /// let mut reader = bam::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    /// reader.read_reference_sequences()?;
+    /// assert_eq!(reader.records().count(), 2);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn append(inner: W) -> io::Result<Self> {
+        bgzf::Writer::append(inner).map(Self::from)
+    }
+}
+
 impl<W> From<W> for Writer<W> {
     fn from(inner: W) -> Self {
         Self {
             inner,
             buf: Vec::new(),
+            reference_sequence_id_cache: ReferenceSequenceIdCache::default(),
         }
     }
 }
 
+/// Creates a BAM file and writes a header, reference sequences, and records to it.
+///
+/// This is a convenience function for the common case of creating a BAM writer for a file,
+/// writing the header and its reference sequences, writing each record, and finishing the stream
+/// with a BGZF EOF block. The first error, either from the records or when writing, stops the
+/// write and is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let records = vec![Ok(bam::Record::default())];
+///
+/// bam::write("out.bam", &header, records)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write<P, I>(dst: P, header: &sam::Header, records: I) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut writer = File::create(dst).map(Writer::new)?;
+    write_all(&mut writer, header, records)?;
+    writer.into_inner().finish()?;
+    Ok(())
+}
+
+/// Writes an empty BAM.
+///
+/// An empty BAM has a header, the reference sequences of the header, no records, and a BGZF EOF
+/// block.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::builder().add_comment("noodles-bam").build();
+///
+/// let mut buf = Vec::new();
+/// bam::write_empty(&mut buf, &header)?;
+///
+/// let mut reader = bam::Reader::new(&buf[..]);
+/// assert_eq!(reader.read_header()?, header.to_string());
+/// reader.read_reference_sequences()?;
+/// assert_eq!(reader.records().count(), 0);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_empty<W>(inner: W, header: &sam::Header) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut writer = Writer::new(inner);
+    write_all(&mut writer, header, iter::empty())?;
+    writer.into_inner().finish()?;
+    Ok(())
+}
+
+fn write_all<W, I>(writer: &mut Writer<W>, header: &sam::Header, records: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    writer.write_header(header)?;
+    writer.write_reference_sequences(header.reference_sequences())?;
+
+    for result in records {
+        let record = result?;
+        writer.write_record(&record)?;
+    }
+
+    Ok(())
+}
+
 impl<W> sam::AlignmentWriter for Writer<W>
 where
     W: Write,
@@ -262,7 +405,12 @@ where
         header: &sam::Header,
         record: &dyn sam::AlignmentRecord,
     ) -> io::Result<()> {
-        encode_alignment_record(&mut self.buf, header.reference_sequences(), record)?;
+        encode_alignment_record(
+            &mut self.buf,
+            header.reference_sequences(),
+            &mut self.reference_sequence_id_cache,
+            record,
+        )?;
 
         let block_size = u32::try_from(self.buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -395,6 +543,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_all() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build();
+
+        let records = vec![Ok(Record::default()), Ok(Record::default())];
+
+        let mut writer = Writer::new(Vec::new());
+        write_all(&mut writer, &header, records)?;
+        let actual = writer.into_inner().finish()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+        writer.write_record(&Record::default())?;
+        writer.write_record(&Record::default())?;
+        let expected = writer.into_inner().finish()?;
+
+        assert_eq!(actual, expected);
+
+        let records = vec![
+            Ok(Record::default()),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            write_all(&mut writer, &header, records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
         let mut writer = Writer::new(Vec::new());
@@ -425,6 +609,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_append() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+        let record = sam::Record::builder().set_read_name("r0".parse()?).build();
+        writer.write_alignment_record(&header, &record)?;
+        let data = writer.into_inner().finish()?;
+
+        let mut writer = Writer::append(Cursor::new(data))?;
+        let record = sam::Record::builder().set_read_name("r1".parse()?).build();
+        writer.write_alignment_record(&header, &record)?;
+        let data = writer.into_inner().finish()?.into_inner();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let read_names = reader
+            .records()
+            .map(|result| result.map(|record| record.read_name().map(|name| name.to_string())))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(
+            read_names,
+            [Some(String::from("r0")), Some(String::from("r1"))]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_alignment_record_with_sequence_length_less_than_quality_scores_length(
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -493,6 +711,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_alignment_record_with_sequence_and_no_quality_scores_round_trip(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+        let sam_record: sam::Record = "r0\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\t*".parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &sam_record)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+
+        let actual = record.try_into_sam_record(header.reference_sequences())?;
+        assert_eq!(actual.to_string(), sam_record.to_string());
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_alignment_record_with_sequence_and_quality_scores(
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -11,22 +11,100 @@ use super::record::{
 // § 4.2.3 SEQ and QUAL encoding (2021-06-03)
 pub(crate) const NULL_QUALITY_SCORE: u8 = 255;
 
+// A cache of the last resolved reference sequence ID.
+//
+// Records are typically grouped by reference sequence, and a record commonly shares a reference
+// sequence with its mate. The name is compared to the name at the cached index before it is looked
+// up in the header.
+#[derive(Debug, Default)]
+pub struct ReferenceSequenceIdCache(Option<usize>);
+
+impl ReferenceSequenceIdCache {
+    fn get(&mut self, reference_sequences: &ReferenceSequences, name: &str) -> Option<usize> {
+        if let Some(id) = self.0 {
+            if let Some((n, _)) = reference_sequences.get_index(id) {
+                if n == name {
+                    return Some(id);
+                }
+            }
+        }
+
+        let id = reference_sequences.get_index_of(name)?;
+        self.0 = Some(id);
+        Some(id)
+    }
+}
+
 pub fn encode_alignment_record<B, R>(
     dst: &mut B,
     reference_sequences: &ReferenceSequences,
+    reference_sequence_id_cache: &mut ReferenceSequenceIdCache,
     record: &R,
 ) -> io::Result<()>
 where
     B: BufMut,
     R: AlignmentRecord + ?Sized,
 {
-    // ref_id
     let reference_sequence_name = record
         .reference_sequence(reference_sequences)
         .transpose()?
         .map(|rs| rs.name());
 
-    put_reference_sequence_id(dst, reference_sequences, reference_sequence_name)?;
+    let mate_reference_sequence_name = record
+        .mate_reference_sequence(reference_sequences)
+        .transpose()?
+        .map(|rs| rs.name());
+
+    encode(
+        dst,
+        reference_sequences,
+        reference_sequence_id_cache,
+        reference_sequence_name,
+        mate_reference_sequence_name,
+        record,
+    )
+}
+
+// Unlike `encode_alignment_record`, this uses the reference sequence names of the record as is
+// rather than first resolving them to reference sequences, so a cache hit skips the header lookup.
+pub fn encode_sam_record<B>(
+    dst: &mut B,
+    reference_sequences: &ReferenceSequences,
+    reference_sequence_id_cache: &mut ReferenceSequenceIdCache,
+    record: &sam::Record,
+) -> io::Result<()>
+where
+    B: BufMut,
+{
+    encode(
+        dst,
+        reference_sequences,
+        reference_sequence_id_cache,
+        record.reference_sequence_name(),
+        record.mate_reference_sequence_name(),
+        record,
+    )
+}
+
+fn encode<B, R>(
+    dst: &mut B,
+    reference_sequences: &ReferenceSequences,
+    reference_sequence_id_cache: &mut ReferenceSequenceIdCache,
+    reference_sequence_name: Option<&sam::record::ReferenceSequenceName>,
+    mate_reference_sequence_name: Option<&sam::record::ReferenceSequenceName>,
+    record: &R,
+) -> io::Result<()>
+where
+    B: BufMut,
+    R: AlignmentRecord + ?Sized,
+{
+    // ref_id
+    put_reference_sequence_id(
+        dst,
+        reference_sequences,
+        reference_sequence_id_cache,
+        reference_sequence_name,
+    )?;
 
     // pos
     put_position(dst, record.alignment_start())?;
@@ -51,12 +129,12 @@ where
     dst.put_u32_le(l_seq);
 
     // next_ref_id
-    let mate_reference_sequence_name = record
-        .mate_reference_sequence(reference_sequences)
-        .transpose()?
-        .map(|rs| rs.name());
-
-    put_reference_sequence_id(dst, reference_sequences, mate_reference_sequence_name)?;
+    put_reference_sequence_id(
+        dst,
+        reference_sequences,
+        reference_sequence_id_cache,
+        mate_reference_sequence_name,
+    )?;
 
     // next_pos
     put_position(dst, record.mate_alignment_start())?;
@@ -100,6 +178,7 @@ where
 fn put_reference_sequence_id<B>(
     dst: &mut B,
     reference_sequences: &ReferenceSequences,
+    reference_sequence_id_cache: &mut ReferenceSequenceIdCache,
     reference_sequence_name: Option<&sam::record::ReferenceSequenceName>,
 ) -> io::Result<()>
 where
@@ -108,8 +187,8 @@ where
     use crate::record::reference_sequence_id;
 
     let id = match reference_sequence_name {
-        Some(name) => reference_sequences
-            .get_index_of(name.as_str())
+        Some(name) => reference_sequence_id_cache
+            .get(reference_sequences, name.as_str())
             .ok_or_else(|| {
                 io::Error::new(
                     io::ErrorKind::InvalidInput,
@@ -144,24 +223,114 @@ mod tests {
             .collect::<Result<_, _>>()?;
 
         let mut buf = Vec::new();
+        let mut cache = ReferenceSequenceIdCache::default();
 
         buf.clear();
         let reference_sequence_name = "sq0".parse()?;
         put_reference_sequence_id(
             &mut buf,
             &reference_sequences,
+            &mut cache,
             Some(&reference_sequence_name),
         )?;
         assert_eq!(buf, [0x00, 0x00, 0x00, 0x00]);
 
         buf.clear();
-        put_reference_sequence_id(&mut buf, &reference_sequences, None)?;
+        let reference_sequence_name = "sq1".parse()?;
+        put_reference_sequence_id(
+            &mut buf,
+            &reference_sequences,
+            &mut cache,
+            Some(&reference_sequence_name),
+        )?;
+        assert_eq!(buf, [0x01, 0x00, 0x00, 0x00]);
+
+        buf.clear();
+        put_reference_sequence_id(&mut buf, &reference_sequences, &mut cache, None)?;
         assert_eq!(buf, [0xff, 0xff, 0xff, 0xff]);
 
         buf.clear();
         let reference_sequence_name = "sq2".parse()?;
         assert!(matches!(
-            put_reference_sequence_id(&mut buf, &reference_sequences, Some(&reference_sequence_name)),
+            put_reference_sequence_id(
+                &mut buf,
+                &reference_sequences,
+                &mut cache,
+                Some(&reference_sequence_name)
+            ),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput,
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_sequence_id_cache() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::{reference_sequence, ReferenceSequence};
+
+        fn build_reference_sequences(
+            names: &[&str],
+        ) -> Result<ReferenceSequences, Box<dyn std::error::Error>> {
+            let mut reference_sequences = ReferenceSequences::default();
+
+            for name in names {
+                let name: reference_sequence::Name = name.parse()?;
+                let sn = name.to_string();
+                reference_sequences.insert(sn, ReferenceSequence::new(name, 8)?);
+            }
+
+            Ok(reference_sequences)
+        }
+
+        let mut cache = ReferenceSequenceIdCache::default();
+
+        let reference_sequences = build_reference_sequences(&["sq0", "sq1"])?;
+        assert_eq!(cache.get(&reference_sequences, "sq1"), Some(1));
+        assert_eq!(cache.get(&reference_sequences, "sq1"), Some(1));
+        assert_eq!(cache.get(&reference_sequences, "sq2"), None);
+
+        // A cached ID is not reused for a different header.
+        let reference_sequences = build_reference_sequences(&["sq1", "sq0"])?;
+        assert_eq!(cache.get(&reference_sequences, "sq1"), Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_sam_record() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use sam::header::ReferenceSequence;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .build();
+        let reference_sequences = header.reference_sequences();
+
+        let record = sam::Record::builder()
+            .set_reference_sequence_name("sq1".parse()?)
+            .set_position(Position::try_from(2)?)
+            .set_mate_reference_sequence_name("sq0".parse()?)
+            .set_mate_position(Position::try_from(5)?)
+            .build();
+
+        let mut expected = Vec::new();
+        let mut cache = ReferenceSequenceIdCache::default();
+        encode_alignment_record(&mut expected, reference_sequences, &mut cache, &record)?;
+
+        let mut actual = Vec::new();
+        let mut cache = ReferenceSequenceIdCache::default();
+        encode_sam_record(&mut actual, reference_sequences, &mut cache, &record)?;
+
+        assert_eq!(actual, expected);
+
+        let record = sam::Record::builder()
+            .set_reference_sequence_name("sq2".parse()?)
+            .build();
+
+        actual.clear();
+        assert!(matches!(
+            encode_sam_record(&mut actual, reference_sequences, &mut cache, &record),
             Err(ref e) if e.kind() == io::ErrorKind::InvalidInput,
         ));
 
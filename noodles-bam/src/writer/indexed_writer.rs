@@ -0,0 +1,279 @@
+use std::io::{self, Write};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_sam::{self as sam, header::ReferenceSequences};
+
+use super::{
+    alignment_record::{encode_alignment_record, ReferenceSequenceIdCache},
+    Writer,
+};
+use crate::{bai, reader::record::decode_record, Record};
+
+/// A BAM writer that builds a BAM index (BAI) from the records it writes.
+///
+/// Each record is added to the index with the chunk of the output stream it was written to, i.e.,
+/// its start and end virtual positions. Like `samtools index`, this requires the records to be
+/// coordinate-sorted.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam::{self as bam, writer::IndexedWriter};
+/// use noodles_sam as sam;
+///
+/// let mut writer = IndexedWriter::new(Vec::new());
+///
+/// let header = sam::Header::default();
+/// writer.write_header(&header)?;
+/// writer.write_reference_sequences(header.reference_sequences())?;
+///
+/// let record = bam::Record::default();
+/// writer.write_record(&record)?;
+///
+/// let (_, index) = writer.finish()?;
+/// assert_eq!(index.unplaced_unmapped_read_count(), Some(1));
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: bai::index::Builder,
+    reference_sequence_count: usize,
+    buf: Vec<u8>,
+    record: Record,
+    reference_sequence_id_cache: ReferenceSequenceIdCache,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BAM writer with a default compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::writer::IndexedWriter;
+    /// let writer = IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: Writer::new(inner),
+            indexer: bai::Index::builder(),
+            reference_sequence_count: 0,
+            buf: Vec::new(),
+            record: Record::default(),
+            reference_sequence_id_cache: ReferenceSequenceIdCache::default(),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::writer::IndexedWriter;
+    /// let writer = IndexedWriter::new(Vec::new());
+    /// assert_eq!(u64::from(writer.get_ref().virtual_position()), 0);
+    /// ```
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a SAM header.
+    ///
+    /// See [`Writer::write_header`].
+    pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes SAM reference sequences.
+    ///
+    /// The number of reference sequences determines the number of reference sequences in the
+    /// index. See [`Writer::write_reference_sequences`].
+    pub fn write_reference_sequences(
+        &mut self,
+        reference_sequences: &ReferenceSequences,
+    ) -> io::Result<()> {
+        self.inner.write_reference_sequences(reference_sequences)?;
+        self.reference_sequence_count = reference_sequences.len();
+        Ok(())
+    }
+
+    /// Writes a BAM record and adds it to the index.
+    ///
+    /// Records without a reference sequence ID or position, i.e., unplaced, unmapped records, are
+    /// only counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::{self as bam, writer::IndexedWriter};
+    /// let mut writer = IndexedWriter::new(Vec::new());
+    /// writer.write_record(&bam::Record::default())?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+        self.indexer.add_record(record, chunk)
+    }
+
+    /// Writes an alignment record and adds it to the index.
+    ///
+    /// The record is encoded as a BAM record, which is then indexed in the same way as
+    /// [`Self::write_record`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::writer::IndexedWriter;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = IndexedWriter::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// let record = sam::Record::default();
+    /// writer.write_alignment_record(&header, &record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::AlignmentRecord,
+    ) -> io::Result<()> {
+        self.buf.clear();
+
+        encode_alignment_record(
+            &mut self.buf,
+            header.reference_sequences(),
+            &mut self.reference_sequence_id_cache,
+            record,
+        )?;
+
+        decode_record(&self.buf[..], &mut self.record)?;
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(&self.record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+        self.indexer.add_record(&self.record, chunk)
+    }
+
+    /// Finishes the output stream and builds the index.
+    ///
+    /// This returns the underlying writer and the BAM index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::writer::IndexedWriter;
+    /// let writer = IndexedWriter::new(Vec::new());
+    /// let (data, index) = writer.finish()?;
+    /// assert!(!data.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<(W, bai::Index)> {
+        let inner = self.inner.into_inner().finish()?;
+        let index = self.indexer.build(self.reference_sequence_count);
+        Ok((inner, index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_csi::{binning_index::ReferenceSequenceExt, BinningIndex};
+    use noodles_sam::header::ReferenceSequence;
+
+    use super::*;
+    use crate::test_helpers::build_index;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::Flags;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .build();
+
+        let records = [
+            Record::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_position(Position::try_from(2)?)
+                .set_cigar("4M".parse()?)
+                .build(),
+            // unmapped, placed
+            Record::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_position(Position::try_from(2)?)
+                .build(),
+            // unplaced, unmapped
+            Record::default(),
+        ];
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+
+        for record in &records {
+            writer.write_record(record)?;
+        }
+
+        let (data, actual) = writer.finish()?;
+        let expected = build_index(&data)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.reference_sequences().len(), 2);
+        assert_eq!(actual.unplaced_unmapped_record_count(), Some(1));
+
+        let metadata = actual.reference_sequences()[0].metadata();
+        assert_eq!(metadata.map(|m| m.mapped_record_count()), Some(1));
+        assert_eq!(metadata.map(|m| m.unmapped_record_count()), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .build();
+
+        let record = sam::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(2)?)
+            .set_cigar("4M".parse()?)
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_reference_sequences(header.reference_sequences())?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.write_alignment_record(&header, &sam::Record::default())?;
+
+        let (_, index) = writer.finish()?;
+
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        let metadata = index.reference_sequences()[0].metadata();
+        assert_eq!(metadata.map(|m| m.mapped_record_count()), Some(1));
+
+        Ok(())
+    }
+}
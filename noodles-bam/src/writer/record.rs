@@ -34,7 +34,11 @@ where
     // pos
     put_position(dst, record.alignment_start())?;
 
-    put_l_read_name(dst, record.read_name())?;
+    if record.is_read_name_empty() {
+        put_l_empty_read_name(dst);
+    } else {
+        put_l_read_name(dst, record.read_name())?;
+    }
 
     // mapq
     put_mapping_quality(dst, record.mapping_quality());
@@ -62,7 +66,11 @@ where
     // tlen
     put_template_length(dst, record.template_length());
 
-    put_read_name(dst, record.read_name());
+    if record.is_read_name_empty() {
+        put_empty_read_name(dst);
+    } else {
+        put_read_name(dst, record.read_name());
+    }
 
     put_cigar(dst, record.cigar())?;
 
@@ -153,6 +161,23 @@ where
     Ok(())
 }
 
+// An empty read name is out of spec, but it is written back as it was read, i.e., as a lone NUL
+// terminator.
+fn put_l_empty_read_name<B>(dst: &mut B)
+where
+    B: BufMut,
+{
+    dst.put_u8(1);
+}
+
+fn put_empty_read_name<B>(dst: &mut B)
+where
+    B: BufMut,
+{
+    const NUL: u8 = 0x00;
+    dst.put_u8(NUL);
+}
+
 pub(super) fn put_mapping_quality<B>(
     dst: &mut B,
     mapping_quality: Option<sam::record::MappingQuality>,
@@ -172,16 +197,21 @@ pub(super) fn put_bin<B>(
 where
     B: BufMut,
 {
-    let bin = match (alignment_start, alignment_end) {
-        (Some(start), Some(end)) => region_to_bin(start, end)?,
-        _ => UNMAPPED_BIN,
-    };
-
+    let bin = calculate_bin(alignment_start, alignment_end)?;
     dst.put_u16_le(bin);
-
     Ok(())
 }
 
+pub(crate) fn calculate_bin(
+    alignment_start: Option<Position>,
+    alignment_end: Option<Position>,
+) -> io::Result<u16> {
+    match (alignment_start, alignment_end) {
+        (Some(start), Some(end)) => region_to_bin(start, end),
+        _ => Ok(UNMAPPED_BIN),
+    }
+}
+
 pub(super) fn put_flags<B>(dst: &mut B, flags: sam::record::Flags)
 where
     B: BufMut,
@@ -299,6 +329,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_record_with_empty_read_name() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::reader::record::decode_record;
+
+        let data = [
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x01, // l_read_name = 1
+            0xff, // mapq = 255
+            0x48, 0x12, // bin = 4680
+            0x00, 0x00, // n_cigar_op = 0
+            0x04, 0x00, // flag = 4
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            0x00, // read_name = "\x00"
+        ];
+
+        let mut record = Record::default();
+        decode_record(&data[..], &mut record)?;
+        assert!(record.read_name().is_none());
+
+        let mut buf = Vec::new();
+        encode_record(&mut buf, &record)?;
+        assert_eq!(buf, data);
+
+        // A read name that is set after reading is written as is.
+        *record.read_name_mut() = Some("r0".parse()?);
+        buf.clear();
+        encode_record(&mut buf, &record)?;
+        assert_eq!(buf[8], 0x03); // l_read_name = 3
+        assert_eq!(&buf[32..], b"r0\x00");
+
+        Ok(())
+    }
+
     #[test]
     fn test_region_to_bin() -> Result<(), Box<dyn std::error::Error>> {
         let start = Position::try_from(8)?;
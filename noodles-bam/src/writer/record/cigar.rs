@@ -46,6 +46,7 @@ fn encode_kind(kind: Kind) -> u32 {
         Kind::Pad => 6,
         Kind::SequenceMatch => 7,
         Kind::SequenceMismatch => 8,
+        Kind::Back => 9,
     }
 }
 
@@ -100,5 +101,6 @@ mod tests {
         assert_eq!(encode_kind(Kind::Pad), 6);
         assert_eq!(encode_kind(Kind::SequenceMatch), 7);
         assert_eq!(encode_kind(Kind::SequenceMismatch), 8);
+        assert_eq!(encode_kind(Kind::Back), 9);
     }
 }
@@ -0,0 +1,88 @@
+//! Times reading a single INFO field compared to decoding all INFO fields.
+//!
+//! The records are read into memory before timing. For each record, the given INFO field is read
+//! with `Info::get`, which skips the values of the other fields, and then by decoding all fields
+//! with `Info::values` and searching for the key.
+//!
+//! This prints the number of records with the field and the time taken for each method, e.g.,
+//! `bcf_info_get sample.bcf DP`.
+
+use std::{
+    env,
+    fs::File,
+    io,
+    time::{Duration, Instant},
+};
+
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_vcf::{self as vcf, header::info::Key};
+
+fn time_get(
+    records: &[bcf::Record],
+    header: &vcf::Header,
+    string_maps: &StringMaps,
+    key: &Key,
+) -> io::Result<(usize, Duration)> {
+    let start = Instant::now();
+    let mut n = 0;
+
+    for record in records {
+        if record
+            .info()
+            .get(header, string_maps.strings(), key)
+            .transpose()?
+            .is_some()
+        {
+            n += 1;
+        }
+    }
+
+    Ok((n, start.elapsed()))
+}
+
+fn time_values(
+    records: &[bcf::Record],
+    header: &vcf::Header,
+    string_maps: &StringMaps,
+    key: &Key,
+) -> io::Result<(usize, Duration)> {
+    let start = Instant::now();
+    let mut n = 0;
+
+    for record in records {
+        let fields: Vec<_> = record
+            .info()
+            .values(header, string_maps.strings())
+            .collect::<io::Result<_>>()?;
+
+        if fields.iter().any(|field| field.key() == key) {
+            n += 1;
+        }
+    }
+
+    Ok((n, start.elapsed()))
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args();
+
+    let src = args.nth(1).expect("missing src");
+    let key: Key = args.next().expect("missing key").parse()?;
+
+    let mut reader = File::open(src).map(bcf::Reader::new)?;
+    reader.read_file_format()?;
+    let raw_header = reader.read_header()?;
+
+    let header: vcf::Header = raw_header.parse()?;
+    let string_maps: StringMaps = raw_header.parse()?;
+
+    let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+
+    let (n, elapsed) = time_get(&records, &header, &string_maps, &key)?;
+    println!("method=get\trecords={}\telapsed={:?}", n, elapsed);
+
+    let (n, elapsed) = time_values(&records, &header, &string_maps, &key)?;
+    println!("method=values\trecords={}\telapsed={:?}", n, elapsed);
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! Creates a new BCF file.
+//!
+//! This writes a BCF file format, VCF header, and a single VCF to stdout.
+//!
+//! Verify the output by piping to `bcftools view --no-version`.
+
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_vcf::{self as vcf, header::Contig, record::Position};
+use tokio::io;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = bcf::AsyncWriter::new(io::stdout());
+    writer.write_file_format().await?;
+
+    let header = vcf::Header::builder()
+        .add_filter(vcf::header::Filter::pass())
+        .add_contig(Contig::new("sq0"))
+        .build();
+
+    writer.write_header(&header).await?;
+
+    let string_maps = StringMaps::try_from(&header)?;
+
+    let record = vcf::Record::builder()
+        .set_chromosome("sq0".parse()?)
+        .set_position(Position::try_from(1)?)
+        .set_reference_bases("A".parse()?)
+        .build()?;
+
+    writer
+        .write_vcf_record(&header, &string_maps, &record)
+        .await?;
+
+    writer.shutdown().await?;
+
+    Ok(())
+}
@@ -0,0 +1,315 @@
+use std::ffi::CString;
+
+use noodles_bgzf as bgzf;
+use noodles_vcf as vcf;
+use tokio::io::{self, AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    header::StringMaps,
+    writer::{record::write_record, vcf_record::write_vcf_record, MAJOR, MINOR},
+    Record,
+};
+
+/// An async BCF writer.
+pub struct Writer<W> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W> Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::AsyncWriter::from(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::from(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::AsyncWriter::from(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Shuts down the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        self.inner.shutdown().await
+    }
+
+    /// Writes a BCF file format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    /// writer.write_file_format().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_file_format(&mut self) -> io::Result<()> {
+        write_file_format(&mut self.inner).await
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        write_header(&mut self.inner, header).await
+    }
+
+    /// Writes a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    /// let record = bcf::Record::default();
+    /// writer.write_record(&record).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        self.buf.clear();
+        write_record(&mut self.buf, record)?;
+        self.inner.write_all(&self.buf).await
+    }
+
+    /// Writes a VCF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_bcf::{self as bcf, header::StringMaps};
+    /// use noodles_vcf::{self as vcf, header::Contig, record::Position};
+    ///
+    /// let mut writer = bcf::AsyncWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig(Contig::new("sq0"))
+    ///     .build();
+    ///
+    /// writer.write_header(&header).await?;
+    ///
+    /// let string_maps = StringMaps::try_from(&header)?;
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// writer.write_vcf_record(&header, &string_maps, &record).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_vcf_record(
+        &mut self,
+        header: &vcf::Header,
+        string_maps: &StringMaps,
+        record: &vcf::Record,
+    ) -> io::Result<()> {
+        self.buf.clear();
+        write_vcf_record(&mut self.buf, header, string_maps, record)?;
+        self.inner.write_all(&self.buf).await
+    }
+}
+
+impl<W> Writer<bgzf::AsyncWriter<W>>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Creates an async BCF writer with a default compression level.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::AsyncWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::from(bgzf::AsyncWriter::new(inner))
+    }
+}
+
+impl<W> From<W> for Writer<W> {
+    fn from(inner: W) -> Self {
+        Self {
+            inner,
+            buf: Vec::new(),
+        }
+    }
+}
+
+async fn write_file_format<W>(writer: &mut W) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    use crate::MAGIC_NUMBER;
+
+    writer.write_all(MAGIC_NUMBER).await?;
+    writer.write_u8(MAJOR).await?;
+    writer.write_u8(MINOR).await?;
+
+    Ok(())
+}
+
+async fn write_header<W>(writer: &mut W, header: &vcf::Header) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let raw_header = header.to_string();
+    let c_raw_header =
+        CString::new(raw_header).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let text = c_raw_header.as_bytes_with_nul();
+    let l_text =
+        u32::try_from(text.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    writer.write_u32_le(l_text).await?;
+    writer.write_all(text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use vcf::{header::Contig, record::Position};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_file_format() -> io::Result<()> {
+        let mut buf = Vec::new();
+        write_file_format(&mut buf).await?;
+
+        let expected = [
+            b'B', b'C', b'F', // magic
+            0x02, // major
+            0x02, // minor
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_vcf_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig(Contig::new("sq0"))
+            .add_contig(Contig::new("sq1"))
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let record = vcf::Record::builder()
+            .set_chromosome("sq1".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let mut writer = Writer::from(Vec::new());
+        writer
+            .write_vcf_record(&header, &string_maps, &record)
+            .await?;
+
+        let mut expected = crate::Writer::from(Vec::new());
+        expected.write_vcf_record(&header, &string_maps, &record)?;
+
+        assert_eq!(writer.get_ref(), expected.get_ref());
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format().await?;
+        writer.write_header(&header).await?;
+        writer
+            .write_vcf_record(&header, &string_maps, &record)
+            .await?;
+        writer.shutdown().await?;
+
+        let data = writer.into_inner().into_inner();
+
+        let mut reader = crate::AsyncReader::new(&data[..]);
+        reader.read_file_format().await?;
+        reader.read_header().await?;
+
+        let records: Vec<_> = reader.records().try_collect().await?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].chromosome_id(), 1);
+        assert_eq!(records[0].position(), Position::try_from(8)?);
+
+        Ok(())
+    }
+}
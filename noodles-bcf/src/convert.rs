@@ -0,0 +1,113 @@
+use std::io::{self, Read, Write};
+
+use noodles_vcf as vcf;
+
+use super::{header::StringMaps, Reader, Record};
+
+/// Converts a BCF stream to a VCF stream.
+///
+/// This reads the BCF file format and header and writes the VCF header. The string maps are built
+/// once from the raw header, and each BCF record is then converted to and written as a VCF record.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf as bcf;
+/// use noodles_vcf as vcf;
+///
+/// let raw_header = "##fileformat=VCFv4.3\n##contig=<ID=sq0>\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+/// let header: vcf::Header = raw_header.parse()?;
+///
+/// let mut writer = bcf::Writer::new(Vec::new());
+/// writer.write_file_format()?;
+/// writer.write_header(&header)?;
+/// writer.write_record(&bcf::Record::default())?;
+/// let data = writer.into_inner().finish()?;
+///
+/// let mut reader = bcf::Reader::new(&data[..]);
+/// let mut writer = vcf::Writer::new(Vec::new());
+/// bcf::convert_to_vcf(&mut reader, &mut writer)?;
+///
+/// assert!(writer.get_ref().ends_with(b"sq0\t1\t.\tA\t.\t.\t.\t.\n"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn convert_to_vcf<R, W>(reader: &mut Reader<R>, writer: &mut vcf::Writer<W>) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    reader.read_file_format()?;
+
+    let raw_header = reader.read_header()?;
+
+    let header: vcf::Header = raw_header
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let string_maps: StringMaps = raw_header
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    writer.write_header(&header)?;
+
+    let mut record = Record::default();
+
+    while reader.read_record(&mut record)? != 0 {
+        let vcf_record = record.try_into_vcf_record(&header, &string_maps)?;
+        writer.write_record(&vcf_record)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_to_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = "##fileformat=VCFv4.3
+##FILTER=<ID=PASS,Description=\"All filters passed\">
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description=\"Read depth\">
+##contig=<ID=sq0,length=8>
+##contig=<ID=sq1,length=13>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+";
+
+        let raw_records = [
+            "sq0\t1\t.\tA\t.\t.\tPASS\tNS=1\tDP\t5",
+            "sq1\t8\tnd0\tC\tG\t13\tPASS\tNS=1\tDP\t8",
+        ];
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for raw_record in raw_records {
+            let record = vcf::Record::try_from_str(raw_record, &header)?;
+            writer.write_vcf_record(&header, &string_maps, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut writer = vcf::Writer::new(Vec::new());
+        convert_to_vcf(&mut reader, &mut writer)?;
+
+        let mut expected = header.to_string();
+
+        for raw_record in raw_records {
+            expected.push_str(raw_record);
+            expected.push('\n');
+        }
+
+        assert_eq!(String::from_utf8(writer.into_inner())?, expected);
+
+        Ok(())
+    }
+}
@@ -47,7 +47,7 @@ impl StringMaps {
     ///     .add_contig(Contig::new("sq0"))
     ///     .build();
     ///
-    /// let string_maps = StringMaps::from(&header);
+    /// let string_maps = StringMaps::try_from(&header)?;
     /// let string_string_map = string_maps.strings();
     ///
     /// assert_eq!(string_string_map.get_index(0), Some("PASS"));
@@ -82,7 +82,7 @@ impl StringMaps {
     ///     .add_contig(Contig::new("sq0"))
     ///     .build();
     ///
-    /// let string_maps = StringMaps::from(&header);
+    /// let string_maps = StringMaps::try_from(&header)?;
     /// let contig_string_map = string_maps.contigs();
     ///
     /// assert_eq!(contig_string_map.get_index(0), Some("sq0"));
@@ -192,6 +192,10 @@ fn insert(string_map: &mut StringMap, id: &str, idx: Option<usize>) -> Result<()
             if actual != expected {
                 return Err(ParseError::StringMapPositionMismatch(actual, expected));
             }
+        } else if let Some(entry) = string_map.get_index(i) {
+            let actual = (i, id.into());
+            let expected = (i, entry.into());
+            return Err(ParseError::StringMapPositionMismatch(actual, expected));
         } else {
             string_map.insert_at(i, id.into());
         }
@@ -202,29 +206,46 @@ fn insert(string_map: &mut StringMap, id: &str, idx: Option<usize>) -> Result<()
     Ok(())
 }
 
-impl From<&vcf::Header> for StringMaps {
-    fn from(header: &vcf::Header) -> Self {
+impl TryFrom<&vcf::Header> for StringMaps {
+    type Error = ParseError;
+
+    /// Builds string maps from a VCF header.
+    ///
+    /// Strings are assigned indices in the order they are written in the header: INFO, FILTER,
+    /// and FORMAT IDs, after the implicit "PASS" filter. Contigs are indexed in header order. If an
+    /// entry has an explicit index (`IDX`), that index is used instead. This is the same
+    /// assignment made when reading the header as written by [`crate::Writer::write_header`].
+    ///
+    /// A [`vcf::Header`] does not keep the relative order of INFO, FILTER, and FORMAT records. If
+    /// the header was read from text where, e.g., FILTER records come before INFO records, this
+    /// assignment differs from one made from the original text, e.g., by bcftools. Parse the raw
+    /// header text (`str::parse`) to assign indices in file order.
+    ///
+    /// This fails if an explicit index conflicts with the index of another entry.
+    fn try_from(header: &vcf::Header) -> Result<Self, Self::Error> {
         let mut string_maps = StringMaps::default();
 
         for contig in header.contigs().values() {
-            string_maps.contigs_mut().insert(contig.id().into());
+            insert(string_maps.contigs_mut(), contig.id(), contig.idx())?;
         }
 
         for info in header.infos().values() {
-            string_maps.strings_mut().insert(info.id().as_ref().into());
+            insert(string_maps.strings_mut(), info.id().as_ref(), info.idx())?;
         }
 
         for filter in header.filters().values() {
-            string_maps.strings_mut().insert(filter.id().into());
+            insert(string_maps.strings_mut(), filter.id(), filter.idx())?;
         }
 
         for format in header.formats().values() {
-            string_maps
-                .strings_mut()
-                .insert(format.id().as_ref().into());
+            insert(
+                string_maps.strings_mut(),
+                format.id().as_ref(),
+                format.idx(),
+            )?;
         }
 
-        string_maps
+        Ok(string_maps)
     }
 }
 
@@ -382,7 +403,7 @@ mod tests {
     }
 
     #[test]
-    fn test_vcf_header_for_string_map() {
+    fn test_vcf_header_for_string_map() -> Result<(), ParseError> {
         use vcf::{
             header::{format::Key as FormatKey, info::Key as InfoKey, AlternativeAllele, Contig},
             record::alternate_bases::allele::{
@@ -407,7 +428,7 @@ mod tests {
             ))
             .build();
 
-        let actual = StringMaps::from(&header);
+        let actual = StringMaps::try_from(&header)?;
 
         let string_string_map = StringMap {
             indices: [
@@ -449,6 +470,8 @@ mod tests {
         };
 
         assert_eq!(actual, expected);
+
+        Ok(())
     }
 
     #[test]
@@ -487,4 +510,101 @@ mod tests {
             Err(ParseError::MissingFileFormat)
         );
     }
+
+    #[test]
+    fn test_from_vcf_header() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##FILTER=<ID=q10,Description="Quality below 10">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth">
+##contig=<ID=sq0,length=8>
+##contig=<ID=sq1,length=13>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        let header: vcf::Header = raw_header.parse()?;
+        let actual = StringMaps::try_from(&header)?;
+
+        let strings: Vec<_> = actual.strings().iter().collect();
+        assert_eq!(
+            strings,
+            [(0, "PASS"), (1, "NS"), (2, "DP"), (3, "q10"), (4, "GT")]
+        );
+
+        let contigs: Vec<_> = actual.contigs().iter().collect();
+        assert_eq!(contigs, [(0, "sq0"), (1, "sq1")]);
+
+        // The string maps of a parsed header match those of its written form.
+        let expected: StringMaps = header.to_string().parse()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_vcf_header_with_idx() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=3>
+##FILTER=<ID=PASS,Description="All filters passed",IDX=0>
+##FILTER=<ID=q10,Description="Quality below 10",IDX=1>
+##contig=<ID=sq0,length=8,IDX=1>
+##contig=<ID=sq1,length=13,IDX=0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        let header: vcf::Header = raw_header.parse()?;
+        let actual = StringMaps::try_from(&header)?;
+
+        let strings: Vec<_> = actual.strings().iter().collect();
+        assert_eq!(strings, [(0, "PASS"), (1, "q10"), (3, "DP")]);
+
+        let contigs: Vec<_> = actual.contigs().iter().collect();
+        assert_eq!(contigs, [(0, "sq1"), (1, "sq0")]);
+
+        let expected: StringMaps = raw_header.parse()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_vcf_header_with_conflicting_idx() -> Result<(), Box<dyn std::error::Error>> {
+        // Two IDs with the same IDX.
+        let raw_header = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=1>
+##FILTER=<ID=q10,Description="Quality below 10",IDX=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        let header: vcf::Header = raw_header.parse()?;
+
+        assert_eq!(
+            StringMaps::try_from(&header),
+            Err(ParseError::StringMapPositionMismatch(
+                (1, String::from("q10")),
+                (1, String::from("DP"))
+            ))
+        );
+
+        // The same ID with two IDXs.
+        let raw_header = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=1>
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth",IDX=2>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        let header: vcf::Header = raw_header.parse()?;
+
+        assert_eq!(
+            StringMaps::try_from(&header),
+            Err(ParseError::StringMapPositionMismatch(
+                (2, String::from("DP")),
+                (1, String::from("DP"))
+            ))
+        );
+
+        Ok(())
+    }
 }
@@ -38,6 +38,27 @@ impl StringMap {
         self.indices.get(value).copied()
     }
 
+    /// Returns an iterator over the index and value of each entry.
+    ///
+    /// Entries are ordered by index. Unassigned indices are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMaps;
+    ///
+    /// let string_maps = StringMaps::default();
+    /// let entries: Vec<_> = string_maps.strings().iter().collect();
+    ///
+    /// assert_eq!(entries, [(0, "PASS")]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| entry.as_deref().map(|value| (i, value)))
+    }
+
     pub(super) fn get_full(&self, value: &str) -> Option<(usize, &str)> {
         self.get_index_of(value)
             .and_then(|i| self.get_index(i).map(|entry| (i, entry)))
@@ -5,14 +5,20 @@
 #[cfg(feature = "async")]
 mod r#async;
 
+mod convert;
 pub mod header;
 pub mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    convert::convert_to_vcf,
+    reader::Reader,
+    record::Record,
+    writer::{write_empty, Writer},
+};
 
 #[cfg(feature = "async")]
-pub use self::r#async::Reader as AsyncReader;
+pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
 static MAGIC_NUMBER: &[u8] = b"BCF";
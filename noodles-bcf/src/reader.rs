@@ -237,6 +237,10 @@ where
 
     /// Returns an iterator over records that intersects the given region.
     ///
+    /// To decompress upcoming blocks on worker threads while records are being read, wrap a BGZF
+    /// reader built with [`bgzf::reader::Builder::set_worker_count`], e.g.,
+    /// `bcf::Reader::from(bgzf::Reader::builder(file).set_worker_count(4).build())`.
+    ///
     /// # Examples
     ///
     /// ```no_run
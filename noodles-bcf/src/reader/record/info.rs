@@ -4,7 +4,10 @@ use noodles_vcf::{self as vcf, header::info::Type};
 
 use crate::{
     header::string_maps::StringStringMap,
-    reader::{string_map::read_string_map_index, value::read_value},
+    reader::{
+        string_map::read_string_map_index,
+        value::{read_value, skip_value},
+    },
     record::{
         value::{Float, Int16, Int32, Int8},
         Value,
@@ -52,6 +55,46 @@ where
     Ok(vcf::record::info::Field::new(key, value))
 }
 
+// Finds and decodes the field with the given key.
+//
+// The values of other fields are skipped rather than decoded.
+pub fn find_info_field(
+    src: &mut &[u8],
+    infos: &vcf::header::Infos,
+    string_string_map: &StringStringMap,
+    len: usize,
+    key: &vcf::header::info::Key,
+) -> io::Result<Option<vcf::record::info::Field>> {
+    for _ in 0..len {
+        let j = read_string_map_index(src)?;
+
+        let raw_key = string_string_map.get_index(j).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid string map index: {}", j),
+            )
+        })?;
+
+        if raw_key != key.as_ref() {
+            skip_value(src)?;
+            continue;
+        }
+
+        let info = infos.get(key).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("missing header INFO record for {}", key),
+            )
+        })?;
+
+        let value = read_info_field_value(src, info)?;
+
+        return Ok(Some(vcf::record::info::Field::new(key.clone(), value)));
+    }
+
+    Ok(None)
+}
+
 fn read_info_field_key<R>(
     reader: &mut R,
     infos: &vcf::header::Infos,
@@ -256,6 +299,53 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_find_info_field() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::info::Key,
+            record::info::{field::Value, Field},
+        };
+
+        use crate::header::StringMaps;
+
+        let header = vcf::Header::builder()
+            .add_info(vcf::header::Info::from(Key::AlleleCount))
+            .add_info(vcf::header::Info::from(Key::TotalDepth))
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let data = [
+            0x11, 0x01, 0x31, 0x05, 0x08, 0x0d, // AC=5,8,13
+            0x11, 0x02, 0x11, 0x08, // DP=8
+        ];
+
+        let mut src = &data[..];
+        let actual = find_info_field(
+            &mut src,
+            header.infos(),
+            string_maps.strings(),
+            2,
+            &Key::TotalDepth,
+        )?;
+        assert_eq!(
+            actual,
+            Some(Field::new(Key::TotalDepth, Some(Value::Integer(8))))
+        );
+
+        let mut src = &data[..];
+        let actual = find_info_field(
+            &mut src,
+            header.infos(),
+            string_maps.strings(),
+            2,
+            &Key::AncestralAllele,
+        )?;
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_info_field_value_with_integer_value() -> Result<(), Box<dyn std::error::Error>> {
         fn t(
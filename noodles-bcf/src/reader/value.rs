@@ -67,6 +67,25 @@ where
     }
 }
 
+// Advances the source past a typed value without decoding it.
+pub fn skip_value(src: &mut &[u8]) -> io::Result<()> {
+    let len = match read_type(src)? {
+        Some(Type::Int8(n) | Type::String(n)) => Some(n),
+        Some(Type::Int16(n)) => n.checked_mul(2),
+        Some(Type::Int32(n) | Type::Float(n)) => n.checked_mul(4),
+        None => Some(0),
+    }
+    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid value length"))?;
+
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    *src = &src[len..];
+
+    Ok(())
+}
+
 fn read_i8<R>(reader: &mut R) -> io::Result<i8>
 where
     R: Read,
@@ -144,6 +163,34 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_skip_value() -> io::Result<()> {
+        let data = [
+            0x00, // None
+            0x11, 0x05, // Int8(5)
+            0x32, 0x05, 0x00, 0x08, 0x00, 0x0d, 0x00, // Int16Array([5, 8, 13])
+            0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, // FloatArray([0.0, 0.5])
+            0x37, b'n', b'd', b'l', // String("ndl")
+        ];
+
+        let mut src = &data[..];
+
+        for _ in 0..5 {
+            skip_value(&mut src)?;
+        }
+
+        assert!(src.is_empty());
+
+        let data = [0x32, 0x05, 0x00];
+        let mut src = &data[..];
+        assert!(matches!(
+            skip_value(&mut src),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_value() {
         let data = [0x00];
@@ -13,6 +13,8 @@ use std::io;
 
 use noodles_vcf as vcf;
 
+use crate::header::string_maps::StringStringMap;
+
 /// A chromosome ID.
 pub type ChromosomeId = usize;
 
@@ -119,6 +121,73 @@ impl Record {
         Position::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     }
 
+    /// Validates that the reference length (`rlen`) matches the end position (`END`) info field.
+    ///
+    /// The reference length of a record with symbolic alternate alleles (e.g., `<DEL>`) is the
+    /// span to `END` rather than the length of the reference bases. This checks that the end
+    /// position calculated from the reference length (see [`Self::end`]) is equal to the value
+    /// of the `END` info field, if present.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`io::ErrorKind::InvalidData`] error if the info fields fail to be read,
+    /// the `END` value is not an integer, or the end positions do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::default();
+    /// let string_maps = bcf::header::StringMaps::default();
+    ///
+    /// let record = bcf::Record::default();
+    /// assert!(record.validate_end(&header, string_maps.strings()).is_ok());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn validate_end(
+        &self,
+        header: &vcf::Header,
+        string_string_map: &StringStringMap,
+    ) -> io::Result<()> {
+        use vcf::{header::info::Key, record::info::field::Value};
+
+        let info = self.info();
+
+        let field = match info.get(header, string_string_map, &Key::EndPosition) {
+            Some(result) => result?,
+            None => return Ok(()),
+        };
+
+        let info_end = match field.value() {
+            Some(Value::Integer(n)) => *n,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid END info field value",
+                ))
+            }
+        };
+
+        let end = i32::from(self.end()?);
+
+        if end == info_end {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "rlen ({}) does not match END ({}): expected end {}",
+                    self.rlen(),
+                    info_end,
+                    end
+                ),
+            ))
+        }
+    }
+
     /// Returns the quality score.
     ///
     /// # Examples
@@ -260,3 +329,47 @@ impl Default for Record {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::StringMaps;
+
+    #[test]
+    fn test_validate_end() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::info::Key;
+
+        let header = vcf::Header::builder()
+            .add_info(vcf::header::Info::from(Key::EndPosition))
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+        let end_key = string_maps
+            .strings()
+            .get_index_of("END")
+            .and_then(|i| u8::try_from(i).ok())
+            .expect("missing END");
+
+        let mut record = Record {
+            pos: vcf::record::Position::try_from(1)?,
+            rlen: 8,
+            alt: "<DEL>".parse()?,
+            info: Info::new(vec![0x11, end_key, 0x11, 0x08], 1), // END=8
+            ..Default::default()
+        };
+
+        assert_eq!(i32::from(record.end()?), 8);
+        assert!(record.validate_end(&header, string_maps.strings()).is_ok());
+
+        record.rlen = 1;
+        assert!(matches!(
+            record.validate_end(&header, string_maps.strings()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        record.info = Info::default();
+        assert!(record.validate_end(&header, string_maps.strings()).is_ok());
+
+        Ok(())
+    }
+}
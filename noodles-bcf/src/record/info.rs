@@ -102,10 +102,12 @@ impl Info {
 
     /// Returns the field with the given key.
     ///
+    /// Only the value of the matching field is decoded. The values of the other fields are
+    /// skipped.
+    ///
     /// # Examples
     ///
     /// ```
-    /// # use std::io;
     /// use noodles_bcf::{header::StringMaps, record::Info};
     /// use noodles_vcf::{self as vcf, header::info::Key, record::info::{field::Value, Field}};
     ///
@@ -114,7 +116,7 @@ impl Info {
     ///     .add_info(vcf::header::Info::from(Key::TotalDepth))
     ///     .build();
     ///
-    /// let string_maps = StringMaps::from(&header);
+    /// let string_maps = StringMaps::try_from(&header)?;
     ///
     /// let data = vec![
     ///     0x11, 0x01, 0x11, 0x05, // AC=5
@@ -129,7 +131,7 @@ impl Info {
     /// );
     ///
     /// assert!(info.get(&header, string_maps.strings(), &Key::AncestralAllele).is_none());
-    /// # Ok::<_, io::Error>(())
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn get(
         &self,
@@ -137,18 +139,17 @@ impl Info {
         string_string_map: &StringStringMap,
         key: &vcf::header::info::Key,
     ) -> Option<io::Result<vcf::record::info::Field>> {
-        for result in self.values(header, string_string_map) {
-            match result {
-                Ok(field) => {
-                    if field.key() == key {
-                        return Some(Ok(field));
-                    }
-                }
-                Err(e) => return Some(Err(e)),
-            }
-        }
+        use crate::reader::record::info::find_info_field;
 
-        None
+        let mut reader = &self.buf[..];
+        find_info_field(
+            &mut reader,
+            header.infos(),
+            string_string_map,
+            self.len(),
+            key,
+        )
+        .transpose()
     }
 
     /// Returns an iterator over all info fields.
@@ -156,7 +157,6 @@ impl Info {
     /// # Examples
     ///
     /// ```
-    /// # use std::io;
     /// use noodles_bcf::{header::StringMaps, record::Info};
     /// use noodles_vcf::{self as vcf, header::info::Key, record::info::{field::Value, Field}};
     ///
@@ -165,7 +165,7 @@ impl Info {
     ///     .add_info(vcf::header::Info::from(Key::TotalDepth))
     ///     .build();
     ///
-    /// let string_maps = StringMaps::from(&header);
+    /// let string_maps = StringMaps::try_from(&header)?;
     ///
     /// let data = vec![
     ///     0x11, 0x01, 0x11, 0x05, // AC=5
@@ -186,7 +186,7 @@ impl Info {
     /// );
     ///
     /// assert!(fields.next().is_none());
-    /// # Ok::<_, io::Error>(())
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn values<'a>(
         &'a self,
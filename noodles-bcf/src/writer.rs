@@ -1,7 +1,7 @@
-mod record;
+pub(crate) mod record;
 mod string_map;
 mod value;
-mod vcf_record;
+pub(crate) mod vcf_record;
 
 use std::{
     ffi::CString,
@@ -14,8 +14,8 @@ use noodles_vcf as vcf;
 
 use super::{header::StringMaps, Record};
 
-const MAJOR: u8 = 2;
-const MINOR: u8 = 2;
+pub(crate) const MAJOR: u8 = 2;
+pub(crate) const MINOR: u8 = 2;
 
 /// A BCF writer.
 pub struct Writer<W> {
@@ -131,7 +131,7 @@ where
     ///
     /// writer.write_header(&header)?;
     ///
-    /// let string_maps = StringMaps::from(&header);
+    /// let string_maps = StringMaps::try_from(&header)?;
     ///
     /// let record = vcf::Record::builder()
     ///     .set_chromosome("sq0".parse()?)
@@ -195,6 +195,39 @@ impl<W> From<W> for Writer<W> {
     }
 }
 
+/// Writes an empty BCF.
+///
+/// An empty BCF has a file format, a VCF header, no records, and a BGZF EOF block.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf as bcf;
+/// use noodles_vcf as vcf;
+///
+/// let header = vcf::Header::default();
+///
+/// let mut buf = Vec::new();
+/// bcf::write_empty(&mut buf, &header)?;
+///
+/// let mut reader = bcf::Reader::new(&buf[..]);
+/// reader.read_file_format()?;
+/// assert_eq!(reader.read_header()?, header.to_string());
+/// assert_eq!(reader.records().count(), 0);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_empty<W>(inner: W, header: &vcf::Header) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut writer = Writer::new(inner);
+    writer.write_file_format()?;
+    writer.write_header(header)?;
+    writer.into_inner().finish()?;
+    Ok(())
+}
+
 fn write_file_format<W>(writer: &mut W) -> io::Result<()>
 where
     W: Write,
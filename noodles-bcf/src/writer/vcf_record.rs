@@ -59,7 +59,7 @@ mod tests {
             .add_contig(vcf::header::Contig::new("sq0"))
             .build();
 
-        let string_maps = StringMaps::from(&header);
+        let string_maps = StringMaps::try_from(&header)?;
 
         let record = vcf::Record::builder()
             .set_chromosome("sq0".parse()?)
@@ -522,7 +522,7 @@ mod tests {
             .add_filter(Filter::new("q10", "Quality below 10"))
             .build();
 
-        let string_maps = StringMaps::from(&header);
+        let string_maps = StringMaps::try_from(&header)?;
 
         let mut buf = Vec::new();
 
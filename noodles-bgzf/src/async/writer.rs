@@ -15,8 +15,6 @@ use futures::{ready, sink::Buffer, Sink};
 use pin_project_lite::pin_project;
 use tokio::io::{self, AsyncWrite};
 
-use crate::block;
-
 use self::{deflate::Deflate, deflater::Deflater};
 
 #[cfg(feature = "libdeflate")]
@@ -30,6 +28,7 @@ pin_project! {
         #[pin]
         sink: Buffer<Deflater<W>, Deflate>,
         buf: BytesMut,
+        block_size: usize,
         #[pin]
         eof_buf: Bytes,
         compression_level: CompressionLevel,
@@ -88,16 +87,13 @@ where
         cx: &mut Context<'_>,
         buf: &[u8],
     ) -> Poll<Result<usize, io::Error>> {
-        if self.buf.len() >= block::MAX_UNCOMPRESSED_DATA_LENGTH {
+        if self.buf.len() >= self.block_size {
             if let Err(e) = ready!(self.as_mut().poll_flush(cx)) {
                 return Poll::Ready(Err(e));
             }
         }
 
-        let n = cmp::min(
-            block::MAX_UNCOMPRESSED_DATA_LENGTH - self.buf.len(),
-            buf.len(),
-        );
+        let n = cmp::min(self.block_size - self.buf.len(), buf.len());
 
         self.as_mut().buf.extend_from_slice(&buf[..n]);
 
@@ -5,9 +5,8 @@ use tokio_util::codec::FramedWrite;
 
 use super::{Deflater, Writer};
 use crate::{
-    block,
     r#async::BlockCodec,
-    writer::{CompressionLevel, BGZF_EOF},
+    writer::{BlockSize, CompressionLevel, BGZF_EOF},
 };
 
 /// An async BGZF writer builder.
@@ -15,6 +14,7 @@ use crate::{
 pub struct Builder<W> {
     inner: W,
     compression_level: Option<CompressionLevel>,
+    block_size: Option<BlockSize>,
     worker_count: Option<usize>,
 }
 
@@ -26,6 +26,7 @@ where
         Self {
             inner,
             compression_level: None,
+            block_size: None,
             worker_count: None,
         }
     }
@@ -47,6 +48,26 @@ where
         self
     }
 
+    /// Sets a block size.
+    ///
+    /// This is the maximum number of uncompressed bytes written to a single block.
+    ///
+    /// By default, the block size is set to 65536 bytes (64 KiB).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, writer::BlockSize};
+    ///
+    /// let block_size = BlockSize::try_from(4096)?;
+    /// let builder = bgzf::AsyncWriter::builder(Vec::new()).set_block_size(block_size);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_block_size(mut self, block_size: BlockSize) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
     /// Sets a worker count.
     ///
     /// By default, the worker count is set to the number of available logical CPUs.
@@ -72,11 +93,13 @@ where
     /// ```
     pub fn build(self) -> Writer<W> {
         let compression_level = self.compression_level.unwrap_or_default();
+        let block_size = usize::from(self.block_size.unwrap_or_default());
         let worker_count = self.worker_count.unwrap_or_else(num_cpus::get);
 
         Writer {
             sink: Deflater::new(FramedWrite::new(self.inner, BlockCodec)).buffer(worker_count),
-            buf: BytesMut::with_capacity(block::MAX_UNCOMPRESSED_DATA_LENGTH),
+            buf: BytesMut::with_capacity(block_size),
+            block_size,
             eof_buf: Bytes::from_static(BGZF_EOF),
             compression_level: compression_level.into(),
         }
@@ -91,6 +114,7 @@ mod tests {
     fn test_new() {
         let builder = Builder::new(Vec::new());
         assert!(builder.compression_level.is_none());
+        assert!(builder.block_size.is_none());
         assert!(builder.worker_count.is_none());
     }
 }
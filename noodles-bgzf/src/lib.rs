@@ -39,7 +39,7 @@ mod r#async;
 
 mod block;
 mod gz;
-mod reader;
+pub mod reader;
 pub mod virtual_position;
 pub mod writer;
 
@@ -1,3 +1,10 @@
+//! BGZF reader.
+
+mod builder;
+mod inflater;
+
+pub use self::builder::Builder;
+
 use std::{
     cmp,
     io::{self, BufRead, Read, Seek, SeekFrom},
@@ -5,6 +12,7 @@ use std::{
 
 use byteorder::{ByteOrder, LittleEndian};
 
+use self::inflater::Inflater;
 use super::{gz, Block, VirtualPosition, BGZF_HEADER_SIZE};
 
 /// A BGZF reader.
@@ -32,12 +40,27 @@ pub struct Reader<R> {
     position: u64,
     cdata: Vec<u8>,
     block: Block,
+    inflater: Option<Inflater>,
 }
 
 impl<R> Reader<R>
 where
     R: Read,
 {
+    /// Creates a BGZF reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let builder = bgzf::Reader::builder(&data[..]);
+    /// let reader = builder.build();
+    /// ```
+    pub fn builder(inner: R) -> Builder<R> {
+        Builder::new(inner)
+    }
+
     /// Creates a BGZF reader.
     ///
     /// # Examples
@@ -48,12 +71,7 @@ where
     /// let reader = bgzf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self {
-            inner,
-            position: 0,
-            cdata: Vec::new(),
-            block: Block::default(),
-        }
+        Self::builder(inner).build()
     }
 
     /// Returns a reference to the underlying reader.
@@ -149,6 +167,10 @@ where
     pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
         let (cpos, upos) = pos.into();
 
+        if let Some(inflater) = self.inflater.as_mut() {
+            inflater.clear();
+        }
+
         self.inner.seek(SeekFrom::Start(cpos))?;
 
         let block_size = read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
@@ -171,7 +193,12 @@ where
         // If a new block is about to be read and the given buffer is guaranteed to be larger than
         // next block, reading to the block buffer can be skipped. The uncompressed data is read
         // directly to the given buffer to avoid double copying.
-        if self.block.is_eof() && buf.len() >= block::MAX_UNCOMPRESSED_DATA_LENGTH {
+        //
+        // This is skipped when blocks are decompressed by workers, as they are already queued.
+        if self.inflater.is_none()
+            && self.block.is_eof()
+            && buf.len() >= block::MAX_UNCOMPRESSED_DATA_LENGTH
+        {
             let block_size =
                 read_block_into(&mut self.inner, &mut self.cdata, &mut self.block, buf)?;
             self.block.set_cpos(self.position);
@@ -214,9 +241,17 @@ where
 
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         if self.block.is_eof() {
-            let block_size = read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
-            self.block.set_cpos(self.position);
-            self.position += block_size as u64;
+            if let Some(inflater) = self.inflater.as_mut() {
+                if let Some(block) = read_block_with_inflater(&mut self.inner, inflater)? {
+                    self.block = block;
+                    self.block.set_cpos(self.position);
+                    self.position += self.block.clen();
+                }
+            } else {
+                let block_size = read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
+                self.block.set_cpos(self.position);
+                self.position += block_size as u64;
+            }
         }
 
         Ok(self.block.buffer())
@@ -358,6 +393,33 @@ where
     Ok(clen)
 }
 
+// Fills the inflater queue with compressed blocks and returns the next decompressed block.
+//
+// An error reading a compressed block is queued after the blocks already read, i.e., it is only
+// returned once the preceding blocks are returned. Errors are terminal: no more blocks are read
+// from the stream after an error until the reader seeks.
+//
+// This returns `None` when there are no more blocks in the stream.
+fn read_block_with_inflater<R>(reader: &mut R, inflater: &mut Inflater) -> io::Result<Option<Block>>
+where
+    R: Read,
+{
+    while !inflater.is_closed() && !inflater.is_full() {
+        let mut cdata = Vec::new();
+
+        match read_compressed_block(reader, &mut cdata) {
+            Ok((0, 0)) => break,
+            Ok((clen, ulen)) => inflater.push(cdata, clen, ulen),
+            Err(e) => {
+                inflater.push_error(e);
+                break;
+            }
+        }
+    }
+
+    inflater.pop().transpose()
+}
+
 /// This is effectively the same as `std::io::default_read_exact`.
 fn default_read_exact<R>(reader: &mut R, mut buf: &mut [u8]) -> io::Result<()>
 where
@@ -423,6 +485,121 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_with_workers() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let expected: Vec<u8> = (0..=u8::MAX).cycle().take(1 << 18).collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&expected)?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::builder(&data[..]).set_worker_count(4).build();
+        let mut actual = Vec::new();
+        reader.read_to_end(&mut actual)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(reader.position(), data.len() as u64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_with_workers() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.virtual_position();
+        writer.write_all(b"-bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::builder(Cursor::new(&data))
+            .set_worker_count(2)
+            .build();
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        reader.seek(position)?;
+
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"-bgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_workers_and_invalid_block() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"-bgzf")?;
+        writer.flush()?;
+        let mut data = writer.finish()?;
+
+        // Truncates the EOF block.
+        data.truncate(data.len() - 4);
+
+        let mut reader = Reader::builder(&data[..]).set_worker_count(4).build();
+
+        assert_eq!(reader.fill_buf()?, b"noodles");
+        reader.consume(7);
+        assert_eq!(reader.fill_buf()?, b"-bgzf");
+        reader.consume(5);
+        assert!(reader.fill_buf().is_err());
+        assert!(reader.fill_buf().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_workers_and_invalid_block_data() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.virtual_position();
+        writer.write_all(b"-bgzf")?;
+        writer.flush()?;
+        writer.write_all(b"!")?;
+        let mut data = writer.finish()?;
+
+        // Sets an invalid DEFLATE block type (BTYPE = 11) in the second block.
+        let i = position.compressed() as usize + BGZF_HEADER_SIZE;
+        data[i] = 0x07;
+
+        let mut reader = Reader::builder(&data[..]).set_worker_count(4).build();
+
+        assert_eq!(reader.fill_buf()?, b"noodles");
+        reader.consume(7);
+        assert!(reader.fill_buf().is_err());
+        assert!(reader.fill_buf().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reader_is_send_and_sync() {
+        fn is_send_and_sync<T: Send + Sync>() {}
+        is_send_and_sync::<Reader<&[u8]>>();
+    }
+
     #[test]
     fn test_read_header() -> io::Result<()> {
         let mut reader = BGZF_EOF;
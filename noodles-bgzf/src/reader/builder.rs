@@ -0,0 +1,67 @@
+use std::io::Read;
+
+use super::{Inflater, Reader};
+use crate::Block;
+
+/// A BGZF reader builder.
+pub struct Builder<R> {
+    inner: R,
+    worker_count: Option<usize>,
+}
+
+impl<R> Builder<R>
+where
+    R: Read,
+{
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            worker_count: None,
+        }
+    }
+
+    /// Sets a worker count.
+    ///
+    /// When set to a value greater than 0, the reader reads ahead up to `worker_count` blocks and
+    /// decompresses them on worker threads while the current block is being consumed. This is
+    /// most beneficial when reading long runs of blocks, e.g., chunks of a region query.
+    ///
+    /// By default, the worker count is set to 0, i.e., blocks are decompressed on the calling
+    /// thread as they are needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let builder = bgzf::Reader::builder(&data[..]).set_worker_count(4);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: usize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds a BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::Reader::builder(&data[..]).build();
+    /// ```
+    pub fn build(self) -> Reader<R> {
+        let inflater = self
+            .worker_count
+            .filter(|&worker_count| worker_count > 0)
+            .map(Inflater::new);
+
+        Reader {
+            inner: self.inner,
+            position: 0,
+            cdata: Vec::new(),
+            block: Block::default(),
+            inflater,
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{mpsc, Arc, Condvar, Mutex, MutexGuard},
+    thread::{self, JoinHandle},
+};
+
+use super::inflate_data;
+use crate::Block;
+
+// A decompressed block (or error) that is set by a worker and taken by the reader.
+#[derive(Default)]
+struct Slot {
+    result: Mutex<Option<io::Result<Block>>>,
+    ready: Condvar,
+}
+
+impl Slot {
+    fn with_result(result: io::Result<Block>) -> Self {
+        Self {
+            result: Mutex::new(Some(result)),
+            ready: Condvar::new(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<io::Result<Block>>> {
+        self.result.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // Sets the result if it is not already set.
+    fn set(&self, result: io::Result<Block>) {
+        let mut guard = self.lock();
+
+        if guard.is_none() {
+            *guard = Some(result);
+            self.ready.notify_one();
+        }
+    }
+
+    fn take(&self) -> io::Result<Block> {
+        let mut guard = self.lock();
+
+        loop {
+            if let Some(result) = guard.take() {
+                return result;
+            }
+
+            guard = self.ready.wait(guard).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+}
+
+struct Job {
+    cdata: Vec<u8>,
+    clen: usize,
+    ulen: usize,
+    slot: Arc<Slot>,
+}
+
+impl Job {
+    fn run(self) {
+        let mut block = Block::default();
+        block.set_clen(self.clen as u64);
+        block.set_upos(0);
+        block.set_ulen(self.ulen);
+
+        let result = inflate_data(&self.cdata, block.buffer_mut()).map(|_| block);
+
+        // The slot is orphaned when pending blocks are discarded, e.g., after a seek.
+        self.slot.set(result);
+    }
+}
+
+impl Drop for Job {
+    // A job that is dropped without being run, e.g., if the workers are gone or a worker panics,
+    // still sets its slot so that the reader does not wait on it forever.
+    fn drop(&mut self) {
+        self.slot.set(Err(io::Error::new(
+            io::ErrorKind::Other,
+            "inflater worker disconnected",
+        )));
+    }
+}
+
+/// A pool of workers that decompresses blocks ahead of the reader.
+///
+/// Blocks are returned in the order they were submitted.
+pub(super) struct Inflater {
+    // `mpsc::Sender` is not `Sync`, so it is guarded to keep the reader `Sync`.
+    job_tx: Option<Mutex<mpsc::Sender<Job>>>,
+    handles: Vec<JoinHandle<()>>,
+    queue: VecDeque<Arc<Slot>>,
+    capacity: usize,
+    is_closed: bool,
+}
+
+impl Inflater {
+    pub fn new(worker_count: usize) -> Self {
+        let (job_tx, job_rx) = mpsc::channel::<Job>();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+
+        let handles = (0..worker_count)
+            .map(|_| {
+                let job_rx = Arc::clone(&job_rx);
+
+                thread::spawn(move || loop {
+                    let job = match job_rx.lock() {
+                        Ok(rx) => rx.recv(),
+                        Err(_) => break,
+                    };
+
+                    match job {
+                        Ok(job) => job.run(),
+                        Err(_) => break,
+                    }
+                })
+            })
+            .collect();
+
+        Self {
+            job_tx: Some(Mutex::new(job_tx)),
+            handles,
+            queue: VecDeque::with_capacity(worker_count),
+            capacity: worker_count,
+            is_closed: false,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.queue.len() >= self.capacity
+    }
+
+    pub fn push(&mut self, cdata: Vec<u8>, clen: usize, ulen: usize) {
+        let slot = Arc::new(Slot::default());
+
+        let job = Job {
+            cdata,
+            clen,
+            ulen,
+            slot: Arc::clone(&slot),
+        };
+
+        // If the workers are gone, the job is dropped, which sets an error in its slot.
+        if let Some(job_tx) = self.job_tx.as_ref() {
+            let job_tx = job_tx.lock().unwrap_or_else(|e| e.into_inner());
+            let _ = job_tx.send(job);
+        }
+
+        self.queue.push_back(slot);
+    }
+
+    /// Queues an error after the pending blocks and closes the inflater.
+    ///
+    /// This is returned by [`Self::pop`] after all previously pushed blocks.
+    pub fn push_error(&mut self, error: io::Error) {
+        let slot = Slot::with_result(Err(error));
+        self.queue.push_back(Arc::new(slot));
+        self.is_closed = true;
+    }
+
+    /// Returns whether the inflater is closed after an error.
+    ///
+    /// A closed inflater does not accept blocks until it is cleared.
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    /// Returns the next decompressed block.
+    ///
+    /// Errors are terminal: after an error is returned, pending blocks are discarded, and every
+    /// subsequent call returns an error until the inflater is cleared.
+    pub fn pop(&mut self) -> Option<io::Result<Block>> {
+        match self.queue.pop_front() {
+            Some(slot) => {
+                let result = slot.take();
+
+                if result.is_err() {
+                    self.queue.clear();
+                    self.is_closed = true;
+                }
+
+                Some(result)
+            }
+            None if self.is_closed => Some(Err(io::Error::new(
+                io::ErrorKind::Other,
+                "inflater is closed after a previous error",
+            ))),
+            None => None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.is_closed = false;
+    }
+}
+
+impl Drop for Inflater {
+    fn drop(&mut self) {
+        self.queue.clear();
+        self.job_tx.take();
+
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
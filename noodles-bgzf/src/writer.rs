@@ -1,19 +1,20 @@
 //! BGZF writer.
 
+mod block_size;
 mod builder;
 mod compression_level;
 
-pub use self::{builder::Builder, compression_level::CompressionLevel};
+pub use self::{block_size::BlockSize, builder::Builder, compression_level::CompressionLevel};
 
 use std::{
     cmp,
-    io::{self, Write},
+    io::{self, Read, Seek, SeekFrom, Write},
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use flate2::Crc;
 
-use super::{block, gz, VirtualPosition, BGZF_HEADER_SIZE};
+use super::{gz, VirtualPosition, BGZF_HEADER_SIZE};
 
 const BGZF_FLG: u8 = 0x04; // FEXTRA
 const BGZF_XFL: u8 = 0x00; // none
@@ -69,6 +70,7 @@ where
     inner: Option<W>,
     position: u64,
     buf: Vec<u8>,
+    block_size: usize,
     compression_level: CompressionLevelImpl,
 }
 
@@ -226,6 +228,49 @@ where
     }
 }
 
+impl<W> Writer<W>
+where
+    W: Read + Write + Seek,
+{
+    /// Creates a writer that appends to an existing BGZF stream.
+    ///
+    /// The stream must end with a BGZF EOF block. The writer is positioned at the start of this
+    /// block, which is then overwritten by new data. A new EOF block is written when the writer
+    /// is finished.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`io::ErrorKind::InvalidData`] error if the stream does not end with a BGZF
+    /// EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Read, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut writer = bgzf::Writer::append(Cursor::new(data))?;
+    /// writer.write_all(b"-bgzf")?;
+    /// let data = writer.finish()?.into_inner();
+    ///
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    /// let mut buf = String::new();
+    /// reader.read_to_string(&mut buf)?;
+    /// assert_eq!(buf, "noodles-bgzf");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn append(mut inner: W) -> io::Result<Self> {
+        let position = seek_to_eof_block(&mut inner)?;
+        let mut writer = Self::new(inner);
+        writer.position = position;
+        Ok(writer)
+    }
+}
+
 impl<W> Drop for Writer<W>
 where
     W: Write,
@@ -242,14 +287,11 @@ where
     W: Write,
 {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let max_write_len = cmp::min(
-            block::MAX_UNCOMPRESSED_DATA_LENGTH - self.buf.len(),
-            buf.len(),
-        );
+        let max_write_len = cmp::min(self.block_size - self.buf.len(), buf.len());
 
         self.buf.extend_from_slice(&buf[..max_write_len]);
 
-        if self.buf.len() >= block::MAX_UNCOMPRESSED_DATA_LENGTH {
+        if self.buf.len() >= self.block_size {
             self.flush()?;
         }
 
@@ -265,6 +307,31 @@ where
     }
 }
 
+fn seek_to_eof_block<R>(reader: &mut R) -> io::Result<u64>
+where
+    R: Read + Seek,
+{
+    let len = reader.seek(SeekFrom::End(0))?;
+
+    let eof_start = len
+        .checked_sub(BGZF_EOF.len() as u64)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing BGZF EOF block"))?;
+
+    reader.seek(SeekFrom::Start(eof_start))?;
+
+    let mut buf = vec![0; BGZF_EOF.len()];
+    reader.read_exact(&mut buf)?;
+
+    if buf[..] != BGZF_EOF[..] {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing BGZF EOF block",
+        ));
+    }
+
+    reader.seek(SeekFrom::Start(eof_start))
+}
+
 fn write_header<W>(writer: &mut W, cdata_len: usize) -> io::Result<()>
 where
     W: Write,
@@ -378,4 +445,77 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_append() -> io::Result<()> {
+        use std::io::Cursor;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?;
+        let eof_start = (data.len() - BGZF_EOF.len()) as u64;
+
+        let mut writer = Writer::append(Cursor::new(data))?;
+        assert_eq!(writer.position(), eof_start);
+        writer.write_all(b"-bgzf")?;
+        let data = writer.finish()?.into_inner();
+
+        let eof_start = data.len() - BGZF_EOF.len();
+        assert_eq!(&data[eof_start..], BGZF_EOF);
+
+        let mut reader = crate::Reader::new(&data[..]);
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        assert_eq!(buf, "noodles-bgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_with_missing_eof_block() {
+        use std::io::Cursor;
+
+        assert!(matches!(
+            Writer::append(Cursor::new(Vec::new())),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = vec![0; BGZF_EOF.len()];
+
+        assert!(matches!(
+            Writer::append(Cursor::new(data)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_write_with_block_size() -> Result<(), Box<dyn std::error::Error>> {
+        let block_size = BlockSize::try_from(100)?;
+        let mut writer = Writer::builder(Vec::new())
+            .set_block_size(block_size)
+            .build();
+
+        writer.write_all(&[b'n'; 1000])?;
+        let data = writer.finish()?;
+
+        let mut block_count = 0;
+        let mut src = &data[..];
+
+        while !src.is_empty() {
+            // BSIZE is the total block size minus 1.
+            let bsize = u16::from_le_bytes([src[16], src[17]]);
+            src = &src[usize::from(bsize) + 1..];
+            block_count += 1;
+        }
+
+        // 10 data blocks and the EOF block
+        assert_eq!(block_count, 11);
+
+        let mut reader = crate::Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, [b'n'; 1000]);
+
+        Ok(())
+    }
 }
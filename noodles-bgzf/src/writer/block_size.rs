@@ -0,0 +1,77 @@
+use std::{error, fmt};
+
+use crate::block::MAX_UNCOMPRESSED_DATA_LENGTH;
+
+/// A BGZF block size.
+///
+/// This is the maximum number of uncompressed bytes written to a single block. It must be
+/// between 1 and 65536 (64 KiB), inclusive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BlockSize(usize);
+
+impl Default for BlockSize {
+    fn default() -> Self {
+        Self(MAX_UNCOMPRESSED_DATA_LENGTH)
+    }
+}
+
+/// An error returned when a raw BGZF block size fails to convert.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryFromUsizeError {
+    /// The block size is invalid.
+    Invalid(usize),
+}
+
+impl error::Error for TryFromUsizeError {}
+
+impl fmt::Display for TryFromUsizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(n) => write!(
+                f,
+                "invalid input: expected 1..={}, got {}",
+                MAX_UNCOMPRESSED_DATA_LENGTH, n
+            ),
+        }
+    }
+}
+
+impl TryFrom<usize> for BlockSize {
+    type Error = TryFromUsizeError;
+
+    fn try_from(n: usize) -> Result<Self, Self::Error> {
+        match n {
+            1..=MAX_UNCOMPRESSED_DATA_LENGTH => Ok(Self(n)),
+            _ => Err(TryFromUsizeError::Invalid(n)),
+        }
+    }
+}
+
+impl From<BlockSize> for usize {
+    fn from(block_size: BlockSize) -> Self {
+        block_size.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(BlockSize::default(), BlockSize(65536));
+    }
+
+    #[test]
+    fn test_try_from_usize_for_block_size() {
+        assert_eq!(BlockSize::try_from(1), Ok(BlockSize(1)));
+        assert_eq!(BlockSize::try_from(4096), Ok(BlockSize(4096)));
+        assert_eq!(BlockSize::try_from(65536), Ok(BlockSize(65536)));
+
+        assert_eq!(BlockSize::try_from(0), Err(TryFromUsizeError::Invalid(0)));
+        assert_eq!(
+            BlockSize::try_from(65537),
+            Err(TryFromUsizeError::Invalid(65537))
+        );
+    }
+}
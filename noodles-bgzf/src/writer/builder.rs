@@ -1,13 +1,13 @@
 use std::io::Write;
 
-use super::{CompressionLevel, Writer};
-use crate::block;
+use super::{BlockSize, CompressionLevel, Writer};
 
 /// A BGZF writer builder.
 #[derive(Debug)]
 pub struct Builder<W> {
     inner: W,
     compression_level: Option<CompressionLevel>,
+    block_size: Option<BlockSize>,
 }
 
 impl<W> Builder<W>
@@ -18,6 +18,7 @@ where
         Self {
             inner,
             compression_level: None,
+            block_size: None,
         }
     }
 
@@ -38,21 +39,44 @@ where
         self
     }
 
-    /// Builds an async BGZF writer.
+    /// Sets a block size.
+    ///
+    /// This is the maximum number of uncompressed bytes written to a single block. Smaller blocks
+    /// allow finer-grained random access, and larger blocks typically compress better.
+    ///
+    /// By default, the block size is set to 65536 bytes (64 KiB).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, writer::BlockSize};
+    ///
+    /// let block_size = BlockSize::try_from(4096)?;
+    /// let builder = bgzf::Writer::builder(Vec::new()).set_block_size(block_size);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_block_size(mut self, block_size: BlockSize) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
+    /// Builds a BGZF writer.
     ///
     /// # Examples
     ///
     /// ```
     /// use noodles_bgzf as bgzf;
-    /// let writer = bgzf::AsyncWriter::builder(Vec::new()).build();
+    /// let writer = bgzf::Writer::builder(Vec::new()).build();
     /// ```
     pub fn build(self) -> Writer<W> {
         let compression_level = self.compression_level.unwrap_or_default();
+        let block_size = usize::from(self.block_size.unwrap_or_default());
 
         Writer {
             inner: Some(self.inner),
             position: 0,
-            buf: Vec::with_capacity(block::MAX_UNCOMPRESSED_DATA_LENGTH),
+            buf: Vec::with_capacity(block_size),
+            block_size,
             compression_level: compression_level.into(),
         }
     }
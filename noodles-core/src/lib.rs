@@ -3,6 +3,7 @@
 //! **noodles-core** contains shared structures and behavior among noodles libraries.
 
 pub mod position;
+pub mod records_ext;
 pub mod region;
 
-pub use self::{position::Position, region::Region};
+pub use self::{position::Position, records_ext::RecordsExt, region::Region};
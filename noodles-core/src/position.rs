@@ -53,6 +53,36 @@ impl Position {
     pub fn checked_add(self, other: usize) -> Option<Self> {
         usize::from(self).checked_add(other).and_then(Self::new)
     }
+
+    /// Returns the number of bases in the closed interval between this position and another.
+    ///
+    /// Both positions are included in the count, i.e., the distance from a position to itself
+    /// is 1. The order of the positions does not matter: if `other` is before this position, the
+    /// result is the same as `other.distance_to(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    ///
+    /// let start = Position::try_from(10)?;
+    /// let end = Position::try_from(20)?;
+    ///
+    /// assert_eq!(start.distance_to(end), 11);
+    /// assert_eq!(end.distance_to(start), 11);
+    /// assert_eq!(start.distance_to(start), 1);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn distance_to(self, other: Self) -> usize {
+        let (a, b) = (usize::from(self), usize::from(other));
+
+        // This cannot overflow because both positions are > 0.
+        if a < b {
+            b - a + 1
+        } else {
+            a - b + 1
+        }
+    }
 }
 
 impl fmt::Display for Position {
@@ -0,0 +1,82 @@
+//! Record iterator adapters.
+
+mod with_progress;
+mod with_record_number;
+
+pub use self::{with_progress::WithProgress, with_record_number::WithRecordNumber};
+
+use std::io;
+
+/// An extension trait for iterators over records.
+///
+/// This is implemented for all iterators with items of `io::Result<T>`, e.g., the record
+/// iterators of format readers.
+pub trait RecordsExt<T>: Iterator<Item = io::Result<T>> + Sized {
+    /// Returns an iterator that pairs each record with its 1-based record number.
+    ///
+    /// The record number is incremented for every item, including errors, which allows reporting
+    /// the position of a failed record, e.g., "record 8 failed".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::RecordsExt;
+    ///
+    /// let records: Vec<io::Result<&str>> = vec![
+    ///     Ok("r0"),
+    ///     Err(io::Error::from(io::ErrorKind::InvalidData)),
+    ///     Ok("r1"),
+    /// ];
+    ///
+    /// let mut iter = records.into_iter().with_record_number();
+    ///
+    /// assert!(matches!(iter.next(), Some((1, Ok("r0")))));
+    /// assert!(matches!(iter.next(), Some((2, Err(_)))));
+    /// assert!(matches!(iter.next(), Some((3, Ok("r1")))));
+    /// assert!(iter.next().is_none());
+    /// ```
+    fn with_record_number(self) -> WithRecordNumber<Self> {
+        WithRecordNumber::new(self)
+    }
+
+    /// Returns an iterator that calls the given callback with the number of records read so far
+    /// after every `interval` records.
+    ///
+    /// Every item, including errors, is counted. When the underlying iterator is exhausted, the
+    /// callback is called once more with the total number of records if it has not already been
+    /// reported. The counts passed to the callback are strictly increasing.
+    ///
+    /// The callback can also read other progress state, e.g., the number of bytes read from a
+    /// shared counter of the input stream.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `interval` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::RecordsExt;
+    ///
+    /// let records: Vec<io::Result<u32>> = (0..5).map(Ok).collect();
+    ///
+    /// let mut counts = Vec::new();
+    ///
+    /// for result in records.into_iter().with_progress(2, |n| counts.push(n)) {
+    ///     let _record = result?;
+    /// }
+    ///
+    /// assert_eq!(counts, [2, 4, 5]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn with_progress<F>(self, interval: u64, callback: F) -> WithProgress<Self, F>
+    where
+        F: FnMut(u64),
+    {
+        WithProgress::new(self, interval, callback)
+    }
+}
+
+impl<I, T> RecordsExt<T> for I where I: Iterator<Item = io::Result<T>> {}
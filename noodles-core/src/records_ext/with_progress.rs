@@ -0,0 +1,94 @@
+use std::io;
+
+/// An iterator that reports the number of records read at a fixed interval.
+///
+/// This is created by calling [`super::RecordsExt::with_progress`].
+pub struct WithProgress<I, F> {
+    inner: I,
+    interval: u64,
+    callback: F,
+    record_count: u64,
+    last_reported_record_count: u64,
+}
+
+impl<I, F> WithProgress<I, F> {
+    pub(super) fn new(inner: I, interval: u64, callback: F) -> Self {
+        assert!(interval > 0, "invalid progress interval: {}", interval);
+
+        Self {
+            inner,
+            interval,
+            callback,
+            record_count: 0,
+            last_reported_record_count: 0,
+        }
+    }
+}
+
+impl<I, F, T> Iterator for WithProgress<I, F>
+where
+    I: Iterator<Item = io::Result<T>>,
+    F: FnMut(u64),
+{
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next() {
+            Some(result) => {
+                self.record_count += 1;
+
+                if self.record_count % self.interval == 0 {
+                    (self.callback)(self.record_count);
+                    self.last_reported_record_count = self.record_count;
+                }
+
+                Some(result)
+            }
+            None => {
+                if self.record_count > self.last_reported_record_count {
+                    (self.callback)(self.record_count);
+                    self.last_reported_record_count = self.record_count;
+                }
+
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let records: Vec<io::Result<i32>> = (0..7).map(Ok).collect();
+
+        let mut counts = Vec::new();
+        let n = WithProgress::new(records.into_iter(), 3, |n| counts.push(n)).count();
+
+        assert_eq!(n, 7);
+        assert_eq!(counts, [3, 6, 7]);
+
+        let records: Vec<io::Result<i32>> = (0..6).map(Ok).collect();
+
+        let mut counts = Vec::new();
+        let mut iter = WithProgress::new(records.into_iter(), 3, |n| counts.push(n));
+        while iter.next().is_some() {}
+        assert!(iter.next().is_none());
+        drop(iter);
+
+        assert_eq!(counts, [3, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_with_zero_interval() {
+        let records: Vec<io::Result<i32>> = Vec::new();
+        WithProgress::new(records.into_iter(), 0, |_: u64| {});
+    }
+}
@@ -0,0 +1,56 @@
+use std::io;
+
+/// An iterator that pairs records with their 1-based record numbers.
+///
+/// This is created by calling [`super::RecordsExt::with_record_number`].
+pub struct WithRecordNumber<I> {
+    inner: I,
+    record_number: u64,
+}
+
+impl<I> WithRecordNumber<I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self {
+            inner,
+            record_number: 0,
+        }
+    }
+}
+
+impl<I, T> Iterator for WithRecordNumber<I>
+where
+    I: Iterator<Item = io::Result<T>>,
+{
+    type Item = (u64, io::Result<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.inner.next()?;
+        self.record_number += 1;
+        Some((self.record_number, result))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let records: Vec<io::Result<i32>> = vec![
+            Ok(8),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+            Ok(13),
+        ];
+
+        let actual: Vec<_> = WithRecordNumber::new(records.into_iter())
+            .map(|(n, result)| (n, result.ok()))
+            .collect();
+
+        assert_eq!(actual, [(1, Some(8)), (2, None), (3, None), (4, Some(13))]);
+    }
+}
@@ -12,6 +12,8 @@ use noodles_sam as sam;
 
 use super::{data_container::Slice, writer, DataContainer};
 
+const MIN_HEADER_CONTAINER_LEN: usize = 10000;
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Container {
     header: Header,
@@ -39,8 +41,11 @@ impl Container {
             .set_data(buf.into())
             .build();
 
-        let mut blocks = vec![block];
+        // Landmarks are slice offsets from the start of the container's data, which begins with
+        // the compression header block.
+        let mut landmark = block.len();
         let mut landmarks = Vec::new();
+        let mut blocks = vec![block];
 
         let container_reference_sequence_id =
             find_container_reference_sequence_id(data_container.slices())?;
@@ -86,9 +91,8 @@ impl Container {
                 slice_len += external_block.len();
             }
 
-            let last_landmark = landmarks.last().copied().unwrap_or(0);
-            let landmark = last_landmark + slice_len;
             landmarks.push(landmark);
+            landmark += slice_len;
         }
 
         let len = blocks.iter().map(|b| b.len()).sum();
@@ -215,9 +219,22 @@ impl TryFrom<&sam::Header> for Container {
             .set_data(data.freeze())
             .build();
 
-        let len = block.len();
-        let blocks = vec![block];
-        let landmarks = vec![0];
+        // The SAM header block is followed by an empty block that reserves space to allow the
+        // header to be rewritten in place. Its size follows htslib, which pads the container to
+        // `MAX(len * 1.5, 10000)` bytes, where `len` is the length of the SAM header block. htslib
+        // writes the padding as raw bytes after the blocks, so the padding block header makes this
+        // container slightly larger.
+        let padding_len = cmp::max(block.len() * 3 / 2, MIN_HEADER_CONTAINER_LEN) - block.len();
+
+        let padding_block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(padding_len)
+            .set_data(vec![0; padding_len].into())
+            .build();
+
+        let landmarks = vec![0, block.len()];
+        let blocks = vec![block, padding_block];
+        let len = blocks.iter().map(|b| b.len()).sum();
 
         let container_header = Header::builder()
             .set_length(len)
@@ -267,14 +284,14 @@ mod tests {
         let container = Container::try_from(&sam_header)?;
 
         let expected_header = Header::builder()
-            .set_length(65)
+            .set_length(10011)
             .set_reference_sequence_id(ReferenceSequenceId::None)
             .set_alignment_span(0)
             .set_record_count(0)
             .set_record_counter(0)
             .set_base_count(0)
-            .set_block_count(1)
-            .set_landmarks(vec![0])
+            .set_block_count(2)
+            .set_landmarks(vec![0, 65])
             .set_crc32(0)
             .build();
 
@@ -288,14 +305,24 @@ mod tests {
         data.put_i32_le(header_data_len);
         data.extend_from_slice(&header_data);
 
-        let expected_blocks = vec![Block::builder()
-            .set_compression_method(block::CompressionMethod::None)
-            .set_content_type(block::ContentType::FileHeader)
-            .set_content_id(0)
-            .set_uncompressed_len(56)
-            .set_data(data.into())
-            .set_crc32(0)
-            .build()];
+        let expected_blocks = vec![
+            Block::builder()
+                .set_compression_method(block::CompressionMethod::None)
+                .set_content_type(block::ContentType::FileHeader)
+                .set_content_id(0)
+                .set_uncompressed_len(56)
+                .set_data(data.into())
+                .set_crc32(0)
+                .build(),
+            Block::builder()
+                .set_compression_method(block::CompressionMethod::None)
+                .set_content_type(block::ContentType::FileHeader)
+                .set_content_id(0)
+                .set_uncompressed_len(9935)
+                .set_data(vec![0; 9935].into())
+                .set_crc32(0)
+                .build(),
+        ];
 
         assert_eq!(container.blocks(), expected_blocks);
 
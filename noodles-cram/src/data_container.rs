@@ -1,7 +1,7 @@
 //! CRAM data container and fields.
 
 pub(crate) mod builder;
-pub(crate) mod compression_header;
+pub mod compression_header;
 pub(crate) mod slice;
 
 pub use self::{compression_header::CompressionHeader, slice::Slice};
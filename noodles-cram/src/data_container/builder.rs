@@ -6,8 +6,6 @@ use noodles_sam as sam;
 use super::{slice, CompressionHeader, DataContainer, Slice};
 use crate::{writer::Options, Record};
 
-const MAX_SLICE_COUNT: usize = 1;
-
 #[derive(Debug)]
 pub struct Builder {
     slice_builder: slice::Builder,
@@ -40,12 +38,15 @@ impl Builder {
         self.base_count
     }
 
-    pub fn add_record(&mut self, record: Record) -> Result<(), AddRecordError> {
-        if self.slice_builders.len() >= MAX_SLICE_COUNT {
+    pub fn add_record(&mut self, options: &Options, record: Record) -> Result<(), AddRecordError> {
+        if self.slice_builders.len() >= options.slices_per_container.get() {
             return Err(AddRecordError::ContainerFull(record));
         }
 
-        match self.slice_builder.add_record(record) {
+        match self
+            .slice_builder
+            .add_record(options.records_per_slice.get(), record)
+        {
             Ok(r) => {
                 self.base_count += r.read_length() as i64;
                 Ok(())
@@ -72,19 +73,22 @@ impl Builder {
 
         let compression_header = build_compression_header(options, &self.slice_builders);
 
-        let record_counter = self.record_counter;
-        let slices = self
-            .slice_builders
-            .into_iter()
-            .map(|builder| {
-                builder.build(
-                    reference_sequence_repository,
-                    header,
-                    &compression_header,
-                    record_counter,
-                )
-            })
-            .collect::<Result<_, _>>()?;
+        let mut record_counter = self.record_counter;
+        let mut slices = Vec::with_capacity(self.slice_builders.len());
+
+        for builder in self.slice_builders {
+            let record_count = builder.records().len() as i64;
+
+            let slice = builder.build(
+                reference_sequence_repository,
+                header,
+                &compression_header,
+                record_counter,
+            )?;
+
+            slices.push(slice);
+            record_counter += record_count;
+        }
 
         Ok(DataContainer {
             compression_header,
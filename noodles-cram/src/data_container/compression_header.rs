@@ -1,15 +1,19 @@
+//! CRAM data container compression header.
+
 mod builder;
 pub(crate) mod data_series_encoding_map;
 pub(crate) mod encoding;
 pub(crate) mod preservation_map;
 mod tag_encoding_map;
 
+pub use self::{
+    data_series_encoding_map::DataSeriesEncodingMap, encoding::Encoding,
+    preservation_map::PreservationMap, tag_encoding_map::TagEncodingMap,
+};
+
 pub(crate) use self::{
     builder::Builder,
-    data_series_encoding_map::DataSeriesEncodingMap,
-    encoding::Encoding,
-    preservation_map::{PreservationMap, SubstitutionMatrix, TagIdsDictionary},
-    tag_encoding_map::TagEncodingMap,
+    preservation_map::{SubstitutionMatrix, TagIdsDictionary},
 };
 
 /// A CRAM data container compression header.
@@ -40,15 +44,24 @@ impl CompressionHeader {
         }
     }
 
-    pub(crate) fn preservation_map(&self) -> &PreservationMap {
+    /// Returns the preservation map.
+    pub fn preservation_map(&self) -> &PreservationMap {
         &self.preservation_map
     }
 
-    pub(crate) fn data_series_encoding_map(&self) -> &DataSeriesEncodingMap {
+    /// Returns the data series encoding map.
+    ///
+    /// This describes how each data series is encoded, e.g., whether it is stored in an external
+    /// block or using a Huffman code.
+    pub fn data_series_encoding_map(&self) -> &DataSeriesEncodingMap {
         &self.data_series_encoding_map
     }
 
-    pub(crate) fn tag_encoding_map(&self) -> &TagEncodingMap {
+    /// Returns the tag encoding map.
+    ///
+    /// This describes how the values of each tag are encoded. The map key is the tag ID, i.e., the
+    /// tag name and value type packed as a 24-bit integer.
+    pub fn tag_encoding_map(&self) -> &TagEncodingMap {
         &self.tag_encoding_map
     }
 }
@@ -5,7 +5,9 @@ pub use self::{builder::Builder, data_series::DataSeries};
 
 use super::Encoding;
 
-/// A container compression header data series encoding map.
+/// A CRAM data container compression header data series encoding map.
+///
+/// Optional data series are `None` when they are not used in the data container.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DataSeriesEncodingMap {
     bam_bit_flags_encoding: Encoding,
@@ -39,11 +41,11 @@ pub struct DataSeriesEncodingMap {
 }
 
 impl DataSeriesEncodingMap {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
-    pub fn len(&self) -> usize {
+    pub(crate) fn len(&self) -> usize {
         // BAM bit flags, CRAM bit flags, read lengths, in-seq positions, read groups, tag IDs
         let mut n = 6;
 
@@ -141,114 +143,142 @@ impl DataSeriesEncodingMap {
         n
     }
 
+    /// Returns the encoding of the BAM bit flags (`BF`) data series.
     pub fn bam_bit_flags_encoding(&self) -> &Encoding {
         &self.bam_bit_flags_encoding
     }
 
+    /// Returns the encoding of the CRAM bit flags (`CF`) data series.
     pub fn cram_bit_flags_encoding(&self) -> &Encoding {
         &self.cram_bit_flags_encoding
     }
 
+    /// Returns the encoding of the reference ID (`RI`) data series.
     pub fn reference_id_encoding(&self) -> Option<&Encoding> {
         self.reference_id_encoding.as_ref()
     }
 
+    /// Returns the encoding of the read lengths (`RL`) data series.
     pub fn read_lengths_encoding(&self) -> &Encoding {
         &self.read_lengths_encoding
     }
 
+    /// Returns the encoding of the in-seq positions (`AP`) data series.
     pub fn in_seq_positions_encoding(&self) -> &Encoding {
         &self.in_seq_positions_encoding
     }
 
+    /// Returns the encoding of the read groups (`RG`) data series.
     pub fn read_groups_encoding(&self) -> &Encoding {
         &self.read_groups_encoding
     }
 
+    /// Returns the encoding of the read names (`RN`) data series.
     pub fn read_names_encoding(&self) -> Option<&Encoding> {
         self.read_names_encoding.as_ref()
     }
 
+    /// Returns the encoding of the next mate bit flags (`MF`) data series.
     pub fn next_mate_bit_flags_encoding(&self) -> Option<&Encoding> {
         self.next_mate_bit_flags_encoding.as_ref()
     }
 
+    /// Returns the encoding of the next fragment reference sequence ID (`NS`) data series.
     pub fn next_fragment_reference_sequence_id_encoding(&self) -> Option<&Encoding> {
         self.next_fragment_reference_sequence_id_encoding.as_ref()
     }
 
+    /// Returns the encoding of the next mate alignment start (`NP`) data series.
     pub fn next_mate_alignment_start_encoding(&self) -> Option<&Encoding> {
         self.next_mate_alignment_start_encoding.as_ref()
     }
 
+    /// Returns the encoding of the template size (`TS`) data series.
     pub fn template_size_encoding(&self) -> Option<&Encoding> {
         self.template_size_encoding.as_ref()
     }
 
+    /// Returns the encoding of the distance to next fragment (`NF`) data series.
     pub fn distance_to_next_fragment_encoding(&self) -> Option<&Encoding> {
         self.distance_to_next_fragment_encoding.as_ref()
     }
 
+    /// Returns the encoding of the tag IDs (`TL`) data series.
     pub fn tag_ids_encoding(&self) -> &Encoding {
         &self.tag_ids_encoding
     }
 
+    /// Returns the encoding of the number of read features (`FN`) data series.
     pub fn number_of_read_features_encoding(&self) -> Option<&Encoding> {
         self.number_of_read_features_encoding.as_ref()
     }
 
+    /// Returns the encoding of the read features codes (`FC`) data series.
     pub fn read_features_codes_encoding(&self) -> Option<&Encoding> {
         self.read_features_codes_encoding.as_ref()
     }
 
+    /// Returns the encoding of the in-read positions (`FP`) data series.
     pub fn in_read_positions_encoding(&self) -> Option<&Encoding> {
         self.in_read_positions_encoding.as_ref()
     }
 
+    /// Returns the encoding of the deletion lengths (`DL`) data series.
     pub fn deletion_lengths_encoding(&self) -> Option<&Encoding> {
         self.deletion_lengths_encoding.as_ref()
     }
 
+    /// Returns the encoding of the stretches of bases (`BB`) data series.
     pub fn stretches_of_bases_encoding(&self) -> Option<&Encoding> {
         self.stretches_of_bases_encoding.as_ref()
     }
 
+    /// Returns the encoding of the stretches of quality scores (`QQ`) data series.
     pub fn stretches_of_quality_scores_encoding(&self) -> Option<&Encoding> {
         self.stretches_of_quality_scores_encoding.as_ref()
     }
 
+    /// Returns the encoding of the base substitution codes (`BS`) data series.
     pub fn base_substitution_codes_encoding(&self) -> Option<&Encoding> {
         self.base_substitution_codes_encoding.as_ref()
     }
 
+    /// Returns the encoding of the insertion (`IN`) data series.
     pub fn insertion_encoding(&self) -> Option<&Encoding> {
         self.insertion_encoding.as_ref()
     }
 
+    /// Returns the encoding of the reference skip length (`RS`) data series.
     pub fn reference_skip_length_encoding(&self) -> Option<&Encoding> {
         self.reference_skip_length_encoding.as_ref()
     }
 
+    /// Returns the encoding of the padding (`PD`) data series.
     pub fn padding_encoding(&self) -> Option<&Encoding> {
         self.padding_encoding.as_ref()
     }
 
+    /// Returns the encoding of the hard clip (`HC`) data series.
     pub fn hard_clip_encoding(&self) -> Option<&Encoding> {
         self.hard_clip_encoding.as_ref()
     }
 
+    /// Returns the encoding of the soft clip (`SC`) data series.
     pub fn soft_clip_encoding(&self) -> Option<&Encoding> {
         self.soft_clip_encoding.as_ref()
     }
 
+    /// Returns the encoding of the mapping qualities (`MQ`) data series.
     pub fn mapping_qualities_encoding(&self) -> Option<&Encoding> {
         self.mapping_qualities_encoding.as_ref()
     }
 
+    /// Returns the encoding of the bases (`BA`) data series.
     pub fn bases_encoding(&self) -> Option<&Encoding> {
         self.bases_encoding.as_ref()
     }
 
+    /// Returns the encoding of the quality scores (`QS`) data series.
     pub fn quality_scores_encoding(&self) -> Option<&Encoding> {
         self.quality_scores_encoding.as_ref()
     }
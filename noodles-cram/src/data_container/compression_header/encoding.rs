@@ -2,25 +2,45 @@ mod kind;
 
 pub use self::kind::Kind;
 
+/// A CRAM data container compression header encoding.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Encoding {
+    /// No encoding (`NULL`).
     Null,
-    // block_content_id
+    /// Values are stored in an external block (`EXTERNAL`).
+    ///
+    /// The field is the block content ID.
     External(i32),
-    // offset, m
+    /// Golomb coding (`GOLOMB`).
+    ///
+    /// The fields are the offset and `M`.
     Golomb(i32, i32),
-    // alphabet, bit_lens
+    /// Canonical Huffman coding (`HUFFMAN`).
+    ///
+    /// The fields are the alphabet and the bit lengths of each symbol.
     Huffman(Vec<i32>, Vec<u32>),
-    // len_encoding, value_encoding
+    /// A byte array prefixed with its length (`BYTE_ARRAY_LEN`).
+    ///
+    /// The fields are the length encoding and the value encoding.
     ByteArrayLen(Box<Encoding>, Box<Encoding>),
-    // stop_byte, block_content_id
+    /// A byte array terminated by a stop byte (`BYTE_ARRAY_STOP`).
+    ///
+    /// The fields are the stop byte and the block content ID.
     ByteArrayStop(u8, i32),
-    // offset, len
+    /// Binary coding (`BETA`).
+    ///
+    /// The fields are the offset and the number of bits.
     Beta(i32, u32),
-    // offset, k
+    /// Subexponential coding (`SUBEXP`).
+    ///
+    /// The fields are the offset and `K`.
     Subexp(i32, i32),
-    // offset, log2_m
+    /// Golomb-Rice coding (`GOLOMB_RICE`).
+    ///
+    /// The fields are the offset and log2(`M`).
     GolombRice(i32, i32),
-    // offset
+    /// Elias gamma coding (`GAMMA`).
+    ///
+    /// The field is the offset.
     Gamma(i32),
 }
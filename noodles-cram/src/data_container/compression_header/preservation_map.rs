@@ -8,6 +8,7 @@ pub use {
     tag_ids_dictionary::TagIdsDictionary,
 };
 
+/// A CRAM data container compression header preservation map.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PreservationMap {
     read_names_included: bool,
@@ -18,7 +19,7 @@ pub struct PreservationMap {
 }
 
 impl PreservationMap {
-    pub fn new(
+    pub(crate) fn new(
         read_names_included: bool,
         ap_data_series_delta: bool,
         is_reference_required: bool,
@@ -34,23 +35,26 @@ impl PreservationMap {
         }
     }
 
+    /// Returns whether read names are preserved for all records (`RN`).
     pub fn read_names_included(&self) -> bool {
         self.read_names_included
     }
 
+    /// Returns whether the in-seq positions (`AP`) data series is delta encoded (`AP`).
     pub fn ap_data_series_delta(&self) -> bool {
         self.ap_data_series_delta
     }
 
+    /// Returns whether a reference sequence is required to restore the data (`RR`).
     pub fn is_reference_required(&self) -> bool {
         self.is_reference_required
     }
 
-    pub fn substitution_matrix(&self) -> &SubstitutionMatrix {
+    pub(crate) fn substitution_matrix(&self) -> &SubstitutionMatrix {
         &self.substitution_matrix
     }
 
-    pub fn tag_ids_dictionary(&self) -> &TagIdsDictionary {
+    pub(crate) fn tag_ids_dictionary(&self) -> &TagIdsDictionary {
         &self.tag_ids_dictionary
     }
 }
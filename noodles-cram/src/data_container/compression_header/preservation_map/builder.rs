@@ -6,6 +6,7 @@ pub struct Builder {
     read_names_included: bool,
     ap_data_series_delta: bool,
     reference_required: bool,
+    has_mapped_records: bool,
     substitution_matrix_builder: substitution_matrix::Builder,
     tag_ids_dictionary_builder: tag_ids_dictionary::Builder,
 }
@@ -17,6 +18,10 @@ impl Builder {
     }
 
     pub fn update(&mut self, record: &Record) {
+        if !record.bam_flags().is_unmapped() {
+            self.has_mapped_records = true;
+        }
+
         self.substitution_matrix_builder.update(record);
         self.tag_ids_dictionary_builder.update(record);
     }
@@ -25,10 +30,14 @@ impl Builder {
         let substitution_matrix = self.substitution_matrix_builder.build();
         let tag_ids_dictionary = self.tag_ids_dictionary_builder.build();
 
+        // Unmapped records are stored verbatim, so a container with only unmapped records can be
+        // decoded without a reference sequence.
+        let reference_required = self.reference_required && self.has_mapped_records;
+
         PreservationMap::new(
             self.read_names_included,
             self.ap_data_series_delta,
-            self.reference_required,
+            reference_required,
             substitution_matrix,
             tag_ids_dictionary,
         )
@@ -43,6 +52,7 @@ impl Default for Builder {
             read_names_included: true,
             ap_data_series_delta: true,
             reference_required: true,
+            has_mapped_records: false,
             substitution_matrix_builder: substitution_matrix::Builder::default(),
             tag_ids_dictionary_builder: tag_ids_dictionary::Builder::default(),
         }
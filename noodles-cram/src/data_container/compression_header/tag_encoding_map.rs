@@ -6,6 +6,9 @@ use std::{collections::HashMap, ops::Deref};
 
 use super::Encoding;
 
+/// A CRAM data container compression header tag encoding map.
+///
+/// This maps tag IDs to the encodings of their values.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TagEncodingMap(HashMap<i32, Encoding>);
 
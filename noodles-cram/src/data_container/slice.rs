@@ -52,6 +52,70 @@ impl Slice {
         &self.external_blocks
     }
 
+    /// Returns the embedded reference sequence of this slice.
+    ///
+    /// A CRAM with an embedded reference stores the reference bases used by a slice in one of its
+    /// external blocks. The returned sequence starts at the returned position, which is the slice
+    /// alignment start, and covers the span of the slice.
+    ///
+    /// This returns `None` if the slice does not have an embedded reference.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the embedded reference block is missing or fails to decompress or
+    /// if the slice does not have an alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// while let Some(container) = reader.read_data_container()? {
+    ///     for slice in container.slices() {
+    ///         if let Some((start, sequence)) = slice.embedded_reference_sequence()? {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn embedded_reference_sequence(
+        &self,
+    ) -> io::Result<Option<(Position, fasta::record::Sequence)>> {
+        let block_content_id = match self.header().embedded_reference_bases_block_content_id() {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+
+        let block = self
+            .external_blocks()
+            .iter()
+            .find(|block| block.content_id() == block_content_id)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "missing embedded reference block: content ID {}",
+                        block_content_id
+                    ),
+                )
+            })?;
+
+        let alignment_start = self.header().alignment_start().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid slice alignment start")
+        })?;
+
+        let data = block.decompressed_data()?;
+        let sequence = fasta::record::Sequence::from(data);
+
+        Ok(Some((alignment_start, sequence)))
+    }
+
     /// Reads and returns a list of raw records in this slice.
     ///
     /// # Examples
@@ -130,7 +194,7 @@ impl Slice {
             records,
         )?;
 
-        self.resolve_quality_scores(records);
+        self.resolve_quality_scores(records)?;
 
         Ok(())
     }
@@ -142,22 +206,7 @@ impl Slice {
         compression_header: &CompressionHeader,
         records: &mut [Record],
     ) -> io::Result<()> {
-        let embedded_reference_sequence = if let Some(block_content_id) =
-            self.header().embedded_reference_bases_block_content_id()
-        {
-            let block = self
-                .external_blocks()
-                .iter()
-                .find(|block| block.content_id() == block_content_id)
-                .expect("invalid block content ID");
-
-            let data = block.decompressed_data()?;
-            let sequence = fasta::record::Sequence::from(data);
-
-            Some(sequence)
-        } else {
-            None
-        };
+        let embedded_reference_sequence = self.embedded_reference_sequence()?;
 
         for record in records {
             if record.bam_flags().is_unmapped() || record.cram_flags().decode_sequence_as_unknown()
@@ -182,14 +231,8 @@ impl Slice {
                     .expect("invalid reference sequence name");
 
                 Some(sequence)
-            } else if let Some(ref sequence) = embedded_reference_sequence {
-                let offset = self
-                    .header()
-                    .alignment_start()
-                    .map(usize::from)
-                    .expect("invalid slice alignment start");
-
-                let start = usize::from(alignment_start) - offset + 1;
+            } else if let Some((offset, ref sequence)) = embedded_reference_sequence {
+                let start = usize::from(alignment_start) - usize::from(offset) + 1;
                 alignment_start = Position::try_from(start)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
@@ -214,17 +257,19 @@ impl Slice {
         Ok(())
     }
 
-    fn resolve_quality_scores(&self, records: &mut [Record]) {
+    fn resolve_quality_scores(&self, records: &mut [Record]) -> io::Result<()> {
         for record in records {
             if !record.flags().is_unmapped()
                 && !record.cram_flags().are_quality_scores_stored_as_array()
             {
                 let quality_scores =
-                    resolve_quality_scores(record.features(), record.read_length());
+                    resolve_quality_scores(record.features(), record.read_length())?;
 
                 record.quality_scores = quality_scores;
             }
         }
+
+        Ok(())
     }
 }
 
@@ -240,7 +285,7 @@ fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
 
     let mut i = 0;
 
-    while i < records.len() - 1 {
+    while i < records.len().saturating_sub(1) {
         if mate_indices[i].is_none() {
             i += 1;
             continue;
@@ -344,6 +389,56 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_embedded_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::container::block::ContentType;
+
+        let alignment_start = Position::try_from(8)?;
+
+        let build_slice = |embedded_reference_bases_block_content_id| {
+            let mut builder = Header::builder()
+                .set_alignment_start(alignment_start)
+                .set_alignment_span(4);
+
+            if let Some(id) = embedded_reference_bases_block_content_id {
+                builder = builder.set_embedded_reference_bases_block_content_id(id);
+            }
+
+            let core_data_block = Block::builder()
+                .set_content_type(ContentType::CoreData)
+                .build();
+
+            let external_blocks = vec![Block::builder()
+                .set_content_type(ContentType::ExternalData)
+                .set_content_id(1)
+                .set_uncompressed_len(4)
+                .set_data(b"ACGT".to_vec().into())
+                .build()];
+
+            Slice::new(builder.build(), core_data_block, external_blocks)
+        };
+
+        let slice = build_slice(Some(1));
+        assert_eq!(
+            slice.embedded_reference_sequence()?,
+            Some((
+                alignment_start,
+                fasta::record::Sequence::from(b"ACGT".to_vec())
+            ))
+        );
+
+        let slice = build_slice(None);
+        assert!(slice.embedded_reference_sequence()?.is_none());
+
+        let slice = build_slice(Some(2));
+        assert!(matches!(
+            slice.embedded_reference_sequence(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_mates() -> Result<(), Box<dyn std::error::Error>> {
         use sam::record::ReadName;
@@ -404,19 +499,29 @@ mod tests {
         // assert_eq!(records[2].read_name(), b"3");
 
         assert_eq!(records[3].read_name(), Some(&read_name_1));
-        // FIXME
-        /* assert_eq!(
+        assert_eq!(
             records[3].next_fragment_reference_sequence_id(),
             records[0].reference_sequence_id()
         );
         assert_eq!(
             records[3].mate_alignment_start(),
             records[0].alignment_start(),
-        ); */
+        );
+
+        assert_eq!(records[0].template_size(), 9);
+        assert_eq!(records[3].template_size(), -9);
 
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_mates_with_no_records() -> io::Result<()> {
+        let mut records = Vec::new();
+        resolve_mates(&mut records)?;
+        assert!(records.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_template_size() -> Result<(), noodles_core::position::TryFromIntError> {
         use sam::record::Flags;
@@ -485,4 +590,118 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_resolve_records_with_bases_and_scores() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::ReferenceSequence;
+
+        use crate::{data_container, record::Feature, writer::Options};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 32)?)
+            .build();
+
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(vec![b'A'; 32]),
+        )]);
+
+        let sam_record = sam::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(5)?)
+            .set_cigar("2S16M2I4M".parse()?)
+            .set_sequence("TTACGTACGTACGTACGTCCGTAC".parse()?)
+            .set_quality_scores("!!0123456789:;<=>?@ABCDE".parse()?)
+            .build();
+
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+
+        // Long stretches of read bases are stored as bases (`BB`) and scores (`QQ`).
+        assert!(record
+            .features()
+            .iter()
+            .any(|feature| matches!(feature, Feature::Bases(..))));
+        assert!(record
+            .features()
+            .iter()
+            .any(|feature| matches!(feature, Feature::Scores(..))));
+
+        let options = Options::default();
+        let mut builder = data_container::Builder::new(0);
+        assert!(builder.add_record(&options, record).is_ok());
+
+        let data_container = builder.build(&options, &reference_sequence_repository, &header)?;
+        let compression_header = data_container.compression_header();
+        let slice = &data_container.slices()[0];
+
+        let mut records = slice.records(compression_header)?;
+        slice.resolve_records(
+            &reference_sequence_repository,
+            &header,
+            compression_header,
+            &mut records,
+        )?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].sequence(), sam_record.sequence());
+        assert_eq!(records[0].quality_scores(), sam_record.quality_scores());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_multiple_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::ReferenceSequence;
+
+        use crate::{container::ReferenceSequenceId, data_container, writer::Options};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .build();
+
+        let records = [(1, 5), (0, 1), (1, 2)]
+            .into_iter()
+            .map(|(reference_sequence_id, alignment_start)| {
+                Position::try_from(alignment_start).map(|position| {
+                    Record::builder()
+                        .set_reference_sequence_id(reference_sequence_id)
+                        .set_alignment_start(position)
+                        .build()
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let options = Options::default();
+        let mut builder = data_container::Builder::new(0);
+
+        for record in records {
+            assert!(builder.add_record(&options, record).is_ok());
+        }
+
+        let data_container = builder.build(&options, &fasta::Repository::default(), &header)?;
+
+        let slice = &data_container.slices()[0];
+        assert_eq!(
+            slice.header().reference_sequence_id(),
+            ReferenceSequenceId::Many
+        );
+
+        let actual: Vec<_> = slice
+            .records(data_container.compression_header())?
+            .iter()
+            .map(|record| (record.reference_sequence_id(), record.alignment_start()))
+            .collect();
+
+        let expected = [
+            (Some(1), Position::new(5)),
+            (Some(0), Position::new(1)),
+            (Some(1), Position::new(2)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -22,7 +22,6 @@ use crate::{
 use super::{Header, Slice};
 
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
-const MAX_RECORD_COUNT: usize = 10240;
 
 #[derive(Debug, Default)]
 pub struct Builder {
@@ -43,8 +42,12 @@ impl Builder {
         &self.records
     }
 
-    pub fn add_record(&mut self, record: Record) -> Result<&Record, AddRecordError> {
-        if self.records.len() >= MAX_RECORD_COUNT {
+    pub fn add_record(
+        &mut self,
+        max_record_count: usize,
+        record: Record,
+    ) -> Result<&Record, AddRecordError> {
+        if self.records.len() >= max_record_count {
             return Err(AddRecordError::SliceFull(record));
         }
 
@@ -61,6 +64,10 @@ impl Builder {
         record_counter: i64,
     ) -> io::Result<Slice> {
         let slice_reference_sequence_id = find_slice_reference_sequence_id(&self.records);
+        let is_unmapped = self
+            .records
+            .iter()
+            .all(|record| record.bam_flags().is_unmapped());
 
         let (slice_alignment_start, slice_alignment_end) = if slice_reference_sequence_id.is_some()
         {
@@ -83,12 +90,14 @@ impl Builder {
             block_content_ids.push(block.content_id());
         }
 
+        // Unmapped records do not use the reference sequence, so the MD5 of a slice with only
+        // (placed) unmapped records is left blank to not require the reference sequence.
         let reference_md5 = match (
             slice_reference_sequence_id,
             slice_alignment_start,
             slice_alignment_end,
         ) {
-            (ReferenceSequenceId::Some(id), Some(start), Some(end)) => {
+            (ReferenceSequenceId::Some(id), Some(start), Some(end)) if !is_unmapped => {
                 let reference_sequence_name = header
                     .reference_sequences()
                     .get_index(id as usize)
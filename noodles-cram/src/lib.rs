@@ -20,8 +20,12 @@ pub mod record;
 pub(crate) mod writer;
 
 pub use self::{
-    data_container::DataContainer, file_definition::FileDefinition, indexer::index, reader::Reader,
-    record::Record, writer::Writer,
+    data_container::DataContainer,
+    file_definition::FileDefinition,
+    indexer::index,
+    reader::Reader,
+    record::Record,
+    writer::{write_empty, QualityScoreBinning, Writer},
 };
 
 #[cfg(feature = "async")]
@@ -416,9 +416,18 @@ pub(crate) fn read_file_header_block(block: &Block) -> io::Result<String> {
     let data = block.decompressed_data()?;
     let mut reader = &data[..];
 
-    let _header_len = reader.read_i32::<LittleEndian>()?;
+    let header_len = reader.read_i32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
 
-    str::from_utf8(reader)
+    let buf = reader.get(..header_len).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid header length: exceeds block data length",
+        )
+    })?;
+
+    str::from_utf8(buf)
         .map(|s| s.into())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
@@ -475,6 +484,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_file_header_block_with_padding() -> io::Result<()> {
+        use bytes::BufMut;
+
+        let mut data = BytesMut::new();
+        data.put_i32_le(7);
+        data.extend_from_slice(b"noodles");
+        data.extend_from_slice(&[0; 8]);
+
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.freeze())
+            .build();
+
+        assert_eq!(read_file_header_block(&block)?, "noodles");
+
+        let mut data = BytesMut::new();
+        data.put_i32_le(8);
+        data.extend_from_slice(b"noodles");
+
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.freeze())
+            .build();
+
+        assert!(matches!(
+            read_file_header_block(&block),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_file_header_block_with_invalid_content_type() {
         let block = Block::builder()
@@ -16,10 +16,15 @@ pub use self::{
 use std::io;
 
 use noodles_core::Position;
+use noodles_fasta as fasta;
 use noodles_sam as sam;
 use once_cell::sync::OnceCell;
 
 /// A CRAM record.
+///
+/// A CRAM record implements [`sam::AlignmentRecord`], which allows it to be used in generic code
+/// alongside SAM and BAM records. Alignment fields that are not stored directly (e.g., the CIGAR)
+/// are lazily resolved from the read features.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Record {
     pub(crate) id: i64,
@@ -137,6 +142,43 @@ impl Record {
         &self.features
     }
 
+    /// Calculates the edit distance (`NM`) of the read to the reference sequence.
+    ///
+    /// This returns `None` if the record is unmapped. See [`resolve::resolve_edit_distance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::{self as cram, record::Feature};
+    /// use noodles_fasta as fasta;
+    /// use noodles_sam::record::{sequence::Base, Flags};
+    ///
+    /// let record = cram::Record::builder()
+    ///     .set_bam_flags(Flags::empty())
+    ///     .set_read_length(4)
+    ///     .set_alignment_start(Position::MIN)
+    ///     .add_feature(Feature::Bases(Position::MIN, vec![Base::A, Base::T, Base::G, Base::T]))
+    ///     .build();
+    ///
+    /// let reference_sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
+    ///
+    /// assert_eq!(record.edit_distance(Some(&reference_sequence)).transpose()?, Some(1));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn edit_distance(
+        &self,
+        reference_sequence: Option<&fasta::record::Sequence>,
+    ) -> Option<io::Result<u32>> {
+        if self.bam_bit_flags.is_unmapped() {
+            return None;
+        }
+
+        self.alignment_start.map(|alignment_start| {
+            resolve::resolve_edit_distance(reference_sequence, &self.features, alignment_start)
+        })
+    }
+
     pub(crate) fn add_feature(&mut self, feature: Feature) {
         self.features.push(feature);
     }
@@ -181,7 +223,9 @@ impl sam::AlignmentRecord for Record {
             if self.flags().is_unmapped() {
                 sam::record::Cigar::default()
             } else {
-                resolve::resolve_features(self.features(), self.read_length())
+                // A read position can only overflow with invalid features, which are otherwise
+                // reported when the bases of the record are resolved.
+                resolve::resolve_features(self.features(), self.read_length()).unwrap_or_default()
             }
         })
     }
@@ -273,4 +317,179 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_edit_distance() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::ReferenceSequence;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 12)?)
+            .build();
+
+        let reference_sequence = fasta::record::Sequence::from(b"ACGTACGTACGT".to_vec());
+
+        // ACGTAC--GT-ACGT
+        // ACTTACGG-TAACG-
+        let sam_record = sam::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_cigar("6M2I1D1M1I3M1D".parse()?)
+            .set_sequence("ACTTACGGTAACG".parse()?)
+            .set_quality_scores("NNNNNNNNNNNNN".parse()?)
+            .build();
+
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+        let actual = record
+            .edit_distance(Some(&reference_sequence))
+            .transpose()?;
+
+        // 1 mismatch + 2 inserted bases + 1 deleted base + 1 inserted base + 1 deleted base
+        assert_eq!(actual, Some(6));
+
+        assert!(Record::default().edit_distance(None).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_alignment_record_with_sam_bam_and_cram_records(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use md5::{Digest, Md5};
+        use noodles_bam as bam;
+        use sam::{header::ReferenceSequence, AlignmentRecord, AlignmentWriter};
+
+        type Summary = (
+            bam::stats::FlagStats,
+            bam::count::RecordCounts,
+            f64,
+            [u64; 256],
+            Vec<String>,
+        );
+
+        fn summarize<R>(header: &sam::Header, records: &[R]) -> io::Result<Summary>
+        where
+            R: AlignmentRecord + Clone,
+        {
+            let reference_sequences = header.reference_sequences();
+            let records = || records.iter().cloned().map(Ok);
+
+            Ok((
+                bam::flagstat(reference_sequences, records())?,
+                bam::count_records(header, records())?,
+                bam::stats::proper_pair_rate(records())?,
+                bam::stats::mapq_histogram(records())?,
+                bam::coverage_bedgraph(records(), reference_sequences, true, &[])
+                    .map(|result| result.map(|record| record.to_string()))
+                    .collect::<io::Result<_>>()?,
+            ))
+        }
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGTACGTACGT";
+
+        let md5_checksum: [u8; 16] = Md5::digest(REFERENCE_SEQUENCE).into();
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq0".parse()?)
+                    .set_length(i32::try_from(REFERENCE_SEQUENCE.len())?)
+                    .set_md5_checksum(md5_checksum.into())
+                    .build()?,
+            )
+            .build();
+
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(REFERENCE_SEQUENCE.to_vec()),
+        )]);
+
+        let sam_records: Vec<sam::Record> = [
+            "r0\t99\tsq0\t1\t60\t4M\t=\t9\t12\tACGT\tNDLS",
+            "r1\t0\tsq0\t3\t60\t2M1I2M\t*\t0\t0\tGTTAC\tNDLSN",
+            "r2\t16\tsq0\t6\t30\t2S3M1D2M\t*\t0\t0\tTTCATCG\tNDLSNDL",
+            "r0\t147\tsq0\t9\t60\t4M\t=\t1\t-12\tACGT\tSLDN",
+            "r3\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS",
+        ]
+        .iter()
+        .map(|s| s.parse())
+        .collect::<Result<_, _>>()?;
+
+        let mut writer = bam::Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+
+        for record in &sam_records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish()?;
+
+        let mut reader = bam::Reader::new(&writer.get_ref().get_ref()[..]);
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+        let bam_records: Vec<_> = reader.records().collect::<Result<_, _>>()?;
+
+        let mut writer = crate::Writer::builder(Vec::new())
+            .set_reference_sequence_repository(reference_sequence_repository.clone())
+            .build();
+        writer.write_alignment_header(&header)?;
+
+        for record in &sam_records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = crate::Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let cram_records: Vec<_> = reader
+            .records(&reference_sequence_repository, &header)
+            .collect::<Result<_, _>>()?;
+
+        let expected = summarize(&header, &sam_records)?;
+
+        let (flag_stats, record_counts, proper_pair_rate, mapq_histogram, coverage) = &expected;
+
+        let qc_pass = flag_stats.qc_pass();
+        assert_eq!(qc_pass.read(), 5);
+        assert_eq!(qc_pass.mapped(), 4);
+        assert_eq!(qc_pass.paired(), 2);
+        assert_eq!(qc_pass.proper_pair(), 2);
+        assert_eq!(qc_pass.mate_mapped(), 2);
+        assert_eq!(qc_pass.mate_reference_sequence_id_mismatch(), 0);
+        assert_eq!(flag_stats.qc_fail().read(), 0);
+
+        let reference_sequence_counts = record_counts.reference_sequences();
+        assert_eq!(reference_sequence_counts.len(), 1);
+        assert_eq!(reference_sequence_counts[0].mapped_record_count(), 4);
+        assert_eq!(reference_sequence_counts[0].unmapped_record_count(), 0);
+        assert_eq!(record_counts.unplaced_unmapped_record_count(), 1);
+
+        assert_eq!(*proper_pair_rate, 1.0);
+
+        assert_eq!(mapq_histogram[30], 1);
+        assert_eq!(mapq_histogram[60], 3);
+        assert_eq!(mapq_histogram[255], 1);
+
+        assert_eq!(
+            coverage,
+            &[
+                "sq0\t0\t2\t1",
+                "sq0\t2\t4\t2",
+                "sq0\t4\t5\t1",
+                "sq0\t5\t6\t2",
+                "sq0\t6\t8\t1",
+                "sq0\t8\t11\t2",
+                "sq0\t11\t12\t1",
+                "sq0\t12\t16\t0",
+            ]
+        );
+
+        assert_eq!(summarize(&header, &bam_records)?, expected);
+        assert_eq!(summarize(&header, &cram_records)?, expected);
+
+        Ok(())
+    }
 }
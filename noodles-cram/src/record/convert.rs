@@ -1,11 +1,14 @@
 use std::io;
 
-use noodles_sam::{self as sam, AlignmentRecord};
+use noodles_sam::{self as sam, record::cigar::op::Kind, AlignmentRecord};
 
-use super::{resolve::resolve_features, Features, Flags, Record};
+use super::{resolve::resolve_features, Features, Flags, NextMateFlags, Record};
 
 impl Record {
     /// Converts an alignment record to a CRAM record.
+    ///
+    /// The read group (`RG`) data field is removed from the tags and stored as the read group ID.
+    /// The order of the other data fields is preserved.
     pub fn try_from_alignment_record<R>(header: &sam::Header, record: &R) -> io::Result<Self>
     where
         R: AlignmentRecord + ?Sized,
@@ -40,7 +43,7 @@ impl Record {
             builder = builder.set_read_name(read_name.clone());
         }
 
-        // next mate bit flags
+        builder = builder.set_next_mate_flags(get_next_mate_flags(bam_flags));
 
         if let Some(reference_sequence) = record
             .mate_reference_sequence(header.reference_sequences())
@@ -60,21 +63,26 @@ impl Record {
         // distance to next fragment
 
         if !record.data().is_empty() {
-            use sam::record::data::field::Tag;
-            let mut data = record.data().clone();
-            data.remove(Tag::ReadGroup);
-            builder = builder.set_tags(data);
+            let tags = build_tags(record.data())?;
+            builder = builder.set_tags(tags);
         }
 
         builder = builder.set_bases(record.sequence().clone());
 
         if !bam_flags.is_unmapped() {
+            if record.cigar().iter().any(|op| op.kind() == Kind::Back) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "unsupported CIGAR operation: B",
+                ));
+            }
+
             let features = Features::from_cigar(
                 flags,
                 record.cigar(),
                 record.sequence(),
                 record.quality_scores(),
-            );
+            )?;
 
             builder = builder.set_features(features);
         }
@@ -97,6 +105,10 @@ impl Record {
     /// Converts this CRAM record to a SAM record.
     ///
     /// This assumes this record is fully resolved.
+    ///
+    /// If the record has a read group ID, the read group (`RG`) data field is appended as the last
+    /// field. CRAM does not store the original position of the field, so a record that had `RG`
+    /// before other data fields does not round trip with the same field order.
     pub fn try_into_sam_record(&self, header: &sam::Header) -> io::Result<sam::Record> {
         let mut builder = sam::Record::builder();
 
@@ -121,7 +133,7 @@ impl Record {
         }
 
         if !self.bam_flags().is_unmapped() {
-            let cigar = resolve_features(self.features(), self.read_length());
+            let cigar = resolve_features(self.features(), self.read_length())?;
             builder = builder.set_cigar(cigar);
         }
 
@@ -153,6 +165,20 @@ impl Record {
     }
 }
 
+fn get_next_mate_flags(flags: sam::record::Flags) -> NextMateFlags {
+    let mut next_mate_flags = NextMateFlags::default();
+
+    if flags.is_mate_reverse_complemented() {
+        next_mate_flags.insert(NextMateFlags::ON_NEGATIVE_STRAND);
+    }
+
+    if flags.is_mate_unmapped() {
+        next_mate_flags.insert(NextMateFlags::UNMAPPED);
+    }
+
+    next_mate_flags
+}
+
 fn get_reference_sequence_id(
     reference_sequences: &sam::header::ReferenceSequences,
     reference_sequence: &sam::header::ReferenceSequence,
@@ -208,6 +234,21 @@ fn get_reference_sequence_name(
         .transpose()
 }
 
+// Returns the data without the read group (`RG`) field, which is stored as a read group ID.
+//
+// The order of the other fields is preserved.
+fn build_tags(data: &sam::record::Data) -> io::Result<sam::record::Data> {
+    use sam::record::data::field::Tag;
+
+    let fields: Vec<_> = data
+        .values()
+        .filter(|field| field.tag() != Tag::ReadGroup)
+        .cloned()
+        .collect();
+
+    sam::record::Data::try_from(fields).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
 fn build_data(
     read_groups: &sam::header::ReadGroups,
     tags: &sam::record::Data,
@@ -232,3 +273,111 @@ fn build_data(
 
     Ok(data)
 }
+
+#[cfg(test)]
+mod tests {
+    use sam::{header::ReadGroup, record::data::field::Tag};
+
+    use super::*;
+
+    #[test]
+    fn test_mate_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use sam::header::ReferenceSequence;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 13)?)
+            .build();
+
+        let flags = sam::record::Flags::SEGMENTED
+            | sam::record::Flags::UNMAPPED
+            | sam::record::Flags::MATE_REVERSE_COMPLEMENTED
+            | sam::record::Flags::MATE_UNMAPPED;
+
+        let sam_record = sam::Record::builder()
+            .set_flags(flags)
+            .set_mate_reference_sequence_name("sq1".parse()?)
+            .set_mate_position(Position::try_from(5)?)
+            .set_template_length(-21)
+            .build();
+
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+        assert_eq!(
+            record.next_mate_flags(),
+            NextMateFlags::ON_NEGATIVE_STRAND | NextMateFlags::UNMAPPED
+        );
+        assert_eq!(record.next_fragment_reference_sequence_id(), Some(1));
+        assert_eq!(record.mate_alignment_start(), Some(Position::try_from(5)?));
+        assert_eq!(record.template_size(), -21);
+
+        let actual = record.try_into_sam_record(&header)?;
+        assert_eq!(actual.flags(), flags);
+        assert_eq!(
+            actual.mate_reference_sequence_name(),
+            sam_record.mate_reference_sequence_name()
+        );
+        assert_eq!(actual.mate_position(), sam_record.mate_position());
+        assert_eq!(actual.template_length(), -21);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_next_mate_flags() {
+        use sam::record::Flags;
+
+        assert_eq!(get_next_mate_flags(Flags::empty()), NextMateFlags::empty());
+        assert_eq!(
+            get_next_mate_flags(Flags::SEGMENTED | Flags::MATE_REVERSE_COMPLEMENTED),
+            NextMateFlags::ON_NEGATIVE_STRAND
+        );
+        assert_eq!(
+            get_next_mate_flags(Flags::MATE_UNMAPPED),
+            NextMateFlags::UNMAPPED
+        );
+    }
+
+    #[test]
+    fn test_read_group_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_read_group(ReadGroup::new("rg0"))
+            .add_read_group(ReadGroup::new("rg1"))
+            .build();
+
+        let sam_record = sam::Record::builder()
+            .set_data("RG:Z:rg1\tNH:i:1".parse()?)
+            .build();
+
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+        assert_eq!(record.read_group_id(), Some(1));
+        assert!(record.tags().get(Tag::ReadGroup).is_none());
+
+        let actual = record.try_into_sam_record(&header)?;
+        let rg = actual.data().get(Tag::ReadGroup).map(|field| field.value());
+        assert_eq!(rg.and_then(|value| value.as_str()), Some("rg1"));
+
+        let sam_record = sam::Record::builder()
+            .set_data("NH:i:1\tRG:Z:rg1\tCO:Z:noodles\tAS:i:13".parse()?)
+            .build();
+
+        let record = Record::try_from_alignment_record(&header, &sam_record)?;
+        assert_eq!(record.tags().to_string(), "NH:i:1\tCO:Z:noodles\tAS:i:13");
+
+        // CRAM does not store the position of the read group field, so it is restored last.
+        let actual = record.try_into_sam_record(&header)?;
+        assert_eq!(
+            actual.data().to_string(),
+            "NH:i:1\tCO:Z:noodles\tAS:i:13\tRG:Z:rg1"
+        );
+
+        let sam_record = sam::Record::builder().set_data("RG:Z:rg2".parse()?).build();
+
+        assert!(matches!(
+            Record::try_from_alignment_record(&header, &sam_record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ mod with_positions;
 pub use self::with_positions::WithPositions;
 
 use std::{
+    io,
     ops::{Deref, DerefMut},
     slice,
 };
@@ -18,12 +19,17 @@ pub struct Features(Vec<Feature>);
 
 impl Features {
     /// Converts SAM record CIGAR operations to CRAM record features.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the CIGAR operations consume more bases than in the sequence or
+    /// quality scores.
     pub fn from_cigar(
         flags: Flags,
         cigar: &sam::record::Cigar,
         sequence: &sam::record::Sequence,
         quality_scores: &sam::record::QualityScores,
-    ) -> Self {
+    ) -> io::Result<Self> {
         cigar_to_features(flags, cigar, sequence, quality_scores)
     }
 
@@ -60,7 +66,7 @@ fn cigar_to_features(
     cigar: &sam::record::Cigar,
     sequence: &sam::record::Sequence,
     quality_scores: &sam::record::QualityScores,
-) -> Features {
+) -> io::Result<Features> {
     use sam::record::cigar::op::Kind;
 
     let mut features = Features::default();
@@ -70,42 +76,50 @@ fn cigar_to_features(
         match op.kind() {
             Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
                 if op.len() == 1 {
-                    let base = sequence[read_position];
-                    let score = quality_scores[read_position];
-                    features.push(Feature::ReadBase(read_position, base, score));
+                    let base = sequence.get(read_position).ok_or_else(invalid_sequence)?;
+                    let score = quality_scores
+                        .get(read_position)
+                        .ok_or_else(invalid_quality_scores)?;
+                    features.push(Feature::ReadBase(read_position, *base, *score));
                 } else {
-                    let end = read_position
-                        .checked_add(op.len())
-                        .expect("attempt to add with overflow");
+                    let end = checked_end(read_position, op.len())?;
 
-                    let bases = &sequence[read_position..end];
+                    let bases = sequence
+                        .get(read_position..end)
+                        .ok_or_else(invalid_sequence)?;
                     features.push(Feature::Bases(read_position, bases.to_vec()));
 
                     if !flags.are_quality_scores_stored_as_array() {
-                        let scores = &quality_scores[read_position..end];
+                        let scores = quality_scores
+                            .get(read_position..end)
+                            .ok_or_else(invalid_quality_scores)?;
                         features.push(Feature::Scores(read_position, scores.to_vec()));
                     }
                 }
             }
             Kind::Insertion => {
                 if op.len() == 1 {
-                    let base = sequence[read_position];
-                    features.push(Feature::InsertBase(read_position, base));
+                    let base = sequence.get(read_position).ok_or_else(invalid_sequence)?;
+                    features.push(Feature::InsertBase(read_position, *base));
 
                     if !flags.are_quality_scores_stored_as_array() {
-                        let score = quality_scores[read_position];
-                        features.push(Feature::QualityScore(read_position, score));
+                        let score = quality_scores
+                            .get(read_position)
+                            .ok_or_else(invalid_quality_scores)?;
+                        features.push(Feature::QualityScore(read_position, *score));
                     }
                 } else {
-                    let end = read_position
-                        .checked_add(op.len())
-                        .expect("attempt to add with overflow");
+                    let end = checked_end(read_position, op.len())?;
 
-                    let bases = &sequence[read_position..end];
+                    let bases = sequence
+                        .get(read_position..end)
+                        .ok_or_else(invalid_sequence)?;
                     features.push(Feature::Insertion(read_position, bases.to_vec()));
 
                     if !flags.are_quality_scores_stored_as_array() {
-                        let scores = &quality_scores[read_position..end];
+                        let scores = quality_scores
+                            .get(read_position..end)
+                            .ok_or_else(invalid_quality_scores)?;
                         features.push(Feature::Scores(read_position, scores.to_vec()));
                     }
                 }
@@ -113,25 +127,31 @@ fn cigar_to_features(
             Kind::Deletion => features.push(Feature::Deletion(read_position, op.len())),
             Kind::Skip => features.push(Feature::ReferenceSkip(read_position, op.len())),
             Kind::SoftClip => {
-                let end = read_position
-                    .checked_add(op.len())
-                    .expect("attempt to add with overflow");
+                let end = checked_end(read_position, op.len())?;
 
-                let bases = &sequence[read_position..end];
+                let bases = sequence
+                    .get(read_position..end)
+                    .ok_or_else(invalid_sequence)?;
                 features.push(Feature::SoftClip(read_position, bases.to_vec()));
 
                 if !flags.are_quality_scores_stored_as_array() {
                     if bases.len() == 1 {
-                        let score = quality_scores[read_position];
-                        features.push(Feature::QualityScore(read_position, score));
+                        let score = quality_scores
+                            .get(read_position)
+                            .ok_or_else(invalid_quality_scores)?;
+                        features.push(Feature::QualityScore(read_position, *score));
                     } else {
-                        let scores = &quality_scores[read_position..end];
+                        let scores = quality_scores
+                            .get(read_position..end)
+                            .ok_or_else(invalid_quality_scores)?;
                         features.push(Feature::Scores(read_position, scores.to_vec()));
                     }
                 }
             }
             Kind::HardClip => features.push(Feature::HardClip(read_position, op.len())),
             Kind::Pad => features.push(Feature::Padding(read_position, op.len())),
+            // CRAM has no read feature for a back operation.
+            Kind::Back => {}
         };
 
         if matches!(
@@ -142,13 +162,31 @@ fn cigar_to_features(
                 | Kind::SequenceMatch
                 | Kind::SequenceMismatch
         ) {
-            read_position = read_position
-                .checked_add(op.len())
-                .expect("attempt to add with overflow");
+            read_position = checked_end(read_position, op.len())?;
         }
     }
 
-    features
+    Ok(features)
+}
+
+fn checked_end(read_position: Position, len: usize) -> io::Result<Position> {
+    read_position
+        .checked_add(len)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "read position overflow"))
+}
+
+fn invalid_sequence() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "CIGAR operations are outside of the sequence",
+    )
+}
+
+fn invalid_quality_scores() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "CIGAR operations are outside of the quality scores",
+    )
 }
 
 #[cfg(test)]
@@ -164,7 +202,7 @@ mod tests {
         let cigar = "1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![Feature::ReadBase(
             Position::try_from(1)?,
             Base::A,
@@ -175,7 +213,7 @@ mod tests {
         let cigar = "2M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::Bases(Position::try_from(1)?, vec![Base::A, Base::C]),
             Feature::Scores(
@@ -188,7 +226,7 @@ mod tests {
         let cigar = "1I1M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::InsertBase(Position::try_from(1)?, Base::A),
             Feature::QualityScore(Position::try_from(1)?, Score::try_from('N')?),
@@ -199,7 +237,7 @@ mod tests {
         let cigar = "2I1M".parse()?;
         let sequence = "ACG".parse()?;
         let quality_scores = "NDL".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::Insertion(Position::try_from(1)?, vec![Base::A, Base::C]),
             Feature::Scores(
@@ -213,7 +251,7 @@ mod tests {
         let cigar = "1D2M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::Deletion(Position::try_from(1)?, 1),
             Feature::Bases(Position::try_from(1)?, vec![Base::A, Base::C]),
@@ -227,7 +265,7 @@ mod tests {
         let cigar = "1N1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::ReferenceSkip(Position::try_from(1)?, 1),
             Feature::ReadBase(Position::try_from(1)?, Base::A, Score::try_from('N')?),
@@ -237,7 +275,7 @@ mod tests {
         let cigar = "1S1M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::SoftClip(Position::try_from(1)?, vec![Base::A]),
             Feature::QualityScore(Position::try_from(1)?, Score::try_from('N')?),
@@ -248,7 +286,7 @@ mod tests {
         let cigar = "2S1M".parse()?;
         let sequence = "ACG".parse()?;
         let quality_scores = "NDL".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::SoftClip(Position::try_from(1)?, vec![Base::A, Base::C]),
             Feature::Scores(
@@ -262,7 +300,7 @@ mod tests {
         let cigar = "1H1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::HardClip(Position::try_from(1)?, 1),
             Feature::ReadBase(Position::try_from(1)?, Base::A, Score::try_from('N')?),
@@ -272,7 +310,7 @@ mod tests {
         let cigar = "1P1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::Padding(Position::try_from(1)?, 1),
             Feature::ReadBase(Position::try_from(1)?, Base::A, Score::try_from('N')?),
@@ -292,7 +330,7 @@ mod tests {
         let cigar = "1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![Feature::ReadBase(
             Position::try_from(1)?,
             Base::A,
@@ -303,7 +341,7 @@ mod tests {
         let cigar = "2M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![Feature::Bases(
             Position::try_from(1)?,
             vec![Base::A, Base::C],
@@ -313,7 +351,7 @@ mod tests {
         let cigar = "1I1M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::InsertBase(Position::try_from(1)?, Base::A),
             Feature::ReadBase(Position::try_from(2)?, Base::C, Score::try_from('D')?),
@@ -323,7 +361,7 @@ mod tests {
         let cigar = "2I1M".parse()?;
         let sequence = "ACG".parse()?;
         let quality_scores = "NDL".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::Insertion(Position::try_from(1)?, vec![Base::A, Base::C]),
             Feature::ReadBase(Position::try_from(3)?, Base::G, Score::try_from('L')?),
@@ -333,7 +371,7 @@ mod tests {
         let cigar = "1D2M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::Deletion(Position::try_from(1)?, 1),
             Feature::Bases(Position::try_from(1)?, vec![Base::A, Base::C]),
@@ -343,7 +381,7 @@ mod tests {
         let cigar = "1N1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::ReferenceSkip(Position::try_from(1)?, 1),
             Feature::ReadBase(Position::try_from(1)?, Base::A, Score::try_from('N')?),
@@ -353,7 +391,7 @@ mod tests {
         let cigar = "1S1M".parse()?;
         let sequence = "AC".parse()?;
         let quality_scores = "ND".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::SoftClip(Position::try_from(1)?, vec![Base::A]),
             Feature::ReadBase(Position::try_from(2)?, Base::C, Score::try_from('D')?),
@@ -363,7 +401,7 @@ mod tests {
         let cigar = "2S1M".parse()?;
         let sequence = "ACG".parse()?;
         let quality_scores = "NDL".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::SoftClip(Position::try_from(1)?, vec![Base::A, Base::C]),
             Feature::ReadBase(Position::try_from(3)?, Base::G, Score::try_from('L')?),
@@ -373,7 +411,7 @@ mod tests {
         let cigar = "1H1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::HardClip(Position::try_from(1)?, 1),
             Feature::ReadBase(Position::try_from(1)?, Base::A, Score::try_from('N')?),
@@ -383,7 +421,7 @@ mod tests {
         let cigar = "1P1M".parse()?;
         let sequence = "A".parse()?;
         let quality_scores = "N".parse()?;
-        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores)?;
         let expected = Features::from(vec![
             Feature::Padding(Position::try_from(1)?, 1),
             Feature::ReadBase(Position::try_from(1)?, Base::A, Score::try_from('N')?),
@@ -392,4 +430,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cigar_to_features_with_invalid_lengths() -> Result<(), Box<dyn std::error::Error>> {
+        let flags = Flags::default();
+
+        let cigar = "4M".parse()?;
+        let sequence = "AC".parse()?;
+        let quality_scores = "NDLS".parse()?;
+        assert!(matches!(
+            cigar_to_features(flags, &cigar, &sequence, &quality_scores),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let cigar = "1M1I".parse()?;
+        let sequence = "AC".parse()?;
+        let quality_scores = "N".parse()?;
+        assert!(matches!(
+            cigar_to_features(flags, &cigar, &sequence, &quality_scores),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+use std::io;
+
 use noodles_core::Position;
 
 use crate::record::Feature;
@@ -29,13 +31,33 @@ where
     pub fn positions(&self) -> (Position, Position) {
         (self.reference_position, self.read_position)
     }
+
+    fn advance(
+        &mut self,
+        reference_position_delta: usize,
+        read_position_delta: usize,
+    ) -> io::Result<()> {
+        self.reference_position = self
+            .reference_position
+            .checked_add(reference_position_delta)
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "reference position overflow")
+            })?;
+
+        self.read_position = self
+            .read_position
+            .checked_add(read_position_delta)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "read position overflow"))?;
+
+        Ok(())
+    }
 }
 
 impl<'a, I> Iterator for WithPositions<'a, I>
 where
     I: Iterator<Item = &'a Feature>,
 {
-    type Item = ((Position, Position), I::Item);
+    type Item = io::Result<((Position, Position), I::Item)>;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
@@ -57,31 +79,27 @@ where
             };
 
             let feature_position = usize::from(feature.position());
-            let match_len = feature_position - usize::from(self.read_position);
-
-            self.reference_position = self
-                .reference_position
-                .checked_add(match_len)
-                .expect("attempt to add with overflow");
-
-            self.read_position = self
-                .read_position
-                .checked_add(match_len)
-                .expect("attempt to add with overflow");
 
-            let positions = self.positions();
-
-            self.reference_position = self
-                .reference_position
-                .checked_add(reference_position_delta)
-                .expect("attempt to add with overflow");
+            let match_len = match feature_position.checked_sub(usize::from(self.read_position)) {
+                Some(len) => len,
+                None => {
+                    return Some(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "invalid feature position: expected >= {}, got {}",
+                            self.read_position, feature_position
+                        ),
+                    )))
+                }
+            };
 
-            self.read_position = self
-                .read_position
-                .checked_add(read_position_delta)
-                .expect("attempt to add with overflow");
+            let result = self.advance(match_len, match_len).and_then(|_| {
+                let positions = self.positions();
+                self.advance(reference_position_delta, read_position_delta)?;
+                Ok((positions, feature))
+            });
 
-            return Some((positions, feature));
+            return Some(result);
         }
     }
 }
@@ -106,11 +124,38 @@ mod tests {
         let mut iter = WithPositions::new(features.iter(), Position::MIN);
 
         assert_eq!(
-            iter.next(),
+            iter.next().transpose()?,
             Some(((Position::MIN, Position::MIN), &features[0]))
         );
         assert!(iter.next().is_none());
 
         Ok(())
     }
+
+    #[test]
+    fn test_next_with_invalid_positions() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Features;
+        use noodles_sam::record::sequence::Base;
+
+        let features = Features::from(vec![
+            Feature::Bases(Position::try_from(3)?, vec![Base::A, Base::C]),
+            Feature::InsertBase(Position::try_from(2)?, Base::G),
+        ]);
+
+        let mut iter = WithPositions::new(features.iter(), Position::MIN);
+        assert!(iter.next().transpose()?.is_some());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let features = Features::from(vec![Feature::Deletion(Position::MIN, usize::MAX)]);
+        let mut iter = WithPositions::new(features.iter(), Position::MIN);
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
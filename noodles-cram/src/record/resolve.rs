@@ -26,10 +26,18 @@ pub(crate) fn resolve_bases(
 
     let (mut last_reference_position, mut last_read_position) = it.positions();
 
-    while let Some(((reference_position, read_position), feature)) = it.next() {
+    while let Some(result) = it.next() {
+        let ((reference_position, read_position), feature) = result?;
+
         if let Some(reference_sequence) = reference_sequence {
-            let dst = &mut buf[last_read_position..read_position];
-            let src = &reference_sequence[last_reference_position..reference_position];
+            let dst = buf
+                .get_mut(last_read_position..read_position)
+                .ok_or_else(invalid_read_position)?;
+
+            let src = reference_sequence
+                .get(last_reference_position..reference_position)
+                .ok_or_else(invalid_reference_position)?;
+
             copy_from_raw_bases(dst, src)?;
         } else if read_position != last_read_position {
             return Err(io::Error::new(
@@ -39,15 +47,20 @@ pub(crate) fn resolve_bases(
         }
 
         match feature {
-            Feature::Bases(_, bases) => copy_from_bases(&mut buf[read_position..], bases),
+            Feature::Bases(_, bases) => copy_from_bases(&mut buf, read_position, bases)?,
             Feature::Scores(..) => {}
-            Feature::ReadBase(_, base, _) => buf[read_position] = *base,
+            Feature::ReadBase(_, base, _) => set_base(&mut buf, read_position, *base)?,
             Feature::Substitution(_, substitution::Value::Code(code)) => {
                 if let Some(reference_sequence) = reference_sequence {
-                    let base = reference_sequence[reference_position];
+                    // Reference bases may be softmasked (lowercase).
+                    let base = reference_sequence
+                        .get(reference_position)
+                        .map(|b| b.to_ascii_uppercase())
+                        .ok_or_else(invalid_reference_position)?;
+
                     let reference_base = SubstitutionBase::try_from(base).unwrap_or_default();
                     let read_base = substitution_matrix.get(reference_base, *code);
-                    buf[read_position] = Base::from(read_base);
+                    set_base(&mut buf, read_position, Base::from(read_base))?;
                 } else {
                     return Err(io::Error::new(
                         io::ErrorKind::InvalidData,
@@ -61,12 +74,12 @@ pub(crate) fn resolve_bases(
                     "cannot resolve base substitution with bases",
                 ))
             }
-            Feature::Insertion(_, bases) => copy_from_bases(&mut buf[read_position..], bases),
+            Feature::Insertion(_, bases) => copy_from_bases(&mut buf, read_position, bases)?,
             Feature::Deletion(..) => {}
-            Feature::InsertBase(_, base) => buf[read_position] = *base,
+            Feature::InsertBase(_, base) => set_base(&mut buf, read_position, *base)?,
             Feature::QualityScore(..) => {}
             Feature::ReferenceSkip(..) => {}
-            Feature::SoftClip(_, bases) => copy_from_bases(&mut buf[read_position..], bases),
+            Feature::SoftClip(_, bases) => copy_from_bases(&mut buf, read_position, bases)?,
             Feature::Padding(..) => {}
             Feature::HardClip(..) => {}
         }
@@ -77,15 +90,20 @@ pub(crate) fn resolve_bases(
     }
 
     if let Some(reference_sequence) = reference_sequence {
-        let dst = &mut buf[last_read_position..];
+        let dst = buf
+            .get_mut(last_read_position..)
+            .ok_or_else(invalid_read_position)?;
 
         let end = last_reference_position
             .checked_add(dst.len())
-            .expect("attempt to add with overflow");
-        let src = &reference_sequence[last_reference_position..end];
+            .ok_or_else(invalid_reference_position)?;
+
+        let src = reference_sequence
+            .get(last_reference_position..end)
+            .ok_or_else(invalid_reference_position)?;
 
         copy_from_raw_bases(dst, src)?;
-    } else if buf[..last_read_position].len() != buf.len() {
+    } else if usize::from(last_read_position) - 1 != buf.len() {
         return Err(io::Error::new(
             io::ErrorKind::InvalidData,
             "cannot resolve bases without reference sequence",
@@ -95,10 +113,38 @@ pub(crate) fn resolve_bases(
     Ok(buf)
 }
 
-fn copy_from_bases(dst: &mut [Base], src: &[Base]) {
+fn invalid_read_position() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "read feature is outside of the read",
+    )
+}
+
+fn invalid_reference_position() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "read feature is outside of the reference sequence",
+    )
+}
+
+fn set_base(buf: &mut sam::record::Sequence, position: Position, base: Base) -> io::Result<()> {
+    let b = buf.get_mut(position).ok_or_else(invalid_read_position)?;
+    *b = base;
+    Ok(())
+}
+
+fn copy_from_bases(
+    buf: &mut sam::record::Sequence,
+    position: Position,
+    src: &[Base],
+) -> io::Result<()> {
+    let dst = buf.get_mut(position..).ok_or_else(invalid_read_position)?;
+
     for (&base, b) in src.iter().zip(dst.iter_mut()) {
         *b = base;
     }
+
+    Ok(())
 }
 
 fn copy_from_raw_bases(dst: &mut [Base], src: &[u8]) -> io::Result<()> {
@@ -112,7 +158,11 @@ fn copy_from_raw_bases(dst: &mut [Base], src: &[u8]) -> io::Result<()> {
 }
 
 /// Resolves the read features as CIGAR operations.
-pub fn resolve_features(features: &Features, read_length: usize) -> sam::record::Cigar {
+///
+/// # Errors
+///
+/// This returns an error if a read position overflows.
+pub fn resolve_features(features: &Features, read_length: usize) -> io::Result<sam::record::Cigar> {
     use noodles_sam::record::cigar::{op::Kind, Op};
 
     fn merge_or_insert_op(ops: &mut Vec<(Kind, usize)>, kind: Kind, len: usize) {
@@ -160,7 +210,7 @@ pub fn resolve_features(features: &Features, read_length: usize) -> sam::record:
         ) {
             read_position = read_position
                 .checked_add(len)
-                .expect("attempt to add with overflow");
+                .ok_or_else(invalid_read_position)?;
         }
     }
 
@@ -169,15 +219,22 @@ pub fn resolve_features(features: &Features, read_length: usize) -> sam::record:
         merge_or_insert_op(&mut ops, Kind::Match, len);
     }
 
-    sam::record::Cigar::from(
+    Ok(sam::record::Cigar::from(
         ops.into_iter()
             .map(|(kind, len)| Op::new(kind, len))
             .collect::<Vec<_>>(),
-    )
+    ))
 }
 
 /// Resolves the quality scores.
-pub fn resolve_quality_scores(features: &[Feature], read_len: usize) -> sam::record::QualityScores {
+///
+/// # Errors
+///
+/// This returns an error if a quality score feature is outside of the read.
+pub fn resolve_quality_scores(
+    features: &[Feature],
+    read_len: usize,
+) -> io::Result<sam::record::QualityScores> {
     use sam::record::quality_scores::Score;
 
     let mut quality_scores = sam::record::QualityScores::from(vec![Score::default(); read_len]);
@@ -189,17 +246,97 @@ pub fn resolve_quality_scores(features: &[Feature], read_len: usize) -> sam::rec
             Feature::Scores(_, scores) => {
                 let end = read_position
                     .checked_add(scores.len())
-                    .expect("attempt to add with overflow");
+                    .ok_or_else(invalid_read_position)?;
+
+                quality_scores
+                    .get_mut(read_position..end)
+                    .ok_or_else(invalid_read_position)?
+                    .copy_from_slice(scores);
+            }
+            Feature::ReadBase(_, _, score) | Feature::QualityScore(_, score) => {
+                let s = quality_scores
+                    .get_mut(read_position)
+                    .ok_or_else(invalid_read_position)?;
 
-                quality_scores[read_position..end].copy_from_slice(scores);
+                *s = *score;
             }
-            Feature::ReadBase(_, _, score) => quality_scores[read_position] = *score,
-            Feature::QualityScore(_, score) => quality_scores[read_position] = *score,
             _ => continue,
         }
     }
 
-    quality_scores
+    Ok(quality_scores)
+}
+
+/// Resolves the edit distance (`NM`) from the read features.
+///
+/// This is the number of substituted, inserted, and deleted bases. Read bases given as features
+/// (`ReadBase` and `Bases`) are compared to the reference sequence and counted if they differ
+/// (case-insensitive).
+///
+/// # Errors
+///
+/// This returns an error if there are read bases to compare but no reference sequence is given or
+/// the read bases are outside of the reference sequence.
+pub fn resolve_edit_distance(
+    reference_sequence: Option<&fasta::record::Sequence>,
+    features: &Features,
+    alignment_start: Position,
+) -> io::Result<u32> {
+    let mut edit_distance = 0;
+
+    for result in features.with_positions(alignment_start) {
+        let ((reference_position, _), feature) = result?;
+
+        edit_distance += match feature {
+            Feature::Bases(_, bases) => {
+                count_mismatches(reference_sequence, reference_position, bases)?
+            }
+            Feature::ReadBase(_, base, _) => count_mismatches(
+                reference_sequence,
+                reference_position,
+                std::slice::from_ref(base),
+            )?,
+            Feature::Substitution(..) => 1,
+            Feature::Insertion(_, bases) => bases.len(),
+            Feature::Deletion(_, len) => *len,
+            Feature::InsertBase(..) => 1,
+            _ => 0,
+        };
+    }
+
+    u32::try_from(edit_distance).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn count_mismatches(
+    reference_sequence: Option<&fasta::record::Sequence>,
+    reference_position: Position,
+    bases: &[Base],
+) -> io::Result<usize> {
+    let reference_sequence = reference_sequence.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "cannot resolve edit distance without reference sequence",
+        )
+    })?;
+
+    let end = reference_position
+        .checked_add(bases.len())
+        .ok_or_else(invalid_reference_position)?;
+
+    let reference_bases = reference_sequence
+        .get(reference_position..end)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "read bases are outside of the reference sequence",
+            )
+        })?;
+
+    Ok(reference_bases
+        .iter()
+        .zip(bases)
+        .filter(|(&reference_base, &base)| !reference_base.eq_ignore_ascii_case(&u8::from(base)))
+        .count())
 }
 
 #[cfg(test)]
@@ -303,6 +440,58 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_bases_with_invalid_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"ACGTACGT".to_vec());
+        let substitution_matrix = SubstitutionMatrix::default();
+        let alignment_start = Position::try_from(1)?;
+
+        let t = |features: Vec<Feature>, alignment_start: Position| {
+            let features = Features::from(features);
+
+            assert!(matches!(
+                resolve_bases(
+                    Some(&reference_sequence),
+                    &substitution_matrix,
+                    &features,
+                    alignment_start,
+                    4,
+                ),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        };
+
+        // read base after the end of the read
+        t(
+            vec![Feature::ReadBase(
+                Position::try_from(8)?,
+                Base::A,
+                Score::default(),
+            )],
+            alignment_start,
+        );
+
+        // insertion after the end of the read
+        t(
+            vec![Feature::Insertion(Position::try_from(8)?, vec![Base::A])],
+            alignment_start,
+        );
+
+        // substitution after the end of the reference sequence
+        t(
+            vec![Feature::Substitution(
+                Position::try_from(2)?,
+                substitution::Value::Code(0),
+            )],
+            Position::try_from(8)?,
+        );
+
+        // read extends past the end of the reference sequence
+        t(Vec::new(), Position::try_from(6)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_bases_without_a_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
         let substitution_matrix = SubstitutionMatrix::default();
@@ -321,7 +510,40 @@ mod tests {
     }
 
     #[test]
-    fn test_resolve_features() -> Result<(), noodles_core::position::TryFromIntError> {
+    fn test_resolve_bases_with_lowercase_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"acgtacgt".to_vec());
+        let substitution_matrix = Default::default();
+        let alignment_start = Position::try_from(1)?;
+
+        let t = |features: &Features, expected: &sam::record::Sequence| {
+            let actual = resolve_bases(
+                Some(&reference_sequence),
+                &substitution_matrix,
+                features,
+                alignment_start,
+                4,
+            )?;
+
+            assert_eq!(&actual, expected);
+
+            Ok::<_, io::Error>(())
+        };
+
+        t(&Features::default(), &"ACGT".parse()?)?;
+        t(
+            &Features::from(vec![Feature::Substitution(
+                Position::try_from(2)?,
+                substitution::Value::Code(1),
+            )]),
+            &"AGGT".parse()?,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_features() -> Result<(), Box<dyn std::error::Error>> {
         use noodles_sam::record::{
             cigar::{op::Kind, Op},
             Cigar,
@@ -329,7 +551,7 @@ mod tests {
 
         let features = Features::default();
         assert_eq!(
-            resolve_features(&features, 4),
+            resolve_features(&features, 4)?,
             Cigar::from(vec![Op::new(Kind::Match, 4)])
         );
 
@@ -338,7 +560,7 @@ mod tests {
             vec![Base::A, Base::T],
         )]);
         assert_eq!(
-            resolve_features(&features, 4),
+            resolve_features(&features, 4)?,
             Cigar::from(vec![Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 2)])
         );
 
@@ -347,13 +569,13 @@ mod tests {
             vec![Base::G],
         )]);
         assert_eq!(
-            resolve_features(&features, 4),
+            resolve_features(&features, 4)?,
             Cigar::from(vec![Op::new(Kind::Match, 3), Op::new(Kind::SoftClip, 1)])
         );
 
         let features = Features::from(vec![Feature::HardClip(Position::try_from(1)?, 2)]);
         assert_eq!(
-            resolve_features(&features, 4),
+            resolve_features(&features, 4)?,
             Cigar::from(vec![Op::new(Kind::HardClip, 2), Op::new(Kind::Match, 4)]),
         );
 
@@ -362,7 +584,7 @@ mod tests {
             Feature::Substitution(Position::try_from(3)?, substitution::Value::Code(0)),
         ]);
         assert_eq!(
-            resolve_features(&features, 4),
+            resolve_features(&features, 4)?,
             Cigar::from(vec![Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 3)])
         );
 
@@ -371,13 +593,37 @@ mod tests {
             substitution::Value::Code(0),
         )]);
         assert_eq!(
-            resolve_features(&features, 4),
+            resolve_features(&features, 4)?,
             Cigar::from(vec![Op::new(Kind::Match, 4)])
         );
 
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_quality_scores_with_invalid_positions() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let features = [Feature::QualityScore(
+            Position::try_from(5)?,
+            Score::default(),
+        )];
+        assert!(matches!(
+            resolve_quality_scores(&features, 4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let features = [Feature::Scores(
+            Position::try_from(4)?,
+            vec![Score::default(), Score::default()],
+        )];
+        assert!(matches!(
+            resolve_quality_scores(&features, 4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
         use sam::record::{quality_scores::Score, QualityScores};
@@ -391,7 +637,7 @@ mod tests {
             ),
         ];
 
-        let actual = resolve_quality_scores(&features, 6);
+        let actual = resolve_quality_scores(&features, 6)?;
 
         let expected = [5, 0, 8, 0, 13, 21]
             .into_iter()
@@ -403,4 +649,77 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_resolve_edit_distance() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"ACGTacgt".to_vec());
+        let alignment_start = Position::try_from(1)?;
+
+        let t = |features: Vec<Feature>, expected: u32| {
+            let features = Features::from(features);
+            let actual =
+                resolve_edit_distance(Some(&reference_sequence), &features, alignment_start)?;
+            assert_eq!(actual, expected);
+            Ok::<_, io::Error>(())
+        };
+
+        t(Vec::new(), 0)?;
+
+        // ACGTACGT vs. ACGTACGT
+        t(
+            vec![Feature::Bases(
+                Position::try_from(1)?,
+                vec![Base::A, Base::C, Base::G, Base::T],
+            )],
+            0,
+        )?;
+
+        // AT vs. AC
+        t(
+            vec![Feature::Bases(
+                Position::try_from(1)?,
+                vec![Base::A, Base::T],
+            )],
+            1,
+        )?;
+
+        // 2M1X1M1D2M3I, where the base after the deletion is also a mismatch
+        t(
+            vec![
+                Feature::Substitution(Position::try_from(3)?, substitution::Value::Code(0)),
+                Feature::Deletion(Position::try_from(5)?, 1),
+                Feature::ReadBase(Position::try_from(5)?, Base::G, Score::default()),
+                Feature::Insertion(Position::try_from(7)?, vec![Base::A, Base::A]),
+                Feature::InsertBase(Position::try_from(9)?, Base::A),
+            ],
+            6,
+        )?;
+
+        let features = Features::from(vec![Feature::ReadBase(
+            Position::try_from(1)?,
+            Base::A,
+            Score::default(),
+        )]);
+
+        assert!(matches!(
+            resolve_edit_distance(None, &features, alignment_start),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let features = Features::from(vec![Feature::Bases(
+            Position::try_from(1)?,
+            vec![Base::A, Base::A],
+        )]);
+
+        assert!(matches!(
+            resolve_edit_distance(
+                Some(&reference_sequence),
+                &features,
+                Position::try_from(8)?
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -3,10 +3,11 @@ mod container;
 pub(crate) mod data_container;
 pub(crate) mod num;
 mod options;
+mod quality_score_binning;
 pub(crate) mod record;
 
-pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+pub use self::{builder::Builder, quality_score_binning::QualityScoreBinning};
 
 use std::{
     io::{self, Write},
@@ -206,8 +207,15 @@ where
     pub fn write_record(&mut self, header: &sam::Header, mut record: Record) -> io::Result<()> {
         use super::data_container::builder::AddRecordError;
 
+        if let Some(binning) = self.options.quality_score_binning {
+            quality_score_binning::bin_quality_scores(binning, &mut record);
+        }
+
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
@@ -274,6 +282,38 @@ where
     }
 }
 
+/// Writes an empty CRAM.
+///
+/// An empty CRAM has a file definition, a file header, no data containers, and an EOF container.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_cram as cram;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::builder().add_comment("noodles-cram").build();
+///
+/// let mut buf = Vec::new();
+/// cram::write_empty(&mut buf, &header)?;
+///
+/// let mut reader = cram::Reader::new(&buf[..]);
+/// reader.read_file_definition()?;
+/// assert_eq!(reader.read_file_header()?, header.to_string());
+/// assert!(reader.read_data_container()?.is_none());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_empty<W>(inner: W, header: &sam::Header) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut writer = Writer::new(inner);
+    writer.write_file_definition()?;
+    writer.write_file_header(header)?;
+    writer.try_finish(header)
+}
+
 fn write_file_definition<W>(writer: &mut W, file_definition: &FileDefinition) -> io::Result<()>
 where
     W: Write,
@@ -291,3 +331,431 @@ where
     let format = [version.major(), version.minor()];
     writer.write_all(&format)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::Reader;
+
+    #[test]
+    fn test_write_file_header() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::{Program, ReadGroup};
+
+        let header = sam::Header::builder()
+            .set_header(Default::default())
+            .add_read_group(ReadGroup::new("rg0"))
+            .add_program(Program::new("noodles"))
+            .add_comment("noodles-cram")
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        assert_eq!(reader.read_file_header()?, header.to_string());
+        assert!(reader.read_data_container()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_slice_and_container_sizes() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_records_per_slice(NonZeroUsize::try_from(2)?)
+            .set_slices_per_container(NonZeroUsize::try_from(2)?)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for _ in 0..5 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut slice_record_counts = Vec::new();
+        let mut record_count = 0;
+
+        while let Some(data_container) = reader.read_data_container()? {
+            let counts: Vec<_> = data_container
+                .slices()
+                .iter()
+                .map(|slice| slice.header().record_count())
+                .collect();
+
+            slice_record_counts.push(counts);
+
+            for slice in data_container.slices() {
+                record_count += slice.records(data_container.compression_header())?.len();
+            }
+        }
+
+        assert_eq!(slice_record_counts, [vec![2, 2], vec![1]]);
+        assert_eq!(record_count, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_read_groups() -> io::Result<()> {
+        use sam::header::ReadGroup;
+
+        let header = sam::Header::builder()
+            .add_read_group(ReadGroup::new("rg0"))
+            .add_read_group(ReadGroup::new("rg1"))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        writer.write_record(&header, Record::builder().set_read_group_id(1).build())?;
+        writer.write_record(&header, Record::default())?;
+        writer.write_record(&header, Record::builder().set_read_group_id(0).build())?;
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut read_group_ids = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container()? {
+            for slice in data_container.slices() {
+                let records = slice.records(data_container.compression_header())?;
+                read_group_ids.extend(records.iter().map(|record| record.read_group_id()));
+            }
+        }
+
+        assert_eq!(read_group_ids, [Some(1), None, Some(0)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_tag_order() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        let raw_tags = [
+            "NH:i:1\tCO:Z:noodles",
+            "CO:Z:noodles\tNH:i:1",
+            "AS:i:13\tNH:i:2\tCO:Z:ndls",
+            "NH:i:3\tCO:Z:noodles",
+        ];
+
+        for raw_tags in raw_tags {
+            let record = Record::builder().set_tags(raw_tags.parse()?).build();
+            writer.write_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut actual = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container()? {
+            let compression_header = data_container.compression_header();
+            let tag_ids_dictionary = compression_header.preservation_map().tag_ids_dictionary();
+            assert_eq!(tag_ids_dictionary.len(), 3);
+
+            for slice in data_container.slices() {
+                let records = slice.records(compression_header)?;
+                actual.extend(records.iter().map(|record| record.tags().to_string()));
+            }
+        }
+
+        assert_eq!(actual, raw_tags);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_mate_data() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use sam::record::Flags;
+
+        use crate::record::NextMateFlags;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        let record = Record::builder()
+            .set_bam_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_REVERSE_COMPLEMENTED)
+            .set_next_mate_flags(NextMateFlags::ON_NEGATIVE_STRAND)
+            .set_next_fragment_reference_sequence_id(0)
+            .set_next_mate_alignment_start(Position::try_from(8)?)
+            .set_template_size(-13)
+            .build();
+
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut records = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container()? {
+            for slice in data_container.slices() {
+                records.extend(slice.records(data_container.compression_header())?);
+            }
+        }
+
+        assert_eq!(records.len(), 1);
+
+        let record = &records[0];
+        assert!(record.bam_flags().is_mate_reverse_complemented());
+        assert_eq!(record.next_mate_flags(), NextMateFlags::ON_NEGATIVE_STRAND);
+        assert_eq!(record.next_fragment_reference_sequence_id(), Some(0));
+        assert_eq!(
+            record.next_mate_alignment_start(),
+            Some(Position::try_from(8)?)
+        );
+        assert_eq!(record.template_size(), -13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_unmapped_records() -> Result<(), Box<dyn std::error::Error>> {
+        use md5::{Digest, Md5};
+        use noodles_core::Position;
+        use sam::{header::ReferenceSequence, record::Flags, AlignmentRecord, AlignmentWriter};
+
+        use crate::container::ReferenceSequenceId;
+
+        const REFERENCE_SEQUENCE: &[u8] = b"ACGTACGT";
+
+        let md5_checksum: [u8; 16] = Md5::digest(REFERENCE_SEQUENCE).into();
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                ReferenceSequence::builder()
+                    .set_name("sq0".parse()?)
+                    .set_length(i32::try_from(REFERENCE_SEQUENCE.len())?)
+                    .set_md5_checksum(md5_checksum.into())
+                    .build()?,
+            )
+            .build();
+
+        let sam_records = [
+            sam::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            sam::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(3)?)
+                .set_sequence("TGCA".parse()?)
+                .set_quality_scores("SLDN".parse()?)
+                .build(),
+        ];
+
+        // The writer has no reference sequences.
+        let mut writer = Writer::builder(Vec::new())
+            .set_records_per_slice(NonZeroUsize::try_from(1)?)
+            .build();
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &sam_records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let mut slice_reference_sequence_ids = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container()? {
+            let compression_header = data_container.compression_header();
+            assert!(!compression_header
+                .preservation_map()
+                .is_reference_required());
+
+            for slice in data_container.slices() {
+                assert_eq!(slice.header().reference_md5(), &[0; 16]);
+                slice_reference_sequence_ids.push(slice.header().reference_sequence_id());
+            }
+        }
+
+        assert_eq!(
+            slice_reference_sequence_ids,
+            [ReferenceSequenceId::None, ReferenceSequenceId::Some(0)]
+        );
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let reference_sequence_repository = fasta::Repository::default();
+        let records: Vec<_> = reader
+            .records(&reference_sequence_repository, &header)
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(records.len(), sam_records.len());
+
+        let reference_sequence_ids: Vec<_> = records
+            .iter()
+            .map(|record| record.reference_sequence_id())
+            .collect();
+        assert_eq!(reference_sequence_ids, [None, Some(0)]);
+
+        for (actual, expected) in records.iter().zip(&sam_records) {
+            assert_eq!(actual.read_name(), expected.read_name());
+            assert!(actual.bam_flags().is_unmapped());
+            assert_eq!(actual.alignment_start(), expected.alignment_start());
+            assert_eq!(actual.sequence(), expected.sequence());
+            assert_eq!(actual.quality_scores(), expected.quality_scores());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_quality_score_binning() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{AlignmentRecord, AlignmentWriter};
+
+        fn write_and_read_quality_scores(
+            builder: Builder<Vec<u8>>,
+        ) -> Result<String, Box<dyn std::error::Error>> {
+            let header = sam::Header::default();
+
+            let record = sam::Record::builder()
+                .set_sequence("ACGTACGT".parse()?)
+                .set_quality_scores("#+5:?DIO".parse()?)
+                .build();
+
+            let mut writer = builder.build();
+            writer.write_file_definition()?;
+            writer.write_file_header(&header)?;
+            writer.write_alignment_record(&header, &record)?;
+            writer.try_finish(&header)?;
+
+            let data = writer.get_ref();
+            let mut reader = Reader::new(&data[..]);
+            reader.read_file_definition()?;
+            reader.read_file_header()?;
+
+            let data_container = reader
+                .read_data_container()?
+                .expect("missing data container");
+            let records =
+                data_container.slices()[0].records(data_container.compression_header())?;
+
+            Ok(records[0].quality_scores().to_string())
+        }
+
+        let actual = write_and_read_quality_scores(Writer::builder(Vec::new()))?;
+        assert_eq!(actual, "#+5:?DIO");
+
+        let actual = write_and_read_quality_scores(
+            Writer::builder(Vec::new()).set_quality_score_binning(QualityScoreBinning::Illumina8),
+        )?;
+        assert_eq!(actual, "'07<BFII");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_multiple_slices() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Read;
+
+        use bytes::Bytes;
+
+        use crate::{
+            container::block::ContentType,
+            reader::{
+                container::{read_block, read_header},
+                data_container::read_slice,
+            },
+        };
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::builder(Vec::new())
+            .set_records_per_slice(NonZeroUsize::try_from(2)?)
+            .set_slices_per_container(NonZeroUsize::try_from(3)?)
+            .build();
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+
+        for _ in 0..5 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let container_header = read_header(reader.get_mut())?;
+        assert_eq!(container_header.record_counter(), 0);
+
+        let mut buf = vec![0; container_header.len()];
+        reader.get_mut().read_exact(&mut buf)?;
+
+        let mut src = Bytes::copy_from_slice(&buf);
+        let compression_header_block = read_block(&mut src)?;
+        assert_eq!(
+            compression_header_block.content_type(),
+            ContentType::CompressionHeader
+        );
+
+        let landmarks = container_header.landmarks();
+        assert_eq!(landmarks.len(), 3);
+        assert_eq!(landmarks[0], compression_header_block.len());
+
+        let mut record_counters = Vec::new();
+
+        for &landmark in landmarks {
+            let mut src = Bytes::copy_from_slice(&buf[landmark..]);
+            let slice = read_slice(&mut src)?;
+            record_counters.push(slice.header().record_counter());
+        }
+
+        assert_eq!(record_counters, [0, 2, 4]);
+
+        Ok(())
+    }
+}
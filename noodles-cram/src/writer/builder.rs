@@ -1,8 +1,8 @@
-use std::io::Write;
+use std::{io::Write, num::NonZeroUsize};
 
 use noodles_fasta as fasta;
 
-use super::{Options, Writer};
+use super::{Options, QualityScoreBinning, Writer};
 use crate::DataContainer;
 
 /// A CRAM writer builder.
@@ -53,6 +53,67 @@ where
         self
     }
 
+    /// Sets the maximum number of records in a slice.
+    ///
+    /// Smaller slices allow finer query granularity at the cost of compression.
+    ///
+    /// The default is 10240.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram as cram;
+    ///
+    /// let records_per_slice = NonZeroUsize::try_from(1024)?;
+    /// let builder = cram::Writer::builder(Vec::new()).set_records_per_slice(records_per_slice);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices in a container.
+    ///
+    /// Slices in the same container share a compression header.
+    ///
+    /// The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram as cram;
+    ///
+    /// let slices_per_container = NonZeroUsize::try_from(4)?;
+    /// let builder = cram::Writer::builder(Vec::new()).set_slices_per_container(slices_per_container);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
+    /// Sets the quality score binning scheme.
+    ///
+    /// Quality scores are binned before records are written. This is lossy: read quality scores
+    /// are the binned values.
+    ///
+    /// By default, quality scores are not binned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::Writer::builder(Vec::new())
+    ///     .set_quality_score_binning(cram::QualityScoreBinning::Illumina8);
+    /// ```
+    pub fn set_quality_score_binning(mut self, quality_score_binning: QualityScoreBinning) -> Self {
+        self.options.quality_score_binning = Some(quality_score_binning);
+        self
+    }
+
     /// Builds a CRAM writer.
     ///
     /// # Examples
@@ -1,7 +1,14 @@
+use std::num::NonZeroUsize;
+
+use super::QualityScoreBinning;
+
 #[derive(Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
+    pub records_per_slice: NonZeroUsize,
+    pub slices_per_container: NonZeroUsize,
+    pub quality_score_binning: Option<QualityScoreBinning>,
 }
 
 impl Default for Options {
@@ -9,6 +16,9 @@ impl Default for Options {
         Self {
             preserve_read_names: true,
             encode_alignment_start_positions_as_deltas: true,
+            records_per_slice: NonZeroUsize::new(10240).unwrap(),
+            slices_per_container: NonZeroUsize::new(1).unwrap(),
+            quality_score_binning: None,
         }
     }
 }
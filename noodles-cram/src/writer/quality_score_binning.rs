@@ -0,0 +1,130 @@
+use noodles_sam::record::quality_scores::Score;
+
+use crate::{record::Feature, Record};
+
+/// A quality score binning scheme.
+///
+/// Binning is lossy: quality scores are mapped to a smaller set of representative values, which
+/// compress better.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum QualityScoreBinning {
+    /// The Illumina 8-level binning scheme.
+    ///
+    /// | original | binned |
+    /// |---------:|-------:|
+    /// |      0–1 | (same) |
+    /// |      2–9 |      6 |
+    /// |    10–19 |     15 |
+    /// |    20–24 |     22 |
+    /// |    25–29 |     27 |
+    /// |    30–34 |     33 |
+    /// |    35–39 |     37 |
+    /// |     ≥ 40 |     40 |
+    Illumina8,
+}
+
+impl QualityScoreBinning {
+    fn bin(self, score: Score) -> Score {
+        let n = u8::from(score);
+
+        let binned = match self {
+            Self::Illumina8 => match n {
+                0..=1 => n,
+                2..=9 => 6,
+                10..=19 => 15,
+                20..=24 => 22,
+                25..=29 => 27,
+                30..=34 => 33,
+                35..=39 => 37,
+                _ => 40,
+            },
+        };
+
+        // A binned score is never greater than the original, so it is always a valid score.
+        Score::try_from(binned).unwrap_or(score)
+    }
+}
+
+pub(super) fn bin_quality_scores(binning: QualityScoreBinning, record: &mut Record) {
+    for score in record.quality_scores.as_mut().iter_mut() {
+        *score = binning.bin(*score);
+    }
+
+    for feature in record.features.iter_mut() {
+        match feature {
+            Feature::Scores(_, scores) => {
+                for score in scores.iter_mut() {
+                    *score = binning.bin(*score);
+                }
+            }
+            Feature::ReadBase(_, _, score) | Feature::QualityScore(_, score) => {
+                *score = binning.bin(*score);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam as sam;
+
+    use super::*;
+
+    #[test]
+    fn test_bin() -> Result<(), sam::record::quality_scores::score::TryFromUByteError> {
+        let binning = QualityScoreBinning::Illumina8;
+
+        for (n, expected) in [
+            (0, 0),
+            (1, 1),
+            (2, 6),
+            (9, 6),
+            (10, 15),
+            (19, 15),
+            (20, 22),
+            (24, 22),
+            (25, 27),
+            (29, 27),
+            (30, 33),
+            (34, 33),
+            (35, 37),
+            (39, 37),
+            (40, 40),
+            (41, 40),
+            (93, 40),
+        ] {
+            assert_eq!(binning.bin(Score::try_from(n)?), Score::try_from(expected)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bin_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let mut record = Record::builder()
+            .set_quality_scores("+5?I".parse()?)
+            .add_feature(Feature::ReadBase(
+                Position::MIN,
+                sam::record::sequence::Base::A,
+                Score::try_from(42)?,
+            ))
+            .build();
+
+        bin_quality_scores(QualityScoreBinning::Illumina8, &mut record);
+
+        assert_eq!(record.quality_scores.to_string(), "07BI");
+        assert_eq!(
+            record.features[0],
+            Feature::ReadBase(
+                Position::MIN,
+                sam::record::sequence::Base::A,
+                Score::try_from(40)?
+            )
+        );
+
+        Ok(())
+    }
+}
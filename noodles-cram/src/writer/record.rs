@@ -210,27 +210,20 @@ where
             .in_seq_positions_encoding();
 
         let alignment_start_or_delta = if ap_data_series_delta {
-            match (alignment_start, self.prev_alignment_start) {
-                (None, None) => 0,
-                (Some(alignment_start), Some(prev_alignment_start)) => {
-                    let alignment_start = i32::try_from(usize::from(alignment_start))
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-                    let prev_alignment_start = i32::try_from(usize::from(prev_alignment_start))
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-                    alignment_start - prev_alignment_start
-                }
-                _ => {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidInput,
-                        format!(
-                            "invalid alignment start ({:?}) or previous alignment start ({:?})",
-                            alignment_start, self.prev_alignment_start
-                        ),
-                    ));
-                }
-            }
+            // The previous alignment start of the first record in a slice without an alignment
+            // start (e.g., a multi-reference slice) is 0, as when reading.
+            let alignment_start =
+                i32::try_from(alignment_start.map(usize::from).unwrap_or_default())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            let prev_alignment_start = i32::try_from(
+                self.prev_alignment_start
+                    .map(usize::from)
+                    .unwrap_or_default(),
+            )
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            alignment_start - prev_alignment_start
         } else {
             i32::try_from(alignment_start.map(usize::from).unwrap_or_default())
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?
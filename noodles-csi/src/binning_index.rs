@@ -1,8 +1,9 @@
 //! Binning index utilities.
 
+mod merged_chunks;
 mod reference_sequence_ext;
 
-pub use reference_sequence_ext::ReferenceSequenceExt;
+pub use self::{merged_chunks::MergedChunks, reference_sequence_ext::ReferenceSequenceExt};
 
 use std::{io, ops::RangeBounds};
 
@@ -27,6 +28,24 @@ where
     where
         B: RangeBounds<Position> + Clone;
 
+    /// Returns an iterator over the merged chunks that overlap with the given region.
+    ///
+    /// Unlike [`Self::query`], the chunks are merged lazily, i.e., the full list of chunks is not
+    /// built upfront.
+    ///
+    /// The default implementation merges the chunks returned by [`Self::query`].
+    fn query_merged_chunks<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<MergedChunks<'_>>
+    where
+        B: RangeBounds<Position> + Clone,
+    {
+        self.query(reference_sequence_id, interval)
+            .map(MergedChunks::from)
+    }
+
     /// Returns the start position of the first record in the last linear bin.
     ///
     /// This is the closest position to the unplaced, unmapped records, if any, that is available
@@ -218,4 +237,25 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_optimize_chunks_with_adjacent_chunks() {
+        let chunks: Vec<_> = (0..1024)
+            .map(|i| {
+                Chunk::new(
+                    bgzf::VirtualPosition::from(i * 8),
+                    bgzf::VirtualPosition::from((i + 1) * 8),
+                )
+            })
+            .collect();
+
+        let actual = optimize_chunks(&chunks, bgzf::VirtualPosition::default());
+
+        let expected = [Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(8192),
+        )];
+
+        assert_eq!(actual, expected);
+    }
 }
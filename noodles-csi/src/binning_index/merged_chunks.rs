@@ -0,0 +1,204 @@
+use std::{borrow::Cow, cmp::Reverse, collections::BinaryHeap};
+
+use noodles_bgzf as bgzf;
+
+use crate::index::reference_sequence::bin::Chunk;
+
+/// An iterator over merged, non-overlapping chunks.
+///
+/// This lazily merges the chunks of one or more bins. The chunks of each bin are typically sorted
+/// by start position, as they are when built by an indexer. Only the next chunk of each bin is
+/// held at a time, so the memory used is bounded by the number of bins rather than the number of
+/// chunks.
+///
+/// Bins with chunks that are not sorted by start position (e.g., from an externally built index)
+/// are copied and sorted first.
+///
+/// This is created by calling [`super::BinningIndex::query_merged_chunks`].
+pub struct MergedChunks<'a> {
+    bins: Vec<Cow<'a, [Chunk]>>,
+    queue: BinaryHeap<Reverse<(bgzf::VirtualPosition, usize, usize)>>,
+    min_offset: bgzf::VirtualPosition,
+}
+
+impl<'a> MergedChunks<'a> {
+    /// Creates an iterator over merged chunks from lists of chunks.
+    ///
+    /// Chunks that end at or before `min_offset` (typically from the linear index) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{binning_index::MergedChunks, index::reference_sequence::bin::Chunk};
+    ///
+    /// let a = [
+    ///     Chunk::new(bgzf::VirtualPosition::from(2), bgzf::VirtualPosition::from(3)),
+    ///     Chunk::new(bgzf::VirtualPosition::from(7), bgzf::VirtualPosition::from(13)),
+    /// ];
+    ///
+    /// let b = [
+    ///     Chunk::new(bgzf::VirtualPosition::from(5), bgzf::VirtualPosition::from(8)),
+    ///     Chunk::new(bgzf::VirtualPosition::from(21), bgzf::VirtualPosition::from(34)),
+    /// ];
+    ///
+    /// let min_offset = bgzf::VirtualPosition::from(5);
+    /// let chunks: Vec<_> = MergedChunks::new([&a[..], &b[..]], min_offset).collect();
+    ///
+    /// assert_eq!(chunks, [
+    ///     Chunk::new(bgzf::VirtualPosition::from(5), bgzf::VirtualPosition::from(13)),
+    ///     Chunk::new(bgzf::VirtualPosition::from(21), bgzf::VirtualPosition::from(34)),
+    /// ]);
+    /// ```
+    pub fn new<I>(bins: I, min_offset: bgzf::VirtualPosition) -> Self
+    where
+        I: IntoIterator<Item = &'a [Chunk]>,
+    {
+        Self::from_bins(bins.into_iter().map(Cow::Borrowed).collect(), min_offset)
+    }
+
+    fn from_bins(mut bins: Vec<Cow<'a, [Chunk]>>, min_offset: bgzf::VirtualPosition) -> Self {
+        for chunks in &mut bins {
+            if !is_sorted_by_start(chunks) {
+                chunks.to_mut().sort_unstable_by_key(|chunk| chunk.start());
+            }
+        }
+
+        let mut merged_chunks = Self {
+            queue: BinaryHeap::with_capacity(bins.len()),
+            bins,
+            min_offset,
+        };
+
+        for i in 0..merged_chunks.bins.len() {
+            merged_chunks.push_next(i, 0);
+        }
+
+        merged_chunks
+    }
+
+    // Queues the first chunk at or after `j` in bin `i` that ends after the minimum offset.
+    fn push_next(&mut self, i: usize, j: usize) {
+        let chunks = &self.bins[i];
+        let min_offset = self.min_offset;
+
+        if let Some((k, chunk)) = chunks
+            .iter()
+            .enumerate()
+            .skip(j)
+            .find(|(_, c)| c.end() > min_offset)
+        {
+            self.queue.push(Reverse((chunk.start(), i, k)));
+        }
+    }
+}
+
+fn is_sorted_by_start(chunks: &[Chunk]) -> bool {
+    chunks.windows(2).all(|w| w[0].start() <= w[1].start())
+}
+
+impl From<Vec<Chunk>> for MergedChunks<'_> {
+    /// Creates an iterator over merged chunks from a list of chunks.
+    fn from(chunks: Vec<Chunk>) -> Self {
+        Self::from_bins(vec![Cow::Owned(chunks)], bgzf::VirtualPosition::default())
+    }
+}
+
+impl<'a> Iterator for MergedChunks<'a> {
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((_, i, j)) = self.queue.pop()?;
+        let mut chunk = self.bins[i][j];
+        self.push_next(i, j + 1);
+
+        while let Some(&Reverse((start, i, j))) = self.queue.peek() {
+            if start > chunk.end() {
+                break;
+            }
+
+            self.queue.pop();
+
+            let next_chunk = self.bins[i][j];
+
+            if next_chunk.end() > chunk.end() {
+                chunk = Chunk::new(chunk.start(), next_chunk.end());
+            }
+
+            self.push_next(i, j + 1);
+        }
+
+        Some(chunk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binning_index::optimize_chunks;
+
+    fn build_chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start),
+            bgzf::VirtualPosition::from(end),
+        )
+    }
+
+    #[test]
+    fn test_next() {
+        let bins = [
+            vec![build_chunk(2, 5), build_chunk(8, 13), build_chunk(55, 89)],
+            vec![build_chunk(3, 8), build_chunk(21, 34)],
+            vec![],
+            vec![build_chunk(34, 40)],
+        ];
+
+        let min_offset = bgzf::VirtualPosition::default();
+
+        let actual: Vec<_> = MergedChunks::new(bins.iter().map(|c| &c[..]), min_offset).collect();
+        let expected = [build_chunk(2, 13), build_chunk(21, 40), build_chunk(55, 89)];
+        assert_eq!(actual, expected);
+
+        let chunks: Vec<_> = bins.iter().flatten().copied().collect();
+        assert_eq!(actual, optimize_chunks(&chunks, min_offset));
+
+        let min_offset = bgzf::VirtualPosition::from(13);
+
+        let actual: Vec<_> = MergedChunks::new(bins.iter().map(|c| &c[..]), min_offset).collect();
+        let expected = [build_chunk(21, 40), build_chunk(55, 89)];
+        assert_eq!(actual, expected);
+        assert_eq!(actual, optimize_chunks(&chunks, min_offset));
+    }
+
+    #[test]
+    fn test_next_with_unsorted_bins() {
+        let bins = [
+            vec![build_chunk(55, 89), build_chunk(2, 5), build_chunk(8, 13)],
+            vec![build_chunk(21, 34), build_chunk(3, 8)],
+            vec![build_chunk(34, 40)],
+        ];
+
+        let chunks: Vec<_> = bins.iter().flatten().copied().collect();
+
+        for min_offset in [
+            bgzf::VirtualPosition::default(),
+            bgzf::VirtualPosition::from(13),
+        ] {
+            let actual: Vec<_> =
+                MergedChunks::new(bins.iter().map(|c| &c[..]), min_offset).collect();
+            assert_eq!(actual, optimize_chunks(&chunks, min_offset));
+        }
+
+        let actual: Vec<_> = MergedChunks::from(chunks).collect();
+        let expected = [build_chunk(2, 13), build_chunk(21, 40), build_chunk(55, 89)];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_from_vec_chunk() {
+        let chunks = vec![build_chunk(2, 5), build_chunk(5, 8), build_chunk(13, 21)];
+        let actual: Vec<_> = MergedChunks::from(chunks).collect();
+        let expected = [build_chunk(2, 8), build_chunk(13, 21)];
+        assert_eq!(actual, expected);
+    }
+}
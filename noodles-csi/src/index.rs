@@ -1,18 +1,28 @@
 //! Coordinate-sorted index and fields.
 
 mod builder;
+pub mod header;
 pub mod reference_sequence;
+pub mod tabix_config;
 
-pub use self::{builder::Builder, reference_sequence::ReferenceSequence};
+pub use self::{
+    builder::Builder, header::Header, reference_sequence::ReferenceSequence,
+    tabix_config::TabixConfig,
+};
 
 use std::{
     io,
     ops::{Bound, RangeBounds},
 };
 
+use noodles_bgzf as bgzf;
 use noodles_core::Position;
 
-use super::{index::reference_sequence::bin::Chunk, BinningIndex};
+use super::{
+    binning_index::{optimize_chunks, MergedChunks},
+    index::reference_sequence::{bin::Chunk, Bin},
+    BinningIndex,
+};
 
 /// A coordinate-sorted index (CSI).
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -63,6 +73,86 @@ impl Index {
         self.depth
     }
 
+    /// Returns the maximum reference sequence length addressable by the binning index.
+    ///
+    /// This is 2^(min_shift + 3 * depth) - 1.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the length overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::default();
+    /// assert_eq!(index.max_reference_length()?, 536870911);
+    ///
+    /// let index = csi::Index::builder().set_min_shift(4).set_depth(2).build();
+    /// assert_eq!(index.max_reference_length()?, 1023);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn max_reference_length(&self) -> io::Result<usize> {
+        let shift = u32::from(self.min_shift) + 3 * u32::from(self.depth);
+
+        1usize.checked_shl(shift).map(|n| n - 1).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "max reference length overflows usize",
+            )
+        })
+    }
+
+    /// Returns the total number of bins in the binning index.
+    ///
+    /// This does not include the metadata pseudo-bin. Bin IDs are in the range [0, `bin_count`),
+    /// and level _l_ has 8^_l_ bins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::default();
+    /// assert_eq!(index.bin_count(), 37449);
+    ///
+    /// let index = csi::Index::builder().set_depth(2).build();
+    /// assert_eq!(index.bin_count(), 73);
+    /// ```
+    pub fn bin_count(&self) -> u32 {
+        Bin::max_id(self.depth)
+    }
+
+    /// Returns the level of the given bin ID.
+    ///
+    /// Level 0 is the root bin, and level `depth` contains the smallest bins. This returns `None`
+    /// if the bin ID is out of range, e.g., the metadata pseudo-bin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::default();
+    /// assert_eq!(index.bin_level(0), Some(0));
+    /// assert_eq!(index.bin_level(1), Some(1));
+    /// assert_eq!(index.bin_level(4680), Some(4));
+    /// assert_eq!(index.bin_level(4681), Some(5));
+    /// assert_eq!(index.bin_level(37448), Some(5));
+    /// assert!(index.bin_level(37449).is_none());
+    /// ```
+    pub fn bin_level(&self, bin_id: u32) -> Option<u8> {
+        if bin_id >= self.bin_count() {
+            return None;
+        }
+
+        (0..=self.depth)
+            .rev()
+            .find(|&level| bin_id >= level_start(level))
+    }
+
     /// Returns the auxiliary data.
     ///
     /// # Examples
@@ -76,6 +166,54 @@ impl Index {
         &self.aux
     }
 
+    /// Parses the auxiliary data as a tabix configuration.
+    ///
+    /// A CSI index of a generic tab-delimited file (e.g., a bgzipped VCF) stores the tabix
+    /// configuration, including the reference sequence names, in its auxiliary data. The
+    /// reference sequence names can be used to resolve a reference sequence ID for a query.
+    ///
+    /// This returns `None` if the auxiliary data is empty.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the auxiliary data is not a valid tabix configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_csi::{self as csi, index::header::Format};
+    ///
+    /// let index = csi::Index::default();
+    /// assert!(index.tabix_config()?.is_none());
+    ///
+    /// let aux = [
+    ///     0x02, 0x00, 0x00, 0x00, // format = 2 (VCF)
+    ///     0x01, 0x00, 0x00, 0x00, // col_seq = 1
+    ///     0x02, 0x00, 0x00, 0x00, // col_beg = 2
+    ///     0x00, 0x00, 0x00, 0x00, // col_end = 0
+    ///     0x23, 0x00, 0x00, 0x00, // meta = '#'
+    ///     0x00, 0x00, 0x00, 0x00, // skip = 0
+    ///     0x04, 0x00, 0x00, 0x00, // l_nm = 4
+    ///     b's', b'q', b'0', 0x00, // names = ["sq0"]
+    /// ];
+    /// let index = csi::Index::builder().set_aux(aux.to_vec()).build();
+    ///
+    /// let tabix_config = index.tabix_config()?.expect("missing tabix config");
+    /// assert_eq!(tabix_config.header().format(), Format::Vcf);
+    ///
+    /// let reference_sequence_names = tabix_config.reference_sequence_names();
+    /// assert_eq!(reference_sequence_names.get_index_of("sq0"), Some(0));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn tabix_config(&self) -> io::Result<Option<TabixConfig>> {
+        if self.aux.is_empty() {
+            Ok(None)
+        } else {
+            tabix_config::parse_tabix_config(&self.aux).map(Some)
+        }
+    }
+
     /// Returns the number of unmapped records in the associated file.
     ///
     /// # Examples
@@ -122,6 +260,42 @@ impl BinningIndex<ReferenceSequence> for Index {
     }
 
     fn query<B>(&self, reference_sequence_id: usize, interval: B) -> io::Result<Vec<Chunk>>
+    where
+        B: RangeBounds<Position> + Clone,
+    {
+        let (query_bins, min_offset) = self.query_bins(reference_sequence_id, interval)?;
+
+        let chunks: Vec<_> = query_bins
+            .iter()
+            .flat_map(|bin| bin.chunks())
+            .copied()
+            .collect();
+
+        let merged_chunks = optimize_chunks(&chunks, min_offset);
+
+        Ok(merged_chunks)
+    }
+
+    fn query_merged_chunks<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<MergedChunks<'_>>
+    where
+        B: RangeBounds<Position> + Clone,
+    {
+        let (query_bins, min_offset) = self.query_bins(reference_sequence_id, interval)?;
+        let bins = query_bins.into_iter().map(|bin| bin.chunks());
+        Ok(MergedChunks::new(bins, min_offset))
+    }
+}
+
+impl Index {
+    fn query_bins<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<(Vec<&Bin>, bgzf::VirtualPosition)>
     where
         B: RangeBounds<Position> + Clone,
     {
@@ -136,16 +310,13 @@ impl BinningIndex<ReferenceSequence> for Index {
             })?;
 
         let query_bins = reference_sequence
-            .query(self.min_shift(), self.depth(), interval)
+            .query(self.min_shift(), self.depth(), interval.clone())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        let chunks: Vec<_> = query_bins
-            .iter()
-            .flat_map(|bin| bin.chunks())
-            .copied()
-            .collect();
+        let (start, _) = resolve_interval(self.min_shift(), self.depth(), interval)?;
+        let min_offset = reference_sequence.min_offset(self.min_shift(), self.depth(), start);
 
-        Ok(chunks)
+        Ok((query_bins, min_offset))
     }
 }
 
@@ -155,6 +326,11 @@ impl Default for Index {
     }
 }
 
+// `CSIv1.pdf` (2020-07-21)
+fn level_start(level: u8) -> u32 {
+    ((1 << (3 * u32::from(level))) - 1) / 7
+}
+
 fn resolve_interval<B>(min_shift: u8, depth: u8, interval: B) -> io::Result<(Position, Position)>
 where
     B: RangeBounds<Position>,
@@ -192,3 +368,124 @@ where
         Ok((start, end))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_index(bins: Vec<Bin>) -> Index {
+        Index::builder()
+            .set_min_shift(4)
+            .set_depth(2)
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, None)])
+            .build()
+    }
+
+    fn build_chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start),
+            bgzf::VirtualPosition::from(end),
+        )
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(49)?;
+        let end = Position::try_from(64)?;
+
+        // all chunks are before the minimum offset
+        let index = build_index(vec![
+            Bin::new(
+                0,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(10, 20)],
+            ),
+            Bin::new(
+                12,
+                bgzf::VirtualPosition::from(100),
+                vec![build_chunk(50, 100)],
+            ),
+        ]);
+        assert!(index.query(0, start..=end)?.is_empty());
+
+        // two mergeable chunks
+        let index = build_index(vec![
+            Bin::new(
+                1,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(15, 30)],
+            ),
+            Bin::new(
+                12,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(10, 20)],
+            ),
+        ]);
+        assert_eq!(index.query(0, start..=end)?, [build_chunk(10, 30)]);
+
+        // chunks straddling the minimum offset
+        let index = build_index(vec![
+            Bin::new(
+                0,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(5, 10), build_chunk(25, 30)],
+            ),
+            Bin::new(
+                12,
+                bgzf::VirtualPosition::from(15),
+                vec![build_chunk(12, 20)],
+            ),
+        ]);
+        assert_eq!(
+            index.query(0, start..=end)?,
+            [build_chunk(12, 20), build_chunk(25, 30)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_reference_length() -> io::Result<()> {
+        let index = Index::default();
+        assert_eq!(index.max_reference_length()?, 536870911);
+
+        let index = Index::builder().set_min_shift(4).set_depth(2).build();
+        assert_eq!(index.max_reference_length()?, 1023);
+
+        let index = Index::builder().set_min_shift(64).set_depth(0).build();
+        assert!(matches!(
+            index.max_reference_length(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bin_count() {
+        assert_eq!(Index::default().bin_count(), 37449);
+        assert_eq!(Index::builder().set_depth(0).build().bin_count(), 1);
+        assert_eq!(Index::builder().set_depth(2).build().bin_count(), 73);
+    }
+
+    #[test]
+    fn test_bin_level() {
+        let index = Index::default();
+
+        assert_eq!(index.bin_level(0), Some(0));
+        assert_eq!(index.bin_level(1), Some(1));
+        assert_eq!(index.bin_level(8), Some(1));
+        assert_eq!(index.bin_level(9), Some(2));
+        assert_eq!(index.bin_level(72), Some(2));
+        assert_eq!(index.bin_level(73), Some(3));
+        assert_eq!(index.bin_level(585), Some(4));
+        assert_eq!(index.bin_level(4681), Some(5));
+        assert_eq!(index.bin_level(37448), Some(5));
+        assert_eq!(index.bin_level(37449), None);
+        assert_eq!(index.bin_level(Bin::metadata_id(5)), None);
+
+        let index = Index::builder().set_depth(2).build();
+        assert_eq!(index.bin_level(72), Some(2));
+        assert_eq!(index.bin_level(73), None);
+    }
+}
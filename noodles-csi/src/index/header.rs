@@ -6,6 +6,8 @@ pub mod format;
 pub use self::{builder::Builder, format::Format};
 
 /// A tabix index header.
+///
+/// This is also used as the tabix-style configuration in the auxiliary data of a CSI index.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Header {
     format: Format,
@@ -22,8 +24,8 @@ impl Header {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
-    /// let builder = tabix::index::Header::builder();
+    /// use noodles_csi as csi;
+    /// let builder = csi::index::Header::builder();
     /// ```
     pub fn builder() -> Builder {
         Builder::default()
@@ -34,9 +36,9 @@ impl Header {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix::{self as tabix, index::header::Format};
+    /// use noodles_csi::{self as csi, index::header::Format};
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_format(Format::Vcf)
     ///     .build();
     ///
@@ -53,9 +55,9 @@ impl Header {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_reference_sequence_name_index(1)
     ///     .build();
     ///
@@ -72,9 +74,9 @@ impl Header {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_start_position_index(4)
     ///     .build();
     ///
@@ -92,9 +94,9 @@ impl Header {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_end_position_index(Some(5))
     ///     .build();
     ///
@@ -109,9 +111,9 @@ impl Header {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_line_comment_prefix(b'#')
     ///     .build();
     ///
@@ -126,9 +128,9 @@ impl Header {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_line_skip_count(0)
     ///     .build();
     ///
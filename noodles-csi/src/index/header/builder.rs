@@ -18,8 +18,8 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
-    /// let builder = tabix::index::header::Builder::bed();
+    /// use noodles_csi as csi;
+    /// let builder = csi::index::header::Builder::bed();
     /// ```
     pub fn bed() -> Self {
         Self {
@@ -37,8 +37,8 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
-    /// let builder = tabix::index::header::Builder::gff();
+    /// use noodles_csi as csi;
+    /// let builder = csi::index::header::Builder::gff();
     /// ```
     pub fn gff() -> Self {
         Self {
@@ -56,8 +56,8 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
-    /// let builder = tabix::index::header::Builder::sam();
+    /// use noodles_csi as csi;
+    /// let builder = csi::index::header::Builder::sam();
     /// ```
     pub fn sam() -> Self {
         Self {
@@ -75,8 +75,8 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
-    /// let builder = tabix::index::header::Builder::vcf();
+    /// use noodles_csi as csi;
+    /// let builder = csi::index::header::Builder::vcf();
     /// ```
     pub fn vcf() -> Self {
         Self {
@@ -94,9 +94,9 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix::{self as tabix, index::header::Format};
+    /// use noodles_csi::{self as csi, index::header::Format};
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_format(Format::Vcf)
     ///     .build();
     ///
@@ -112,9 +112,9 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_reference_sequence_name_index(1)
     ///     .build();
     ///
@@ -133,9 +133,9 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_start_position_index(4)
     ///     .build();
     ///
@@ -151,9 +151,9 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_end_position_index(Some(5))
     ///     .build();
     ///
@@ -169,9 +169,9 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_line_comment_prefix(b'#')
     ///     .build();
     ///
@@ -187,9 +187,9 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
+    /// use noodles_csi as csi;
     ///
-    /// let header = tabix::index::Header::builder()
+    /// let header = csi::index::Header::builder()
     ///     .set_line_skip_count(0)
     ///     .build();
     ///
@@ -205,8 +205,8 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_tabix as tabix;
-    /// let index = tabix::index::Header::builder().build();
+    /// use noodles_csi as csi;
+    /// let index = csi::index::Header::builder().build();
     /// ```
     pub fn build(self) -> Header {
         Header {
@@ -92,6 +92,58 @@ impl ReferenceSequence {
 
         Ok(query_bins)
     }
+
+    /// Returns the start position of the first record that could be in a query at the given
+    /// start position.
+    ///
+    /// This is the linear offset of the bin at the deepest level that contains the start position.
+    /// If that bin is not in the index, the closest preceding bin on the same level is used, and
+    /// then the parent bin, as done in htslib. The offset is 0 if no bin is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::index::{reference_sequence::Bin, ReferenceSequence};
+    ///
+    /// let bins = vec![Bin::new(4681, bgzf::VirtualPosition::from(8), Vec::new())];
+    /// let reference_sequence = ReferenceSequence::new(bins, None);
+    ///
+    /// let start = Position::try_from(16385)?;
+    /// assert_eq!(
+    ///     reference_sequence.min_offset(14, 5, start),
+    ///     bgzf::VirtualPosition::from(8)
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn min_offset(&self, min_shift: u8, depth: u8, start: Position) -> bgzf::VirtualPosition {
+        // The first bin ID of the deepest level.
+        let first_bin_id = ((1 << (3 * u32::from(depth))) - 1) / 7;
+        let beg = (usize::from(start) - 1) >> min_shift;
+        let mut bin_id = first_bin_id + beg as u32;
+
+        loop {
+            if let Some(bin) = self.bins().iter().find(|bin| bin.id() == bin_id) {
+                return bin.loffset();
+            }
+
+            if bin_id == 0 {
+                break;
+            }
+
+            let parent_bin_id = (bin_id - 1) >> 3;
+            let first_sibling_bin_id = (parent_bin_id << 3) + 1;
+
+            if bin_id > first_sibling_bin_id {
+                bin_id -= 1;
+            } else {
+                bin_id = parent_bin_id;
+            }
+        }
+
+        bgzf::VirtualPosition::default()
+    }
 }
 
 impl ReferenceSequenceExt for ReferenceSequence {
@@ -202,6 +254,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_min_offset() -> Result<(), noodles_core::position::TryFromIntError> {
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        let reference_sequence = ReferenceSequence::new(Vec::new(), None);
+        let start = Position::try_from(1)?;
+        assert_eq!(
+            reference_sequence.min_offset(MIN_SHIFT, DEPTH, start),
+            bgzf::VirtualPosition::default()
+        );
+
+        // See `test_reg2bins` for the bin layout.
+        let bins = vec![
+            Bin::new(0, bgzf::VirtualPosition::from(2), Vec::new()),
+            Bin::new(1, bgzf::VirtualPosition::from(3), Vec::new()),
+            Bin::new(10, bgzf::VirtualPosition::from(5), Vec::new()),
+            Bin::new(12, bgzf::VirtualPosition::from(8), Vec::new()),
+        ];
+        let reference_sequence = ReferenceSequence::new(bins, None);
+
+        let t =
+            |start: usize, expected: u64| -> Result<(), noodles_core::position::TryFromIntError> {
+                let start = Position::try_from(start)?;
+                let actual = reference_sequence.min_offset(MIN_SHIFT, DEPTH, start);
+                assert_eq!(actual, bgzf::VirtualPosition::from(expected));
+                Ok(())
+            };
+
+        // bin 12
+        t(49, 8)?;
+        // bin 11 -> bin 10
+        t(33, 5)?;
+        // bin 9 -> bin 1
+        t(1, 3)?;
+        // bin 17 -> bin 2 -> bin 1
+        t(129, 3)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_reg2bins() -> Result<(), noodles_core::position::TryFromIntError> {
         // +------------------------------------------------------------------------------------...
@@ -0,0 +1,212 @@
+//! CSI index tabix configuration.
+
+use std::{
+    io::{self, Read},
+    str,
+};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use indexmap::IndexSet;
+
+use super::{header::Format, Header};
+
+const NUL: u8 = b'\x00';
+
+/// An ordered set of reference sequence names.
+pub type ReferenceSequenceNames = IndexSet<String>;
+
+/// A tabix configuration stored in the auxiliary data of a CSI index.
+///
+/// This is written when a CSI indexes a generic tab-delimited file (e.g., a bgzipped VCF) and has
+/// the same layout as the header and reference sequence names of a tabix index.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TabixConfig {
+    header: Header,
+    reference_sequence_names: ReferenceSequenceNames,
+}
+
+impl TabixConfig {
+    /// Returns the header.
+    ///
+    /// This describes the format and columns of the indexed file.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the reference sequence names.
+    ///
+    /// The position of a name is its reference sequence ID in the index.
+    pub fn reference_sequence_names(&self) -> &ReferenceSequenceNames {
+        &self.reference_sequence_names
+    }
+}
+
+pub(super) fn parse_tabix_config(mut src: &[u8]) -> io::Result<TabixConfig> {
+    let format = src.read_i32::<LittleEndian>().and_then(|n| {
+        Format::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let reference_sequence_name_index = read_index(&mut src)?;
+    let start_position_index = read_index(&mut src)?;
+
+    let end_position_index = read_index(&mut src).map(|i| if i == 0 { None } else { Some(i) })?;
+
+    let line_comment_prefix = src
+        .read_i32::<LittleEndian>()
+        .and_then(|b| u8::try_from(b).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))?;
+
+    let line_skip_count = src.read_i32::<LittleEndian>().and_then(|n| {
+        u32::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let header = Header::builder()
+        .set_format(format)
+        .set_reference_sequence_name_index(reference_sequence_name_index)
+        .set_start_position_index(start_position_index)
+        .set_end_position_index(end_position_index)
+        .set_line_comment_prefix(line_comment_prefix)
+        .set_line_skip_count(line_skip_count)
+        .build();
+
+    let l_nm = src.read_i32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let mut names = vec![0; l_nm];
+    src.read_exact(&mut names)?;
+
+    let reference_sequence_names = parse_names(&names)?;
+
+    Ok(TabixConfig {
+        header,
+        reference_sequence_names,
+    })
+}
+
+fn read_index<R>(reader: &mut R) -> io::Result<usize>
+where
+    R: Read,
+{
+    reader
+        .read_i32::<LittleEndian>()
+        .and_then(|i| usize::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
+fn parse_names(buf: &[u8]) -> io::Result<ReferenceSequenceNames> {
+    let mut names = ReferenceSequenceNames::new();
+
+    // Names are NUL-terminated. An empty name is kept so that the indices of the following names
+    // still match their reference sequence IDs.
+    for raw_name in buf.split_inclusive(|&b| b == NUL) {
+        let raw_name = match raw_name.split_last() {
+            Some((&NUL, raw_name)) => raw_name,
+            _ => break,
+        };
+
+        let name =
+            str::from_utf8(raw_name).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if !names.insert(name.into()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("duplicate reference sequence name: {}", name),
+            ));
+        }
+    }
+
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tabix_config() -> io::Result<()> {
+        let data = [
+            0x02, 0x00, 0x00, 0x00, // format = 2 (VCF)
+            0x01, 0x00, 0x00, 0x00, // col_seq = 1
+            0x02, 0x00, 0x00, 0x00, // col_beg = 2
+            0x00, 0x00, 0x00, 0x00, // col_end = 0
+            0x23, 0x00, 0x00, 0x00, // meta = '#'
+            0x00, 0x00, 0x00, 0x00, // skip = 0
+            0x08, 0x00, 0x00, 0x00, // l_nm = 8
+            b's', b'q', b'0', 0x00, b's', b'q', b'1', 0x00, // names = ["sq0", "sq1"]
+        ];
+
+        let actual = parse_tabix_config(&data)?;
+
+        let expected = TabixConfig {
+            header: Header::builder()
+                .set_format(Format::Vcf)
+                .set_reference_sequence_name_index(1)
+                .set_start_position_index(2)
+                .set_end_position_index(None)
+                .set_line_comment_prefix(b'#')
+                .set_line_skip_count(0)
+                .build(),
+            reference_sequence_names: [String::from("sq0"), String::from("sq1")]
+                .into_iter()
+                .collect(),
+        };
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_tabix_config_with_empty_name() -> io::Result<()> {
+        let data = [
+            0x02, 0x00, 0x00, 0x00, // format = 2 (VCF)
+            0x01, 0x00, 0x00, 0x00, // col_seq = 1
+            0x02, 0x00, 0x00, 0x00, // col_beg = 2
+            0x00, 0x00, 0x00, 0x00, // col_end = 0
+            0x23, 0x00, 0x00, 0x00, // meta = '#'
+            0x00, 0x00, 0x00, 0x00, // skip = 0
+            0x09, 0x00, 0x00, 0x00, // l_nm = 9
+            b's', b'q', b'0', 0x00, // names[0] = "sq0"
+            0x00, // names[1] = ""
+            b's', b'q', b'2', 0x00, // names[2] = "sq2"
+        ];
+
+        let tabix_config = parse_tabix_config(&data)?;
+        let names = tabix_config.reference_sequence_names();
+
+        assert_eq!(names.len(), 3);
+        assert_eq!(names.get_index_of("sq0"), Some(0));
+        assert_eq!(names.get_index_of(""), Some(1));
+        assert_eq!(names.get_index_of("sq2"), Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_tabix_config_with_duplicate_name() {
+        let data = [
+            0x02, 0x00, 0x00, 0x00, // format = 2 (VCF)
+            0x01, 0x00, 0x00, 0x00, // col_seq = 1
+            0x02, 0x00, 0x00, 0x00, // col_beg = 2
+            0x00, 0x00, 0x00, 0x00, // col_end = 0
+            0x23, 0x00, 0x00, 0x00, // meta = '#'
+            0x00, 0x00, 0x00, 0x00, // skip = 0
+            0x08, 0x00, 0x00, 0x00, // l_nm = 8
+            b's', b'q', b'0', 0x00, b's', b'q', b'0', 0x00, // names = ["sq0", "sq0"]
+        ];
+
+        assert!(matches!(
+            parse_tabix_config(&data),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_parse_tabix_config_with_truncated_data() {
+        let data = [0x02, 0x00, 0x00, 0x00];
+
+        assert!(matches!(
+            parse_tabix_config(&data),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+}
@@ -1,8 +1,10 @@
 //! FASTA reader and iterators.
 
+mod alphabet;
+mod builder;
 mod records;
 
-pub use self::records::Records;
+pub use self::{alphabet::Alphabet, builder::Builder, records::Records};
 
 use std::{
     io::{self, BufRead, Read, Seek, SeekFrom},
@@ -24,12 +26,27 @@ const CARRIAGE_RETURN: char = '\r';
 /// A FASTA reader.
 pub struct Reader<R> {
     inner: R,
+    alphabet: Option<Alphabet>,
 }
 
 impl<R> Reader<R>
 where
     R: BufRead,
 {
+    /// Creates a FASTA reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let data = b">sq0\nACGT\n";
+    /// let builder = fasta::Reader::builder(&data[..]);
+    /// let reader = builder.build();
+    /// ```
+    pub fn builder(inner: R) -> Builder<R> {
+        Builder::new(inner)
+    }
+
     /// Creates a FASTA reader.
     ///
     /// # Examples
@@ -40,7 +57,7 @@ where
     /// let mut reader = fasta::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self::builder(inner).build()
     }
 
     /// Reads a raw definition line.
@@ -84,6 +101,9 @@ where
     /// If successful, this returns the number of bytes read from the stream. If the number of
     /// bytes read is 0, the stream reached EOF (though this case is likely an error).
     ///
+    /// If the reader was built with an alphabet (see [`Builder::set_alphabet`]), the sequence is
+    /// validated against it.
+    ///
     /// # Examples
     ///
     /// ```
@@ -101,7 +121,25 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_sequence(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
-        read_sequence(&mut self.inner, buf)
+        self.read_validated_sequence(None, buf)
+    }
+
+    // Reads a sequence and, if the reader has an alphabet, validates the bases that were read.
+    //
+    // The name is only used to describe an invalid sequence.
+    fn read_validated_sequence(
+        &mut self,
+        name: Option<&str>,
+        buf: &mut Vec<u8>,
+    ) -> io::Result<usize> {
+        let start = buf.len();
+        let bytes_read = read_sequence(&mut self.inner, buf)?;
+
+        if let Some(alphabet) = self.alphabet {
+            validate_sequence(alphabet, name, &buf[start..])?;
+        }
+
+        Ok(bytes_read)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -131,6 +169,25 @@ where
     /// assert!(records.next().is_none());
     /// # Ok::<(), io::Error>(())
     /// ```
+    ///
+    /// If the reader was built with an alphabet (see [`Builder::set_alphabet`]), each sequence is
+    /// validated against it, as in [`Self::read_sequence`].
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, reader::Alphabet};
+    ///
+    /// let data = b">sq0\nACZT\n";
+    ///
+    /// let mut reader = fasta::Reader::builder(&data[..])
+    ///     .set_alphabet(Alphabet::Dna)
+    ///     .build();
+    /// assert!(reader.records().next().transpose().is_err());
+    ///
+    /// let mut reader = fasta::Reader::builder(&data[..])
+    ///     .set_alphabet(Alphabet::Protein)
+    ///     .build();
+    /// assert!(reader.records().next().transpose().is_ok());
+    /// ```
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
@@ -168,6 +225,9 @@ where
 {
     /// Returns a record of the given region.
     ///
+    /// If the reader was built with an alphabet (see [`Builder::set_alphabet`]), the entire
+    /// reference sequence is validated against it, as in [`Self::read_sequence`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -211,7 +271,7 @@ where
         let definition = Definition::new(region.to_string(), None);
 
         let mut raw_sequence = Vec::new();
-        self.read_sequence(&mut raw_sequence)?;
+        self.read_validated_sequence(Some(index_record.name()), &mut raw_sequence)?;
 
         let range = interval_to_slice_range(interval, raw_sequence.len());
         let sequence = Sequence::from(raw_sequence[range].to_vec());
@@ -343,6 +403,31 @@ where
     Ok(bytes_read)
 }
 
+// Returns an error with the name, if given, and 1-based position of the first base not in the
+// alphabet.
+fn validate_sequence(alphabet: Alphabet, name: Option<&str>, sequence: &[u8]) -> io::Result<()> {
+    let i = match sequence.iter().position(|&b| !alphabet.contains(b)) {
+        Some(i) => i,
+        None => return Ok(()),
+    };
+
+    let location = match name {
+        Some(name) => format!(" in {}", name),
+        None => String::new(),
+    };
+
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "invalid base '{}'{} at position {} for alphabet {:?}",
+            char::from(sequence[i]),
+            location,
+            i + 1,
+            alphabet
+        ),
+    ))
+}
+
 fn resolve_region(index: &[fai::Record], region: &Region) -> io::Result<(usize, Interval)> {
     let i = index
         .iter()
@@ -449,6 +534,69 @@ mod tests {
         assert_eq!(buf, b"NNNN");
     }
 
+    #[test]
+    fn test_validate_sequence() {
+        assert!(validate_sequence(Alphabet::Dna, Some("sq0"), b"ACGTN").is_ok());
+
+        assert!(matches!(
+            validate_sequence(Alphabet::Dna, Some("sq0"), b"ACZT"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+                && e.to_string() == "invalid base 'Z' in sq0 at position 3 for alphabet Dna"
+        ));
+
+        assert!(matches!(
+            validate_sequence(Alphabet::Dna, None, b"ACZT"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+                && e.to_string() == "invalid base 'Z' at position 3 for alphabet Dna"
+        ));
+
+        assert!(validate_sequence(Alphabet::Protein, Some("sq0"), b"ACZT").is_ok());
+    }
+
+    #[test]
+    fn test_read_sequence_with_alphabet() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1\nACZT\n";
+        let mut reader = Reader::builder(&data[..])
+            .set_alphabet(Alphabet::Dna)
+            .build();
+
+        let mut buf = Vec::new();
+
+        reader.read_definition(&mut String::new())?;
+        reader.read_sequence(&mut buf)?;
+        assert_eq!(buf, b"ACGT");
+
+        reader.read_definition(&mut String::new())?;
+        assert!(matches!(
+            reader.read_sequence(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_alphabet() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        let data = b">sq0\nACZT\n";
+        let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+
+        let mut reader = Reader::builder(Cursor::new(&data[..]))
+            .set_alphabet(Alphabet::Dna)
+            .build();
+
+        let region = "sq0:1-2".parse()?;
+
+        assert!(matches!(
+            reader.query(&index, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+                && e.to_string() == "invalid base 'Z' in sq0 at position 3 for alphabet Dna"
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -0,0 +1,51 @@
+/// A sequence alphabet.
+///
+/// Bases are validated case-insensitively, and each alphabet allows gaps (`-`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Alphabet {
+    /// IUPAC nucleotide codes for DNA (`ACGTRYSWKMBDHVN`).
+    Dna,
+    /// IUPAC nucleotide codes for RNA (`ACGURYSWKMBDHVN`).
+    Rna,
+    /// IUPAC amino acid codes (`A`-`Z`) and translation stops (`*`).
+    Protein,
+}
+
+impl Alphabet {
+    pub(crate) fn contains(self, b: u8) -> bool {
+        match b.to_ascii_uppercase() {
+            b'-' => true,
+            b'A' | b'C' | b'G' | b'R' | b'Y' | b'S' | b'W' | b'K' | b'M' | b'B' | b'D' | b'H'
+            | b'V' | b'N' => matches!(self, Self::Dna | Self::Rna | Self::Protein),
+            b'T' => matches!(self, Self::Dna | Self::Protein),
+            b'U' => matches!(self, Self::Rna | Self::Protein),
+            b'A'..=b'Z' | b'*' => self == Self::Protein,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        assert!(Alphabet::Dna.contains(b'A'));
+        assert!(Alphabet::Dna.contains(b't'));
+        assert!(Alphabet::Dna.contains(b'N'));
+        assert!(Alphabet::Dna.contains(b'-'));
+        assert!(!Alphabet::Dna.contains(b'U'));
+        assert!(!Alphabet::Dna.contains(b'Z'));
+        assert!(!Alphabet::Dna.contains(b'*'));
+
+        assert!(Alphabet::Rna.contains(b'U'));
+        assert!(!Alphabet::Rna.contains(b'T'));
+
+        assert!(Alphabet::Protein.contains(b'Z'));
+        assert!(Alphabet::Protein.contains(b'*'));
+        assert!(Alphabet::Protein.contains(b'-'));
+        assert!(!Alphabet::Protein.contains(b'1'));
+        assert!(!Alphabet::Protein.contains(b' '));
+    }
+}
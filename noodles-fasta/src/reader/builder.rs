@@ -0,0 +1,57 @@
+use std::io::BufRead;
+
+use super::{Alphabet, Reader};
+
+/// A FASTA reader builder.
+pub struct Builder<R> {
+    inner: R,
+    alphabet: Option<Alphabet>,
+}
+
+impl<R> Builder<R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            alphabet: None,
+        }
+    }
+
+    /// Sets an alphabet to validate sequences against.
+    ///
+    /// When set, reading a record with a sequence that has a base outside the alphabet returns an
+    /// [`std::io::ErrorKind::InvalidData`] error with the reference sequence name and the
+    /// position of the base.
+    ///
+    /// By default, sequences are not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, reader::Alphabet};
+    /// let data = [];
+    /// let builder = fasta::Reader::builder(&data[..]).set_alphabet(Alphabet::Dna);
+    /// ```
+    pub fn set_alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = Some(alphabet);
+        self
+    }
+
+    /// Builds a FASTA reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let data = [];
+    /// let reader = fasta::Reader::builder(&data[..]).build();
+    /// ```
+    pub fn build(self) -> Reader<R> {
+        Reader {
+            inner: self.inner,
+            alphabet: self.alphabet,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::io::{self, BufRead};
 
-use crate::{record::Sequence, Record};
+use crate::{
+    record::{Definition, Sequence},
+    Record,
+};
 
 use super::Reader;
 
@@ -39,19 +42,21 @@ where
             Err(e) => return Some(Err(e)),
         }
 
-        let definition = match self.line_buf.parse() {
+        let definition: Definition = match self.line_buf.parse() {
             Ok(d) => d,
             Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
         };
 
         let mut sequence_buf = Vec::new();
 
-        match self.inner.read_sequence(&mut sequence_buf) {
-            Ok(_) => {
-                let record = Record::new(definition, Sequence::from(sequence_buf));
-                Some(Ok(record))
-            }
-            Err(e) => Some(Err(e)),
+        if let Err(e) = self
+            .inner
+            .read_validated_sequence(Some(definition.name()), &mut sequence_buf)
+        {
+            return Some(Err(e));
         }
+
+        let record = Record::new(definition, Sequence::from(sequence_buf));
+        Some(Ok(record))
     }
 }
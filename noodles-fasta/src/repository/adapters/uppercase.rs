@@ -0,0 +1,68 @@
+use std::io;
+
+use crate::{record::Sequence, repository::Adapter, Record};
+
+/// An adapter that converts sequences to uppercase.
+///
+/// This wraps another adapter and converts the bases of each fetched sequence to uppercase, e.g.,
+/// to normalize softmasked (lowercase) bases in a reference sequence.
+pub struct Uppercase<A> {
+    inner: A,
+}
+
+impl<A> Uppercase<A>
+where
+    A: Adapter,
+{
+    /// Creates an uppercase adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::{Empty, Uppercase};
+    /// let adapter = Uppercase::new(Empty::new());
+    /// ```
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A> Adapter for Uppercase<A>
+where
+    A: Adapter,
+{
+    fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        self.inner.get(name).map(|result| {
+            result.map(|record| {
+                let sequence = Sequence::from(record.sequence().as_ref().to_ascii_uppercase());
+                Record::new(record.definition().clone(), sequence)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Definition;
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let records = vec![Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACgtNn".to_vec()),
+        )];
+
+        let mut adapter = Uppercase::new(records);
+
+        let record = adapter.get("sq0").transpose()?;
+        assert_eq!(
+            record.map(|record| record.sequence().as_ref().to_vec()),
+            Some(b"ACGTNN".to_vec())
+        );
+
+        assert!(adapter.get("sq1").is_none());
+
+        Ok(())
+    }
+}
@@ -66,6 +66,51 @@ where
     }
 }
 
+#[cfg(feature = "gzip")]
+impl<W> Writer<flate2::write::GzEncoder<W>>
+where
+    W: Write,
+{
+    /// Creates a FASTA writer that compresses its output as plain gzip.
+    ///
+    /// The output is a single gzip member, not BGZF. This requires the `gzip` feature to be
+    /// enabled. Call [`Self::finish`] to write the gzip trailer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::Writer::new_gzip(Vec::new());
+    /// ```
+    pub fn new_gzip(inner: W) -> Self {
+        use flate2::{write::GzEncoder, Compression};
+        Self::new(GzEncoder::new(inner, Compression::default()))
+    }
+
+    /// Finishes the gzip stream and returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let mut writer = fasta::Writer::new_gzip(Vec::new());
+    /// let record = fasta::Record::new(
+    ///     fasta::record::Definition::new("sq0", None),
+    ///     fasta::record::Sequence::from(b"ACGT".to_vec()),
+    /// );
+    /// writer.write_record(&record)?;
+    ///
+    /// let data = writer.finish()?;
+    /// assert_eq!(&data[..2], [0x1f, 0x8b]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<W> {
+        self.inner.finish()
+    }
+}
+
 fn write_record_sequence<W>(
     writer: &mut W,
     sequence: &Sequence,
@@ -110,4 +155,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_new_gzip() -> io::Result<()> {
+        use std::io::Read;
+
+        use flate2::read::GzDecoder;
+
+        let mut writer = Writer::new_gzip(Vec::new());
+        let record = Record::new(
+            crate::record::Definition::new("sq0", None),
+            Sequence::from(b"ACGT".to_vec()),
+        );
+        writer.write_record(&record)?;
+        writer.write_record(&record)?;
+        let data = writer.finish()?;
+
+        let mut decoder = GzDecoder::new(&data[..]);
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+        assert_eq!(buf, b">sq0\nACGT\n>sq0\nACGT\n");
+
+        Ok(())
+    }
 }
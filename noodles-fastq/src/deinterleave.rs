@@ -0,0 +1,181 @@
+use std::io::{self, BufRead, Write};
+
+use super::{Reader, Record, Writer};
+
+/// Splits an interleaved FASTQ into two streams.
+///
+/// Records are read in pairs and written alternately to `r1_writer` and `r2_writer`. The names of
+/// the records in a pair must match up to the first whitespace, i.e., ignoring comments (e.g.,
+/// Casava 1.8 `1:N:0:ATCACG`), and after removing the optional mate suffixes (`/1` and `/2`,
+/// respectively).
+///
+/// # Errors
+///
+/// This returns an [`io::ErrorKind::InvalidData`] error if the names of a pair do not match or if
+/// the last record is missing its mate.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fastq as fastq;
+///
+/// let data = b"@r0/1\nACGT\n+\nNDLS\n@r0/2\nTGCA\n+\nSLDN\n";
+/// let mut reader = fastq::Reader::new(&data[..]);
+///
+/// let mut r1_writer = fastq::Writer::new(Vec::new());
+/// let mut r2_writer = fastq::Writer::new(Vec::new());
+///
+/// fastq::deinterleave(&mut reader, &mut r1_writer, &mut r2_writer)?;
+///
+/// assert_eq!(r1_writer.get_ref(), b"@r0/1\nACGT\n+\nNDLS\n");
+/// assert_eq!(r2_writer.get_ref(), b"@r0/2\nTGCA\n+\nSLDN\n");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn deinterleave<R, W, X>(
+    reader: &mut Reader<R>,
+    r1_writer: &mut Writer<W>,
+    r2_writer: &mut Writer<X>,
+) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+    X: Write,
+{
+    let mut r1 = Record::default();
+    let mut r2 = Record::default();
+
+    loop {
+        if reader.read_record(&mut r1)? == 0 {
+            break;
+        }
+
+        if reader.read_record(&mut r2)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "missing mate for record: {}",
+                    String::from_utf8_lossy(r1.name())
+                ),
+            ));
+        }
+
+        if !is_mate_pair(r1.name(), r2.name()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "name mismatch: expected mate of {}, got {}",
+                    String::from_utf8_lossy(r1.name()),
+                    String::from_utf8_lossy(r2.name())
+                ),
+            ));
+        }
+
+        r1_writer.write_record(&r1)?;
+        r2_writer.write_record(&r2)?;
+    }
+
+    Ok(())
+}
+
+fn is_mate_pair(r1_name: &[u8], r2_name: &[u8]) -> bool {
+    let r1_name = read_id(r1_name);
+    let r1_name = r1_name.strip_suffix(b"/1").unwrap_or(r1_name);
+
+    let r2_name = read_id(r2_name);
+    let r2_name = r2_name.strip_suffix(b"/2").unwrap_or(r2_name);
+
+    r1_name == r2_name
+}
+
+// Returns the read ID, i.e., the name up to the first whitespace. The rest of the name is an
+// optional comment, e.g., the Casava 1.8 `<read>:<is filtered>:<control number>:<index>`.
+fn read_id(name: &[u8]) -> &[u8] {
+    name.split(|b| b.is_ascii_whitespace())
+        .next()
+        .unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deinterleave() -> io::Result<()> {
+        let data = b"\
+@r0/1\nACGT\n+\nNDLS\n\
+@r0/2\nTGCA\n+\nSLDN\n\
+@r1\nGGCC\n+\nNNNN\n\
+@r1\nCCGG\n+\nDDDD\n\
+@r2 1:N:0:X\nACGT\n+\nNDLS\n\
+@r2 2:N:0:X\nTGCA\n+\nSLDN\n";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut r1_writer = Writer::new(Vec::new());
+        let mut r2_writer = Writer::new(Vec::new());
+
+        deinterleave(&mut reader, &mut r1_writer, &mut r2_writer)?;
+
+        assert_eq!(
+            r1_writer.get_ref(),
+            b"@r0/1\nACGT\n+\nNDLS\n@r1\nGGCC\n+\nNNNN\n@r2 1:N:0:X\nACGT\n+\nNDLS\n"
+        );
+        assert_eq!(
+            r2_writer.get_ref(),
+            b"@r0/2\nTGCA\n+\nSLDN\n@r1\nCCGG\n+\nDDDD\n@r2 2:N:0:X\nTGCA\n+\nSLDN\n"
+        );
+
+        // Re-interleaving the outputs gives the original input.
+        let mut r1_reader = Reader::new(&r1_writer.get_ref()[..]);
+        let mut r2_reader = Reader::new(&r2_writer.get_ref()[..]);
+        let mut writer = Writer::new(Vec::new());
+
+        for (r1, r2) in r1_reader.records().zip(r2_reader.records()) {
+            writer.write_record(&r1?)?;
+            writer.write_record(&r2?)?;
+        }
+
+        assert_eq!(writer.get_ref(), &data[..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deinterleave_with_desynced_input() {
+        let data = b"@r0/1\nACGT\n+\nNDLS\n@r1/1\nTGCA\n+\nSLDN\n";
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(matches!(
+            deinterleave(
+                &mut reader,
+                &mut Writer::new(Vec::new()),
+                &mut Writer::new(Vec::new())
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = b"@r0/1\nACGT\n+\nNDLS\n";
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(matches!(
+            deinterleave(
+                &mut reader,
+                &mut Writer::new(Vec::new()),
+                &mut Writer::new(Vec::new())
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_is_mate_pair() {
+        assert!(is_mate_pair(b"r0/1", b"r0/2"));
+        assert!(is_mate_pair(b"r0", b"r0"));
+        assert!(!is_mate_pair(b"r0/1", b"r1/2"));
+        assert!(!is_mate_pair(b"r0/2", b"r0/1"));
+
+        assert!(is_mate_pair(b"r0 1:N:0:X", b"r0 2:N:0:X"));
+        assert!(is_mate_pair(b"r0/1 comment", b"r0/2\tcomment"));
+        assert!(!is_mate_pair(b"r0 1:N:0:X", b"r1 2:N:0:X"));
+    }
+}
@@ -31,13 +31,19 @@
 #[cfg(feature = "async")]
 mod r#async;
 
+mod deinterleave;
 pub mod fai;
 mod indexer;
-mod reader;
+mod quality_offset;
+pub mod reader;
 mod record;
+pub mod stats;
 mod writer;
 
-pub use self::{indexer::Indexer, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    deinterleave::deinterleave, indexer::Indexer, quality_offset::detect_quality_offset,
+    reader::Reader, record::Record, writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -0,0 +1,153 @@
+use std::io::{self, BufRead};
+
+use super::{Reader, Record};
+
+// The lowest character of Phred+64 encodings, i.e., Solexa Q-5.
+const MIN_PHRED_64_CHAR: u8 = b';';
+
+// The highest character of Phred+33 encodings up to Illumina 1.8+, i.e., Q41.
+const MAX_PHRED_33_CHAR: u8 = b'J';
+
+/// Detects the offset of the quality scores encoding (Phred+33 or Phred+64) from a sample of
+/// records.
+///
+/// Up to `sample_size` records are read, and the range of their quality score characters is used
+/// to infer the offset:
+///
+///   * A character lower than `;` can only be Phred+33, so the offset is 33.
+///   * Otherwise, a character higher than `J` is outside the range of common Phred+33 encodings,
+///     so the offset is 64.
+///   * Otherwise, the range fits both encodings, and this returns `None`.
+///
+/// This also returns `None` if the sample has no quality scores.
+///
+/// Note that Phred+33 data with unusually high quality scores (e.g., PacBio HiFi) and no scores
+/// lower than 26 can be misdetected as Phred+64.
+///
+/// # Errors
+///
+/// This returns an [`io::ErrorKind::InvalidData`] error if a quality score character is not
+/// printable ASCII (`!`..=`~`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fastq as fastq;
+///
+/// let data = b"@r0\nACGT\n+\nNDLS\n@r1\nACGT\n+\n#+5?\n";
+/// let mut reader = fastq::Reader::new(&data[..]);
+/// assert_eq!(fastq::detect_quality_offset(&mut reader, 100)?, Some(33));
+///
+/// let data = b"@r0\nACGT\n+\nhhSB\n";
+/// let mut reader = fastq::Reader::new(&data[..]);
+/// assert_eq!(fastq::detect_quality_offset(&mut reader, 100)?, Some(64));
+///
+/// let data = b"@r0\nACGT\n+\nIIII\n";
+/// let mut reader = fastq::Reader::new(&data[..]);
+/// assert!(fastq::detect_quality_offset(&mut reader, 100)?.is_none());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn detect_quality_offset<R>(
+    reader: &mut Reader<R>,
+    sample_size: usize,
+) -> io::Result<Option<u8>>
+where
+    R: BufRead,
+{
+    let mut record = Record::default();
+    let mut range: Option<(u8, u8)> = None;
+
+    for _ in 0..sample_size {
+        if reader.read_record(&mut record)? == 0 {
+            break;
+        }
+
+        for &c in record.quality_scores() {
+            if !c.is_ascii_graphic() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid quality score character: {:#04x}", c),
+                ));
+            }
+
+            range = Some(match range {
+                Some((min, max)) => (min.min(c), max.max(c)),
+                None => (c, c),
+            });
+        }
+    }
+
+    Ok(range.and_then(|(min, max)| infer_offset(min, max)))
+}
+
+fn infer_offset(min: u8, max: u8) -> Option<u8> {
+    if min < MIN_PHRED_64_CHAR {
+        Some(33)
+    } else if max > MAX_PHRED_33_CHAR {
+        Some(64)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_quality_offset() -> io::Result<()> {
+        // Illumina 1.8+
+        let data = b"@r0\nACGT\n+\n#-5?\n@r1\nACGT\n+\nJJJJ\n";
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(detect_quality_offset(&mut reader, 100)?, Some(33));
+
+        // Illumina 1.3+
+        let data = b"@r0\nACGT\n+\n@HPh\n@r1\nACGT\n+\nhhhh\n";
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(detect_quality_offset(&mut reader, 100)?, Some(64));
+
+        let data = b"@r0\nACGT\n+\nDEFG\n@r1\nACGT\n+\nIIII\n";
+        let mut reader = Reader::new(&data[..]);
+        assert!(detect_quality_offset(&mut reader, 100)?.is_none());
+
+        let data = b"";
+        let mut reader = Reader::new(&data[..]);
+        assert!(detect_quality_offset(&mut reader, 100)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_quality_offset_with_sample_size() -> io::Result<()> {
+        let data = b"@r0\nACGT\n+\nIIII\n@r1\nACGT\n+\n####\n";
+
+        let mut reader = Reader::new(&data[..]);
+        assert!(detect_quality_offset(&mut reader, 1)?.is_none());
+
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(detect_quality_offset(&mut reader, 2)?, Some(33));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_quality_offset_with_invalid_quality_scores() {
+        let data = b"@r0\nACGT\n+\nII I\n";
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(matches!(
+            detect_quality_offset(&mut reader, 100),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_infer_offset() {
+        assert_eq!(infer_offset(b'!', b'J'), Some(33));
+        assert_eq!(infer_offset(b':', b'h'), Some(33));
+        assert_eq!(infer_offset(b';', b'h'), Some(64));
+        assert_eq!(infer_offset(b'B', b'K'), Some(64));
+        assert_eq!(infer_offset(b';', b'J'), None);
+    }
+}
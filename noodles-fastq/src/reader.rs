@@ -1,6 +1,9 @@
+//! FASTQ reader and iterators.
+
+mod builder;
 mod records;
 
-pub use self::records::Records;
+pub use self::{builder::Builder, records::Records};
 
 use std::io::{self, BufRead, Read};
 
@@ -12,12 +15,27 @@ const CARRIAGE_RETURN: u8 = b'\r';
 /// A FASTQ reader.
 pub struct Reader<R> {
     inner: R,
+    strict_plus_line: bool,
 }
 
 impl<R> Reader<R>
 where
     R: BufRead,
 {
+    /// Creates a FASTQ reader builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let builder = fastq::Reader::builder(&data[..]);
+    /// let reader = builder.build();
+    /// ```
+    pub fn builder(inner: R) -> Builder<R> {
+        Builder::new(inner)
+    }
+
     /// Creates a FASTQ reader.
     ///
     /// # Examples
@@ -28,7 +46,7 @@ where
     /// let reader = fastq::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self::builder(inner).build()
     }
 
     /// Returns a reference to the underlying reader.
@@ -80,6 +98,9 @@ where
     ///
     /// The stream is expected to be at the start of a record.
     ///
+    /// If the reader was built with a strict plus line (see [`Builder::set_strict_plus_line`]), a
+    /// nonempty plus line must match the read name.
+    ///
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
@@ -101,7 +122,13 @@ where
     /// Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = read_record(&mut self.inner, record)?;
+
+        if self.strict_plus_line && n > 0 {
+            validate_plus_line(record)?;
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -151,6 +178,23 @@ where
     Ok(len)
 }
 
+fn validate_plus_line(record: &Record) -> io::Result<()> {
+    let description = record.description();
+
+    if description.is_empty() || description == record.name() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "plus line name mismatch: expected {:?}, got {:?}",
+                String::from_utf8_lossy(record.name()),
+                String::from_utf8_lossy(description),
+            ),
+        ))
+    }
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
@@ -248,6 +292,47 @@ dcba
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_strict_plus_line() -> io::Result<()> {
+        let data = b"\
+@r0
+ACGT
++r0
+NDLS
+@r1
+ACGT
++
+NDLS
+@r2
+ACGT
++r0
+NDLS
+";
+
+        let mut reader = Reader::builder(&data[..])
+            .set_strict_plus_line(true)
+            .build();
+
+        let mut record = Record::default();
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record.name(), b"r0");
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record.name(), b"r1");
+
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = Reader::new(&data[..]);
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 3);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = Vec::new();
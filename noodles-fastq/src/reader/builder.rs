@@ -0,0 +1,57 @@
+use std::io::BufRead;
+
+use super::Reader;
+
+/// A FASTQ reader builder.
+pub struct Builder<R> {
+    inner: R,
+    strict_plus_line: bool,
+}
+
+impl<R> Builder<R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            strict_plus_line: false,
+        }
+    }
+
+    /// Sets whether to validate the name on the plus line.
+    ///
+    /// The plus line (line 3) may repeat the read name. When set, reading a record with a
+    /// nonempty plus line that differs from the read name returns an
+    /// [`std::io::ErrorKind::InvalidData`] error.
+    ///
+    /// By default, the plus line is not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = [];
+    /// let builder = fastq::Reader::builder(&data[..]).set_strict_plus_line(true);
+    /// ```
+    pub fn set_strict_plus_line(mut self, strict_plus_line: bool) -> Self {
+        self.strict_plus_line = strict_plus_line;
+        self
+    }
+
+    /// Builds a FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = [];
+    /// let reader = fastq::Reader::builder(&data[..]).build();
+    /// ```
+    pub fn build(self) -> Reader<R> {
+        Reader {
+            inner: self.inner,
+            strict_plus_line: self.strict_plus_line,
+        }
+    }
+}
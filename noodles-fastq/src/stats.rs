@@ -0,0 +1,430 @@
+//! FASTQ record statistics.
+
+use std::{collections::BTreeMap, io};
+
+use super::Record;
+
+const QUALITY_SCORE_OFFSET: u8 = b'!';
+
+/// The fractions of bases at a read cycle.
+///
+/// Bases are case-insensitive. Any base that is not `A`, `C`, `G`, or `T` is counted as `N`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BaseComposition {
+    a: f64,
+    c: f64,
+    g: f64,
+    t: f64,
+    n: f64,
+}
+
+impl BaseComposition {
+    /// Returns the fraction of `A` bases.
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the fraction of `C` bases.
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+
+    /// Returns the fraction of `G` bases.
+    pub fn g(&self) -> f64 {
+        self.g
+    }
+
+    /// Returns the fraction of `T` bases.
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Returns the fraction of `N` (or any other) bases.
+    pub fn n(&self) -> f64 {
+        self.n
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Cycle {
+    read_count: u64,
+    quality_score_sum: u64,
+    // A, C, G, T, N
+    base_counts: [u64; 5],
+}
+
+/// Per-read-cycle statistics.
+///
+/// A read cycle is a 0-based position in a read. Reads of different lengths are allowed: a read
+/// only contributes to the cycles it covers.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::{self as fastq, stats::PerCycle};
+///
+/// let mut stats = PerCycle::default();
+/// stats.add(&fastq::Record::new("r0", "ACGT", "NDLS"))?;
+/// stats.add(&fastq::Record::new("r1", "AC", "II"))?;
+///
+/// assert_eq!(stats.len(), 4);
+/// assert_eq!(stats.read_counts(), [2, 2, 1, 1]);
+/// assert_eq!(stats.mean_quality_scores(), [42.5, 37.5, 43.0, 50.0]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PerCycle {
+    cycles: Vec<Cycle>,
+}
+
+impl PerCycle {
+    /// Returns the number of cycles, i.e., the length of the longest read.
+    pub fn len(&self) -> usize {
+        self.cycles.len()
+    }
+
+    /// Returns whether there are any cycles.
+    pub fn is_empty(&self) -> bool {
+        self.cycles.is_empty()
+    }
+
+    /// Adds a record.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the sequence and quality scores lengths differ or a quality score
+    /// is not a printable ASCII character (`!`-`~`). The statistics are unchanged on error.
+    pub fn add(&mut self, record: &Record) -> io::Result<()> {
+        let sequence = record.sequence();
+        let quality_scores = record.quality_scores();
+
+        if sequence.len() != quality_scores.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "sequence-quality scores length mismatch: expected {}, got {}",
+                    sequence.len(),
+                    quality_scores.len()
+                ),
+            ));
+        }
+
+        for &score in quality_scores {
+            decode_quality_score(score)?;
+        }
+
+        if sequence.len() > self.cycles.len() {
+            self.cycles.resize_with(sequence.len(), Cycle::default);
+        }
+
+        for ((cycle, &base), &score) in self.cycles.iter_mut().zip(sequence).zip(quality_scores) {
+            let score = score - QUALITY_SCORE_OFFSET;
+
+            cycle.read_count += 1;
+            cycle.quality_score_sum += u64::from(score);
+
+            let i = match base.to_ascii_uppercase() {
+                b'A' => 0,
+                b'C' => 1,
+                b'G' => 2,
+                b'T' => 3,
+                _ => 4,
+            };
+
+            cycle.base_counts[i] += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of reads that cover each cycle.
+    pub fn read_counts(&self) -> Vec<u64> {
+        self.cycles.iter().map(|cycle| cycle.read_count).collect()
+    }
+
+    /// Returns the mean quality score of each cycle.
+    ///
+    /// Quality scores are decoded as Phred+33. This is the same as the mean in FastQC's per base
+    /// sequence quality module when it is run with `--nogroup`.
+    pub fn mean_quality_scores(&self) -> Vec<f64> {
+        self.cycles
+            .iter()
+            .map(|cycle| cycle.quality_score_sum as f64 / cycle.read_count as f64)
+            .collect()
+    }
+
+    /// Returns the base composition of each cycle.
+    pub fn base_composition(&self) -> Vec<BaseComposition> {
+        self.cycles
+            .iter()
+            .map(|cycle| {
+                let n = cycle.read_count as f64;
+                let [a, c, g, t, other] = cycle.base_counts;
+
+                BaseComposition {
+                    a: a as f64 / n,
+                    c: c as f64 / n,
+                    g: g as f64 / n,
+                    t: t as f64 / n,
+                    n: other as f64 / n,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Calculates per-read-cycle statistics over a stream of records.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+///
+/// let data = b"@r0\nACGT\n+\nNDLS\n@r1\nAC\n+\nII\n";
+/// let mut reader = fastq::Reader::new(&data[..]);
+///
+/// let stats = fastq::stats::per_cycle(reader.records())?;
+/// assert_eq!(stats.len(), 4);
+/// assert_eq!(stats.base_composition()[0].a(), 1.0);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn per_cycle<I>(records: I) -> io::Result<PerCycle>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut stats = PerCycle::default();
+
+    for result in records {
+        let record = result?;
+        stats.add(&record)?;
+    }
+
+    Ok(stats)
+}
+
+/// Per-read GC content and length distributions.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::{self as fastq, stats::ReadGcAndLength};
+///
+/// let mut stats = ReadGcAndLength::default();
+/// stats.add(&fastq::Record::new("r0", "ACGT", "NDLS"));
+/// stats.add(&fastq::Record::new("r1", "GGCN", "NDLS"));
+///
+/// assert_eq!(stats.gc_histogram()[50], 1);
+/// assert_eq!(stats.gc_histogram()[100], 1);
+/// assert_eq!(stats.length_histogram().get(&4), Some(&2));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadGcAndLength {
+    gc_histogram: [u64; 101],
+    length_histogram: BTreeMap<usize, u64>,
+}
+
+impl ReadGcAndLength {
+    /// Adds a record.
+    pub fn add(&mut self, record: &Record) {
+        self.add_sequence(record.sequence());
+    }
+
+    /// Adds a read sequence.
+    ///
+    /// This can be used to add reads from other formats, e.g., the sequence of a BAM record.
+    pub fn add_sequence(&mut self, sequence: &[u8]) {
+        *self.length_histogram.entry(sequence.len()).or_insert(0) += 1;
+
+        let mut gc_count = 0;
+        let mut at_count = 0;
+
+        for base in sequence {
+            match base.to_ascii_uppercase() {
+                b'G' | b'C' => gc_count += 1,
+                b'A' | b'T' => at_count += 1,
+                _ => {}
+            }
+        }
+
+        let base_count = gc_count + at_count;
+
+        if base_count > 0 {
+            let gc_percent = (gc_count as f64 * 100.0 / base_count as f64).round() as usize;
+            self.gc_histogram[gc_percent] += 1;
+        }
+    }
+
+    /// Returns the number of reads at each GC content percentage (0-100).
+    ///
+    /// The GC content of a read is the fraction of `G` and `C` bases over its `A`, `C`, `G`, and
+    /// `T` bases, rounded to the nearest percent. Bases are case-insensitive. Other bases (e.g.,
+    /// `N`) are excluded from the denominator, and reads without any `A`, `C`, `G`, or `T` bases
+    /// are not counted.
+    pub fn gc_histogram(&self) -> &[u64] {
+        &self.gc_histogram
+    }
+
+    /// Returns the number of reads of each length.
+    ///
+    /// All reads are counted, including empty reads.
+    pub fn length_histogram(&self) -> &BTreeMap<usize, u64> {
+        &self.length_histogram
+    }
+
+    /// Returns the mean GC content percentage.
+    ///
+    /// This is NaN if no reads are counted in the GC histogram.
+    pub fn mean_gc_percent(&self) -> f64 {
+        let (sum, n) = self
+            .gc_histogram
+            .iter()
+            .enumerate()
+            .fold((0, 0), |(sum, n), (gc_percent, &count)| {
+                (sum + gc_percent as u64 * count, n + count)
+            });
+
+        sum as f64 / n as f64
+    }
+}
+
+impl Default for ReadGcAndLength {
+    fn default() -> Self {
+        Self {
+            gc_histogram: [0; 101],
+            length_histogram: BTreeMap::new(),
+        }
+    }
+}
+
+/// Calculates the per-read GC content and length distributions over a stream of records.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+///
+/// let data = b"@r0\nACGT\n+\nNDLS\n@r1\nAC\n+\nII\n";
+/// let mut reader = fastq::Reader::new(&data[..]);
+///
+/// let stats = fastq::stats::read_gc_and_length(reader.records())?;
+/// assert_eq!(stats.gc_histogram()[50], 2);
+/// assert_eq!(stats.length_histogram().len(), 2);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read_gc_and_length<I>(records: I) -> io::Result<ReadGcAndLength>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let mut stats = ReadGcAndLength::default();
+
+    for result in records {
+        let record = result?;
+        stats.add(&record);
+    }
+
+    Ok(stats)
+}
+
+fn decode_quality_score(n: u8) -> io::Result<u8> {
+    if (b'!'..=b'~').contains(&n) {
+        Ok(n - QUALITY_SCORE_OFFSET)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid quality score",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_per_cycle() -> io::Result<()> {
+        let records = vec![
+            Ok(Record::new("r0", "ACGT", "?5+!")),
+            Ok(Record::new("r1", "AGgN", "5?+?")),
+            Ok(Record::new("r2", "TC", "++")),
+        ];
+
+        let stats = per_cycle(records)?;
+
+        assert_eq!(stats.len(), 4);
+        assert_eq!(stats.read_counts(), [3, 3, 2, 2]);
+        assert_eq!(stats.mean_quality_scores(), [20.0, 20.0, 10.0, 15.0]);
+
+        let base_composition = stats.base_composition();
+        assert_eq!(base_composition[0].a(), 2.0 / 3.0);
+        assert_eq!(base_composition[0].t(), 1.0 / 3.0);
+        assert_eq!(base_composition[1].c(), 2.0 / 3.0);
+        assert_eq!(base_composition[2].g(), 1.0);
+        assert_eq!(base_composition[3].t(), 0.5);
+        assert_eq!(base_composition[3].n(), 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_gc_and_length() -> io::Result<()> {
+        let records = vec![
+            Ok(Record::new("r0", "ACGT", "NDLS")),
+            Ok(Record::new("r1", "GCgcNN", "NDLSND")),
+            Ok(Record::new("r2", "AAAC", "NDLS")),
+            Ok(Record::new("r3", "AAAAAAG", "NDLSNDL")),
+            Ok(Record::new("r4", "NN", "NN")),
+            Ok(Record::new("r5", "", "")),
+        ];
+
+        let stats = read_gc_and_length(records)?;
+
+        let gc_histogram = stats.gc_histogram();
+        assert_eq!(gc_histogram.len(), 101);
+        assert_eq!(gc_histogram.iter().sum::<u64>(), 4);
+        assert_eq!(gc_histogram[50], 1);
+        assert_eq!(gc_histogram[100], 1);
+        assert_eq!(gc_histogram[25], 1);
+        // 1 / 7 = 14.3%
+        assert_eq!(gc_histogram[14], 1);
+
+        assert_eq!(stats.mean_gc_percent(), 189.0 / 4.0);
+
+        let expected: BTreeMap<_, _> = [(0, 1), (2, 1), (4, 2), (6, 1), (7, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(stats.length_histogram(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_per_cycle_with_invalid_record() {
+        let records = vec![Ok(Record::new("r0", "ACGT", "NDL"))];
+        assert!(matches!(
+            per_cycle(records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = vec![Ok(Record::new("r0", "A", " "))];
+        assert!(matches!(
+            per_cycle(records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_per_cycle_add_with_invalid_quality_score() -> io::Result<()> {
+        let mut stats = PerCycle::default();
+        stats.add(&Record::new("r0", "AC", "NN"))?;
+
+        let expected = stats.clone();
+
+        assert!(matches!(
+            stats.add(&Record::new("r1", "ACGT", "NDL ")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert_eq!(stats, expected);
+
+        Ok(())
+    }
+}
@@ -57,6 +57,48 @@ where
     }
 }
 
+#[cfg(feature = "gzip")]
+impl<W> Writer<flate2::write::GzEncoder<W>>
+where
+    W: Write,
+{
+    /// Creates a FASTQ writer that compresses its output as plain gzip.
+    ///
+    /// The output is a single gzip member, not BGZF. This requires the `gzip` feature to be
+    /// enabled. Call [`Self::finish`] to write the gzip trailer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let writer = fastq::Writer::new_gzip(Vec::new());
+    /// ```
+    pub fn new_gzip(inner: W) -> Self {
+        use flate2::{write::GzEncoder, Compression};
+        Self::new(GzEncoder::new(inner, Compression::default()))
+    }
+
+    /// Finishes the gzip stream and returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let mut writer = fastq::Writer::new_gzip(Vec::new());
+    /// let record = fastq::Record::new("r0", "ACGT", "NDLS");
+    /// writer.write_record(&record)?;
+    ///
+    /// let data = writer.finish()?;
+    /// assert_eq!(&data[..2], [0x1f, 0x8b]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<W> {
+        self.inner.finish()
+    }
+}
+
 fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
 where
     W: Write,
@@ -100,4 +142,25 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_new_gzip() -> io::Result<()> {
+        use std::io::Read;
+
+        use flate2::read::GzDecoder;
+
+        let mut writer = Writer::new_gzip(Vec::new());
+        let record = Record::new("r0", "ACGT", "NDLS");
+        writer.write_record(&record)?;
+        writer.write_record(&record)?;
+        let data = writer.finish()?;
+
+        let mut decoder = GzDecoder::new(&data[..]);
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"@r0\nACGT\n+\nNDLS\n@r0\nACGT\n+\nNDLS\n");
+
+        Ok(())
+    }
 }
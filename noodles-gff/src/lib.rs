@@ -33,8 +33,12 @@
 
 pub mod directive;
 pub mod line;
+mod overlaps;
 pub mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{directive::Directive, line::Line, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    directive::Directive, line::Line, overlaps::overlaps, reader::Reader, record::Record,
+    writer::Writer,
+};
@@ -0,0 +1,114 @@
+use std::ops::RangeInclusive;
+
+use noodles_core::Position;
+
+use super::Record;
+
+/// Returns the features that overlap a reference interval with their overlap lengths.
+///
+/// This is typically used to annotate an alignment record, where the interval is its alignment
+/// span. A feature overlaps when it is on the given reference sequence and shares at least one
+/// position with the interval. The overlap length is the number of shared positions.
+///
+/// The given features are scanned linearly, so they can be any set of candidates, e.g., all
+/// records of a GFF file or the records of a region.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_gff as gff;
+///
+/// let features = [
+///     gff::Record::builder()
+///         .set_reference_sequence_name(String::from("sq0"))
+///         .set_type(String::from("exon"))
+///         .set_start(Position::try_from(1)?)
+///         .set_end(Position::try_from(100)?)
+///         .build(),
+///     gff::Record::builder()
+///         .set_reference_sequence_name(String::from("sq0"))
+///         .set_type(String::from("exon"))
+///         .set_start(Position::try_from(201)?)
+///         .set_end(Position::try_from(300)?)
+///         .build(),
+/// ];
+///
+/// let start = Position::try_from(91)?;
+/// let end = Position::try_from(205)?;
+/// let overlaps = gff::overlaps(&features, "sq0", start..=end);
+///
+/// assert_eq!(overlaps, [(&features[0], 10), (&features[1], 5)]);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn overlaps<'r, I>(
+    features: I,
+    reference_sequence_name: &str,
+    interval: RangeInclusive<Position>,
+) -> Vec<(&'r Record, usize)>
+where
+    I: IntoIterator<Item = &'r Record>,
+{
+    features
+        .into_iter()
+        .filter(|feature| feature.reference_sequence_name() == reference_sequence_name)
+        .filter_map(|feature| {
+            let start = feature.start().max(*interval.start());
+            let end = feature.end().min(*interval.end());
+
+            if start <= end {
+                let len = usize::from(end) - usize::from(start) + 1;
+                Some((feature, len))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(
+        reference_sequence_name: &str,
+        start: usize,
+        end: usize,
+    ) -> Result<Record, noodles_core::position::TryFromIntError> {
+        Ok(Record::builder()
+            .set_reference_sequence_name(reference_sequence_name.into())
+            .set_type(String::from("exon"))
+            .set_start(Position::try_from(start)?)
+            .set_end(Position::try_from(end)?)
+            .build())
+    }
+
+    #[test]
+    fn test_overlaps() -> Result<(), noodles_core::position::TryFromIntError> {
+        let features = [
+            build_record("sq0", 1, 100)?,
+            build_record("sq0", 201, 300)?,
+            build_record("sq1", 1, 300)?,
+        ];
+
+        // spans both exons
+        let start = Position::try_from(91)?;
+        let end = Position::try_from(210)?;
+        assert_eq!(
+            overlaps(&features, "sq0", start..=end),
+            [(&features[0], 10), (&features[1], 10)]
+        );
+
+        // in the intron
+        let start = Position::try_from(101)?;
+        let end = Position::try_from(200)?;
+        assert!(overlaps(&features, "sq0", start..=end).is_empty());
+
+        // shares one position
+        let start = Position::try_from(100)?;
+        let end = Position::try_from(150)?;
+        assert_eq!(overlaps(&features, "sq0", start..=end), [(&features[0], 1)]);
+
+        Ok(())
+    }
+}
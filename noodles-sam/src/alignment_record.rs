@@ -47,7 +47,10 @@ pub trait AlignmentRecord {
     /// ```
     fn alignment_end(&self) -> Option<Position> {
         self.alignment_start().and_then(|alignment_start| {
-            let end = usize::from(alignment_start) + self.alignment_span() - 1;
+            let end = usize::from(alignment_start)
+                .checked_add(self.alignment_span())?
+                .checked_sub(1)?;
+
             Position::new(end)
         })
     }
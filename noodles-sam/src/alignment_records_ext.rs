@@ -0,0 +1,329 @@
+//! Alignment record iterator adapters.
+
+mod consensus;
+mod min_aligned_length;
+mod original_orientation;
+mod read_group_usage;
+mod representative_alignment;
+mod soft_clip_stats;
+mod subsample;
+
+pub use self::{
+    consensus::{Consensus, ConsensusColumn},
+    min_aligned_length::MinAlignedLength,
+    original_orientation::{OrientedRead, OriginalOrientation},
+    read_group_usage::ReadGroupUsage,
+    representative_alignment::{MissingPrimaryPolicy, RepresentativeAlignment},
+    soft_clip_stats::SoftClipStats,
+    subsample::Subsample,
+};
+
+use std::{io, ops::RangeInclusive};
+
+use noodles_core::Position;
+
+use super::{AlignmentRecord, Header};
+
+/// An extension trait for iterators over alignment records.
+///
+/// This is implemented for all iterators with items of `io::Result<R>`, where `R` is an
+/// [`AlignmentRecord`], e.g., the record iterators of SAM, BAM, and CRAM readers.
+pub trait AlignmentRecordsExt<R>: Iterator<Item = io::Result<R>> + Sized
+where
+    R: AlignmentRecord,
+{
+    /// Returns an iterator that skips records with fewer aligned bases than the given length.
+    ///
+    /// The aligned length is the sum of the lengths of the CIGAR alignment match (`M`), sequence
+    /// match (`=`), and sequence mismatch (`X`) operations (see
+    /// [`crate::record::Cigar::aligned_len`]). Clips and insertions are not counted. Errors are
+    /// passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, AlignmentRecordsExt};
+    ///
+    /// let records: Vec<io::Result<sam::Record>> = vec![
+    ///     Ok(sam::Record::builder()
+    ///         .set_position(Position::try_from(1)?)
+    ///         .set_cigar("20M80S".parse()?)
+    ///         .build()),
+    ///     Ok(sam::Record::builder()
+    ///         .set_position(Position::try_from(1)?)
+    ///         .set_cigar("100M".parse()?)
+    ///         .build()),
+    /// ];
+    ///
+    /// let records: Vec<_> = records
+    ///     .into_iter()
+    ///     .min_aligned_length(50)
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(records.len(), 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn min_aligned_length(self, min_len: usize) -> MinAlignedLength<Self> {
+        MinAlignedLength::new(self, min_len)
+    }
+
+    /// Returns an iterator that restores reverse complemented records to their original orientation.
+    ///
+    /// SAM records store the sequence of a read aligned to the reverse strand as the reverse
+    /// complement of the sequenced read. Each record is wrapped in an [`OrientedRead`], which
+    /// has the sequence, quality scores, and CIGAR operations in the orientation the read was
+    /// sequenced, i.e., reversed (and complemented) for a record with the reverse complemented
+    /// flag set. The record itself is not changed. Errors are passed through.
+    ///
+    /// An [`OrientedRead`] is not an alignment record and cannot be written as one. Use
+    /// [`OrientedRead::into_record`] to get the unchanged record back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, record::Flags, AlignmentRecord, AlignmentRecordsExt};
+    ///
+    /// let records: Vec<io::Result<sam::Record>> = vec![Ok(sam::Record::builder()
+    ///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+    ///     .set_cigar("1S3M".parse()?)
+    ///     .set_sequence("ACGG".parse()?)
+    ///     .set_quality_scores("NDLS".parse()?)
+    ///     .build())];
+    ///
+    /// let reads: Vec<_> = records
+    ///     .into_iter()
+    ///     .original_orientation()
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(reads[0].cigar().to_string(), "3M1S");
+    /// assert_eq!(reads[0].sequence().to_string(), "CCGT");
+    /// assert_eq!(reads[0].quality_scores().to_string(), "SLDN");
+    ///
+    /// assert_eq!(reads[0].record().sequence().to_string(), "ACGG");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn original_orientation(self) -> OriginalOrientation<Self> {
+        OriginalOrientation::new(self)
+    }
+
+    /// Returns an iterator that keeps a deterministic fraction of records.
+    ///
+    /// Each record is selected by a hash of its read name and the given seed, which maps the name
+    /// to a value in [0, 1). A record is kept if the value is less than `fraction`. Because the
+    /// selection depends only on the read name, all records of a template (e.g., both mates of a
+    /// pair) are kept or dropped together, and the same seed always selects the same reads. The
+    /// hash is the same as `samtools view --subsample`. Errors are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, AlignmentRecordsExt};
+    ///
+    /// let records: Vec<io::Result<sam::Record>> = (0..1000)
+    ///     .map(|i| {
+    ///         Ok(sam::Record::builder()
+    ///             .set_read_name(format!("r{}", i).parse().unwrap())
+    ///             .build())
+    ///     })
+    ///     .collect();
+    ///
+    /// let n = records.into_iter().subsample(0.5, 0).count();
+    /// assert!(n > 400 && n < 600);
+    /// ```
+    fn subsample(self, fraction: f64, seed: u32) -> Subsample<Self> {
+        Subsample::new(self, fraction, seed)
+    }
+
+    /// Returns an iterator that yields one representative alignment per segment.
+    ///
+    /// The representative alignment of a segment is its primary alignment, i.e., the record that
+    /// is neither a secondary nor a supplementary alignment. Secondary alignments are always
+    /// dropped. Supplementary alignments of a segment without a primary alignment are handled by
+    /// the given [`MissingPrimaryPolicy`].
+    ///
+    /// Segments are identified by read name and segment position (first and last segment flags).
+    /// With [`MissingPrimaryPolicy::FirstSupplementary`], the input must be grouped by read name.
+    /// Errors are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{
+    ///     self as sam, alignment_records_ext::MissingPrimaryPolicy, record::Flags,
+    ///     AlignmentRecord, AlignmentRecordsExt,
+    /// };
+    ///
+    /// let records: Vec<io::Result<sam::Record>> = [
+    ///     Flags::empty(),
+    ///     Flags::SUPPLEMENTARY,
+    ///     Flags::SUPPLEMENTARY | Flags::REVERSE_COMPLEMENTED,
+    /// ]
+    /// .into_iter()
+    /// .map(|flags| {
+    ///     Ok(sam::Record::builder()
+    ///         .set_read_name("r0".parse().unwrap())
+    ///         .set_flags(flags)
+    ///         .build())
+    /// })
+    /// .collect();
+    ///
+    /// let records: Vec<_> = records
+    ///     .into_iter()
+    ///     .representative_alignment(MissingPrimaryPolicy::Skip)
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(records[0].flags(), Flags::empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn representative_alignment(
+        self,
+        policy: MissingPrimaryPolicy,
+    ) -> RepresentativeAlignment<Self, R> {
+        RepresentativeAlignment::new(self, policy)
+    }
+
+    /// Consumes the records and collects the read groups used by them.
+    ///
+    /// The read group of a record is the value of its read group (`RG`) data field. Each used read
+    /// group ID is checked against the read groups declared in the given header, and the result
+    /// lists the declared-and-used and used-but-undeclared read group IDs.
+    ///
+    /// # Errors
+    ///
+    /// This returns the first error of the underlying iterator or an error if a read group value
+    /// is not a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::ReadGroup,
+    ///     record::data::{field::{Tag, Value}, Field},
+    ///     AlignmentRecordsExt,
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_read_group(ReadGroup::new("rg0"))
+    ///     .build();
+    ///
+    /// let records: Vec<io::Result<sam::Record>> = ["rg0", "rg1"]
+    ///     .into_iter()
+    ///     .map(|id| {
+    ///         let field = Field::new(Tag::ReadGroup, Value::String(id.into()));
+    ///         let data = vec![field].try_into().unwrap();
+    ///         Ok(sam::Record::builder().set_data(data).build())
+    ///     })
+    ///     .collect();
+    ///
+    /// let usage = records.into_iter().read_group_usage(&header)?;
+    ///
+    /// assert_eq!(usage.used(), ["rg0"]);
+    /// assert_eq!(usage.undeclared(), ["rg1"]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn read_group_usage(self, header: &Header) -> io::Result<ReadGroupUsage> {
+        ReadGroupUsage::collect(header, self)
+    }
+
+    /// Consumes the records and builds a majority consensus over the given reference interval.
+    ///
+    /// Each aligned base or deletion of a mapped record votes for its reference position, and the
+    /// most common base is the consensus base. Insertions are anchored to the reference position
+    /// before them and are included in the consensus when most records spanning the junction carry
+    /// the same insertion. Ties are broken deterministically.
+    ///
+    /// Records without a sequence are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This returns the first error of the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::Flags, AlignmentRecordsExt};
+    ///
+    /// let records: Vec<io::Result<sam::Record>> = ["ACGT", "ACGT", "AGGT"]
+    ///     .into_iter()
+    ///     .map(|sequence| {
+    ///         Ok(sam::Record::builder()
+    ///             .set_flags(Flags::empty())
+    ///             .set_position(Position::MIN)
+    ///             .set_cigar("4M".parse().unwrap())
+    ///             .set_sequence(sequence.parse().unwrap())
+    ///             .build())
+    ///     })
+    ///     .collect();
+    ///
+    /// let start = Position::try_from(1)?;
+    /// let end = Position::try_from(4)?;
+    /// let consensus = records.into_iter().consensus(start..=end)?;
+    ///
+    /// assert_eq!(consensus.sequence().to_string(), "ACGT");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn consensus(self, interval: RangeInclusive<Position>) -> io::Result<Consensus> {
+        Consensus::collect(interval, self)
+    }
+
+    /// Consumes the records and collects statistics of their 3' soft clips.
+    ///
+    /// This can be used to detect adapter contamination, where a read is longer than its insert
+    /// and the adapter sequence it reads through is soft clipped by the aligner. A read is
+    /// considered clipped if its 3' soft clip is at least `min_len` bases. If an adapter sequence
+    /// is given, e.g., `AGATCGGAAGAGC` for Illumina TruSeq, the clipped bases of each clipped read
+    /// are also compared to the start of the adapter.
+    ///
+    /// Unmapped, secondary, and supplementary records are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This returns the first error of the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::Flags, AlignmentRecordsExt};
+    ///
+    /// let records: Vec<io::Result<sam::Record>> = [("8M", "ACGTACGT"), ("4M4S", "ACGTAGAT")]
+    ///     .into_iter()
+    ///     .map(|(cigar, sequence)| {
+    ///         Ok(sam::Record::builder()
+    ///             .set_flags(Flags::empty())
+    ///             .set_position(Position::MIN)
+    ///             .set_cigar(cigar.parse().unwrap())
+    ///             .set_sequence(sequence.parse().unwrap())
+    ///             .build())
+    ///     })
+    ///     .collect();
+    ///
+    /// let adapter = b"AGATCGGAAGAGC";
+    /// let stats = records.into_iter().soft_clip_stats(3, Some(&adapter[..]))?;
+    ///
+    /// assert_eq!(stats.clipped_fraction(), 0.5);
+    /// assert_eq!(stats.adapter_count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn soft_clip_stats(self, min_len: usize, adapter: Option<&[u8]>) -> io::Result<SoftClipStats> {
+        SoftClipStats::collect(self, min_len, adapter)
+    }
+}
+
+impl<I, R> AlignmentRecordsExt<R> for I
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+}
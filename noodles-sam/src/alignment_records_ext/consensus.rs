@@ -0,0 +1,340 @@
+use std::{collections::BTreeMap, io, ops::RangeInclusive};
+
+use noodles_core::Position;
+
+use crate::{
+    record::{cigar::op::Kind, sequence::Base, Sequence},
+    AlignmentRecord,
+};
+
+/// A consensus column at a reference position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsensusColumn {
+    position: Position,
+    base: Option<Base>,
+    depth: usize,
+    support: usize,
+    insertion: Vec<Base>,
+}
+
+impl ConsensusColumn {
+    /// Returns the reference position of the column.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the majority base.
+    ///
+    /// This is `None` if no record covers the position or if a deletion is the majority.
+    pub fn base(&self) -> Option<Base> {
+        self.base
+    }
+
+    /// Returns the number of records covering the position, including deletions.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the fraction of covering records that agree with the majority.
+    ///
+    /// This is 0 if no record covers the position.
+    pub fn agreement(&self) -> f64 {
+        if self.depth == 0 {
+            0.0
+        } else {
+            self.support as f64 / self.depth as f64
+        }
+    }
+
+    /// Returns the majority inserted bases between this position and the next.
+    ///
+    /// This is empty if most records spanning both positions do not have an insertion.
+    pub fn insertion(&self) -> &[Base] {
+        &self.insertion
+    }
+}
+
+/// A majority consensus of alignment records over a reference interval.
+///
+/// This is created by calling [`super::AlignmentRecordsExt::consensus`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Consensus {
+    columns: Vec<ConsensusColumn>,
+}
+
+#[derive(Default)]
+struct Votes {
+    bases: BTreeMap<Option<u8>, usize>,
+    insertions: BTreeMap<Vec<u8>, usize>,
+    spanning_count: usize,
+}
+
+impl Votes {
+    fn add_base(&mut self, base: Option<u8>) {
+        *self.bases.entry(base).or_default() += 1;
+    }
+}
+
+impl Consensus {
+    pub(super) fn collect<I, R>(interval: RangeInclusive<Position>, records: I) -> io::Result<Self>
+    where
+        I: Iterator<Item = io::Result<R>>,
+        R: AlignmentRecord,
+    {
+        let start = usize::from(*interval.start());
+        let end = usize::from(*interval.end());
+
+        let len = (end + 1).saturating_sub(start);
+        let mut votes: Vec<Votes> = (0..len).map(|_| Votes::default()).collect();
+
+        for result in records {
+            let record = result?;
+            add_record(&mut votes, start, &record)?;
+        }
+
+        let columns = votes
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let position = Position::new(start + i).expect("invalid position");
+                build_column(position, v)
+            })
+            .collect();
+
+        Ok(Self { columns })
+    }
+
+    /// Returns the columns of the consensus, one for each reference position in the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment_records_ext::Consensus;
+    /// let consensus = Consensus::default();
+    /// assert!(consensus.columns().is_empty());
+    /// ```
+    pub fn columns(&self) -> &[ConsensusColumn] {
+        &self.columns
+    }
+
+    /// Returns the consensus sequence.
+    ///
+    /// This is the concatenation of the majority base and inserted bases of each column.
+    /// Positions without a majority base, i.e., uncovered or deleted positions, are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment_records_ext::Consensus;
+    /// let consensus = Consensus::default();
+    /// assert!(consensus.sequence().is_empty());
+    /// ```
+    pub fn sequence(&self) -> Sequence {
+        let mut bases = Vec::new();
+
+        for column in &self.columns {
+            bases.extend(column.base());
+            bases.extend_from_slice(column.insertion());
+        }
+
+        Sequence::from(bases)
+    }
+}
+
+fn add_record<R>(votes: &mut [Votes], start: usize, record: &R) -> io::Result<()>
+where
+    R: AlignmentRecord,
+{
+    let alignment_start = match record.alignment_start() {
+        Some(position) => usize::from(position),
+        None => return Ok(()),
+    };
+
+    let sequence = record.sequence().as_ref();
+
+    if sequence.is_empty() || record.flags().is_unmapped() {
+        return Ok(());
+    }
+
+    let mut reference_position = alignment_start;
+    let mut read_position = 0;
+
+    for op in record.cigar().iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                for (i, base) in sequence.iter().skip(read_position).take(len).enumerate() {
+                    if let Some(v) = get(votes, start, reference_position + i) {
+                        v.add_base(Some(u8::from(*base)));
+                    }
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Insertion => {
+                let bases = sequence
+                    .iter()
+                    .skip(read_position)
+                    .take(len)
+                    .map(|&b| u8::from(b))
+                    .collect();
+
+                // An insertion is anchored to the reference position before it.
+                if let Some(v) = reference_position
+                    .checked_sub(1)
+                    .and_then(|p| get(votes, start, p))
+                {
+                    *v.insertions.entry(bases).or_default() += 1;
+                }
+
+                read_position += len;
+            }
+            Kind::Deletion => {
+                for i in 0..len {
+                    if let Some(v) = get(votes, start, reference_position + i) {
+                        v.add_base(None);
+                    }
+                }
+
+                reference_position += len;
+            }
+            Kind::Skip => reference_position += len,
+            Kind::SoftClip => read_position += len,
+            Kind::HardClip | Kind::Pad => {}
+            Kind::Back => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "unsupported CIGAR operation: B",
+                ))
+            }
+        }
+    }
+
+    // The record spans the junction between each pair of adjacent aligned reference positions.
+    let alignment_end = reference_position;
+
+    for position in alignment_start..alignment_end.saturating_sub(1) {
+        if let Some(v) = get(votes, start, position) {
+            v.spanning_count += 1;
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the votes of the given reference position, if it is in the interval.
+fn get(votes: &mut [Votes], start: usize, position: usize) -> Option<&mut Votes> {
+    position.checked_sub(start).and_then(|i| votes.get_mut(i))
+}
+
+fn build_column(position: Position, votes: Votes) -> ConsensusColumn {
+    let depth = votes.bases.values().sum();
+
+    let (base, support) = majority(votes.bases).unwrap_or((None, 0));
+    let base = base.map(|b| Base::try_from(b).expect("invalid base"));
+
+    let insertion_count: usize = votes.insertions.values().sum();
+    let no_insertion_count = votes.spanning_count.saturating_sub(insertion_count);
+
+    let insertion = match majority(votes.insertions) {
+        Some((bases, count)) if count > no_insertion_count => bases
+            .into_iter()
+            .map(|b| Base::try_from(b).expect("invalid base"))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    ConsensusColumn {
+        position,
+        base,
+        depth,
+        support,
+        insertion,
+    }
+}
+
+// Returns the key with the highest count. Ties are broken by the lowest key.
+fn majority<K>(counts: BTreeMap<K, usize>) -> Option<(K, usize)> {
+    let mut best: Option<(K, usize)> = None;
+
+    for (key, count) in counts {
+        match best {
+            Some((_, best_count)) if count <= best_count => {}
+            _ => best = Some((key, count)),
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{Flags, Record};
+
+    #[test]
+    fn test_collect() -> Result<(), Box<dyn std::error::Error>> {
+        let records: Vec<io::Result<Record>> = vec![
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::try_from(1)?)
+                .set_cigar("4M2I4M".parse()?)
+                .set_sequence("ACGTTTACGT".parse()?)
+                .build()),
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::try_from(1)?)
+                .set_cigar("4M2I4M".parse()?)
+                .set_sequence("ACGTTTACGT".parse()?)
+                .build()),
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::try_from(2)?)
+                .set_cigar("3M2I3M".parse()?)
+                .set_sequence("CGTTTACG".parse()?)
+                .build()),
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::try_from(1)?)
+                .set_cigar("8M".parse()?)
+                .set_sequence("ACCTACGT".parse()?)
+                .build()),
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::try_from(3)?)
+                .set_cigar("2M1D3M".parse()?)
+                .set_sequence("GTCGT".parse()?)
+                .build()),
+        ];
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(9)?;
+        let consensus = Consensus::collect(start..=end, records.into_iter())?;
+
+        let columns = consensus.columns();
+        assert_eq!(columns.len(), 9);
+
+        // 3: 4 G, 1 C
+        assert_eq!(columns[2].base(), Some(Base::G));
+        assert_eq!(columns[2].depth(), 5);
+        assert_eq!(columns[2].agreement(), 0.8);
+
+        // 4: an insertion supported by 3 of 5 spanning records
+        assert_eq!(columns[3].insertion(), [Base::T, Base::T]);
+
+        // 5: 4 A, 1 deletion
+        assert_eq!(columns[4].base(), Some(Base::A));
+        assert_eq!(columns[4].depth(), 5);
+
+        // 9: uncovered
+        assert_eq!(columns[8].base(), None);
+        assert_eq!(columns[8].depth(), 0);
+        assert_eq!(columns[8].agreement(), 0.0);
+
+        assert_eq!(consensus.sequence().to_string(), "ACGTTTACGT");
+
+        Ok(())
+    }
+}
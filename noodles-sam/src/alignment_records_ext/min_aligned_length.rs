@@ -0,0 +1,74 @@
+use std::io;
+
+use crate::AlignmentRecord;
+
+/// An iterator that skips alignment records with fewer aligned bases than a minimum length.
+///
+/// This is created by calling [`super::AlignmentRecordsExt::min_aligned_length`].
+pub struct MinAlignedLength<I> {
+    inner: I,
+    min_len: usize,
+}
+
+impl<I> MinAlignedLength<I> {
+    pub(super) fn new(inner: I, min_len: usize) -> Self {
+        Self { inner, min_len }
+    }
+}
+
+impl<I, R> Iterator for MinAlignedLength<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next()? {
+                Ok(record) => {
+                    if record.cigar().aligned_len() >= self.min_len {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Record;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let records = vec![
+            Ok(Record::builder().set_cigar("20M80S".parse()?).build()),
+            Ok(Record::builder().set_cigar("100M".parse()?).build()),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Ok(Record::builder().set_cigar("25=25X".parse()?).build()),
+            Ok(Record::default()),
+        ];
+
+        let mut iter = MinAlignedLength::new(records.into_iter(), 50);
+
+        assert_eq!(
+            iter.next()
+                .transpose()?
+                .map(|record| record.cigar().to_string()),
+            Some(String::from("100M"))
+        );
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert_eq!(
+            iter.next()
+                .transpose()?
+                .map(|record| record.cigar().to_string()),
+            Some(String::from("25=25X"))
+        );
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use std::io;
+
+use crate::{
+    record::{Cigar, QualityScores, Sequence},
+    AlignmentRecord,
+};
+
+/// An iterator that restores reverse complemented records to their original orientation.
+///
+/// This is created by calling [`super::AlignmentRecordsExt::original_orientation`].
+pub struct OriginalOrientation<I> {
+    inner: I,
+}
+
+impl<I> OriginalOrientation<I> {
+    pub(super) fn new(inner: I) -> Self {
+        Self { inner }
+    }
+}
+
+impl<I, R> Iterator for OriginalOrientation<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<OrientedRead<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|result| result.map(OrientedRead::new))
+    }
+}
+
+/// A read in the orientation it was sequenced.
+///
+/// This is created by [`OriginalOrientation`]. For a record with the reverse complemented flag
+/// set, the sequence of the read is the reverse complement of the sequence of the record, and its
+/// quality scores and CIGAR operations are reversed. Otherwise, they are the same as the record's.
+///
+/// The read is **not** an alignment record. Its sequence, quality scores, and CIGAR do not agree
+/// with the position and flags of the record, so they must not be used to locate bases on the
+/// reference sequence or be written as an alignment. The record, as it was read, is available with
+/// [`Self::record`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrientedRead<R> {
+    record: R,
+    sequence: Sequence,
+    quality_scores: QualityScores,
+    cigar: Cigar,
+}
+
+impl<R> OrientedRead<R>
+where
+    R: AlignmentRecord,
+{
+    fn new(record: R) -> Self {
+        let mut sequence = record.sequence().as_ref().to_vec();
+        let mut quality_scores = record.quality_scores().as_ref().to_vec();
+        let mut ops = record.cigar().as_ref().to_vec();
+
+        if record.flags().is_reverse_complemented() {
+            sequence.reverse();
+
+            for base in &mut sequence {
+                *base = base.complement();
+            }
+
+            quality_scores.reverse();
+            ops.reverse();
+        }
+
+        Self {
+            record,
+            sequence: Sequence::from(sequence),
+            quality_scores: QualityScores::from(quality_scores),
+            cigar: Cigar::from(ops),
+        }
+    }
+}
+
+impl<R> OrientedRead<R> {
+    /// Returns the alignment record as it was read.
+    pub fn record(&self) -> &R {
+        &self.record
+    }
+
+    /// Returns the alignment record as it was read, consuming the read.
+    pub fn into_record(self) -> R {
+        self.record
+    }
+
+    /// Returns the sequence in the orientation the read was sequenced.
+    pub fn sequence(&self) -> &Sequence {
+        &self.sequence
+    }
+
+    /// Returns the quality scores in the orientation the read was sequenced.
+    pub fn quality_scores(&self) -> &QualityScores {
+        &self.quality_scores
+    }
+
+    /// Returns the CIGAR operations in the orientation the read was sequenced.
+    pub fn cigar(&self) -> &Cigar {
+        &self.cigar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::Flags, Record};
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let records = vec![
+            Ok(Record::builder()
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_cigar("2S3M1I".parse()?)
+                .set_sequence("AACGTN".parse()?)
+                .set_quality_scores("ABCDEF".parse()?)
+                .build()),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_cigar("2S3M1I".parse()?)
+                .set_sequence("AACGTN".parse()?)
+                .set_quality_scores("ABCDEF".parse()?)
+                .build()),
+        ];
+
+        let mut iter = OriginalOrientation::new(records.into_iter());
+
+        let read = iter.next().transpose()?.expect("missing record");
+        assert_eq!(read.cigar().to_string(), "1I3M2S");
+        assert_eq!(read.sequence().to_string(), "NACGTT");
+        assert_eq!(read.quality_scores().to_string(), "FEDCBA");
+
+        let record = read.record();
+        assert_eq!(record.flags(), Flags::REVERSE_COMPLEMENTED);
+        assert_eq!(record.cigar().to_string(), "2S3M1I");
+        assert_eq!(record.sequence().to_string(), "AACGTN");
+        assert_eq!(record.quality_scores().to_string(), "ABCDEF");
+
+        assert!(matches!(iter.next(), Some(Err(_))));
+
+        let read = iter.next().transpose()?.expect("missing record");
+        assert_eq!(read.cigar().to_string(), "2S3M1I");
+        assert_eq!(read.sequence().to_string(), "AACGTN");
+        assert_eq!(read.quality_scores().to_string(), "ABCDEF");
+
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+}
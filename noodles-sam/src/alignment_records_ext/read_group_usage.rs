@@ -0,0 +1,124 @@
+use std::io;
+
+use crate::{record::data::field::Tag, AlignmentRecord, Header};
+
+/// The read groups used by a set of alignment records.
+///
+/// This is created by calling [`super::AlignmentRecordsExt::read_group_usage`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReadGroupUsage {
+    used: Vec<String>,
+    undeclared: Vec<String>,
+}
+
+impl ReadGroupUsage {
+    pub(super) fn collect<I, R>(header: &Header, records: I) -> io::Result<Self>
+    where
+        I: Iterator<Item = io::Result<R>>,
+        R: AlignmentRecord,
+    {
+        let read_groups = header.read_groups();
+        let mut usage = Self::default();
+
+        for result in records {
+            let record = result?;
+
+            let id = match record.data().get(Tag::ReadGroup) {
+                Some(field) => field.value().as_str().ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid read group value")
+                })?,
+                None => continue,
+            };
+
+            let ids = if read_groups.contains_key(id) {
+                &mut usage.used
+            } else {
+                &mut usage.undeclared
+            };
+
+            // Headers typically declare few read groups, so a linear search is sufficient.
+            if !ids.iter().any(|i| i == id) {
+                ids.push(id.into());
+            }
+        }
+
+        Ok(usage)
+    }
+
+    /// Returns the IDs of read groups that are declared in the header and used by records.
+    ///
+    /// The IDs are in the order of their first use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment_records_ext::ReadGroupUsage;
+    /// let usage = ReadGroupUsage::default();
+    /// assert!(usage.used().is_empty());
+    /// ```
+    pub fn used(&self) -> &[String] {
+        &self.used
+    }
+
+    /// Returns the IDs of read groups that are used by records but not declared in the header.
+    ///
+    /// The IDs are in the order of their first use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment_records_ext::ReadGroupUsage;
+    /// let usage = ReadGroupUsage::default();
+    /// assert!(usage.undeclared().is_empty());
+    /// ```
+    pub fn undeclared(&self) -> &[String] {
+        &self.undeclared
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::ReadGroup,
+        record::data::{field::Value, Field},
+        Record,
+    };
+
+    #[test]
+    fn test_collect() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_read_group(ReadGroup::new("rg0"))
+            .add_read_group(ReadGroup::new("rg1"))
+            .add_read_group(ReadGroup::new("rg2"))
+            .build();
+
+        let build_record =
+            |read_group: Option<&str>| -> Result<Record, Box<dyn std::error::Error>> {
+                let mut builder = Record::builder();
+
+                if let Some(id) = read_group {
+                    let field = Field::new(Tag::ReadGroup, Value::String(id.into()));
+                    builder = builder.set_data(vec![field].try_into()?);
+                }
+
+                Ok(builder.build())
+            };
+
+        let records = vec![
+            build_record(Some("rg1"))?,
+            build_record(None)?,
+            build_record(Some("rg3"))?,
+            build_record(Some("rg0"))?,
+            build_record(Some("rg1"))?,
+            build_record(Some("rg3"))?,
+        ];
+
+        let usage = ReadGroupUsage::collect(&header, records.into_iter().map(Ok))?;
+
+        assert_eq!(usage.used(), ["rg1", "rg0"]);
+        assert_eq!(usage.undeclared(), ["rg3"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    io,
+};
+
+use indexmap::IndexMap;
+
+use crate::AlignmentRecord;
+
+/// The policy for segments that have supplementary alignments but no primary alignment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MissingPrimaryPolicy {
+    /// Drop the segment.
+    Skip,
+    /// Keep the first supplementary alignment of the segment.
+    ///
+    /// This requires the input to be grouped by read name, e.g., queryname-sorted or collated.
+    /// The primary alignment may come after its supplementary alignments, so the first
+    /// supplementary alignment of each segment is held until the read name changes. Memory use is
+    /// therefore bounded by the number of alignments of a single read.
+    ///
+    /// If the input is not grouped by read name (e.g., it is coordinate-sorted), alignments of a
+    /// segment that are not adjacent are treated as separate segments, and more than one
+    /// alignment can be yielded for a segment.
+    FirstSupplementary,
+}
+
+impl Default for MissingPrimaryPolicy {
+    fn default() -> Self {
+        Self::Skip
+    }
+}
+
+// (read name, is first segment, is last segment)
+type SegmentKey = (Vec<u8>, bool, bool);
+
+/// An iterator that yields one representative alignment per segment.
+///
+/// This is created by calling [`super::AlignmentRecordsExt::representative_alignment`].
+pub struct RepresentativeAlignment<I, R> {
+    inner: I,
+    policy: MissingPrimaryPolicy,
+    // The read name of the alignments being grouped.
+    read_name: Option<Vec<u8>>,
+    primary_keys: HashSet<SegmentKey>,
+    pending: IndexMap<SegmentKey, R>,
+    queue: VecDeque<R>,
+    is_done: bool,
+}
+
+impl<I, R> RepresentativeAlignment<I, R> {
+    pub(super) fn new(inner: I, policy: MissingPrimaryPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            read_name: None,
+            primary_keys: HashSet::new(),
+            pending: IndexMap::new(),
+            queue: VecDeque::new(),
+            is_done: false,
+        }
+    }
+
+    // Yields the held supplementary alignments of the current read name.
+    fn flush(&mut self) {
+        self.primary_keys.clear();
+        self.queue
+            .extend(self.pending.drain(..).map(|(_, record)| record));
+    }
+}
+
+impl<I, R> Iterator for RepresentativeAlignment<I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.queue.pop_front() {
+                return Some(Ok(record));
+            }
+
+            if self.is_done {
+                return None;
+            }
+
+            let record = match self.inner.next() {
+                Some(Ok(record)) => record,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.flush();
+                    self.is_done = true;
+                    continue;
+                }
+            };
+
+            let flags = record.flags();
+
+            if flags.is_secondary() {
+                continue;
+            }
+
+            if self.policy == MissingPrimaryPolicy::Skip {
+                if flags.is_supplementary() {
+                    continue;
+                } else {
+                    return Some(Ok(record));
+                }
+            }
+
+            let key = match segment_key(&record) {
+                Some(key) => key,
+                // Records without a read name cannot be matched to other alignments of the same
+                // segment, so each is its own representative.
+                None => {
+                    self.queue.push_back(record);
+                    continue;
+                }
+            };
+
+            if self.read_name.as_ref() != Some(&key.0) {
+                self.flush();
+                self.read_name = Some(key.0.clone());
+            }
+
+            if flags.is_supplementary() {
+                if !self.primary_keys.contains(&key) && !self.pending.contains_key(&key) {
+                    self.pending.insert(key, record);
+                }
+            } else {
+                self.pending.shift_remove(&key);
+                self.primary_keys.insert(key);
+                self.queue.push_back(record);
+            }
+        }
+    }
+}
+
+fn segment_key<R>(record: &R) -> Option<SegmentKey>
+where
+    R: AlignmentRecord,
+{
+    let read_name = record.read_name()?;
+    let flags = record.flags();
+
+    Some((
+        AsRef::<[u8]>::as_ref(read_name).to_vec(),
+        flags.is_first_segment(),
+        flags.is_last_segment(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::Flags, Record};
+
+    fn collect(
+        records: Vec<io::Result<Record>>,
+        policy: MissingPrimaryPolicy,
+    ) -> io::Result<Vec<(String, Flags)>> {
+        RepresentativeAlignment::new(records.into_iter(), policy)
+            .map(|result| {
+                result.map(|record| {
+                    let read_name = record.read_name().map(|name| name.to_string());
+                    (read_name.unwrap_or_default(), record.flags())
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let records = vec![
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::SECONDARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY | Flags::REVERSE_COMPLEMENTED)
+                .build()),
+        ];
+
+        assert_eq!(
+            collect(records, MissingPrimaryPolicy::Skip)?,
+            [(String::from("r0"), Flags::empty())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_first_supplementary_policy() -> Result<(), Box<dyn std::error::Error>> {
+        let records = vec![
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::SUPPLEMENTARY | Flags::REVERSE_COMPLEMENTED)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::SUPPLEMENTARY)
+                .build()),
+            Ok(Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+                .build()),
+        ];
+
+        assert_eq!(
+            collect(records, MissingPrimaryPolicy::FirstSupplementary)?,
+            [
+                (String::from("r0"), Flags::empty()),
+                (String::from("r1"), Flags::SUPPLEMENTARY),
+                (String::from("r2"), Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+                (
+                    String::from("r2"),
+                    Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::SUPPLEMENTARY
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_error() -> Result<(), Box<dyn std::error::Error>> {
+        let records = vec![
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Ok(Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(Flags::empty())
+                .build()),
+        ];
+
+        let mut iter =
+            RepresentativeAlignment::new(records.into_iter(), MissingPrimaryPolicy::Skip);
+
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert!(matches!(iter.next(), Some(Ok(_))));
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+}
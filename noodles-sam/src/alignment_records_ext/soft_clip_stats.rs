@@ -0,0 +1,278 @@
+use std::{collections::BTreeMap, io};
+
+use crate::{record::cigar::op::Kind, AlignmentRecord};
+
+/// Soft clip statistics of a set of alignment records.
+///
+/// Only the soft clip at the 3' end of a read, i.e., the end that is sequenced last, is counted,
+/// as that is where adapter read-through occurs. For reads aligned to the reverse strand, this is
+/// the first CIGAR operation.
+///
+/// This is created by calling [`super::AlignmentRecordsExt::soft_clip_stats`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SoftClipStats {
+    record_count: u64,
+    length_histogram: BTreeMap<usize, u64>,
+    clipped_count: u64,
+    adapter_count: u64,
+}
+
+impl SoftClipStats {
+    pub(super) fn collect<I, R>(
+        records: I,
+        min_len: usize,
+        adapter: Option<&[u8]>,
+    ) -> io::Result<Self>
+    where
+        I: Iterator<Item = io::Result<R>>,
+        R: AlignmentRecord,
+    {
+        let mut stats = Self::default();
+
+        for result in records {
+            let record = result?;
+
+            let flags = record.flags();
+
+            if flags.is_unmapped() || flags.is_secondary() || flags.is_supplementary() {
+                continue;
+            }
+
+            let is_reverse_complemented = flags.is_reverse_complemented();
+            let len = three_prime_soft_clip_len(&record, is_reverse_complemented);
+
+            stats.record_count += 1;
+            *stats.length_histogram.entry(len).or_default() += 1;
+
+            if len == 0 || len < min_len {
+                continue;
+            }
+
+            stats.clipped_count += 1;
+
+            if let Some(adapter) = adapter {
+                let clipped_bases = three_prime_soft_clip(&record, len, is_reverse_complemented);
+
+                if is_adapter_prefix(&clipped_bases, adapter) {
+                    stats.adapter_count += 1;
+                }
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Returns the number of records counted.
+    ///
+    /// Unmapped, secondary, and supplementary records are not counted.
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the number of records keyed by 3' soft clip length.
+    ///
+    /// Records without a 3' soft clip have a length of 0.
+    pub fn length_histogram(&self) -> &BTreeMap<usize, u64> {
+        &self.length_histogram
+    }
+
+    /// Returns the number of records with a 3' soft clip of at least the minimum length.
+    pub fn clipped_count(&self) -> u64 {
+        self.clipped_count
+    }
+
+    /// Returns the fraction of records with a 3' soft clip of at least the minimum length.
+    ///
+    /// This is 0 if no records were counted.
+    pub fn clipped_fraction(&self) -> f64 {
+        fraction(self.clipped_count, self.record_count)
+    }
+
+    /// Returns the number of clipped records whose 3' soft clip matches the adapter.
+    ///
+    /// This is always 0 if no adapter was given.
+    pub fn adapter_count(&self) -> u64 {
+        self.adapter_count
+    }
+
+    /// Returns the fraction of records whose 3' soft clip matches the adapter.
+    ///
+    /// This is 0 if no records were counted.
+    pub fn adapter_fraction(&self) -> f64 {
+        fraction(self.adapter_count, self.record_count)
+    }
+}
+
+fn three_prime_soft_clip_len<R>(record: &R, is_reverse_complemented: bool) -> usize
+where
+    R: AlignmentRecord,
+{
+    let mut ops = record
+        .cigar()
+        .iter()
+        .filter(|op| op.kind() != Kind::HardClip);
+
+    let op = if is_reverse_complemented {
+        ops.next()
+    } else {
+        ops.next_back()
+    };
+
+    op.filter(|op| op.kind() == Kind::SoftClip)
+        .map(|op| op.len())
+        .unwrap_or_default()
+}
+
+// Returns the bases of the 3' soft clip in sequencing order.
+fn three_prime_soft_clip<R>(record: &R, len: usize, is_reverse_complemented: bool) -> Vec<u8>
+where
+    R: AlignmentRecord,
+{
+    let sequence = record.sequence().as_ref();
+
+    if sequence.len() < len {
+        return Vec::new();
+    }
+
+    if is_reverse_complemented {
+        sequence[..len]
+            .iter()
+            .rev()
+            .map(|&base| u8::from(base.complement()))
+            .collect()
+    } else {
+        sequence[sequence.len() - len..]
+            .iter()
+            .map(|&base| u8::from(base))
+            .collect()
+    }
+}
+
+// A clip matches when it and the adapter agree over the shorter of the two, i.e., the read ends
+// within the adapter or the adapter is followed by other sequence.
+fn is_adapter_prefix(clipped_bases: &[u8], adapter: &[u8]) -> bool {
+    !clipped_bases.is_empty()
+        && clipped_bases
+            .iter()
+            .zip(adapter)
+            .all(|(a, b)| a.eq_ignore_ascii_case(b))
+}
+
+fn fraction(n: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        n as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{record::Flags, Record};
+
+    const ADAPTER: &[u8] = b"AGATCGGAAGAGC";
+
+    #[test]
+    fn test_collect() -> Result<(), Box<dyn std::error::Error>> {
+        let records: Vec<io::Result<Record>> = vec![
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::MIN)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .build()),
+            // adapter read-through
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::MIN)
+                .set_cigar("4M6S".parse()?)
+                .set_sequence("ACGTAGATCG".parse()?)
+                .build()),
+            // reverse complement of adapter read-through
+            Ok(Record::builder()
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_position(Position::MIN)
+                .set_cigar("6S4M".parse()?)
+                .set_sequence("CGATCTACGT".parse()?)
+                .build()),
+            // a 3' soft clip that is not adapter
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::MIN)
+                .set_cigar("4M6S2H".parse()?)
+                .set_sequence("ACGTTTTTTT".parse()?)
+                .build()),
+            // a 5' soft clip
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::MIN)
+                .set_cigar("6S4M".parse()?)
+                .set_sequence("AGATCGACGT".parse()?)
+                .build()),
+            // a short 3' soft clip
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::MIN)
+                .set_cigar("8M2S".parse()?)
+                .set_sequence("ACGTACGTAG".parse()?)
+                .build()),
+            Ok(Record::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_position(Position::MIN)
+                .set_cigar("4M6S".parse()?)
+                .set_sequence("ACGTAGATCG".parse()?)
+                .build()),
+            Ok(Record::builder()
+                .set_flags(Flags::SUPPLEMENTARY)
+                .set_position(Position::MIN)
+                .set_cigar("4M6S".parse()?)
+                .set_sequence("ACGTAGATCG".parse()?)
+                .build()),
+        ];
+
+        let stats = SoftClipStats::collect(records.into_iter(), 5, Some(ADAPTER))?;
+
+        assert_eq!(stats.record_count(), 6);
+        assert_eq!(
+            stats.length_histogram(),
+            &[(0, 2), (2, 1), (6, 3)].into_iter().collect()
+        );
+        assert_eq!(stats.clipped_count(), 3);
+        assert_eq!(stats.clipped_fraction(), 0.5);
+        assert_eq!(stats.adapter_count(), 2);
+        assert_eq!(stats.adapter_fraction(), 2.0 / 6.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_with_no_adapter() -> Result<(), Box<dyn std::error::Error>> {
+        let records: Vec<io::Result<Record>> = vec![Ok(Record::builder()
+            .set_flags(Flags::empty())
+            .set_position(Position::MIN)
+            .set_cigar("4M6S".parse()?)
+            .set_sequence("ACGTAGATCG".parse()?)
+            .build())];
+        let stats = SoftClipStats::collect(records.into_iter(), 1, None)?;
+
+        assert_eq!(stats.clipped_count(), 1);
+        assert_eq!(stats.adapter_count(), 0);
+
+        let stats = SoftClipStats::collect(Vec::<io::Result<Record>>::new().into_iter(), 1, None)?;
+        assert_eq!(stats.clipped_fraction(), 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_adapter_prefix() {
+        assert!(is_adapter_prefix(b"AGATC", ADAPTER));
+        assert!(is_adapter_prefix(b"agatc", ADAPTER));
+        assert!(is_adapter_prefix(b"AGATCGGAAGAGCACACGTCT", ADAPTER));
+        assert!(!is_adapter_prefix(b"AGTTC", ADAPTER));
+        assert!(!is_adapter_prefix(b"", ADAPTER));
+    }
+}
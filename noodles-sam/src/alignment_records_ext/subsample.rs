@@ -0,0 +1,155 @@
+use std::io;
+
+use crate::AlignmentRecord;
+
+const MISSING_READ_NAME: &[u8] = b"*";
+
+/// An iterator that keeps a deterministic fraction of alignment records by read name.
+///
+/// This is created by calling [`super::AlignmentRecordsExt::subsample`].
+pub struct Subsample<I> {
+    inner: I,
+    fraction: f64,
+    seed: u32,
+}
+
+impl<I> Subsample<I> {
+    pub(super) fn new(inner: I, fraction: f64, seed: u32) -> Self {
+        Self {
+            inner,
+            fraction,
+            seed,
+        }
+    }
+}
+
+impl<I, R> Iterator for Subsample<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: AlignmentRecord,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next()? {
+                Ok(record) => {
+                    let read_name: &[u8] = match record.read_name() {
+                        Some(name) => name.as_ref(),
+                        None => MISSING_READ_NAME,
+                    };
+
+                    if sample_value(read_name, self.seed) < self.fraction {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+// Maps a read name to a value in [0, 1).
+//
+// This is the same calculation as `samtools view --subsample`, i.e., the X31 string hash of the
+// read name mixed with the seed by Thomas Wang's integer hash, using the lower 24 bits.
+fn sample_value(read_name: &[u8], seed: u32) -> f64 {
+    let k = wang_hash(x31_hash(read_name) ^ seed);
+    f64::from(k & 0xffffff) / f64::from(0x1000000)
+}
+
+fn x31_hash(s: &[u8]) -> u32 {
+    s.iter().fold(0, |h: u32, &b| {
+        (h << 5).wrapping_sub(h).wrapping_add(u32::from(b))
+    })
+}
+
+fn wang_hash(mut key: u32) -> u32 {
+    key = key.wrapping_add(!(key << 15));
+    key ^= key >> 10;
+    key = key.wrapping_add(key << 3);
+    key ^= key >> 6;
+    key = key.wrapping_add(!(key << 11));
+    key ^= key >> 16;
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::Flags, Record};
+
+    fn build_records(n: usize) -> Vec<io::Result<Record>> {
+        (0..n)
+            .flat_map(|i| {
+                let read_name = format!("r{}", i);
+
+                [
+                    Flags::SEGMENTED | Flags::FIRST_SEGMENT,
+                    Flags::SEGMENTED | Flags::LAST_SEGMENT,
+                ]
+                .into_iter()
+                .map(move |flags| {
+                    Ok(Record::builder()
+                        .set_read_name(read_name.parse().unwrap())
+                        .set_flags(flags)
+                        .build())
+                })
+            })
+            .collect()
+    }
+
+    fn read_names(records: &[Record]) -> Vec<String> {
+        records
+            .iter()
+            .filter_map(|record| record.read_name().map(|name| name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        const PAIR_COUNT: usize = 10000;
+
+        let records: Vec<_> = Subsample::new(build_records(PAIR_COUNT).into_iter(), 0.5, 0)
+            .collect::<io::Result<_>>()?;
+
+        // Mates are kept or dropped together.
+        assert_eq!(records.len() % 2, 0);
+
+        for pair in records.chunks(2) {
+            assert_eq!(pair[0].read_name(), pair[1].read_name());
+        }
+
+        let kept_pair_count = records.len() / 2;
+        assert!((4500..=5500).contains(&kept_pair_count));
+
+        // The selection is reproducible for a given seed and differs across seeds.
+        let records_2: Vec<_> = Subsample::new(build_records(PAIR_COUNT).into_iter(), 0.5, 0)
+            .collect::<io::Result<_>>()?;
+        assert_eq!(read_names(&records_2), read_names(&records));
+
+        let records_3: Vec<_> = Subsample::new(build_records(PAIR_COUNT).into_iter(), 0.5, 1)
+            .collect::<io::Result<_>>()?;
+        assert_ne!(read_names(&records_3), read_names(&records));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_bounds() -> io::Result<()> {
+        let n = Subsample::new(build_records(100).into_iter(), 0.0, 0).count();
+        assert_eq!(n, 0);
+
+        let n = Subsample::new(build_records(100).into_iter(), 1.0, 0).count();
+        assert_eq!(n, 200);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_x31_hash() {
+        assert_eq!(x31_hash(b""), 0);
+        assert_eq!(x31_hash(b"a"), 97);
+        assert_eq!(x31_hash(b"ab"), 97 * 31 + 98);
+    }
+}
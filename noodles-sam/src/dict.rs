@@ -0,0 +1,132 @@
+use std::io::{self, BufRead};
+
+use md5::{Digest, Md5};
+use noodles_fasta as fasta;
+
+use super::{
+    header::{self, reference_sequence::Md5Checksum, ReferenceSequence},
+    Header,
+};
+
+/// Creates a sequence dictionary from FASTA records.
+///
+/// This reads all records from the given FASTA reader and returns a SAM header with a header
+/// (`@HD`) line with the current version (`VN`) and a reference sequence (`@SQ`) for each
+/// record. Each reference sequence has its name (`SN`), length (`LN`),
+/// and MD5 checksum (`M5`) set. If a URI is given, it is set as the location (`UR`) of each
+/// reference sequence.
+///
+/// This is equivalent to `samtools dict` or Picard `CreateSequenceDictionary`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta as fasta;
+/// use noodles_sam as sam;
+///
+/// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+/// let mut reader = fasta::Reader::new(&data[..]);
+///
+/// let header = sam::create_dict(&mut reader, None)?;
+/// assert!(header.header().is_some());
+///
+/// let reference_sequences = header.reference_sequences();
+/// assert_eq!(reference_sequences.len(), 2);
+/// assert_eq!(reference_sequences["sq0"].len(), 4);
+/// assert_eq!(reference_sequences["sq1"].len(), 10);
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn create_dict<R>(reader: &mut fasta::Reader<R>, uri: Option<&str>) -> io::Result<Header>
+where
+    R: BufRead,
+{
+    let mut builder = Header::builder().set_header(header::header::Header::default());
+
+    for result in reader.records() {
+        let record = result?;
+        let sequence = record.sequence().as_ref();
+
+        let name = record
+            .name()
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let len = i32::try_from(sequence.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut reference_sequence_builder = ReferenceSequence::builder()
+            .set_name(name)
+            .set_length(len)
+            .set_md5_checksum(calculate_normalized_sequence_digest(sequence));
+
+        if let Some(uri) = uri {
+            reference_sequence_builder = reference_sequence_builder.set_uri(uri);
+        }
+
+        let reference_sequence = reference_sequence_builder
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        builder = builder.add_reference_sequence(reference_sequence);
+    }
+
+    Ok(builder.build())
+}
+
+// § 1.3.2 "Reference MD5 calculation" (2021-06-03)
+fn calculate_normalized_sequence_digest(sequence: &[u8]) -> Md5Checksum {
+    let mut hasher = Md5::new();
+
+    for &b in sequence {
+        // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are stripped out."
+        if b.is_ascii_graphic() {
+            // "All lowercase characters are converted to uppercase."
+            hasher.update([b.to_ascii_uppercase()]);
+        }
+    }
+
+    let digest: [u8; 16] = hasher.finalize().into();
+    Md5Checksum::from(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_dict() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1 LN:8\nacgt\nACGT\n";
+        let mut reader = fasta::Reader::new(&data[..]);
+
+        let header = create_dict(&mut reader, Some("file:///tmp/ref.fa"))?;
+
+        assert_eq!(
+            header.header().map(|hdr| hdr.to_string()),
+            Some(format!("@HD\tVN:{}", header::header::Version::default()))
+        );
+
+        let actual: Vec<_> = header
+            .reference_sequences()
+            .values()
+            .map(|reference_sequence| reference_sequence.to_string())
+            .collect();
+
+        let expected = [
+            "@SQ\tSN:sq0\tLN:4\tM5:f1f8f4bf413b16ad135722aa4591043e\tUR:file:///tmp/ref.fa",
+            "@SQ\tSN:sq1\tLN:8\tM5:cc0af3a4fedb18378b4b57b98068e69f\tUR:file:///tmp/ref.fa",
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_normalized_sequence_digest() {
+        assert_eq!(
+            calculate_normalized_sequence_digest(b"ACgt").to_string(),
+            "f1f8f4bf413b16ad135722aa4591043e"
+        );
+    }
+}
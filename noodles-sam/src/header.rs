@@ -60,6 +60,7 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+mod bins;
 mod builder;
 #[allow(clippy::module_inception)]
 pub mod header;
@@ -72,9 +73,14 @@ pub mod reference_sequence;
 use std::{fmt, str::FromStr};
 
 use indexmap::IndexMap;
+use noodles_core::Region;
 
 pub use self::{
-    builder::Builder, parser::ParseError, program::Program, read_group::ReadGroup,
+    bins::Bins,
+    builder::Builder,
+    parser::{repair, ParseError},
+    program::Program,
+    read_group::ReadGroup,
     reference_sequence::ReferenceSequence,
 };
 
@@ -209,6 +215,85 @@ impl Header {
         &mut self.reference_sequences
     }
 
+    /// Partitions the reference sequences into regions of a fixed size.
+    ///
+    /// Each reference sequence is split into consecutive windows of `size` bases, in header
+    /// order. The last window of each reference sequence is clamped to its length, so a reference
+    /// sequence shorter than `size` is a single region.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::ReferenceSequence};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+    ///     .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 3)?)
+    ///     .build();
+    ///
+    /// let regions: Vec<_> = header
+    ///     .windows(5)
+    ///     .iter()
+    ///     .map(|region| region.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(regions, ["sq0:1-5", "sq0:6-8", "sq1:1-3"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn windows(&self, size: usize) -> Vec<Region> {
+        assert!(size > 0, "invalid window size: {}", size);
+
+        self.bins(size)
+            .map(|(reference_sequence_id, _, interval)| {
+                // SAFETY: `reference_sequence_id` is an index of the reference sequences.
+                let (name, _) = self
+                    .reference_sequences
+                    .get_index(reference_sequence_id)
+                    .unwrap();
+
+                Region::new(name.clone(), interval)
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over fixed-width bins of the reference sequences.
+    ///
+    /// Each item is a tuple of the reference sequence ID, the 0-based bin index in the reference
+    /// sequence, and the bin interval. Bins are consecutive, non-overlapping, and in header order.
+    /// The last bin of each reference sequence is clamped to its length, and a reference sequence
+    /// with a length of 0 has no bins.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `width` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, header::ReferenceSequence};
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 8)?)
+    ///     .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 3)?)
+    ///     .build();
+    ///
+    /// let mut bins = header.bins(5);
+    ///
+    /// assert_eq!(bins.next(), Some((0, 0, Position::try_from(1)?..=Position::try_from(5)?)));
+    /// assert_eq!(bins.next(), Some((0, 1, Position::try_from(6)?..=Position::try_from(8)?)));
+    /// assert_eq!(bins.next(), Some((1, 0, Position::try_from(1)?..=Position::try_from(3)?)));
+    /// assert!(bins.next().is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bins(&self, width: usize) -> Bins<'_> {
+        Bins::new(&self.reference_sequences, width)
+    }
+
     /// Returns the SAM header read groups.
     ///
     /// # Examples
@@ -473,6 +558,82 @@ impl FromStr for Header {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_windows() -> Result<(), Box<dyn std::error::Error>> {
+        const SIZE: usize = 1_000_000;
+
+        let header = Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 2_500_000)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 1_000_000)?)
+            .add_reference_sequence(ReferenceSequence::new("sq2".parse()?, 13)?)
+            .build();
+
+        let actual: Vec<_> = header
+            .windows(SIZE)
+            .iter()
+            .map(|region| region.to_string())
+            .collect();
+
+        let expected = [
+            "sq0:1-1000000",
+            "sq0:1000001-2000000",
+            "sq0:2000001-2500000",
+            "sq1:1-1000000",
+            "sq2:1-13",
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_windows_with_zero_size() {
+        Header::default().windows(0);
+    }
+
+    #[test]
+    fn test_bins() -> Result<(), Box<dyn std::error::Error>> {
+        const WIDTH: usize = 10_000;
+
+        let header = Header::builder()
+            .add_reference_sequence(ReferenceSequence::new("sq0".parse()?, 25_000)?)
+            .add_reference_sequence(ReferenceSequence::new("sq1".parse()?, 10_000)?)
+            .add_reference_sequence(ReferenceSequence::new("sq2".parse()?, 13)?)
+            .build();
+
+        let actual: Vec<_> = header
+            .bins(WIDTH)
+            .map(|(id, i, interval)| {
+                (
+                    id,
+                    i,
+                    usize::from(*interval.start()),
+                    usize::from(*interval.end()),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (0, 0, 1, 10_000),
+            (0, 1, 10_001, 20_000),
+            (0, 2, 20_001, 25_000),
+            (1, 0, 1, 10_000),
+            (2, 0, 1, 13),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bins_with_zero_width() {
+        Header::default().bins(0);
+    }
+
     #[test]
     fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
         let header = Header::builder()
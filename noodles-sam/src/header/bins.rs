@@ -0,0 +1,64 @@
+//! SAM header reference sequence bins.
+
+use std::ops::RangeInclusive;
+
+use noodles_core::Position;
+
+use super::ReferenceSequences;
+
+/// An iterator over fixed-width bins of reference sequences.
+///
+/// This is created by calling [`super::Header::bins`].
+pub struct Bins<'a> {
+    reference_sequences: &'a ReferenceSequences,
+    width: usize,
+    reference_sequence_id: usize,
+    bin_index: usize,
+    start: usize,
+}
+
+impl<'a> Bins<'a> {
+    pub(crate) fn new(reference_sequences: &'a ReferenceSequences, width: usize) -> Self {
+        assert!(width > 0, "invalid bin width: {}", width);
+
+        Self {
+            reference_sequences,
+            width,
+            reference_sequence_id: 0,
+            bin_index: 0,
+            start: 1,
+        }
+    }
+}
+
+impl<'a> Iterator for Bins<'a> {
+    type Item = (usize, usize, RangeInclusive<Position>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (_, reference_sequence) = self
+                .reference_sequences
+                .get_index(self.reference_sequence_id)?;
+
+            let len = usize::try_from(reference_sequence.len()).unwrap_or_default();
+
+            if self.start > len {
+                self.reference_sequence_id += 1;
+                self.bin_index = 0;
+                self.start = 1;
+                continue;
+            }
+
+            let end = self.start.saturating_add(self.width - 1).min(len);
+
+            // SAFETY: `start` is > 0 and <= `end`.
+            let interval = Position::new(self.start).unwrap()..=Position::new(end).unwrap();
+            let item = (self.reference_sequence_id, self.bin_index, interval);
+
+            self.bin_index += 1;
+            self.start = end + 1;
+
+            return Some(item);
+        }
+    }
+}
@@ -181,7 +181,7 @@ impl fmt::Display for Header {
             write!(f, "\t{}:{}", Tag::GroupOrder, group_order)?;
         }
 
-        if let Some(subsort_order) = self.group_order {
+        if let Some(subsort_order) = &self.subsort_order {
             write!(f, "\t{}:{}", Tag::SubsortOrder, subsort_order)?;
         }
 
@@ -210,6 +210,8 @@ pub enum TryFromRecordError {
     InvalidGroupOrder(group_order::ParseError),
     /// The subsort order is invalid.
     InvalidSubsortOrder(subsort_order::ParseError),
+    /// The primary sort order of the subsort order does not match the sort order.
+    SubsortOrderMismatch,
 }
 
 impl error::Error for TryFromRecordError {}
@@ -224,6 +226,9 @@ impl fmt::Display for TryFromRecordError {
             Self::InvalidSortOrder(e) => write!(f, "invalid sort order: {}", e),
             Self::InvalidGroupOrder(e) => write!(f, "invalid group order: {}", e),
             Self::InvalidSubsortOrder(e) => write!(f, "invalid subsort order: {}", e),
+            Self::SubsortOrderMismatch => {
+                f.write_str("subsort order primary sort order does not match sort order")
+            }
         }
     }
 }
@@ -242,6 +247,8 @@ impl TryFrom<Record> for Header {
 fn parse_map(raw_fields: Fields) -> Result<Header, TryFromRecordError> {
     let mut builder = Header::builder();
     let mut version: Option<Version> = None;
+    let mut sort_order: Option<SortOrder> = None;
+    let mut subsort_order: Option<SubsortOrder> = None;
 
     for (raw_tag, value) in raw_fields {
         let tag = raw_tag.parse().map_err(TryFromRecordError::InvalidTag)?;
@@ -256,10 +263,12 @@ fn parse_map(raw_fields: Fields) -> Result<Header, TryFromRecordError> {
                 builder
             }
             Tag::SortOrder => {
-                let sort_order = value
+                sort_order = value
                     .parse()
+                    .map(Some)
                     .map_err(TryFromRecordError::InvalidSortOrder)?;
-                builder.set_sort_order(sort_order)
+
+                builder
             }
             Tag::GroupOrder => {
                 let group_order = value
@@ -268,10 +277,12 @@ fn parse_map(raw_fields: Fields) -> Result<Header, TryFromRecordError> {
                 builder.set_group_order(group_order)
             }
             Tag::SubsortOrder => {
-                let subsort_order = value
+                subsort_order = value
                     .parse()
+                    .map(Some)
                     .map_err(TryFromRecordError::InvalidSubsortOrder)?;
-                builder.set_subsort_order(subsort_order)
+
+                builder
             }
             Tag::Other(..) => builder.insert(tag, value),
         }
@@ -283,6 +294,20 @@ fn parse_map(raw_fields: Fields) -> Result<Header, TryFromRecordError> {
         return Err(TryFromRecordError::MissingRequiredTag(Tag::Version));
     }
 
+    if let (Some(sort_order), Some(subsort_order)) = (sort_order, subsort_order.as_ref()) {
+        if subsort_order.sort_order() != sort_order {
+            return Err(TryFromRecordError::SubsortOrderMismatch);
+        }
+    }
+
+    if let Some(sort_order) = sort_order {
+        builder = builder.set_sort_order(sort_order);
+    }
+
+    if let Some(subsort_order) = subsort_order {
+        builder = builder.set_subsort_order(subsort_order);
+    }
+
     Ok(builder.build())
 }
 
@@ -308,6 +333,54 @@ mod tests {
             .build();
 
         assert_eq!(header.to_string(), "@HD\tVN:1.6\tSO:unknown");
+
+        let header = Header::builder()
+            .set_version(Version::new(1, 6))
+            .set_sort_order(SortOrder::Coordinate)
+            .set_group_order(GroupOrder::None)
+            .set_subsort_order(SubsortOrder::Coordinate(vec![String::from("queryname")]))
+            .build();
+
+        assert_eq!(
+            header.to_string(),
+            "@HD\tVN:1.6\tSO:coordinate\tGO:none\tSS:coordinate:queryname"
+        );
+    }
+
+    #[test]
+    fn test_try_from_record_for_header_with_subsort_order() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let record = Record::new(
+            record::Kind::Header,
+            record::Value::try_from_iter([
+                ("VN", "1.6"),
+                ("SO", "coordinate"),
+                ("SS", "coordinate:queryname"),
+            ])?,
+        );
+
+        let header = Header::try_from(record)?;
+        assert_eq!(header.sort_order(), Some(SortOrder::Coordinate));
+        assert_eq!(
+            header.subsort_order(),
+            Some(&SubsortOrder::Coordinate(vec![String::from("queryname")]))
+        );
+
+        let record = Record::new(
+            record::Kind::Header,
+            record::Value::try_from_iter([
+                ("VN", "1.6"),
+                ("SO", "queryname"),
+                ("SS", "coordinate:queryname"),
+            ])?,
+        );
+
+        assert_eq!(
+            Header::try_from(record),
+            Err(TryFromRecordError::SubsortOrderMismatch)
+        );
+
+        Ok(())
     }
 
     #[test]
@@ -17,12 +17,33 @@ pub enum SubsortOrder {
     Coordinate(Vec<String>),
 }
 
+impl SubsortOrder {
+    /// Returns the primary sort order.
+    ///
+    /// This should match the sort order (`SO`) of the header, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::header::{SortOrder, SubsortOrder};
+    /// let subsort_order = SubsortOrder::Coordinate(vec![String::from("queryname")]);
+    /// assert_eq!(subsort_order.sort_order(), SortOrder::Coordinate);
+    /// ```
+    pub fn sort_order(&self) -> SortOrder {
+        match self {
+            Self::Unsorted(_) => SortOrder::Unsorted,
+            Self::QueryName(_) => SortOrder::QueryName,
+            Self::Coordinate(_) => SortOrder::Coordinate,
+        }
+    }
+}
+
 impl fmt::Display for SubsortOrder {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Unsorted(subsorts) => write_orders(f, SortOrder::Unsorted, subsorts),
-            Self::QueryName(subsorts) => write_orders(f, SortOrder::QueryName, subsorts),
-            Self::Coordinate(subsorts) => write_orders(f, SortOrder::Coordinate, subsorts),
+            Self::Unsorted(subsorts) | Self::QueryName(subsorts) | Self::Coordinate(subsorts) => {
+                write_orders(f, self.sort_order(), subsorts)
+            }
         }
     }
 }
@@ -111,6 +132,24 @@ fn is_valid_subsort(s: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sort_order() {
+        let subsorts = vec![String::from("MI")];
+
+        assert_eq!(
+            SubsortOrder::Unsorted(subsorts.clone()).sort_order(),
+            SortOrder::Unsorted
+        );
+        assert_eq!(
+            SubsortOrder::QueryName(subsorts.clone()).sort_order(),
+            SortOrder::QueryName
+        );
+        assert_eq!(
+            SubsortOrder::Coordinate(subsorts).sort_order(),
+            SortOrder::Coordinate
+        );
+    }
+
     #[test]
     fn test_fmt() {
         assert_eq!(
@@ -140,6 +140,49 @@ pub(super) fn parse(s: &str) -> Result<Header, ParseError> {
     Ok(builder.build())
 }
 
+/// Repairs a raw SAM header by moving a header record (`@HD`) to the first line.
+///
+/// All other lines are kept in their original order and are otherwise unchanged. The repaired
+/// header is validated by parsing it.
+///
+/// # Errors
+///
+/// This returns an error if the repaired header fails to parse, e.g., if there are multiple
+/// header records or duplicate reference sequence names.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam as sam;
+///
+/// let s = "@SQ\tSN:sq0\tLN:8\n@HD\tVN:1.6\n";
+/// assert!(s.parse::<sam::Header>().is_err());
+///
+/// let repaired = sam::header::repair(s)?;
+/// assert_eq!(repaired, "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n");
+/// assert!(repaired.parse::<sam::Header>().is_ok());
+/// # Ok::<_, sam::header::ParseError>(())
+/// ```
+pub fn repair(s: &str) -> Result<String, ParseError> {
+    fn is_header_record(line: &str) -> bool {
+        line.split('\t').next() == Some("@HD")
+    }
+
+    let (header_lines, other_lines): (Vec<_>, Vec<_>) =
+        s.lines().partition(|line| is_header_record(line));
+
+    let mut dst = String::with_capacity(s.len() + 1);
+
+    for line in header_lines.into_iter().chain(other_lines) {
+        dst.push_str(line);
+        dst.push('\n');
+    }
+
+    parse(&dst)?;
+
+    Ok(dst)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +283,52 @@ mod tests {
             Err(ParseError::DuplicateProgramId(String::from("pg0")))
         );
     }
+
+    #[test]
+    fn test_repair() -> Result<(), ParseError> {
+        let s = "\
+@SQ\tSN:sq0\tLN:8
+@CO\tnoodles
+@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq1\tLN:13
+";
+
+        assert_eq!(parse(s), Err(ParseError::UnexpectedHeader));
+
+        let expected = "\
+@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@CO\tnoodles
+@SQ\tSN:sq1\tLN:13
+";
+
+        assert_eq!(repair(s)?, expected);
+
+        let s = "@SQ\tSN:sq0\tLN:8\n";
+        assert_eq!(repair(s)?, s);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_repair_with_invalid_header() {
+        let s = "\
+@SQ\tSN:sq0\tLN:8
+@HD\tVN:1.6
+@HD\tVN:1.6
+";
+
+        assert_eq!(repair(s), Err(ParseError::UnexpectedHeader));
+
+        let s = "\
+@SQ\tSN:sq0\tLN:8
+@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:8
+";
+
+        assert!(matches!(
+            repair(s),
+            Err(ParseError::DuplicateReferenceSequenceName(_))
+        ));
+    }
 }
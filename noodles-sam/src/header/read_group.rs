@@ -17,6 +17,9 @@ use super::{
 ///
 /// A read group typically defines the set of reads that came from the same run on a sequencing
 /// instrument. The read group ID is guaranteed to be set.
+///
+/// When written, the standard tags are always in a fixed order (ID, BC, CN, DS, DT, FO, KS, LB,
+/// PG, PI, PL, PM, PU, SM), regardless of the order they were read.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReadGroup {
     id: String,
@@ -230,6 +233,10 @@ impl ReadGroup {
 
     /// Returns the platform used.
     ///
+    /// A platform that is not a known [`Platform`] is not rejected when parsing. Instead, this
+    /// returns `None`, and the raw value is kept in [`Self::fields`] with the tag
+    /// [`Tag::Platform`]. It is written back as is.
+    ///
     /// # Examples
     ///
     /// ```
@@ -359,6 +366,8 @@ impl fmt::Display for ReadGroup {
 
         if let Some(platform) = self.platform() {
             write!(f, "\t{}:{}", Tag::Platform, platform)?;
+        } else if let Some(raw_platform) = self.fields.get(&Tag::Platform) {
+            write!(f, "\t{}:{}", Tag::Platform, raw_platform)?;
         }
 
         if let Some(platform_model) = self.platform_model() {
@@ -374,6 +383,10 @@ impl fmt::Display for ReadGroup {
         }
 
         for (tag, value) in &self.fields {
+            if *tag == Tag::Platform {
+                continue;
+            }
+
             write!(f, "\t{}:{}", tag, value)?;
         }
 
@@ -448,10 +461,12 @@ fn parse_map(raw_fields: Fields) -> Result<ReadGroup, TryFromRecordError> {
 
                 builder.set_predicted_median_insert_size(predicted_median_insert_size)
             }
-            Tag::Platform => {
-                let platform = value.parse().map_err(TryFromRecordError::InvalidPlatform)?;
-                builder.set_platform(platform)
-            }
+            Tag::Platform => match value.parse() {
+                Ok(platform) => builder.set_platform(platform),
+                // An unknown platform is kept as a raw value.
+                Err(platform::ParseError::Invalid) => builder.insert(tag, value),
+                Err(e) => return Err(TryFromRecordError::InvalidPlatform(e)),
+            },
             Tag::PlatformModel => builder.set_platform_model(value),
             Tag::PlatformUnit => builder.set_platform_unit(value),
             Tag::Sample => builder.set_sample(value),
@@ -527,17 +542,89 @@ mod tests {
     }
 
     #[test]
-    fn test_try_from_record_for_read_group_with_invalid_platform(
+    fn test_try_from_record_for_read_group_with_all_standard_tags(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::new(
+            record::Kind::ReadGroup,
+            record::Value::try_from_iter([
+                ("SM", "sm0"),
+                ("PL", "ILLUMINA"),
+                ("LB", "lb0"),
+                ("ID", "rg0"),
+                ("PU", "pu0"),
+                ("DT", "2022-03-01"),
+                ("BC", "ACGT"),
+                ("PM", "pm0"),
+                ("KS", "TCAG"),
+                ("CN", "ndls"),
+                ("PI", "8"),
+                ("FO", "TACG"),
+                ("DS", "noodles"),
+                ("PG", "pg0"),
+            ])?,
+        );
+
+        let read_group = ReadGroup::try_from(record)?;
+
+        assert_eq!(read_group.id(), "rg0");
+        assert_eq!(read_group.barcode(), Some("ACGT"));
+        assert_eq!(read_group.sequencing_center(), Some("ndls"));
+        assert_eq!(read_group.description(), Some("noodles"));
+        assert_eq!(read_group.produced_at(), Some("2022-03-01"));
+        assert_eq!(read_group.flow_order(), Some("TACG"));
+        assert_eq!(read_group.key_sequence(), Some("TCAG"));
+        assert_eq!(read_group.library(), Some("lb0"));
+        assert_eq!(read_group.program(), Some("pg0"));
+        assert_eq!(read_group.predicted_median_insert_size(), Some(8));
+        assert_eq!(read_group.platform(), Some(Platform::Illumina));
+        assert_eq!(read_group.platform_model(), Some("pm0"));
+        assert_eq!(read_group.platform_unit(), Some("pu0"));
+        assert_eq!(read_group.sample(), Some("sm0"));
+        assert!(read_group.fields().is_empty());
+
+        // Standard tags are written in a fixed order, not the read order.
+        assert_eq!(
+            read_group.to_string(),
+            "@RG\tID:rg0\tBC:ACGT\tCN:ndls\tDS:noodles\tDT:2022-03-01\tFO:TACG\tKS:TCAG\tLB:lb0\tPG:pg0\tPI:8\tPL:ILLUMINA\tPM:pm0\tPU:pu0\tSM:sm0"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_record_for_read_group_with_unknown_platform(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::new(
+            record::Kind::ReadGroup,
+            record::Value::try_from_iter([("ID", "rg0"), ("PL", "unknown"), ("SM", "sm0")])?,
+        );
+
+        let read_group = ReadGroup::try_from(record)?;
+
+        assert!(read_group.platform().is_none());
+        assert_eq!(
+            read_group.fields().get(&Tag::Platform),
+            Some(&String::from("unknown"))
+        );
+        assert_eq!(read_group.to_string(), "@RG\tID:rg0\tPL:unknown\tSM:sm0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_record_for_read_group_with_empty_platform(
     ) -> Result<(), record::value::TryFromIteratorError> {
         let record = Record::new(
             record::Kind::ReadGroup,
-            record::Value::try_from_iter([("ID", "rg0"), ("PL", "unknown")])?,
+            record::Value::try_from_iter([("ID", "rg0"), ("PL", "")])?,
         );
 
-        assert!(matches!(
+        assert_eq!(
             ReadGroup::try_from(record),
-            Err(TryFromRecordError::InvalidPlatform(_))
-        ));
+            Err(TryFromRecordError::InvalidPlatform(
+                platform::ParseError::Empty
+            ))
+        );
 
         Ok(())
     }
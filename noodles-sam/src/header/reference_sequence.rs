@@ -8,7 +8,9 @@ pub mod molecule_topology;
 pub mod name;
 pub mod tag;
 
-use std::{collections::HashMap, error, fmt};
+use std::{error, fmt};
+
+use indexmap::IndexMap;
 
 pub use self::{
     alternative_locus::AlternativeLocus, alternative_names::AlternativeNames, builder::Builder,
@@ -28,6 +30,10 @@ const MIN_LENGTH: i32 = 1;
 /// sequence name and length are guaranteed to be set.
 ///
 /// A list of reference sequences creates a reference sequence dictionary.
+///
+/// When written, the standard tags are always in a fixed order (SN, LN, AH, AN, AS, DS, M5, SP,
+/// TP, UR), regardless of the order they were read. Only the remaining [`Self::fields`] keep
+/// their original order.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReferenceSequence {
     name: Name,
@@ -40,7 +46,7 @@ pub struct ReferenceSequence {
     species: Option<String>,
     molecule_topology: Option<MoleculeTopology>,
     uri: Option<String>,
-    fields: HashMap<Tag, String>,
+    fields: IndexMap<Tag, String>,
 }
 
 /// An error returned when a SAM header reference sequence fails to construct.
@@ -102,7 +108,7 @@ impl ReferenceSequence {
             species: None,
             molecule_topology: None,
             uri: None,
-            fields: HashMap::new(),
+            fields: IndexMap::new(),
         })
     }
 
@@ -307,6 +313,8 @@ impl ReferenceSequence {
     /// this will not include the name and length fields, as they are parsed and available as
     /// [`Self::name`] and [`Self::len`], respectively.
     ///
+    /// The fields are in insertion order, e.g., the order they were read.
+    ///
     /// # Examples
     ///
     /// ```
@@ -332,7 +340,7 @@ impl ReferenceSequence {
     /// assert_eq!(reference_sequence.len(), 13);
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
-    pub fn fields(&self) -> &HashMap<Tag, String> {
+    pub fn fields(&self) -> &IndexMap<Tag, String> {
         &self.fields
     }
 }
@@ -528,6 +536,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_record_for_reference_sequence_with_all_tags(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::new(
+            record::Kind::ReferenceSequence,
+            record::Value::try_from_iter([
+                ("zn", "noodles"),
+                ("UR", "file:///tmp/ref.fasta"),
+                ("TP", "linear"),
+                ("SP", "human"),
+                ("LN", "13"),
+                ("M5", "d7eba311421bbc9d3ada44709dd61534"),
+                ("za", "sam"),
+                ("DS", "noodles"),
+                ("AS", "ndls"),
+                ("SN", "sq0"),
+                ("AN", "0,SQ.0"),
+                ("AH", "sq1:1-8"),
+            ])?,
+        );
+
+        let reference_sequence = ReferenceSequence::try_from(record)?;
+
+        assert_eq!(
+            reference_sequence.alternative_locus(),
+            Some(&AlternativeLocus::Region(String::from("sq1"), Some((1, 8))))
+        );
+        assert_eq!(
+            reference_sequence.alternative_names(),
+            Some(&"0,SQ.0".parse()?)
+        );
+        assert_eq!(reference_sequence.assembly_id(), Some("ndls"));
+        assert_eq!(reference_sequence.description(), Some("noodles"));
+        assert_eq!(
+            reference_sequence.md5_checksum(),
+            Some("d7eba311421bbc9d3ada44709dd61534".parse()?)
+        );
+        assert_eq!(reference_sequence.species(), Some("human"));
+        assert_eq!(
+            reference_sequence.molecule_topology(),
+            Some(MoleculeTopology::Linear)
+        );
+        assert_eq!(reference_sequence.uri(), Some("file:///tmp/ref.fasta"));
+
+        // Standard tags are written in a fixed order; other fields keep their read order.
+        assert_eq!(
+            reference_sequence.to_string(),
+            "@SQ\tSN:sq0\tLN:13\tAH:sq1:1-8\tAN:0,SQ.0\tAS:ndls\tDS:noodles\tM5:d7eba311421bbc9d3ada44709dd61534\tSP:human\tTP:linear\tUR:file:///tmp/ref.fasta\tzn:noodles\tza:sam"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_for_reference_sequence_with_invalid_record() {
         let record = Record::new(
@@ -1,6 +1,8 @@
 //! SAM header reference sequence builder.
 
-use std::{collections::HashMap, error, fmt};
+use std::{error, fmt};
+
+use indexmap::IndexMap;
 
 use super::{
     AlternativeLocus, AlternativeNames, Md5Checksum, MoleculeTopology, Name, ReferenceSequence,
@@ -20,7 +22,7 @@ pub struct Builder {
     species: Option<String>,
     molecule_topology: Option<MoleculeTopology>,
     uri: Option<String>,
-    fields: HashMap<Tag, String>,
+    fields: IndexMap<Tag, String>,
 }
 
 /// An error returned when a SAM header reference sequence fails to build.
@@ -36,15 +36,19 @@ mod r#async;
 
 mod alignment_reader;
 mod alignment_record;
+pub mod alignment_records_ext;
 mod alignment_writer;
+mod dict;
 pub mod header;
 pub mod reader;
 pub mod record;
+pub mod record_ext;
 mod writer;
 
 pub use self::{
     alignment_reader::AlignmentReader, alignment_record::AlignmentRecord,
-    alignment_writer::AlignmentWriter, header::Header, reader::Reader, record::Record,
+    alignment_records_ext::AlignmentRecordsExt, alignment_writer::AlignmentWriter,
+    dict::create_dict, header::Header, reader::Reader, record::Record, record_ext::RecordExt,
     writer::Writer,
 };
 
@@ -9,7 +9,7 @@ use std::io::{self, BufRead, Read, Seek};
 use noodles_bgzf as bgzf;
 use noodles_fasta as fasta;
 
-use super::{AlignmentReader, AlignmentRecord, Header};
+use super::{record::parse_into, AlignmentReader, AlignmentRecord, Header, Record};
 
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
@@ -46,6 +46,7 @@ const HEADER_PREFIX: u8 = b'@';
 #[derive(Debug)]
 pub struct Reader<R> {
     inner: R,
+    buf: String,
 }
 
 impl<R> Reader<R>
@@ -66,7 +67,10 @@ where
     /// let reader = sam::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            buf: String::new(),
+        }
     }
 
     /// Returns a reference to the underlying reader.
@@ -149,6 +153,7 @@ where
     ///
     /// It is more ergonomic to read records using an iterator (see [`Self::records`]), but using
     /// this method allows control of the line buffer and whether the raw record should be parsed.
+    /// To parse records without allocating a new record each time, see [`Self::read_record`].
     ///
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
@@ -167,21 +172,73 @@ where
     /// reader.read_header()?;
     ///
     /// let mut buf = String::new();
-    /// reader.read_record(&mut buf)?;
+    /// reader.read_line(&mut buf)?;
     /// assert_eq!(buf, "*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*");
     ///
-    /// assert_eq!(reader.read_record(&mut buf)?, 0);
+    /// assert_eq!(reader.read_line(&mut buf)?, 0);
     /// # Ok::<(), io::Error>(())
     /// ```
-    pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
+    pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
         read_line(&mut self.inner, buf)
     }
 
+    /// Reads a single record.
+    ///
+    /// The record is parsed into the given record, overwriting all of its fields and reusing the
+    /// allocations of its read name, CIGAR, sequence, quality scores, and data. This avoids an
+    /// allocation per record when reading many records in a loop.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
+    /// stream reached EOF, and the record is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, AlignmentRecord};
+    ///
+    /// let data = b"@HD\tVN:1.6
+    /// r0\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS
+    /// *\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ";
+    ///
+    /// let mut reader = sam::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut record = sam::Record::default();
+    ///
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(record.sequence().len(), 4);
+    ///
+    /// reader.read_record(&mut record)?;
+    /// assert!(record.read_name().is_none());
+    /// assert!(record.sequence().is_empty());
+    ///
+    /// assert_eq!(reader.read_record(&mut record)?, 0);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        self.buf.clear();
+
+        match read_line(&mut self.inner, &mut self.buf)? {
+            0 => Ok(0),
+            n => {
+                parse_into(&self.buf, record)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                Ok(n)
+            }
+        }
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
     ///
-    /// Unlike [`Self::read_record`], each record is parsed as a [`crate::Record`].
+    /// Each record is read using [`Self::read_record`] into a newly allocated [`crate::Record`].
+    /// Use [`Self::read_record`] directly to reuse a single record buffer.
     ///
     /// # Examples
     ///
@@ -342,6 +399,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_record() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"r0\t99\tsq0\t1\t60\t2M1I1M\t=\t9\t12\tACGT\tNDLS\tNH:i:1\tCO:Z:noodles
+*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+r1\t0\tsq1\t3\t30\t2M\t*\t0\t0\tTG\t*
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut record = Record::default();
+
+        for line in std::str::from_utf8(data)?.lines() {
+            assert!(reader.read_record(&mut record)? > 0);
+            let expected: Record = line.parse()?;
+            assert_eq!(record, expected);
+        }
+
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
@@ -9,7 +9,6 @@ use super::Reader;
 /// This is created by calling [`Reader::records`].
 pub struct Records<'a, R> {
     inner: &'a mut Reader<R>,
-    line_buf: String,
 }
 
 impl<'a, R> Records<'a, R>
@@ -17,10 +16,7 @@ where
     R: BufRead,
 {
     pub(crate) fn new(inner: &'a mut Reader<R>) -> Self {
-        Self {
-            inner,
-            line_buf: String::new(),
-        }
+        Self { inner }
     }
 }
 
@@ -31,15 +27,11 @@ where
     type Item = io::Result<Record>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.line_buf.clear();
+        let mut record = Record::default();
 
-        match self.inner.read_record(&mut self.line_buf) {
+        match self.inner.read_record(&mut record) {
             Ok(0) => None,
-            Ok(_) => Some(
-                self.line_buf
-                    .parse()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
-            ),
+            Ok(_) => Some(Ok(record)),
             Err(e) => Some(Err(e)),
         }
     }
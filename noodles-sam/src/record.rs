@@ -2,13 +2,16 @@
 
 pub mod builder;
 pub mod cigar;
+mod clip;
 pub mod data;
 mod field;
-mod flags;
+pub mod flags;
+mod left_align;
 pub mod mapping_quality;
 mod parser;
 pub mod quality_scores;
 pub mod read_name;
+mod reference_position;
 pub mod reference_sequence_name;
 pub mod sequence;
 
@@ -18,9 +21,12 @@ pub use self::{
     read_name::ReadName, reference_sequence_name::ReferenceSequenceName, sequence::Sequence,
 };
 
-use std::{fmt, io, str::FromStr};
+pub(crate) use self::parser::parse_into;
+
+use std::{fmt, io, ops::RangeBounds, str::FromStr};
 
 use noodles_core::Position;
+use noodles_fasta as fasta;
 
 use super::{
     header::{ReferenceSequence, ReferenceSequences},
@@ -429,6 +435,258 @@ impl Record {
     pub fn data_mut(&mut self) -> &mut Data {
         &mut self.data
     }
+
+    /// Sets the mate CIGAR (`MC`) data field from the given mate.
+    ///
+    /// The field is set to the CIGAR string of the mate. If the mate has no CIGAR operations,
+    /// e.g., it is unmapped, the field is removed.
+    ///
+    /// The mate CIGAR can be read back using [`crate::RecordExt::mate_cigar`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let mate = sam::Record::builder().set_cigar("8M2S".parse()?).build();
+    ///
+    /// let mut record = sam::Record::default();
+    /// record.set_mate_cigar(&mate);
+    ///
+    /// assert_eq!(record.mate_cigar(), Ok("8M2S".parse()?));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_mate_cigar<R>(&mut self, mate: &R)
+    where
+        R: AlignmentRecord + ?Sized,
+    {
+        use self::data::field::{Tag, Value};
+
+        let cigar = mate.cigar();
+
+        if cigar.is_empty() {
+            self.data.remove(Tag::MateCigar);
+        } else {
+            let field = data::Field::new(Tag::MateCigar, Value::String(cigar.to_string()));
+            self.data.insert(field);
+        }
+    }
+
+    /// Clips the alignment to the given reference interval.
+    ///
+    /// Aligned bases (and insertions) outside the interval are converted to clips of the given
+    /// kind, which must be either a soft clip ([`cigar::op::Kind::SoftClip`]) or a hard clip
+    /// ([`cigar::op::Kind::HardClip`]). When hard clipping, the clipped bases are also removed
+    /// from the sequence and quality scores. The alignment start is moved to the first aligned
+    /// base within the interval.
+    ///
+    /// An unmapped record (i.e., a record without a position) is not modified.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the clip kind is not a soft or hard clip or if the alignment does
+    /// not overlap the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::cigar::op::Kind, AlignmentRecord};
+    ///
+    /// let mut record = sam::Record::builder()
+    ///     .set_position(Position::try_from(3)?)
+    ///     .set_cigar("8M".parse()?)
+    ///     .set_sequence("ACGTACGT".parse()?)
+    ///     .build();
+    ///
+    /// let start = Position::try_from(5)?;
+    /// let end = Position::try_from(8)?;
+    /// record.clip_to_interval(start..=end, Kind::HardClip)?;
+    ///
+    /// assert_eq!(record.position(), Some(start));
+    /// assert_eq!(record.cigar().to_string(), "2H4M2H");
+    /// assert_eq!(record.sequence().to_string(), "GTAC");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn clip_to_interval<B>(&mut self, interval: B, kind: cigar::op::Kind) -> io::Result<()>
+    where
+        B: RangeBounds<Position>,
+    {
+        clip::clip_to_interval(self, interval, kind)
+    }
+
+    /// Shifts insertions and deletions as far left as the reference sequence allows.
+    ///
+    /// An insertion or deletion that is preceded by an alignment match (`M`) is moved left while
+    /// doing so does not change the aligned bases, e.g., within a homopolymer or tandem repeat.
+    /// This gives a canonical alignment, the same as VCF normalization does for variants. The
+    /// preceding alignment match always keeps at least one base, and insertions are not moved if
+    /// the record has no sequence.
+    ///
+    /// The given reference sequence is the sequence of the record's reference sequence.
+    ///
+    /// An unmapped record (i.e., a record without a position) is not modified.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a deletion is outside of the reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_fasta as fasta;
+    /// use noodles_sam::{self as sam, AlignmentRecord};
+    ///
+    /// let reference_sequence = fasta::record::Sequence::from(b"GACACACTTG".to_vec());
+    ///
+    /// let mut record = sam::Record::builder()
+    ///     .set_position(Position::MIN)
+    ///     .set_cigar("5M2D3M".parse()?)
+    ///     .set_sequence("GACACTTG".parse()?)
+    ///     .build();
+    ///
+    /// record.left_align_indels(&reference_sequence)?;
+    /// assert_eq!(record.cigar().to_string(), "1M2D7M");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn left_align_indels(
+        &mut self,
+        reference_sequence: &fasta::record::Sequence,
+    ) -> io::Result<()> {
+        left_align::left_align_indels(self, reference_sequence)
+    }
+
+    /// Returns the reference position aligned to the given read offset.
+    ///
+    /// The read offset is the 0-based index of a base in the sequence. Hard clipped bases are not
+    /// part of the sequence and are not counted.
+    ///
+    /// This returns `None` if the record is unmapped, the base is inserted (`I`) or soft clipped
+    /// (`S`), or the offset is past the end of the alignment.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the CIGAR has a back (`B`) operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::Flags};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_cigar("1S2M1I1M".parse()?)
+    ///     .build();
+    ///
+    /// assert!(record.reference_position_at(0)?.is_none());
+    /// assert_eq!(record.reference_position_at(2)?, Some(Position::try_from(9)?));
+    /// assert!(record.reference_position_at(3)?.is_none());
+    /// assert_eq!(record.reference_position_at(4)?, Some(Position::try_from(10)?));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reference_position_at(&self, read_offset: usize) -> io::Result<Option<Position>> {
+        reference_position::reference_position_at(self, read_offset)
+    }
+
+    /// Returns the reference base aligned to the given read offset.
+    ///
+    /// The given reference sequence is the sequence of the record's reference sequence. See
+    /// [`Self::reference_position_at`] for when a read base is not aligned to a reference base.
+    /// This also returns `None` if the reference position is outside of the reference sequence.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the CIGAR has a back (`B`) operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_fasta as fasta;
+    /// use noodles_sam::{self as sam, record::Flags};
+    ///
+    /// let reference_sequence = fasta::record::Sequence::from(b"ACGTACGT".to_vec());
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_position(Position::try_from(2)?)
+    ///     .set_cigar("2M1I2M".parse()?)
+    ///     .set_sequence("CTAGT".parse()?)
+    ///     .build();
+    ///
+    /// // mismatch
+    /// assert_eq!(record.reference_base_at(1, &reference_sequence)?, Some(b'G'));
+    /// // insertion
+    /// assert!(record.reference_base_at(2, &reference_sequence)?.is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reference_base_at(
+        &self,
+        read_offset: usize,
+        reference_sequence: &fasta::record::Sequence,
+    ) -> io::Result<Option<u8>> {
+        self.reference_position_at(read_offset).map(|position| {
+            position
+                .and_then(|position| reference_sequence.get(position))
+                .copied()
+        })
+    }
+
+    /// Clips the region where this alignment and its mate overlap from one of the two records.
+    ///
+    /// This avoids counting the bases of a fragment twice, e.g., in a pileup. The record with the
+    /// lower mean base quality is clipped; on a tie, the mate is clipped. Only the overlapping
+    /// bases are clipped, using the given clip kind (see [`Self::clip_to_interval`]).
+    ///
+    /// If one alignment is contained in the other, or both alignment intervals are identical, the
+    /// contained record (or, if identical, the one that would be clipped) is entirely within the
+    /// overlap, regardless of its quality. All of its bases are clipped, and it keeps its position
+    /// and remains mapped, e.g., `2S4M2S` is soft clipped to `8S`.
+    ///
+    /// Nothing is clipped when either record is unmapped, the records are on different reference
+    /// sequences, or the alignments do not overlap.
+    ///
+    /// This returns whether a record was clipped.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the clip kind is not a soft or hard clip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::{cigar::op::Kind, Flags}, AlignmentRecord};
+    ///
+    /// let mut record = sam::Record::builder()
+    ///     .set_flags(Flags::SEGMENTED)
+    ///     .set_reference_sequence_name("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_cigar("8M".parse()?)
+    ///     .build();
+    ///
+    /// let mut mate = sam::Record::builder()
+    ///     .set_flags(Flags::SEGMENTED)
+    ///     .set_reference_sequence_name("sq0".parse()?)
+    ///     .set_position(Position::try_from(5)?)
+    ///     .set_cigar("8M".parse()?)
+    ///     .build();
+    ///
+    /// assert!(record.clip_mate_overlap(&mut mate, Kind::SoftClip)?);
+    /// assert_eq!(mate.position(), Some(Position::try_from(9)?));
+    /// assert_eq!(mate.cigar().to_string(), "4S4M");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn clip_mate_overlap(
+        &mut self,
+        mate: &mut Record,
+        kind: cigar::op::Kind,
+    ) -> io::Result<bool> {
+        clip::clip_overlapping_mates(self, mate, kind)
+    }
 }
 
 impl AlignmentRecord for Record {
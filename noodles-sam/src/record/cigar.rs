@@ -36,7 +36,8 @@ impl Cigar {
     ///
     /// This sums the lengths of the CIGAR operations that consume the reference sequence, i.e.,
     /// alignment matches (`M`), deletions from the reference (`D`), skipped reference regions
-    /// (`S`), sequence matches (`=`), and sequence mismatches (`X`).
+    /// (`S`), sequence matches (`=`), and sequence mismatches (`X`). Back (`B`) operations are not
+    /// counted (see [`Kind::Back`]).
     ///
     /// # Examples
     ///
@@ -59,7 +60,7 @@ impl Cigar {
                 | Kind::Skip
                 | Kind::SequenceMatch
                 | Kind::SequenceMismatch => Some(op.len()),
-                _ => None,
+                Kind::Insertion | Kind::SoftClip | Kind::HardClip | Kind::Pad | Kind::Back => None,
             })
             .sum()
     }
@@ -95,6 +96,83 @@ impl Cigar {
             })
             .sum()
     }
+
+    /// Calculates the aligned length.
+    ///
+    /// This sums the lengths of the CIGAR operations that align read bases to reference bases,
+    /// i.e., alignment matches (`M`), sequence matches (`=`), and sequence mismatches (`X`).
+    /// Unlike [`Self::read_len`], this excludes insertions and soft clips.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{cigar::{op::Kind, Op}, Cigar};
+    ///
+    /// let cigar = Cigar::from(vec![
+    ///     Op::new(Kind::SoftClip, 2),
+    ///     Op::new(Kind::Match, 36),
+    ///     Op::new(Kind::Insertion, 1),
+    ///     Op::new(Kind::SequenceMatch, 4),
+    /// ]);
+    ///
+    /// assert_eq!(cigar.aligned_len(), 40);
+    /// ```
+    pub fn aligned_len(&self) -> usize {
+        self.iter()
+            .filter_map(|op| match op.kind() {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => Some(op.len()),
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// Calculates the total soft clip length.
+    ///
+    /// This sums the lengths of all soft clip (`S`) operations, i.e., at both the start and end of
+    /// the alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Cigar;
+    ///
+    /// let cigar: Cigar = "10S80M10H".parse()?;
+    /// assert_eq!(cigar.soft_clip_len(), 10);
+    ///
+    /// let cigar: Cigar = "100M".parse()?;
+    /// assert_eq!(cigar.soft_clip_len(), 0);
+    /// # Ok::<_, noodles_sam::record::cigar::ParseError>(())
+    /// ```
+    pub fn soft_clip_len(&self) -> usize {
+        self.iter()
+            .filter(|op| op.kind() == Kind::SoftClip)
+            .map(|op| op.len())
+            .sum()
+    }
+
+    /// Calculates the total hard clip length.
+    ///
+    /// This sums the lengths of all hard clip (`H`) operations, i.e., at both the start and end of
+    /// the alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Cigar;
+    ///
+    /// let cigar: Cigar = "10S80M10H".parse()?;
+    /// assert_eq!(cigar.hard_clip_len(), 10);
+    ///
+    /// let cigar: Cigar = "100M".parse()?;
+    /// assert_eq!(cigar.hard_clip_len(), 0);
+    /// # Ok::<_, noodles_sam::record::cigar::ParseError>(())
+    /// ```
+    pub fn hard_clip_len(&self) -> usize {
+        self.iter()
+            .filter(|op| op.kind() == Kind::HardClip)
+            .map(|op| op.len())
+            .sum()
+    }
 }
 
 impl Deref for Cigar {
@@ -154,26 +232,34 @@ impl FromStr for Cigar {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(ParseError::Empty);
-        }
+        let mut cigar = Self::default();
+        parse_into(s, &mut cigar)?;
+        Ok(cigar)
+    }
+}
 
-        let mut ops = Vec::new();
+// Parses a raw CIGAR string into an existing CIGAR, reusing its buffer.
+pub(crate) fn parse_into(s: &str, cigar: &mut Cigar) -> Result<(), ParseError> {
+    let ops = &mut cigar.0;
+    ops.clear();
 
-        let matches = s.match_indices(|c: char| !c.is_ascii_digit());
-        let mut start = 0;
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
 
-        for (end, raw_kind) in matches {
-            let op = s[start..=end].parse().map_err(ParseError::InvalidOp)?;
-            ops.push(op);
-            start = end + raw_kind.len();
-        }
+    let matches = s.match_indices(|c: char| !c.is_ascii_digit());
+    let mut start = 0;
 
-        if start == s.len() {
-            Ok(Self::from(ops))
-        } else {
-            Err(ParseError::Invalid)
-        }
+    for (end, raw_kind) in matches {
+        let op = s[start..=end].parse().map_err(ParseError::InvalidOp)?;
+        ops.push(op);
+        start = end + raw_kind.len();
+    }
+
+    if start == s.len() {
+        Ok(())
+    } else {
+        Err(ParseError::Invalid)
     }
 }
 
@@ -190,6 +276,25 @@ mod tests {
         assert!(!cigar.is_empty());
     }
 
+    #[test]
+    fn test_reference_len() -> Result<(), ParseError> {
+        let cigar: Cigar = "36M".parse()?;
+        assert_eq!(cigar.reference_len(), 36);
+
+        let cigar: Cigar = "10M5I10M".parse()?;
+        assert_eq!(cigar.reference_len(), 20);
+
+        let cigar: Cigar = "5S20M5H".parse()?;
+        assert_eq!(cigar.reference_len(), 20);
+
+        let cigar: Cigar = "2P3=1X4D5N".parse()?;
+        assert_eq!(cigar.reference_len(), 13);
+
+        assert_eq!(Cigar::default().reference_len(), 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         let cigar = Cigar::default();
@@ -215,6 +320,15 @@ mod tests {
             ]))
         );
 
+        assert_eq!(
+            "4M1B4M".parse(),
+            Ok(Cigar::from(vec![
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::Back, 1),
+                Op::new(Kind::Match, 4),
+            ]))
+        );
+
         assert_eq!("".parse::<Cigar>(), Err(ParseError::Empty));
         assert_eq!("8M13".parse::<Cigar>(), Err(ParseError::Invalid));
 
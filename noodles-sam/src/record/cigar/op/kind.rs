@@ -27,6 +27,15 @@ pub enum Kind {
     SequenceMatch,
     /// A sequence mismatch (`X`).
     SequenceMismatch,
+    /// A move back on the reference (`B`).
+    ///
+    /// This is rarely used, and its effect on an alignment is not defined by the SAM specification.
+    /// It is read and written as is, but operations that walk an alignment, e.g., clipping or left
+    /// aligning indels, return an error when a CIGAR has a back operation.
+    ///
+    /// Like `bam_cigar2rlen` in htslib, CIGAR length calculations (e.g.,
+    /// [`crate::record::Cigar::reference_len`]) do not count it.
+    Back,
 }
 
 impl fmt::Display for Kind {
@@ -61,6 +70,7 @@ impl FromStr for Kind {
             "P" => Ok(Self::Pad),
             "=" => Ok(Self::SequenceMatch),
             "X" => Ok(Self::SequenceMismatch),
+            "B" => Ok(Self::Back),
             _ => Err(ParseError(s.into())),
         }
     }
@@ -78,6 +88,7 @@ impl From<Kind> for char {
             Kind::Pad => 'P',
             Kind::SequenceMatch => '=',
             Kind::SequenceMismatch => 'X',
+            Kind::Back => 'B',
         }
     }
 }
@@ -97,6 +108,7 @@ mod tests {
         assert_eq!(Kind::Pad.to_string(), "P");
         assert_eq!(Kind::SequenceMatch.to_string(), "=");
         assert_eq!(Kind::SequenceMismatch.to_string(), "X");
+        assert_eq!(Kind::Back.to_string(), "B");
     }
 
     #[test]
@@ -110,6 +122,7 @@ mod tests {
         assert_eq!("P".parse(), Ok(Kind::Pad));
         assert_eq!("=".parse(), Ok(Kind::SequenceMatch));
         assert_eq!("X".parse(), Ok(Kind::SequenceMismatch));
+        assert_eq!("B".parse(), Ok(Kind::Back));
 
         assert_eq!("".parse::<Kind>(), Err(ParseError(String::from(""))));
         assert_eq!("O".parse::<Kind>(), Err(ParseError(String::from("O"))));
@@ -126,5 +139,6 @@ mod tests {
         assert_eq!(char::from(Kind::Pad), 'P');
         assert_eq!(char::from(Kind::SequenceMatch), '=');
         assert_eq!(char::from(Kind::SequenceMismatch), 'X');
+        assert_eq!(char::from(Kind::Back), 'B');
     }
 }
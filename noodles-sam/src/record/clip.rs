@@ -0,0 +1,590 @@
+use std::{
+    io,
+    ops::{Bound, RangeBounds},
+};
+
+use noodles_core::Position;
+
+use super::{
+    cigar::{op::Kind, Op},
+    Record,
+};
+use crate::AlignmentRecord;
+
+// Bases removed from or clipped on one side of an alignment.
+#[derive(Debug, Default)]
+struct Clip {
+    hard_clip_len: usize,
+    soft_clip_len: usize,
+    len: usize,
+}
+
+impl Clip {
+    // Returns the (hard clip, soft clip) lengths after clipping and the number of bases to remove
+    // from the sequence.
+    fn resolve(&self, kind: Kind) -> (usize, usize, usize) {
+        match kind {
+            Kind::HardClip if self.len > 0 => {
+                let removed_len = self.soft_clip_len + self.len;
+                (self.hard_clip_len + removed_len, 0, removed_len)
+            }
+            Kind::HardClip => (self.hard_clip_len, self.soft_clip_len, 0),
+            _ => (self.hard_clip_len, self.soft_clip_len + self.len, 0),
+        }
+    }
+}
+
+pub(super) fn clip_to_interval<B>(record: &mut Record, interval: B, kind: Kind) -> io::Result<()>
+where
+    B: RangeBounds<Position>,
+{
+    if !matches!(kind, Kind::SoftClip | Kind::HardClip) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid clip kind: expected S or H, got {}", kind),
+        ));
+    }
+
+    let alignment_start = match record.position() {
+        Some(position) => usize::from(position),
+        None => return Ok(()),
+    };
+
+    let (start, end) = resolve_interval(interval);
+
+    let mut left_clip = Clip::default();
+    let mut right_clip = Clip::default();
+    let mut ops = Vec::new();
+    let mut new_alignment_start = None;
+    let mut reference_position = alignment_start;
+
+    for op in record.cigar().iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let (before_len, inner_len, after_len) =
+                    split_op(reference_position, len, start, end);
+
+                left_clip.len += before_len;
+                right_clip.len += after_len;
+
+                if inner_len > 0 {
+                    if new_alignment_start.is_none() {
+                        new_alignment_start = Some(reference_position + before_len);
+                    }
+
+                    ops.push(Op::new(op.kind(), inner_len));
+                }
+
+                reference_position += len;
+            }
+            Kind::Deletion | Kind::Skip => {
+                let (_, inner_len, _) = split_op(reference_position, len, start, end);
+
+                if new_alignment_start.is_some() && inner_len > 0 {
+                    ops.push(Op::new(op.kind(), inner_len));
+                }
+
+                reference_position += len;
+            }
+            Kind::Insertion | Kind::Pad => {
+                let read_len = if op.kind() == Kind::Insertion { len } else { 0 };
+
+                if new_alignment_start.is_none() {
+                    left_clip.len += read_len;
+                } else if reference_position > end {
+                    right_clip.len += read_len;
+                } else {
+                    ops.push(*op);
+                }
+            }
+            Kind::SoftClip => {
+                if new_alignment_start.is_none() {
+                    left_clip.soft_clip_len += len;
+                } else {
+                    right_clip.soft_clip_len += len;
+                }
+            }
+            Kind::HardClip => {
+                if new_alignment_start.is_none() {
+                    left_clip.hard_clip_len += len;
+                } else {
+                    right_clip.hard_clip_len += len;
+                }
+            }
+            Kind::Back => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "unsupported CIGAR operation: B",
+                ))
+            }
+        }
+    }
+
+    let new_alignment_start = new_alignment_start.and_then(Position::new).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "alignment does not overlap interval",
+        )
+    })?;
+
+    // Deletions, skips, and padding cannot end an alignment.
+    while let Some(op) = ops.last() {
+        if matches!(op.kind(), Kind::Deletion | Kind::Skip | Kind::Pad) {
+            ops.pop();
+        } else {
+            break;
+        }
+    }
+
+    let (left_hard_clip_len, left_soft_clip_len, left_removed_len) = left_clip.resolve(kind);
+    let (right_hard_clip_len, right_soft_clip_len, right_removed_len) = right_clip.resolve(kind);
+
+    let mut cigar_ops = Vec::with_capacity(ops.len() + 4);
+
+    if left_hard_clip_len > 0 {
+        cigar_ops.push(Op::new(Kind::HardClip, left_hard_clip_len));
+    }
+
+    if left_soft_clip_len > 0 {
+        cigar_ops.push(Op::new(Kind::SoftClip, left_soft_clip_len));
+    }
+
+    cigar_ops.extend(ops);
+
+    if right_soft_clip_len > 0 {
+        cigar_ops.push(Op::new(Kind::SoftClip, right_soft_clip_len));
+    }
+
+    if right_hard_clip_len > 0 {
+        cigar_ops.push(Op::new(Kind::HardClip, right_hard_clip_len));
+    }
+
+    *record.cigar_mut() = cigar_ops.into();
+    *record.position_mut() = Some(new_alignment_start);
+
+    trim(
+        record.sequence_mut().as_mut(),
+        left_removed_len,
+        right_removed_len,
+    );
+
+    trim(
+        record.quality_scores_mut().as_mut(),
+        left_removed_len,
+        right_removed_len,
+    );
+
+    Ok(())
+}
+
+pub(super) fn clip_overlapping_mates(
+    record: &mut Record,
+    mate: &mut Record,
+    kind: Kind,
+) -> io::Result<bool> {
+    if record.reference_sequence_name() != mate.reference_sequence_name() {
+        return Ok(false);
+    }
+
+    let (record_start, record_end) = match alignment_interval(record) {
+        Some(interval) => interval,
+        None => return Ok(false),
+    };
+
+    let (mate_start, mate_end) = match alignment_interval(mate) {
+        Some(interval) => interval,
+        None => return Ok(false),
+    };
+
+    let overlap_start = record_start.max(mate_start);
+    let overlap_end = record_end.min(mate_end);
+
+    if overlap_start > overlap_end {
+        return Ok(false);
+    }
+
+    // The mate with the lower mean base quality is clipped. On a tie, the mate is clipped.
+    let (first, second) = if mean_quality_score_cmp(record, mate).is_lt() {
+        (record, mate)
+    } else {
+        (mate, record)
+    };
+
+    for target in [&mut *first, &mut *second] {
+        if let Some((start, end)) = remaining_interval(target, overlap_start, overlap_end) {
+            let start = Position::new(start).expect("invalid start");
+            let end = Position::new(end).expect("invalid end");
+            clip_to_interval(target, start..=end, kind)?;
+            return Ok(true);
+        }
+    }
+
+    // Otherwise, one alignment is contained in the other, or both are identical. The containing
+    // record would have to be clipped in the middle, so the contained record (or, if identical,
+    // the one with the lower quality) is clipped entirely.
+    let overlap = Some((overlap_start, overlap_end));
+
+    if alignment_interval(first) == overlap {
+        clip_all(first, kind);
+    } else {
+        clip_all(second, kind);
+    }
+
+    Ok(true)
+}
+
+// Clips all bases of the alignment, keeping its position.
+fn clip_all(record: &mut Record, kind: Kind) {
+    let cigar = record.cigar();
+    let left_hard_clip_len = leading_hard_clip_len(cigar.iter());
+    let right_hard_clip_len = leading_hard_clip_len(cigar.iter().rev());
+    let read_len = cigar.read_len();
+
+    let mut ops = Vec::with_capacity(3);
+
+    if kind == Kind::HardClip {
+        ops.push(Op::new(
+            Kind::HardClip,
+            left_hard_clip_len + read_len + right_hard_clip_len,
+        ));
+
+        record.sequence_mut().as_mut().clear();
+        record.quality_scores_mut().as_mut().clear();
+    } else {
+        if left_hard_clip_len > 0 {
+            ops.push(Op::new(Kind::HardClip, left_hard_clip_len));
+        }
+
+        ops.push(Op::new(Kind::SoftClip, read_len));
+
+        if right_hard_clip_len > 0 {
+            ops.push(Op::new(Kind::HardClip, right_hard_clip_len));
+        }
+    }
+
+    *record.cigar_mut() = ops.into();
+}
+
+fn leading_hard_clip_len<'a, I>(ops: I) -> usize
+where
+    I: Iterator<Item = &'a Op>,
+{
+    ops.take_while(|op| op.kind() == Kind::HardClip)
+        .map(|op| op.len())
+        .sum()
+}
+
+// Returns the inclusive 1-based start and end of the alignment.
+fn alignment_interval(record: &Record) -> Option<(usize, usize)> {
+    if record.flags().is_unmapped() {
+        return None;
+    }
+
+    record
+        .alignment_start()
+        .zip(record.alignment_end())
+        .map(|(start, end)| (usize::from(start), usize::from(end)))
+}
+
+// Compares the mean quality scores of two records without computing the quotients.
+fn mean_quality_score_cmp(a: &Record, b: &Record) -> std::cmp::Ordering {
+    fn sum(record: &Record) -> u64 {
+        record
+            .quality_scores()
+            .as_ref()
+            .iter()
+            .map(|&score| u64::from(u8::from(score)))
+            .sum()
+    }
+
+    let a_len = a.quality_scores().len() as u64;
+    let b_len = b.quality_scores().len() as u64;
+
+    match (a_len, b_len) {
+        (0, 0) => std::cmp::Ordering::Equal,
+        (0, _) => std::cmp::Ordering::Less,
+        (_, 0) => std::cmp::Ordering::Greater,
+        _ => (sum(a) * b_len).cmp(&(sum(b) * a_len)),
+    }
+}
+
+// Returns the part of the alignment outside the overlap, if it is a single nonempty interval.
+fn remaining_interval(
+    record: &Record,
+    overlap_start: usize,
+    overlap_end: usize,
+) -> Option<(usize, usize)> {
+    let (start, end) = alignment_interval(record)?;
+
+    match (start < overlap_start, end > overlap_end) {
+        (true, false) => Some((start, overlap_start - 1)),
+        (false, true) => Some((overlap_end + 1, end)),
+        _ => None,
+    }
+}
+
+// Returns the inclusive 1-based start and end of the given interval.
+fn resolve_interval<B>(interval: B) -> (usize, usize)
+where
+    B: RangeBounds<Position>,
+{
+    let start = match interval.start_bound() {
+        Bound::Included(position) => usize::from(*position),
+        Bound::Excluded(position) => usize::from(*position) + 1,
+        Bound::Unbounded => 1,
+    };
+
+    let end = match interval.end_bound() {
+        Bound::Included(position) => usize::from(*position),
+        Bound::Excluded(position) => usize::from(*position) - 1,
+        Bound::Unbounded => usize::MAX,
+    };
+
+    (start, end)
+}
+
+// Splits an operation that consumes the reference into lengths before, within, and after the
+// given interval.
+fn split_op(
+    reference_position: usize,
+    len: usize,
+    start: usize,
+    end: usize,
+) -> (usize, usize, usize) {
+    let before_len = start.saturating_sub(reference_position).min(len);
+    let after_len = (reference_position + len)
+        .saturating_sub(end.saturating_add(1))
+        .min(len - before_len);
+    let inner_len = len - before_len - after_len;
+    (before_len, inner_len, after_len)
+}
+
+fn trim<T>(buf: &mut Vec<T>, left_len: usize, right_len: usize) {
+    // A missing sequence or quality scores (`*`) is not trimmed.
+    if buf.is_empty() {
+        return;
+    }
+
+    let len = buf.len().saturating_sub(right_len);
+    buf.truncate(len);
+    buf.drain(..left_len.min(len));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Flags;
+
+    #[test]
+    fn test_clip_to_interval_with_hard_clips() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(5)?;
+        let end = Position::try_from(8)?;
+
+        // 3..=10
+        let mut record = Record::builder()
+            .set_position(Position::try_from(3)?)
+            .set_cigar("1S8M".parse()?)
+            .set_sequence("TACGTACGT".parse()?)
+            .build();
+        clip_to_interval(&mut record, start..=end, Kind::HardClip)?;
+        assert_eq!(record.position(), Some(start));
+        assert_eq!(record.cigar().to_string(), "3H4M2H");
+        assert_eq!(record.sequence().to_string(), "GTAC");
+        assert_eq!(record.cigar().read_len(), record.sequence().len());
+
+        // 2..=6 with an insertion
+        let mut record = Record::builder()
+            .set_position(Position::try_from(2)?)
+            .set_cigar("2M2I3M".parse()?)
+            .set_sequence("ACTTGTA".parse()?)
+            .build();
+        clip_to_interval(&mut record, start..=end, Kind::HardClip)?;
+        assert_eq!(record.position(), Some(start));
+        assert_eq!(record.cigar().to_string(), "5H2M");
+        assert_eq!(record.sequence().to_string(), "TA");
+
+        // already within the interval
+        let mut record = Record::builder()
+            .set_position(Position::try_from(5)?)
+            .set_cigar("2S2M".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .build();
+        clip_to_interval(&mut record, start..=end, Kind::HardClip)?;
+        assert_eq!(record.position(), Some(start));
+        assert_eq!(record.cigar().to_string(), "2S2M");
+        assert_eq!(record.sequence().to_string(), "ACGT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_to_interval_with_soft_clips() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(5)?;
+        let end = Position::try_from(8)?;
+
+        let mut record = Record::builder()
+            .set_position(Position::try_from(3)?)
+            .set_cigar("2H1S4M2D4M".parse()?)
+            .set_sequence("TACGTACGT".parse()?)
+            .build();
+        clip_to_interval(&mut record, start..=end, Kind::SoftClip)?;
+        assert_eq!(record.position(), Some(start));
+        assert_eq!(record.cigar().to_string(), "2H3S2M4S");
+        assert_eq!(record.sequence().to_string(), "TACGTACGT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_to_interval_with_no_overlap() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(21)?;
+        let end = Position::try_from(34)?;
+
+        let mut record = Record::builder()
+            .set_position(Position::try_from(3)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .build();
+        assert!(clip_to_interval(&mut record, start..=end, Kind::HardClip).is_err());
+
+        let mut record = Record::default();
+        clip_to_interval(&mut record, start..=end, Kind::HardClip)?;
+        assert_eq!(record, Record::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_overlapping_mates() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_mate(
+            position: usize,
+            quality_scores: &str,
+        ) -> Result<Record, Box<dyn std::error::Error>> {
+            Ok(Record::builder()
+                .set_flags(Flags::SEGMENTED)
+                .set_reference_sequence_name("sq0".parse()?)
+                .set_position(Position::try_from(position)?)
+                .set_cigar("8M".parse()?)
+                .set_sequence("ACGTACGT".parse()?)
+                .set_quality_scores(quality_scores.parse()?)
+                .build())
+        }
+
+        // 1..=8 and 5..=12, where the mate has the lower quality
+        let mut record = build_mate(1, "NNNNNNNN")?;
+        let mut mate = build_mate(5, "DDDDDDDD")?;
+        assert!(clip_overlapping_mates(
+            &mut record,
+            &mut mate,
+            Kind::SoftClip
+        )?);
+        assert_eq!(record, build_mate(1, "NNNNNNNN")?);
+        assert_eq!(mate.position(), Position::new(9));
+        assert_eq!(mate.cigar().to_string(), "4S4M");
+
+        // 1..=8 and 5..=12, where the record has the lower quality
+        let mut record = build_mate(1, "DDDDDDDD")?;
+        let mut mate = build_mate(5, "NNNNNNNN")?;
+        assert!(clip_overlapping_mates(
+            &mut record,
+            &mut mate,
+            Kind::HardClip
+        )?);
+        assert_eq!(record.position(), Position::new(1));
+        assert_eq!(record.cigar().to_string(), "4M4H");
+        assert_eq!(record.sequence().to_string(), "ACGT");
+        assert_eq!(record.quality_scores().to_string(), "DDDD");
+        assert_eq!(mate, build_mate(5, "NNNNNNNN")?);
+
+        // 1..=8 and 3..=6, where the mate is contained and has the lower quality
+        let mut record = build_mate(1, "NNNNNNNN")?;
+        let mut mate = build_mate(3, "DDDDDDDD")?;
+        *mate.cigar_mut() = "2S4M2S".parse()?;
+        assert!(clip_overlapping_mates(
+            &mut record,
+            &mut mate,
+            Kind::SoftClip
+        )?);
+        assert!(!mate.flags().is_unmapped());
+        assert_eq!(mate.position(), Position::new(3));
+        assert_eq!(mate.cigar().to_string(), "8S");
+        assert_eq!(mate.sequence().to_string(), "ACGTACGT");
+        assert_eq!(record, build_mate(1, "NNNNNNNN")?);
+
+        // 3..=6 and 1..=8, where the record is contained and has the higher quality
+        let mut record = build_mate(3, "NNNNNNNN")?;
+        *record.cigar_mut() = "1H2S4M2S".parse()?;
+        let mut mate = build_mate(1, "DDDDDDDD")?;
+        assert!(clip_overlapping_mates(
+            &mut record,
+            &mut mate,
+            Kind::HardClip
+        )?);
+        assert!(!record.flags().is_unmapped());
+        assert_eq!(record.position(), Position::new(3));
+        assert_eq!(record.cigar().to_string(), "9H");
+        assert!(record.sequence().is_empty());
+        assert!(record.quality_scores().is_empty());
+        assert_eq!(mate, build_mate(1, "DDDDDDDD")?);
+
+        // 1..=8 and 1..=8, where the records are identical
+        let mut record = build_mate(1, "NNNNNNNN")?;
+        let mut mate = build_mate(1, "NNNNNNNN")?;
+        assert!(clip_overlapping_mates(
+            &mut record,
+            &mut mate,
+            Kind::SoftClip
+        )?);
+        assert_eq!(record, build_mate(1, "NNNNNNNN")?);
+        assert!(!mate.flags().is_unmapped());
+        assert_eq!(mate.cigar().to_string(), "8S");
+
+        // no overlap
+        let mut record = build_mate(1, "NNNNNNNN")?;
+        let mut mate = build_mate(13, "DDDDDDDD")?;
+        assert!(!clip_overlapping_mates(
+            &mut record,
+            &mut mate,
+            Kind::SoftClip
+        )?);
+        assert_eq!(mate, build_mate(13, "DDDDDDDD")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_to_interval_with_invalid_kind() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(5)?;
+        let mut record = Record::builder()
+            .set_position(Position::try_from(3)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .build();
+
+        assert!(matches!(
+            clip_to_interval(&mut record, start.., Kind::Match),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_to_interval_with_back_op() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(5)?;
+        let mut record = Record::builder()
+            .set_position(Position::try_from(3)?)
+            .set_cigar("4M1B2M".parse()?)
+            .set_sequence("ACGTAC".parse()?)
+            .build();
+
+        assert!(matches!(
+            clip_to_interval(&mut record, start.., Kind::SoftClip),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -159,6 +159,25 @@ impl Data {
         self.fields.iter()
     }
 
+    /// Returns an iterator over all tag-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{data::{field::{Tag, Value}, Field}, Data};
+    ///
+    /// let nh = Field::new(Tag::AlignmentHitCount, Value::Int32(1));
+    /// let data = Data::try_from(vec![nh])?;
+    ///
+    /// let mut iter = data.iter();
+    /// assert_eq!(iter.next(), Some((Tag::AlignmentHitCount, &Value::Int32(1))));
+    /// assert!(iter.next().is_none());
+    /// # Ok::<_, noodles_sam::record::data::ParseError>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (field::Tag, &field::Value)> {
+        self.fields.iter().map(|field| (field.tag(), field.value()))
+    }
+
     /// Inserts a field into the data map.
     ///
     /// This uses the field tag as the key and field as the value.
@@ -251,13 +270,14 @@ impl Data {
         i.map(|j| {
             let removed_field = self.fields.swap_remove(j);
 
-            let swapped_field = &self.fields[j];
-            set_index(
-                &mut self.standard_field_indices,
-                &mut self.other_field_indices,
-                swapped_field.tag(),
-                j,
-            );
+            if let Some(swapped_field) = self.fields.get(j) {
+                set_index(
+                    &mut self.standard_field_indices,
+                    &mut self.other_field_indices,
+                    swapped_field.tag(),
+                    j,
+                );
+            }
 
             removed_field
         })
@@ -328,22 +348,29 @@ impl FromStr for Data {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Ok(Self::default());
-        }
-
         let mut data = Self::default();
+        parse_into(s, &mut data)?;
+        Ok(data)
+    }
+}
 
-        for s in s.split(DELIMITER) {
-            let field = s.parse().map_err(ParseError::InvalidField)?;
+// Parses raw data into existing data, reusing its buffers.
+pub(crate) fn parse_into(s: &str, data: &mut Data) -> Result<(), ParseError> {
+    data.clear();
 
-            if let Some(f) = data.insert(field) {
-                return Err(ParseError::DuplicateTag(f.tag()));
-            }
-        }
+    if s.is_empty() {
+        return Ok(());
+    }
 
-        Ok(data)
+    for s in s.split(DELIMITER) {
+        let field = s.parse().map_err(ParseError::InvalidField)?;
+
+        if let Some(f) = data.insert(field) {
+            return Err(ParseError::DuplicateTag(f.tag()));
+        }
     }
+
+    Ok(())
 }
 
 impl TryFrom<Vec<Field>> for Data {
@@ -484,4 +511,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_remove() -> Result<(), ParseError> {
+        let mut data = Data::try_from(vec![
+            Field::new(Tag::ReadGroup, Value::String(String::from("rg0"))),
+            Field::new(Tag::AlignmentHitCount, Value::Int32(1)),
+        ])?;
+
+        assert_eq!(
+            data.remove(Tag::AlignmentHitCount),
+            Some(Field::new(Tag::AlignmentHitCount, Value::Int32(1)))
+        );
+        assert_eq!(
+            data.remove(Tag::ReadGroup),
+            Some(Field::new(
+                Tag::ReadGroup,
+                Value::String(String::from("rg0"))
+            ))
+        );
+        assert!(data.is_empty());
+        assert!(data.remove(Tag::ReadGroup).is_none());
+
+        Ok(())
+    }
 }
@@ -1,3 +1,9 @@
+//! SAM record flags.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 bitflags::bitflags! {
     /// SAM record flags.
     #[derive(Default)]
@@ -47,6 +53,24 @@ bitflags::bitflags! {
 }
 
 impl Flags {
+    /// Creates a SAM record flags builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    ///
+    /// let flags = Flags::builder()
+    ///     .set_segmented(true)
+    ///     .set_first_segment(true)
+    ///     .build();
+    ///
+    /// assert_eq!(flags, Flags::SEGMENTED | Flags::FIRST_SEGMENT);
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Returns whether the `PAIRED` flag is set.
     ///
     /// # Examples
@@ -0,0 +1,234 @@
+//! SAM record flags builder.
+
+use super::Flags;
+
+/// A SAM record flags builder.
+///
+/// Each flag is unset by default.
+#[derive(Debug, Default)]
+pub struct Builder {
+    flags: Flags,
+}
+
+impl Builder {
+    /// Sets whether the read is segmented (`SEGMENTED`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_segmented(true).build();
+    /// assert_eq!(flags, Flags::SEGMENTED);
+    /// ```
+    pub fn set_segmented(mut self, value: bool) -> Self {
+        self.flags.set(Flags::SEGMENTED, value);
+        self
+    }
+
+    /// Sets whether each segment in the read is properly aligned (`PROPERLY_ALIGNED`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_properly_aligned(true).build();
+    /// assert_eq!(flags, Flags::PROPERLY_ALIGNED);
+    /// ```
+    pub fn set_properly_aligned(mut self, value: bool) -> Self {
+        self.flags.set(Flags::PROPERLY_ALIGNED, value);
+        self
+    }
+
+    /// Sets whether the read is unmapped (`UNMAPPED`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_unmapped(true).build();
+    /// assert_eq!(flags, Flags::UNMAPPED);
+    /// ```
+    pub fn set_unmapped(mut self, value: bool) -> Self {
+        self.flags.set(Flags::UNMAPPED, value);
+        self
+    }
+
+    /// Sets whether the mate is unmapped (`MATE_UNMAPPED`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_mate_unmapped(true).build();
+    /// assert_eq!(flags, Flags::MATE_UNMAPPED);
+    /// ```
+    pub fn set_mate_unmapped(mut self, value: bool) -> Self {
+        self.flags.set(Flags::MATE_UNMAPPED, value);
+        self
+    }
+
+    /// Sets whether the sequence is reverse complemented (`REVERSE_COMPLEMENTED`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_reverse_complemented(true).build();
+    /// assert_eq!(flags, Flags::REVERSE_COMPLEMENTED);
+    /// ```
+    pub fn set_reverse_complemented(mut self, value: bool) -> Self {
+        self.flags.set(Flags::REVERSE_COMPLEMENTED, value);
+        self
+    }
+
+    /// Sets whether the sequence of the mate is reverse complemented (`MATE_REVERSE_COMPLEMENTED`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_mate_reverse_complemented(true).build();
+    /// assert_eq!(flags, Flags::MATE_REVERSE_COMPLEMENTED);
+    /// ```
+    pub fn set_mate_reverse_complemented(mut self, value: bool) -> Self {
+        self.flags.set(Flags::MATE_REVERSE_COMPLEMENTED, value);
+        self
+    }
+
+    /// Sets whether the read is the first segment (`FIRST_SEGMENT`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_first_segment(true).build();
+    /// assert_eq!(flags, Flags::FIRST_SEGMENT);
+    /// ```
+    pub fn set_first_segment(mut self, value: bool) -> Self {
+        self.flags.set(Flags::FIRST_SEGMENT, value);
+        self
+    }
+
+    /// Sets whether the read is the last segment (`LAST_SEGMENT`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_last_segment(true).build();
+    /// assert_eq!(flags, Flags::LAST_SEGMENT);
+    /// ```
+    pub fn set_last_segment(mut self, value: bool) -> Self {
+        self.flags.set(Flags::LAST_SEGMENT, value);
+        self
+    }
+
+    /// Sets whether the read is a secondary alignment (`SECONDARY`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_secondary(true).build();
+    /// assert_eq!(flags, Flags::SECONDARY);
+    /// ```
+    pub fn set_secondary(mut self, value: bool) -> Self {
+        self.flags.set(Flags::SECONDARY, value);
+        self
+    }
+
+    /// Sets whether the read failed quality checks (`QC_FAIL`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_qc_fail(true).build();
+    /// assert_eq!(flags, Flags::QC_FAIL);
+    /// ```
+    pub fn set_qc_fail(mut self, value: bool) -> Self {
+        self.flags.set(Flags::QC_FAIL, value);
+        self
+    }
+
+    /// Sets whether the read is a PCR or optical duplicate (`DUPLICATE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_duplicate(true).build();
+    /// assert_eq!(flags, Flags::DUPLICATE);
+    /// ```
+    pub fn set_duplicate(mut self, value: bool) -> Self {
+        self.flags.set(Flags::DUPLICATE, value);
+        self
+    }
+
+    /// Sets whether the read is a supplementary alignment (`SUPPLEMENTARY`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().set_supplementary(true).build();
+    /// assert_eq!(flags, Flags::SUPPLEMENTARY);
+    /// ```
+    pub fn set_supplementary(mut self, value: bool) -> Self {
+        self.flags.set(Flags::SUPPLEMENTARY, value);
+        self
+    }
+
+    /// Builds SAM record flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Flags;
+    /// let flags = Flags::builder().build();
+    /// assert!(flags.is_empty());
+    /// ```
+    pub fn build(self) -> Flags {
+        self.flags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let flags = Builder::default()
+            .set_segmented(true)
+            .set_properly_aligned(true)
+            .set_mate_reverse_complemented(true)
+            .set_first_segment(true)
+            .build();
+
+        assert_eq!(u16::from(flags), 0x01 | 0x02 | 0x20 | 0x40);
+
+        let flags = Builder::default()
+            .set_unmapped(true)
+            .set_mate_unmapped(true)
+            .set_reverse_complemented(true)
+            .set_last_segment(true)
+            .set_secondary(true)
+            .set_qc_fail(true)
+            .set_duplicate(true)
+            .set_supplementary(true)
+            .build();
+
+        assert_eq!(
+            u16::from(flags),
+            0x04 | 0x08 | 0x10 | 0x80 | 0x0100 | 0x0200 | 0x0400 | 0x0800
+        );
+
+        let flags = Builder::default()
+            .set_duplicate(true)
+            .set_duplicate(false)
+            .build();
+
+        assert!(flags.is_empty());
+    }
+}
@@ -0,0 +1,199 @@
+use std::io;
+
+use noodles_fasta as fasta;
+
+use super::{
+    cigar::{op::Kind, Op},
+    Record,
+};
+use crate::AlignmentRecord;
+
+pub(super) fn left_align_indels(
+    record: &mut Record,
+    reference_sequence: &fasta::record::Sequence,
+) -> io::Result<()> {
+    let alignment_start = match record.position() {
+        Some(position) => usize::from(position),
+        None => return Ok(()),
+    };
+
+    let reference_sequence = reference_sequence.as_ref();
+    let bases: Vec<u8> = record
+        .sequence()
+        .as_ref()
+        .iter()
+        .map(|&base| u8::from(base))
+        .collect();
+
+    let mut ops: Vec<Op> = record.cigar().iter().copied().collect();
+
+    // 0-based
+    let mut reference_position = alignment_start - 1;
+    let mut read_position = 0;
+
+    let mut i = 0;
+
+    while i < ops.len() {
+        let op = ops[i];
+
+        match op.kind() {
+            Kind::Insertion | Kind::Deletion if i > 0 && ops[i - 1].kind() == Kind::Match => {
+                let prev_len = ops[i - 1].len();
+                let mut shift = 0;
+
+                // The preceding alignment match keeps at least one base.
+                while shift + 1 < prev_len {
+                    let can_shift = if op.kind() == Kind::Deletion {
+                        let a = reference_position - shift - 1;
+                        let b = reference_position - shift + op.len() - 1;
+
+                        match (reference_sequence.get(a), reference_sequence.get(b)) {
+                            (Some(x), Some(y)) => x.eq_ignore_ascii_case(y),
+                            _ => return Err(out_of_bounds_error()),
+                        }
+                    } else {
+                        let a = read_position - shift - 1;
+                        let b = read_position - shift + op.len() - 1;
+
+                        // Insertions cannot be moved without a sequence.
+                        match (bases.get(a), bases.get(b)) {
+                            (Some(x), Some(y)) => x == y,
+                            _ => false,
+                        }
+                    };
+
+                    if !can_shift {
+                        break;
+                    }
+
+                    shift += 1;
+                }
+
+                if shift > 0 {
+                    ops[i - 1] = Op::new(Kind::Match, prev_len - shift);
+                    reference_position -= shift;
+                    read_position -= shift;
+
+                    match ops.get(i + 1) {
+                        Some(next) if next.kind() == Kind::Match => {
+                            ops[i + 1] = Op::new(Kind::Match, next.len() + shift);
+                        }
+                        _ => ops.insert(i + 1, Op::new(Kind::Match, shift)),
+                    }
+                }
+
+                if op.kind() == Kind::Deletion {
+                    reference_position += op.len();
+                } else {
+                    read_position += op.len();
+                }
+            }
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                reference_position += op.len();
+                read_position += op.len();
+            }
+            Kind::Insertion | Kind::SoftClip => read_position += op.len(),
+            Kind::Deletion | Kind::Skip => reference_position += op.len(),
+            Kind::HardClip | Kind::Pad => {}
+            Kind::Back => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "unsupported CIGAR operation: B",
+                ))
+            }
+        }
+
+        i += 1;
+    }
+
+    *record.cigar_mut() = ops.into();
+
+    Ok(())
+}
+
+fn out_of_bounds_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "alignment is outside of the reference sequence",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_left_align_indels() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"GACACACTTG".to_vec());
+
+        // deletion of a repeat unit
+        let mut record = Record::builder()
+            .set_position(Position::try_from(1)?)
+            .set_cigar("5M2D3M".parse()?)
+            .set_sequence("GACACTTG".parse()?)
+            .build();
+        left_align_indels(&mut record, &reference_sequence)?;
+        assert_eq!(record.cigar().to_string(), "1M2D7M");
+
+        // insertion of a repeat unit
+        let mut record = Record::builder()
+            .set_position(Position::try_from(1)?)
+            .set_cigar("7M2I3M".parse()?)
+            .set_sequence("GACACACACTTG".parse()?)
+            .build();
+        left_align_indels(&mut record, &reference_sequence)?;
+        assert_eq!(record.cigar().to_string(), "1M2I9M");
+
+        let mut record = Record::builder()
+            .set_position(Position::try_from(8)?)
+            .set_cigar("2M2D".parse()?)
+            .set_sequence("TT".parse()?)
+            .build();
+        assert!(matches!(
+            left_align_indels(&mut record, &reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_left_align_indels_with_left_aligned_indels() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"GACACACTTG".to_vec());
+
+        let mut record = Record::builder()
+            .set_position(Position::try_from(1)?)
+            .set_cigar("1M2D7M".parse()?)
+            .set_sequence("GACACTTG".parse()?)
+            .build();
+        left_align_indels(&mut record, &reference_sequence)?;
+        assert_eq!(record.cigar().to_string(), "1M2D7M");
+
+        let mut record = Record::builder()
+            .set_position(Position::try_from(1)?)
+            .set_cigar("2S3M1I4M".parse()?)
+            .set_sequence("NNACACCTTG".parse()?)
+            .build();
+        left_align_indels(&mut record, &reference_sequence)?;
+        assert_eq!(record.cigar().to_string(), "2S3M1I4M");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_left_align_indels_with_multiple_indels() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"GACACTTTTGCA".to_vec());
+
+        let mut record = Record::builder()
+            .set_position(Position::try_from(1)?)
+            .set_cigar("3M2D3M1D3M".parse()?)
+            .set_sequence("GACTTTGCA".parse()?)
+            .build();
+        left_align_indels(&mut record, &reference_sequence)?;
+        assert_eq!(record.cigar().to_string(), "1M2D2M1D6M");
+
+        Ok(())
+    }
+}
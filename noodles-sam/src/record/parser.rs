@@ -88,58 +88,31 @@ impl fmt::Display for ParseError {
 }
 
 pub(super) fn parse(s: &str) -> Result<Record, ParseError> {
-    let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
-
-    let mut builder = Record::builder();
-
-    if let Some(qname) = parse_qname(&mut fields)? {
-        builder = builder.set_read_name(qname);
-    }
-
-    let flag = parse_flag(&mut fields)?;
-    builder = builder.set_flags(flag);
-
-    let rname = parse_rname(&mut fields)?;
-
-    if let Some(pos) = parse_pos(&mut fields)? {
-        builder = builder.set_position(pos);
-    }
-
-    let mapq = parse_mapq(&mut fields)?;
-
-    if let Some(mapping_quality) = mapq {
-        builder = builder.set_mapping_quality(mapping_quality);
-    }
-
-    let cigar = parse_cigar(&mut fields)?;
-    builder = builder.set_cigar(cigar);
-
-    if let Some(rnext) = parse_rnext(&mut fields, rname.as_ref())? {
-        builder = builder.set_mate_reference_sequence_name(rnext);
-    }
-
-    if let Some(reference_sequence_name) = rname {
-        builder = builder.set_reference_sequence_name(reference_sequence_name);
-    }
-
-    if let Some(pnext) = parse_pnext(&mut fields)? {
-        builder = builder.set_mate_position(pnext);
-    }
-
-    let tlen = parse_tlen(&mut fields)?;
-    builder = builder.set_template_length(tlen);
-
-    let seq = parse_seq(&mut fields)?;
-    builder = builder.set_sequence(seq);
-
-    let qual = parse_qual(&mut fields)?;
-    builder = builder.set_quality_scores(qual);
+    let mut record = Record::default();
+    parse_into(s, &mut record)?;
+    Ok(record)
+}
 
-    if let Some(data) = parse_data(&mut fields)? {
-        builder = builder.set_data(data);
-    }
+// Parses a raw SAM record into an existing record.
+//
+// Every field of the record is overwritten. The buffers of the read name, CIGAR, sequence,
+// quality scores, and data are reused.
+pub(crate) fn parse_into(s: &str, record: &mut Record) -> Result<(), ParseError> {
+    let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
 
-    let record = builder.build();
+    parse_qname_into(&mut fields, &mut record.read_name)?;
+    record.flags = parse_flag(&mut fields)?;
+    record.reference_sequence_name = parse_rname(&mut fields)?;
+    record.position = parse_pos(&mut fields)?;
+    record.mapping_quality = parse_mapq(&mut fields)?;
+    parse_cigar_into(&mut fields, &mut record.cigar)?;
+    record.mate_reference_sequence_name =
+        parse_rnext(&mut fields, record.reference_sequence_name.as_ref())?;
+    record.mate_position = parse_pnext(&mut fields)?;
+    record.template_length = parse_tlen(&mut fields)?;
+    parse_seq_into(&mut fields, &mut record.sequence)?;
+    parse_qual_into(&mut fields, &mut record.quality_scores)?;
+    parse_data_into(&mut fields, &mut record.data)?;
 
     // § 1.4 The alignment section: mandatory fields (2021-06-03): "If not a '*', the length of
     // the sequence must equal the sum of lengths of `M/I/S/=/X` operations in `CIGAR`."
@@ -169,7 +142,7 @@ pub(super) fn parse(s: &str) -> Result<Record, ParseError> {
         }
     }
 
-    Ok(record)
+    Ok(())
 }
 
 fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<&'a str, ParseError>
@@ -188,14 +161,24 @@ where
         .map(Flags::from)
 }
 
-fn parse_qname<'a, I>(fields: &mut I) -> Result<Option<ReadName>, ParseError>
+fn parse_qname_into<'a, I>(
+    fields: &mut I,
+    read_name: &mut Option<ReadName>,
+) -> Result<(), ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
-    parse_string(fields, Field::Name).and_then(|s| match s {
-        NULL_FIELD => Ok(None),
-        _ => s.parse().map(Some).map_err(ParseError::InvalidReadName),
-    })
+    let s = parse_string(fields, Field::Name)?;
+
+    let mut buf = read_name.take().map(Vec::from).unwrap_or_default();
+
+    if s != NULL_FIELD {
+        buf.clear();
+        buf.extend(s.as_bytes());
+        *read_name = Some(ReadName::try_from(buf).map_err(ParseError::InvalidReadName)?);
+    }
+
+    Ok(())
 }
 
 fn parse_rname<'a, I>(fields: &mut I) -> Result<Option<ReferenceSequenceName>, ParseError>
@@ -231,14 +214,17 @@ where
     })
 }
 
-fn parse_cigar<'a, I>(fields: &mut I) -> Result<Cigar, ParseError>
+fn parse_cigar_into<'a, I>(fields: &mut I, cigar: &mut Cigar) -> Result<(), ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
-    parse_string(fields, Field::Cigar).and_then(|s| match s {
-        NULL_FIELD => Ok(Cigar::default()),
-        _ => s.parse().map_err(ParseError::InvalidCigar),
-    })
+    match parse_string(fields, Field::Cigar)? {
+        NULL_FIELD => {
+            cigar.as_mut().clear();
+            Ok(())
+        }
+        s => cigar::parse_into(s, cigar).map_err(ParseError::InvalidCigar),
+    }
 }
 
 fn parse_rnext<'a, I>(
@@ -275,34 +261,43 @@ where
         .and_then(|s| s.parse().map_err(ParseError::InvalidTemplateLength))
 }
 
-fn parse_seq<'a, I>(fields: &mut I) -> Result<Sequence, ParseError>
+fn parse_seq_into<'a, I>(fields: &mut I, sequence: &mut Sequence) -> Result<(), ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
-    parse_string(fields, Field::Sequence).and_then(|s| match s {
-        NULL_FIELD => Ok(Sequence::default()),
-        _ => s.parse().map_err(ParseError::InvalidSequence),
-    })
+    match parse_string(fields, Field::Sequence)? {
+        NULL_FIELD => {
+            sequence.as_mut().clear();
+            Ok(())
+        }
+        s => sequence::parse_into(s, sequence).map_err(ParseError::InvalidSequence),
+    }
 }
 
-fn parse_qual<'a, I>(fields: &mut I) -> Result<QualityScores, ParseError>
+fn parse_qual_into<'a, I>(
+    fields: &mut I,
+    quality_scores: &mut QualityScores,
+) -> Result<(), ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
-    parse_string(fields, Field::QualityScores).and_then(|s| match s {
-        NULL_FIELD => Ok(QualityScores::default()),
-        _ => s.parse().map_err(ParseError::InvalidQualityScores),
-    })
+    match parse_string(fields, Field::QualityScores)? {
+        NULL_FIELD => {
+            quality_scores.as_mut().clear();
+            Ok(())
+        }
+        s => {
+            quality_scores::parse_into(s, quality_scores).map_err(ParseError::InvalidQualityScores)
+        }
+    }
 }
 
-fn parse_data<'a, I>(fields: &mut I) -> Result<Option<Data>, ParseError>
+fn parse_data_into<'a, I>(fields: &mut I, data: &mut Data) -> Result<(), ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
-    fields
-        .next()
-        .map(|s| s.parse().map_err(ParseError::InvalidData))
-        .transpose()
+    let s = fields.next().unwrap_or_default();
+    data::parse_into(s, data).map_err(ParseError::InvalidData)
 }
 
 #[cfg(test)]
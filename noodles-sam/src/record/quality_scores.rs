@@ -1,8 +1,9 @@
 //! SAM record quality scores and score.
 
+pub mod recalibration_table;
 pub mod score;
 
-pub use self::score::Score;
+pub use self::{recalibration_table::RecalibrationTable, score::Score};
 
 use std::{
     error, fmt,
@@ -186,16 +187,27 @@ impl FromStr for QualityScores {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            Err(ParseError::Empty)
-        } else {
-            s.chars()
-                .map(Score::try_from)
-                .collect::<Result<Vec<_>, _>>()
-                .map(Self::from)
-                .map_err(ParseError::InvalidScore)
-        }
+        let mut quality_scores = Self::default();
+        parse_into(s, &mut quality_scores)?;
+        Ok(quality_scores)
+    }
+}
+
+// Parses raw quality scores into existing quality scores, reusing their buffer.
+pub(crate) fn parse_into(s: &str, quality_scores: &mut QualityScores) -> Result<(), ParseError> {
+    let scores = &mut quality_scores.0;
+    scores.clear();
+
+    if s.is_empty() {
+        return Err(ParseError::Empty);
     }
+
+    for c in s.chars() {
+        let score = Score::try_from(c).map_err(ParseError::InvalidScore)?;
+        scores.push(score);
+    }
+
+    Ok(())
 }
 
 impl TryFrom<Vec<u8>> for QualityScores {
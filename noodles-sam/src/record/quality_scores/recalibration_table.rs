@@ -0,0 +1,154 @@
+//! SAM record quality scores recalibration table.
+
+use std::collections::HashMap;
+
+use super::{score, Score};
+
+/// A base quality score recalibration table.
+///
+/// A recalibrated quality score is the reported quality score adjusted by the shifts of its
+/// reported quality score, its read cycle, and its sequence context. The result is clamped to the
+/// range of a [`Score`]. Any covariate without a shift is not adjusted, i.e., an empty table is
+/// the identity.
+///
+/// A read cycle is a 0-based position in a read in sequencing order. A sequence context is a
+/// dinucleotide of the previous base and the current base in sequencing order. The previous base
+/// of the first cycle is `N`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RecalibrationTable {
+    quality_score_shifts: HashMap<u8, i32>,
+    cycle_shifts: HashMap<usize, i32>,
+    context_shifts: HashMap<[u8; 2], i32>,
+}
+
+impl RecalibrationTable {
+    /// Sets the shift of a reported quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::quality_scores::{RecalibrationTable, Score};
+    ///
+    /// let mut table = RecalibrationTable::default();
+    /// table.set_quality_score_shift(Score::try_from(30u8)?, -2);
+    ///
+    /// assert_eq!(table.recalibrate(Score::try_from(30u8)?, 0, *b"AC"), Score::try_from(28u8)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_quality_score_shift(&mut self, quality_score: Score, shift: i32) {
+        self.quality_score_shifts
+            .insert(u8::from(quality_score), shift);
+    }
+
+    /// Sets the shift of a read cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::quality_scores::{RecalibrationTable, Score};
+    ///
+    /// let mut table = RecalibrationTable::default();
+    /// table.set_cycle_shift(0, 3);
+    ///
+    /// assert_eq!(table.recalibrate(Score::try_from(30u8)?, 0, *b"AC"), Score::try_from(33u8)?);
+    /// assert_eq!(table.recalibrate(Score::try_from(30u8)?, 1, *b"AC"), Score::try_from(30u8)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_cycle_shift(&mut self, cycle: usize, shift: i32) {
+        self.cycle_shifts.insert(cycle, shift);
+    }
+
+    /// Sets the shift of a dinucleotide sequence context.
+    ///
+    /// Bases are case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::quality_scores::{RecalibrationTable, Score};
+    ///
+    /// let mut table = RecalibrationTable::default();
+    /// table.set_context_shift(*b"GG", -5);
+    ///
+    /// assert_eq!(table.recalibrate(Score::try_from(30u8)?, 0, *b"gg"), Score::try_from(25u8)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_context_shift(&mut self, context: [u8; 2], shift: i32) {
+        self.context_shifts
+            .insert(normalize_context(context), shift);
+    }
+
+    /// Recalibrates a reported quality score at a read cycle with a sequence context.
+    pub fn recalibrate(&self, quality_score: Score, cycle: usize, context: [u8; 2]) -> Score {
+        let quality_score_shift = self
+            .quality_score_shifts
+            .get(&u8::from(quality_score))
+            .copied()
+            .unwrap_or(0);
+
+        let cycle_shift = self.cycle_shifts.get(&cycle).copied().unwrap_or(0);
+
+        let context_shift = self
+            .context_shifts
+            .get(&normalize_context(context))
+            .copied()
+            .unwrap_or(0);
+
+        let shift = quality_score_shift
+            .saturating_add(cycle_shift)
+            .saturating_add(context_shift);
+
+        let n = i32::from(u8::from(quality_score))
+            .saturating_add(shift)
+            .clamp(0, i32::from(score::MAX));
+
+        // The cast is lossless because `n` is clamped to [0, `score::MAX`].
+        Score(n as u8)
+    }
+}
+
+fn normalize_context(context: [u8; 2]) -> [u8; 2] {
+    [
+        context[0].to_ascii_uppercase(),
+        context[1].to_ascii_uppercase(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recalibrate() -> Result<(), Box<dyn std::error::Error>> {
+        let score = Score::try_from(30u8)?;
+
+        let table = RecalibrationTable::default();
+        assert_eq!(table.recalibrate(score, 8, *b"AC"), score);
+
+        let mut table = RecalibrationTable::default();
+        table.set_quality_score_shift(score, -2);
+        table.set_cycle_shift(8, 1);
+        table.set_context_shift(*b"AC", -4);
+
+        assert_eq!(table.recalibrate(score, 8, *b"ac"), Score::try_from(25u8)?);
+        assert_eq!(table.recalibrate(score, 0, *b"GT"), Score::try_from(28u8)?);
+
+        let mut table = RecalibrationTable::default();
+        table.set_cycle_shift(0, -50);
+        table.set_cycle_shift(1, 100);
+
+        assert_eq!(table.recalibrate(score, 0, *b"AC"), Score::try_from(0u8)?);
+        assert_eq!(table.recalibrate(score, 1, *b"AC"), Score::try_from(93u8)?);
+
+        let mut table = RecalibrationTable::default();
+        table.set_quality_score_shift(score, i32::MAX);
+        table.set_cycle_shift(0, i32::MAX);
+        table.set_context_shift(*b"AC", i32::MIN);
+
+        // The sum of the shifts saturates rather than overflows.
+        assert_eq!(table.recalibrate(score, 0, *b"GT"), Score::try_from(93u8)?);
+        assert_eq!(table.recalibrate(score, 1, *b"AC"), Score::try_from(29u8)?);
+
+        Ok(())
+    }
+}
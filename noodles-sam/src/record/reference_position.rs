@@ -0,0 +1,119 @@
+use std::io;
+
+use noodles_core::Position;
+
+use super::{cigar::op::Kind, Record};
+use crate::AlignmentRecord;
+
+pub(super) fn reference_position_at(
+    record: &Record,
+    read_offset: usize,
+) -> io::Result<Option<Position>> {
+    if record.flags().is_unmapped() {
+        return Ok(None);
+    }
+
+    if record.cigar().iter().any(|op| op.kind() == Kind::Back) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "unsupported CIGAR operation: B",
+        ));
+    }
+
+    let mut reference_position = match record.position() {
+        Some(position) => usize::from(position),
+        None => return Ok(None),
+    };
+
+    let mut read_position = 0;
+
+    for op in record.cigar().iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                if read_offset < read_position + len {
+                    return Ok(Position::new(
+                        reference_position + (read_offset - read_position),
+                    ));
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Insertion | Kind::SoftClip => {
+                // Inserted and soft clipped bases are not aligned to the reference.
+                if read_offset < read_position + len {
+                    return Ok(None);
+                }
+
+                read_position += len;
+            }
+            Kind::Deletion | Kind::Skip => reference_position += len,
+            Kind::HardClip | Kind::Pad => {}
+            // Back operations are rejected above.
+            Kind::Back => return Ok(None),
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Flags;
+
+    #[test]
+    fn test_reference_position_at() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_position(Position::try_from(8)?)
+            .set_cigar("2S3M1I2M2D1M1N2=".parse()?)
+            .build();
+
+        let expected = [
+            None,
+            None,
+            Position::new(8),
+            Position::new(9),
+            Position::new(10),
+            None,
+            Position::new(11),
+            Position::new(12),
+            Position::new(15),
+            Position::new(17),
+            Position::new(18),
+            None,
+        ];
+
+        for (read_offset, expected) in expected.into_iter().enumerate() {
+            assert_eq!(reference_position_at(&record, read_offset)?, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_position_at_with_unaligned_record() -> io::Result<()> {
+        let record = Record::default();
+        assert!(reference_position_at(&record, 0)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_position_at_with_back_operation() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_position(Position::try_from(8)?)
+            .set_cigar("2M1B2M".parse()?)
+            .build();
+
+        assert!(matches!(
+            reference_position_at(&record, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -128,6 +128,141 @@ impl Sequence {
     pub fn push(&mut self, base: Base) {
         self.0.push(base);
     }
+
+    /// Calculates the Shannon entropy of the bases in the sequence.
+    ///
+    /// The entropy is in bits. Each distinct base symbol is counted separately. For a sequence of
+    /// `A`, `C`, `G`, and `T` bases, this ranges from 0 (e.g., a homopolymer) to 2 (an equal
+    /// frequency of each base). An empty sequence has an entropy of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Sequence;
+    ///
+    /// let sequence: Sequence = "AAAA".parse()?;
+    /// assert_eq!(sequence.shannon_entropy(), 0.0);
+    ///
+    /// let sequence: Sequence = "ACGT".parse()?;
+    /// assert_eq!(sequence.shannon_entropy(), 2.0);
+    /// # Ok::<_, noodles_sam::record::sequence::ParseError>(())
+    /// ```
+    pub fn shannon_entropy(&self) -> f64 {
+        let mut counts = [0; 256];
+
+        for &base in &self.0 {
+            counts[usize::from(u8::from(base))] += 1;
+        }
+
+        entropy(&counts, self.0.len())
+    }
+
+    /// Calculates the Shannon entropy of the overlapping dinucleotides in the sequence.
+    ///
+    /// The entropy is in bits. For a sequence of `A`, `C`, `G`, and `T` bases, this ranges from 0
+    /// to 4. A sequence with fewer than 2 bases has an entropy of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Sequence;
+    ///
+    /// let sequence: Sequence = "CACACACA".parse()?;
+    /// assert!(sequence.dinucleotide_entropy() < 1.0);
+    /// # Ok::<_, noodles_sam::record::sequence::ParseError>(())
+    /// ```
+    pub fn dinucleotide_entropy(&self) -> f64 {
+        // `Base::Eq` is the last base.
+        const BASE_COUNT: usize = Base::Eq as usize + 1;
+
+        let mut counts = [[0; BASE_COUNT]; BASE_COUNT];
+
+        for window in self.0.windows(2) {
+            counts[window[0] as usize][window[1] as usize] += 1;
+        }
+
+        entropy(counts.iter().flatten(), self.0.len().saturating_sub(1))
+    }
+
+    /// Calculates the DUST score of the sequence.
+    ///
+    /// The DUST score measures low complexity by the repetition of triplets (Morgulis et al.,
+    /// 2006). For the `l` overlapping triplets of `A`, `C`, `G`, and `T` bases, where a triplet
+    /// `t` occurs `c_t` times, the score is `sum(c_t * (c_t - 1) / 2) / (l - 1)`. Triplets with
+    /// any other base are skipped.
+    ///
+    /// A homopolymer of length `n` has the maximum score of `(n - 2) / 2`, and a random sequence
+    /// has a score near 0. The default threshold of SDUST (20) marks a window as low complexity
+    /// when its score is greater than 2. A sequence with fewer than 2 triplets has a score of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::Sequence;
+    ///
+    /// let sequence: Sequence = "AAAAAAAA".parse()?;
+    /// assert_eq!(sequence.dust_score(), 3.0);
+    ///
+    /// let sequence: Sequence = "ACGTTGCA".parse()?;
+    /// assert_eq!(sequence.dust_score(), 0.0);
+    /// # Ok::<_, noodles_sam::record::sequence::ParseError>(())
+    /// ```
+    pub fn dust_score(&self) -> f64 {
+        const TRIPLET_COUNT: usize = 64;
+
+        let mut counts = [0u64; TRIPLET_COUNT];
+        let mut l = 0;
+
+        for window in self.0.windows(3) {
+            let i = window.iter().try_fold(0, |i, base| {
+                let n = match base {
+                    Base::A => 0,
+                    Base::C => 1,
+                    Base::G => 2,
+                    Base::T => 3,
+                    _ => return None,
+                };
+
+                Some((i << 2) | n)
+            });
+
+            if let Some(i) = i {
+                counts[i] += 1;
+                l += 1;
+            }
+        }
+
+        if l < 2 {
+            return 0.0;
+        }
+
+        let sum: u64 = counts.iter().map(|&c| c * c.saturating_sub(1) / 2).sum();
+
+        sum as f64 / (l - 1) as f64
+    }
+}
+
+fn entropy<'a, I>(counts: I, total: usize) -> f64
+where
+    I: IntoIterator<Item = &'a u64>,
+{
+    if total == 0 {
+        return 0.0;
+    }
+
+    let total = total as f64;
+
+    let sum: f64 = counts
+        .into_iter()
+        .filter(|&&c| c > 0)
+        .map(|&c| {
+            let p = c as f64 / total;
+            p * p.log2()
+        })
+        .sum();
+
+    // Avoid returning -0.0.
+    0.0 - sum
 }
 
 impl AsRef<[Base]> for Sequence {
@@ -182,14 +317,29 @@ impl FromStr for Sequence {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            Err(ParseError::Empty)
-        } else {
-            Self::try_from(s.as_bytes().to_vec())
-        }
+        let mut sequence = Self::default();
+        parse_into(s, &mut sequence)?;
+        Ok(sequence)
     }
 }
 
+// Parses a raw sequence into an existing sequence, reusing its buffer.
+pub(crate) fn parse_into(s: &str, sequence: &mut Sequence) -> Result<(), ParseError> {
+    let bases = &mut sequence.0;
+    bases.clear();
+
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    for &b in s.as_bytes() {
+        let base = Base::try_from(b).map_err(ParseError::InvalidBase)?;
+        bases.push(base);
+    }
+
+    Ok(())
+}
+
 impl<I> Index<I> for Sequence
 where
     I: SequenceIndex<Base>,
@@ -232,6 +382,47 @@ impl From<Sequence> for Vec<Base> {
 mod tests {
     use super::*;
 
+    const RANDOM_SEQUENCE: &str =
+        "GATTACAGCCTAGGCATCGTTAGCATGCAACGTGACTTGCAGTCAAGTCCGATAGGTCTACGGA";
+
+    #[test]
+    fn test_shannon_entropy() -> Result<(), ParseError> {
+        assert_eq!(Sequence::default().shannon_entropy(), 0.0);
+
+        let sequence: Sequence = "A".repeat(64).parse()?;
+        assert_eq!(sequence.shannon_entropy(), 0.0);
+        assert_eq!(sequence.dinucleotide_entropy(), 0.0);
+
+        let sequence: Sequence = "AACC".parse()?;
+        assert_eq!(sequence.shannon_entropy(), 1.0);
+
+        let sequence: Sequence = RANDOM_SEQUENCE.parse()?;
+        assert!((sequence.shannon_entropy() - 1.997180).abs() < 1e-6);
+        assert!((sequence.dinucleotide_entropy() - 3.931756).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dust_score() -> Result<(), ParseError> {
+        assert_eq!(Sequence::default().dust_score(), 0.0);
+
+        let sequence: Sequence = "A".repeat(64).parse()?;
+        assert_eq!(sequence.dust_score(), 31.0);
+
+        let sequence: Sequence = "CA".repeat(10).parse()?;
+        assert!((sequence.dust_score() - 4.235294).abs() < 1e-6);
+
+        let sequence: Sequence = RANDOM_SEQUENCE.parse()?;
+        assert!((sequence.dust_score() - 0.409836).abs() < 1e-6);
+
+        // Triplets with an N are skipped.
+        let sequence: Sequence = "AAANAAA".parse()?;
+        assert_eq!(sequence.dust_score(), 1.0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         let sequence = Sequence::from(vec![Base::A, Base::T, Base::C, Base::G]);
@@ -64,6 +64,38 @@ pub enum Base {
     Eq,
 }
 
+impl Base {
+    /// Returns the complement of the base.
+    ///
+    /// This uses the IUPAC nucleotide complements. Uracil is complemented to adenine, and bases
+    /// without a complement, e.g., `N` and `=`, are their own complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::sequence::Base;
+    /// assert_eq!(Base::A.complement(), Base::T);
+    /// assert_eq!(Base::N.complement(), Base::N);
+    /// ```
+    pub fn complement(self) -> Self {
+        match self {
+            Self::A => Self::T,
+            Self::B => Self::V,
+            Self::C => Self::G,
+            Self::D => Self::H,
+            Self::G => Self::C,
+            Self::H => Self::D,
+            Self::K => Self::M,
+            Self::M => Self::K,
+            Self::R => Self::Y,
+            Self::T | Self::U => Self::A,
+            Self::V => Self::B,
+            Self::Y => Self::R,
+            b => b,
+        }
+    }
+}
+
 impl fmt::Display for Base {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_char(char::from(*self))
@@ -208,6 +240,15 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_complement() {
+        let bases = ALPHA_BASES.iter().map(|base| char::from(base.complement()));
+        let actual: String = bases.collect();
+        assert_eq!(actual, "TVGHEFCDIJMLKNOPQYSAABWXRZ");
+
+        assert_eq!(Base::Eq.complement(), Base::Eq);
+    }
+
     #[test]
     fn test_try_from_char_for_base() {
         for (c, &expected) in ('A'..='Z').zip(ALPHA_BASES) {
@@ -0,0 +1,872 @@
+//! Alignment record extensions.
+
+use std::{collections::BTreeMap, error, fmt, io, ops::RangeInclusive};
+
+use noodles_core::Position;
+
+use super::{
+    record::{
+        cigar::op::Kind,
+        data::field::{value::Type, Tag},
+        quality_scores::{score, RecalibrationTable, Score},
+        sequence::Base,
+        Cigar, QualityScores, Sequence,
+    },
+    AlignmentRecord,
+};
+
+/// An error returned when a typed data field fails to be read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TagError {
+    /// The field is missing.
+    Missing(Tag),
+    /// The field value has an unexpected type.
+    InvalidType(Tag, Type),
+    /// The field value is invalid.
+    InvalidValue(Tag),
+}
+
+impl error::Error for TagError {}
+
+impl fmt::Display for TagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(tag) => write!(f, "missing field: {}", tag),
+            Self::InvalidType(tag, ty) => write!(f, "invalid type for field {}: {:?}", tag, ty),
+            Self::InvalidValue(tag) => write!(f, "invalid value for field {}", tag),
+        }
+    }
+}
+
+/// An extension trait for typed access to common alignment record data fields.
+///
+/// This is implemented for all [`AlignmentRecord`]s.
+pub trait RecordExt: AlignmentRecord {
+    /// Returns the alignment score (`AS`).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the field is missing or is not an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_data("AS:i:57".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.alignment_score(), Ok(57));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn alignment_score(&self) -> Result<i64, TagError> {
+        get_int(self, Tag::AlignmentScore)
+    }
+
+    /// Returns the edit distance to the reference (`NM`).
+    ///
+    /// This is the number of mismatched, inserted, and deleted bases.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the field is missing or is not a nonnegative integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_data("NM:i:3".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.edit_distance(), Ok(3));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn edit_distance(&self) -> Result<u32, TagError> {
+        let tag = Tag::EditDistance;
+        get_int(self, tag).and_then(|n| u32::try_from(n).map_err(|_| TagError::InvalidValue(tag)))
+    }
+
+    /// Returns the number of mismatched bases.
+    ///
+    /// This is the edit distance (`NM`) less the number of inserted (`I`) and deleted (`D`) bases
+    /// in the CIGAR.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the edit distance is missing or invalid or if it is less than the
+    /// number of inserted and deleted bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_cigar("4M2I4M1D4M".parse()?)
+    ///     .set_data("NM:i:5".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.number_of_mismatches(), Ok(2));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn number_of_mismatches(&self) -> Result<u32, TagError> {
+        let edit_distance = self.edit_distance()?;
+
+        let indel_len: usize = self
+            .cigar()
+            .iter()
+            .filter(|op| matches!(op.kind(), Kind::Insertion | Kind::Deletion))
+            .map(|op| op.len())
+            .sum();
+
+        u32::try_from(indel_len)
+            .ok()
+            .and_then(|n| edit_distance.checked_sub(n))
+            .ok_or(TagError::InvalidValue(Tag::EditDistance))
+    }
+
+    /// Returns the mismatched positions string (`MD`).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the field is missing or is not a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_data("MD:Z:4A3".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.mismatch_string(), Ok("4A3"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn mismatch_string(&self) -> Result<&str, TagError> {
+        let tag = Tag::MismatchedPositions;
+        let value = self.data().get(tag).ok_or(TagError::Missing(tag))?.value();
+        value
+            .as_str()
+            .ok_or_else(|| TagError::InvalidType(tag, value.ty()))
+    }
+
+    /// Returns the mate CIGAR (`MC`).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the field is missing, is not a string, or is not a valid CIGAR
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_data("MC:Z:8M2S".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.mate_cigar(), Ok("8M2S".parse()?));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn mate_cigar(&self) -> Result<Cigar, TagError> {
+        let tag = Tag::MateCigar;
+        let value = self.data().get(tag).ok_or(TagError::Missing(tag))?.value();
+        value
+            .as_str()
+            .ok_or_else(|| TagError::InvalidType(tag, value.ty()))
+            .and_then(|s| s.parse().map_err(|_| TagError::InvalidValue(tag)))
+    }
+
+    /// Returns the length of the original read, including hard clips.
+    ///
+    /// This is the sequence length plus the number of hard clipped (`H`) bases in the CIGAR. If
+    /// the sequence is missing, the read length of the CIGAR is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_cigar("2H4M2H".parse()?)
+    ///     .set_sequence("ACGT".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.original_read_length(), 8);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn original_read_length(&self) -> usize {
+        let cigar = self.cigar();
+
+        let read_len = if self.sequence().is_empty() {
+            cigar.read_len()
+        } else {
+            self.sequence().len()
+        };
+
+        read_len + cigar.hard_clip_len()
+    }
+
+    /// Returns the fraction of the original read that is aligned.
+    ///
+    /// This is the aligned length of the CIGAR (see [`Cigar::aligned_len`]) divided by the
+    /// original read length, including hard clips (see [`Self::original_read_length`]).
+    ///
+    /// This returns `None` if the original read length is 0.
+    ///
+    /// [`Cigar::aligned_len`]: crate::record::Cigar::aligned_len
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_cigar("4M4S".parse()?)
+    ///     .set_sequence("ACGTACGT".parse()?)
+    ///     .build();
+    ///
+    /// assert_eq!(record.aligned_fraction(), Some(0.5));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn aligned_fraction(&self) -> Option<f64> {
+        match self.original_read_length() {
+            0 => None,
+            n => Some(self.cigar().aligned_len() as f64 / n as f64),
+        }
+    }
+
+    /// Returns the reference interval covered by the alignment.
+    ///
+    /// This is the closed interval from the alignment start to the alignment end, where the end
+    /// is the start plus the reference length of the CIGAR (see [`Cigar::reference_len`]) minus
+    /// 1. Clips, padding, and insertions do not count toward the reference length.
+    ///
+    /// Unlike [`AlignmentRecord::alignment_end`], this returns `None` if the record is unmapped,
+    /// has no CIGAR operations that consume the reference, or if the end position overflows.
+    ///
+    /// The interval can be used directly as a query interval, e.g., for a binning index.
+    ///
+    /// [`Cigar::reference_len`]: crate::record::Cigar::reference_len
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::Flags, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_cigar("5S20M5H".parse()?)
+    ///     .build();
+    ///
+    /// let interval = record.alignment_interval();
+    /// assert_eq!(interval, Some(Position::try_from(8)?..=Position::try_from(27)?));
+    ///
+    /// assert!(sam::Record::default().alignment_interval().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn alignment_interval(&self) -> Option<RangeInclusive<Position>> {
+        if self.flags().is_unmapped() {
+            return None;
+        }
+
+        let start = self.alignment_start()?;
+
+        let end = match self.cigar().reference_len() {
+            0 => return None,
+            len => start.checked_add(len - 1)?,
+        };
+
+        Some(start..=end)
+    }
+
+    /// Merges this record and its overlapping mate into a single fragment.
+    ///
+    /// The aligned bases of both mates are placed by reference position, and the merged sequence
+    /// and quality scores span both mates in reference order. Positions covered by only one mate
+    /// keep its base and score. Where both mates have a base, they are combined using the
+    /// base-agreement model of `fastp` and `bbmerge`:
+    ///
+    ///   * if the bases agree, the base is kept, and its score is the sum of the two scores, capped
+    ///     at 93; and
+    ///   * if the bases disagree, the base with the higher score is chosen (this record's base on a
+    ///     tie), and its score is the difference of the two scores.
+    ///
+    /// Soft clipped bases are not included. Inserted bases are kept after the position they
+    /// follow, preferring this record's insertion where both mates have one. A position deleted
+    /// in one mate takes the base of the other mate.
+    ///
+    /// Both records are assumed to be on the same reference sequence.
+    ///
+    /// This returns `None` if either record is unmapped, is missing its sequence or quality
+    /// scores, or if the aligned bases of the mates do not overlap.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the CIGAR of either record has a back (`B`) operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{self as sam, record::Flags, RecordExt};
+    ///
+    /// let record = sam::Record::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_cigar("4M".parse()?)
+    ///     .set_sequence("ACGT".parse()?)
+    ///     .set_quality_scores("5555".parse()?)
+    ///     .build();
+    ///
+    /// let mate = sam::Record::builder()
+    ///     .set_flags(Flags::empty())
+    ///     .set_position(Position::try_from(3)?)
+    ///     .set_cigar("4M".parse()?)
+    ///     .set_sequence("GAAC".parse()?)
+    ///     .set_quality_scores("?+55".parse()?)
+    ///     .build();
+    ///
+    /// let (sequence, quality_scores) = record.merge_mate(&mate)?.unwrap();
+    /// assert_eq!(sequence.to_string(), "ACGTAC");
+    /// assert_eq!(quality_scores.to_string(), "55S+55");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn merge_mate<M>(&self, mate: &M) -> io::Result<Option<(Sequence, QualityScores)>>
+    where
+        M: AlignmentRecord + ?Sized,
+    {
+        for cigar in [self.cigar(), mate.cigar()] {
+            if cigar.iter().any(|op| op.kind() == Kind::Back) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "unsupported CIGAR operation: B",
+                ));
+            }
+        }
+
+        let columns = aligned_columns(self);
+        let mate_columns = aligned_columns(mate);
+
+        Ok(columns
+            .zip(mate_columns)
+            .and_then(|(columns, mate_columns)| merge_columns(columns, mate_columns)))
+    }
+
+    /// Returns the quality scores recalibrated using the given table.
+    ///
+    /// Each score is recalibrated with its read cycle and dinucleotide sequence context in
+    /// sequencing order (see [`RecalibrationTable`]). For a reverse complemented record, cycles
+    /// are counted from the end of the read, and bases are complemented. A missing base is `N`.
+    ///
+    /// Use the record's mutable quality scores to replace them with the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     record::quality_scores::RecalibrationTable,
+    ///     AlignmentRecord, RecordExt,
+    /// };
+    ///
+    /// let mut record = sam::Record::builder()
+    ///     .set_sequence("ACGT".parse()?)
+    ///     .set_quality_scores("NDLS".parse()?)
+    ///     .build();
+    ///
+    /// let mut table = RecalibrationTable::default();
+    /// table.set_cycle_shift(0, -10);
+    ///
+    /// *record.quality_scores_mut() = record.recalibrated_quality_scores(&table);
+    /// assert_eq!(record.quality_scores().to_string(), "DDLS");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn recalibrated_quality_scores(&self, table: &RecalibrationTable) -> QualityScores {
+        const MISSING_BASE: u8 = b'N';
+
+        let sequence = self.sequence();
+        let quality_scores = self.quality_scores();
+        let len = quality_scores.len();
+        let is_reverse_complemented = self.flags().is_reverse_complemented();
+
+        let base = |i: usize| match sequence.as_ref().get(i) {
+            Some(b) if is_reverse_complemented => u8::from(b.complement()),
+            Some(b) => u8::from(*b),
+            None => MISSING_BASE,
+        };
+
+        let scores: Vec<_> = quality_scores
+            .as_ref()
+            .iter()
+            .enumerate()
+            .map(|(i, &score)| {
+                let (cycle, previous_base) = if is_reverse_complemented {
+                    let previous_base = if i + 1 < len {
+                        base(i + 1)
+                    } else {
+                        MISSING_BASE
+                    };
+
+                    (len - 1 - i, previous_base)
+                } else {
+                    let previous_base = if i > 0 { base(i - 1) } else { MISSING_BASE };
+                    (i, previous_base)
+                };
+
+                table.recalibrate(score, cycle, [previous_base, base(i)])
+            })
+            .collect();
+
+        QualityScores::from(scores)
+    }
+}
+
+impl<R> RecordExt for R where R: AlignmentRecord {}
+
+// The read bases aligned at a reference position.
+#[derive(Default)]
+struct Column {
+    // `None` is a deletion.
+    base: Option<(Base, Score)>,
+    // The bases inserted after this position.
+    insertion: Vec<(Base, Score)>,
+}
+
+// Returns the aligned bases of the record keyed by reference position.
+fn aligned_columns<R>(record: &R) -> Option<BTreeMap<usize, Column>>
+where
+    R: AlignmentRecord + ?Sized,
+{
+    if record.flags().is_unmapped() {
+        return None;
+    }
+
+    let sequence = record.sequence().as_ref();
+    let scores = record.quality_scores().as_ref();
+
+    if sequence.is_empty() || sequence.len() != scores.len() {
+        return None;
+    }
+
+    let mut columns: BTreeMap<usize, Column> = BTreeMap::new();
+    let mut reference_position = record.alignment_start().map(usize::from)?;
+    let mut i = 0;
+
+    for op in record.cigar().iter() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let bases = sequence.get(i..i + len)?.iter().copied();
+                let op_scores = scores.get(i..i + len)?.iter().copied();
+
+                for (j, base) in bases.zip(op_scores).enumerate() {
+                    columns.insert(
+                        reference_position + j,
+                        Column {
+                            base: Some(base),
+                            insertion: Vec::new(),
+                        },
+                    );
+                }
+
+                reference_position += len;
+                i += len;
+            }
+            Kind::Insertion => {
+                let bases = sequence.get(i..i + len)?.iter().copied();
+                let op_scores = scores.get(i..i + len)?.iter().copied();
+
+                // An insertion before the first aligned base has no position to follow.
+                if let Some(column) = reference_position
+                    .checked_sub(1)
+                    .and_then(|position| columns.get_mut(&position))
+                {
+                    column.insertion.extend(bases.zip(op_scores));
+                }
+
+                i += len;
+            }
+            Kind::Deletion => {
+                for j in 0..len {
+                    columns.insert(reference_position + j, Column::default());
+                }
+
+                reference_position += len;
+            }
+            Kind::Skip => reference_position += len,
+            Kind::SoftClip => i += len,
+            Kind::HardClip | Kind::Pad => {}
+            // Back operations are rejected by `RecordExt::merge_mate`.
+            Kind::Back => return None,
+        }
+    }
+
+    Some(columns)
+}
+
+fn merge_columns(
+    columns: BTreeMap<usize, Column>,
+    mate_columns: BTreeMap<usize, Column>,
+) -> Option<(Sequence, QualityScores)> {
+    let (first_start, first_end) = key_range(&columns)?;
+    let (mate_start, mate_end) = key_range(&mate_columns)?;
+
+    if first_end < mate_start || mate_end < first_start {
+        return None;
+    }
+
+    let mut merged = columns;
+
+    for (position, mate_column) in mate_columns {
+        let column = merged.entry(position).or_default();
+
+        column.base = match (column.base, mate_column.base) {
+            (Some(a), Some(b)) => Some(merge_bases(a, b)),
+            (a, b) => a.or(b),
+        };
+
+        if column.insertion.is_empty() {
+            column.insertion = mate_column.insertion;
+        }
+    }
+
+    let (bases, scores) = merged
+        .into_values()
+        .flat_map(|column| column.base.into_iter().chain(column.insertion))
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    Some((Sequence::from(bases), QualityScores::from(scores)))
+}
+
+fn key_range(columns: &BTreeMap<usize, Column>) -> Option<(usize, usize)> {
+    let (&start, _) = columns.iter().next()?;
+    let (&end, _) = columns.iter().next_back()?;
+    Some((start, end))
+}
+
+fn merge_bases((a, a_score): (Base, Score), (b, b_score): (Base, Score)) -> (Base, Score) {
+    let (m, n) = (u8::from(a_score), u8::from(b_score));
+
+    let (base, score) = if a == b {
+        (a, (m + n).min(score::MAX))
+    } else if m >= n {
+        (a, m - n)
+    } else {
+        (b, n - m)
+    };
+
+    // SAFETY: `score` <= `score::MAX`.
+    (base, Score::try_from(score).unwrap())
+}
+
+fn get_int<R>(record: &R, tag: Tag) -> Result<i64, TagError>
+where
+    R: AlignmentRecord + ?Sized,
+{
+    let value = record
+        .data()
+        .get(tag)
+        .ok_or(TagError::Missing(tag))?
+        .value();
+    value
+        .as_int()
+        .ok_or_else(|| TagError::InvalidType(tag, value.ty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Record;
+
+    #[test]
+    fn test_alignment_score() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder().set_data("AS:i:57".parse()?).build();
+        assert_eq!(record.alignment_score(), Ok(57));
+
+        let record = Record::builder().set_data("AS:i:-8".parse()?).build();
+        assert_eq!(record.alignment_score(), Ok(-8));
+
+        let record = Record::default();
+        assert_eq!(
+            record.alignment_score(),
+            Err(TagError::Missing(Tag::AlignmentScore))
+        );
+
+        let record = Record::builder().set_data("AS:Z:57".parse()?).build();
+        assert_eq!(
+            record.alignment_score(),
+            Err(TagError::InvalidType(Tag::AlignmentScore, Type::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_distance() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder().set_data("NM:i:-1".parse()?).build();
+        assert_eq!(
+            record.edit_distance(),
+            Err(TagError::InvalidValue(Tag::EditDistance))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_number_of_mismatches() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_cigar("4M2I4M".parse()?)
+            .set_data("NM:i:1".parse()?)
+            .build();
+
+        assert_eq!(
+            record.number_of_mismatches(),
+            Err(TagError::InvalidValue(Tag::EditDistance))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mismatch_string() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder().set_data("MD:i:8".parse()?).build();
+        assert_eq!(
+            record.mismatch_string(),
+            Err(TagError::InvalidType(Tag::MismatchedPositions, Type::UInt8))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mate_cigar() -> Result<(), Box<dyn std::error::Error>> {
+        let mate = Record::builder().set_cigar("5S90M2I3M".parse()?).build();
+
+        let mut record = Record::default();
+        record.set_mate_cigar(&mate);
+        assert_eq!(record.mate_cigar().as_ref(), Ok(mate.cigar()));
+
+        record.set_mate_cigar(&Record::default());
+        assert_eq!(record.mate_cigar(), Err(TagError::Missing(Tag::MateCigar)));
+
+        let record = Record::builder().set_data("MC:Z:8Q".parse()?).build();
+        assert_eq!(
+            record.mate_cigar(),
+            Err(TagError::InvalidValue(Tag::MateCigar))
+        );
+
+        let record = Record::builder().set_data("MC:i:8".parse()?).build();
+        assert_eq!(
+            record.mate_cigar(),
+            Err(TagError::InvalidType(Tag::MateCigar, Type::UInt8))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_original_read_length() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_cigar("5H90M5H".parse()?)
+            .set_sequence("A".repeat(90).parse()?)
+            .build();
+        assert_eq!(record.original_read_length(), 100);
+
+        let record = Record::builder().set_cigar("5H90M5H".parse()?).build();
+        assert_eq!(record.original_read_length(), 100);
+
+        let record = Record::builder()
+            .set_cigar("4M".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .build();
+        assert_eq!(record.original_read_length(), 4);
+
+        assert_eq!(Record::default().original_read_length(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recalibrated_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::{quality_scores::Score, Flags};
+
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_sequence("ACGT".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        let table = RecalibrationTable::default();
+        assert_eq!(
+            &record.recalibrated_quality_scores(&table),
+            record.quality_scores()
+        );
+
+        let mut table = RecalibrationTable::default();
+        table.set_quality_score_shift(Score::try_from('N')?, -1);
+        table.set_cycle_shift(3, 2);
+        table.set_context_shift(*b"CG", 4);
+        table.set_context_shift(*b"NA", -3);
+
+        // N (45) - 1 - 3, D (35), L (43) + 4, S (50) + 2
+        let expected: QualityScores = "JDPU".parse()?;
+        assert_eq!(record.recalibrated_quality_scores(&table), expected);
+
+        // In sequencing order, the read is ACGT with quality scores SLDN.
+        let record = Record::builder()
+            .set_flags(Flags::REVERSE_COMPLEMENTED)
+            .set_sequence("ACGT".parse()?)
+            .set_quality_scores("NDLS".parse()?)
+            .build();
+
+        // N (45) - 1 + 2, D (35) + 4, L (43), S (50) - 3
+        let expected: QualityScores = "OHLP".parse()?;
+        assert_eq!(record.recalibrated_quality_scores(&table), expected);
+
+        assert!(Record::default()
+            .recalibrated_quality_scores(&table)
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aligned_fraction() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_cigar("50M50S".parse()?)
+            .set_sequence("A".repeat(100).parse()?)
+            .build();
+        assert_eq!(record.aligned_fraction(), Some(0.5));
+
+        let record = Record::builder()
+            .set_cigar("100M".parse()?)
+            .set_sequence("A".repeat(100).parse()?)
+            .build();
+        assert_eq!(record.aligned_fraction(), Some(1.0));
+
+        let record = Record::builder().set_cigar("25H50M25H".parse()?).build();
+        assert_eq!(record.aligned_fraction(), Some(0.5));
+
+        assert!(Record::default().aligned_fraction().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_alignment_interval() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Flags;
+
+        fn t(
+            position: Position,
+            cigar: &str,
+        ) -> Result<Option<RangeInclusive<Position>>, Box<dyn std::error::Error>> {
+            let record = Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(position)
+                .set_cigar(cigar.parse()?)
+                .build();
+
+            Ok(record.alignment_interval())
+        }
+
+        let start = Position::try_from(8)?;
+
+        assert_eq!(t(start, "36M")?, Some(start..=Position::try_from(43)?));
+        assert_eq!(t(start, "10M5I10M")?, Some(start..=Position::try_from(27)?));
+        assert_eq!(t(start, "5S20M5H")?, Some(start..=Position::try_from(27)?));
+        assert_eq!(t(start, "1M")?, Some(start..=start));
+        assert!(t(start, "4S")?.is_none());
+
+        let position = Position::new(usize::MAX).unwrap();
+        assert_eq!(t(position, "1M")?, Some(position..=position));
+        assert!(t(position, "2M")?.is_none());
+
+        let record = Record::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_position(start)
+            .set_cigar("4M".parse()?)
+            .build();
+        assert!(record.alignment_interval().is_none());
+
+        let record = Record::builder()
+            .set_flags(Flags::empty())
+            .set_position(start)
+            .build();
+        assert!(record.alignment_interval().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_mate() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::Flags;
+
+        fn build_record(
+            position: usize,
+            cigar: &str,
+            sequence: &str,
+            quality_scores: &str,
+        ) -> Result<Record, Box<dyn std::error::Error>> {
+            Ok(Record::builder()
+                .set_flags(Flags::empty())
+                .set_position(Position::try_from(position)?)
+                .set_cigar(cigar.parse()?)
+                .set_sequence(sequence.parse()?)
+                .set_quality_scores(quality_scores.parse()?)
+                .build())
+        }
+
+        // Positions 3-4 overlap: G/G agree (20 + 30), and T/A disagree (20 - 10).
+        let record = build_record(1, "4M", "ACGT", "5555")?;
+        let mate = build_record(3, "4M", "GAAC", "?+55")?;
+
+        let (sequence, quality_scores) = record.merge_mate(&mate)?.unwrap();
+        assert_eq!(sequence.to_string(), "ACGTAC");
+        assert_eq!(quality_scores.to_string(), "55S+55");
+
+        // The mate's base wins a disagreement with a higher score, and it is symmetric.
+        let (sequence, quality_scores) = mate.merge_mate(&record)?.unwrap();
+        assert_eq!(sequence.to_string(), "ACGTAC");
+        assert_eq!(quality_scores.to_string(), "55S+55");
+
+        // Agreeing scores are capped.
+        let record = build_record(1, "2M", "AC", "~~")?;
+        let mate = build_record(2, "2M", "CG", "~~")?;
+        let (sequence, quality_scores) = record.merge_mate(&mate)?.unwrap();
+        assert_eq!(sequence.to_string(), "ACG");
+        assert_eq!(quality_scores.to_string(), "~~~");
+
+        // Soft clips are dropped, insertions are kept, and a deletion takes the mate's base.
+        let record = build_record(1, "1S2M1I2M", "TACGTA", "555555")?;
+        let mate = build_record(2, "1M1D2M", "CAC", "555")?;
+        let (sequence, quality_scores) = record.merge_mate(&mate)?.unwrap();
+        assert_eq!(sequence.to_string(), "ACGTAC");
+        assert_eq!(quality_scores.to_string(), "5I55I5");
+
+        let record = build_record(1, "2M", "AC", "55")?;
+        let mate = build_record(3, "2M", "GT", "55")?;
+        assert!(record.merge_mate(&mate)?.is_none());
+
+        let mut mate = build_record(2, "2M", "CG", "55")?;
+        assert!(record.merge_mate(&mate)?.is_some());
+        *mate.flags_mut() = Flags::UNMAPPED;
+        assert!(record.merge_mate(&mate)?.is_none());
+
+        let mate = build_record(2, "1M1B2M", "CGT", "555")?;
+        assert!(matches!(
+            record.merge_mate(&mate),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
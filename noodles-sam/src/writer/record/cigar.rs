@@ -27,6 +27,7 @@ where
                 Kind::Pad => b'P',
                 Kind::SequenceMatch => b'=',
                 Kind::SequenceMismatch => b'X',
+                Kind::Back => b'B',
             };
 
             writer.write_all(&[c])?;
@@ -1,10 +1,11 @@
 //! Tabix index and fields.
 
 pub mod builder;
-pub mod header;
 mod indexer;
 pub mod reference_sequence;
 
+pub use noodles_csi::index::header;
+
 pub use self::{
     builder::Builder, header::Header, indexer::Indexer, reference_sequence::ReferenceSequence,
 };
@@ -15,11 +16,16 @@ use std::{
 };
 
 use indexmap::IndexSet;
+use noodles_bgzf as bgzf;
 use noodles_core::Position;
 use noodles_csi::{
-    binning_index::optimize_chunks, index::reference_sequence::bin::Chunk, BinningIndex,
+    binning_index::{optimize_chunks, MergedChunks},
+    index::reference_sequence::bin::Chunk,
+    BinningIndex,
 };
 
+use self::reference_sequence::Bin;
+
 const MIN_SHIFT: u8 = 14;
 const DEPTH: u8 = 5;
 
@@ -145,6 +151,42 @@ impl BinningIndex<ReferenceSequence> for Index {
     }
 
     fn query<B>(&self, reference_sequence_id: usize, interval: B) -> io::Result<Vec<Chunk>>
+    where
+        B: RangeBounds<Position> + Clone,
+    {
+        let (query_bins, min_offset) = self.query_bins(reference_sequence_id, interval)?;
+
+        let chunks: Vec<_> = query_bins
+            .iter()
+            .flat_map(|bin| bin.chunks())
+            .copied()
+            .collect();
+
+        let merged_chunks = optimize_chunks(&chunks, min_offset);
+
+        Ok(merged_chunks)
+    }
+
+    fn query_merged_chunks<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<MergedChunks<'_>>
+    where
+        B: RangeBounds<Position> + Clone,
+    {
+        let (query_bins, min_offset) = self.query_bins(reference_sequence_id, interval)?;
+        let bins = query_bins.into_iter().map(|bin| bin.chunks());
+        Ok(MergedChunks::new(bins, min_offset))
+    }
+}
+
+impl Index {
+    fn query_bins<B>(
+        &self,
+        reference_sequence_id: usize,
+        interval: B,
+    ) -> io::Result<(Vec<&Bin>, bgzf::VirtualPosition)>
     where
         B: RangeBounds<Position> + Clone,
     {
@@ -162,17 +204,10 @@ impl BinningIndex<ReferenceSequence> for Index {
             .query(interval.clone())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        let chunks: Vec<_> = query_bins
-            .iter()
-            .flat_map(|bin| bin.chunks())
-            .copied()
-            .collect();
-
         let (start, _) = resolve_interval(interval)?;
         let min_offset = reference_sequence.min_offset(start);
-        let merged_chunks = optimize_chunks(&chunks, min_offset);
 
-        Ok(merged_chunks)
+        Ok((query_bins, min_offset))
     }
 }
 
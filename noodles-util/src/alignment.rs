@@ -2,6 +2,14 @@
 
 mod format;
 mod reader;
+#[cfg(feature = "test-util")]
+mod round_trip;
 mod writer;
 
 pub use self::{format::Format, reader::Reader, writer::Writer};
+
+#[cfg(feature = "test-util")]
+pub use {
+    self::round_trip::{assert_round_trip, check_round_trip},
+    crate::round_trip::Mismatch,
+};
@@ -0,0 +1,325 @@
+//! Alignment format round-trip verification.
+
+use std::{
+    cell::RefCell,
+    io::{self, Cursor, Read, Seek, Write},
+    rc::Rc,
+};
+
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, AlignmentRecord};
+
+use super::{Format, Reader, Writer};
+use crate::round_trip::{compare, invalid_data, FieldMismatch, Mismatch};
+
+/// Reads alignment records, writes them in the given format, reads them back, and compares the
+/// results.
+///
+/// The input format is autodetected. The header and records are compared semantically, i.e.,
+/// by their fields rather than by their encoded form; e.g., the order of data fields is
+/// ignored.
+///
+/// The first difference found is returned as an error of kind [`io::ErrorKind::InvalidData`]
+/// wrapping a [`Mismatch`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor};
+/// use noodles_fasta as fasta;
+/// use noodles_util::alignment::{self, Format};
+///
+/// let data = Cursor::new(b"@HD\tVN:1.6
+/// *\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS\tNH:i:1
+/// ");
+///
+/// alignment::check_round_trip(data, Format::Bam, fasta::Repository::default())?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn check_round_trip<R>(
+    inner: R,
+    format: Format,
+    reference_sequence_repository: fasta::Repository,
+) -> io::Result<()>
+where
+    R: Read + Seek + 'static,
+{
+    let mut reader = Reader::builder(inner)
+        .set_reference_sequence_repository(reference_sequence_repository.clone())
+        .build()?;
+
+    let expected_header = reader.read_header()?;
+    let expected_records: Vec<_> = reader
+        .records(&expected_header)
+        .collect::<io::Result<_>>()?;
+
+    let buf = SharedBuf::default();
+
+    let mut writer = Writer::builder(buf.clone())
+        .set_format(format)
+        .set_reference_sequence_repository(reference_sequence_repository.clone())
+        .build();
+
+    writer.write_header(&expected_header)?;
+
+    for record in &expected_records {
+        writer.write_record(&expected_header, record.as_ref())?;
+    }
+
+    writer.finish(&expected_header)?;
+    drop(writer);
+
+    let mut reader = Reader::builder(Cursor::new(buf.into_inner()))
+        .set_format(format)
+        .set_reference_sequence_repository(reference_sequence_repository)
+        .build()?;
+
+    let actual_header = reader.read_header()?;
+
+    if actual_header != expected_header {
+        return Err(invalid_data(Mismatch::Header));
+    }
+
+    let actual_records: Vec<_> = reader.records(&actual_header).collect::<io::Result<_>>()?;
+
+    if actual_records.len() != expected_records.len() {
+        return Err(invalid_data(Mismatch::RecordCount(
+            expected_records.len(),
+            actual_records.len(),
+        )));
+    }
+
+    for (index, (expected, actual)) in expected_records.iter().zip(&actual_records).enumerate() {
+        compare_records(
+            &expected_header,
+            expected.as_ref(),
+            &actual_header,
+            actual.as_ref(),
+        )
+        .map_err(|(field, expected, actual)| {
+            invalid_data(Mismatch::Record {
+                index,
+                field,
+                expected,
+                actual,
+            })
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Verifies a round trip, panicking on the first difference.
+///
+/// This is the same as [`check_round_trip`] but panics with a description of the first
+/// differing record and field.
+///
+/// # Panics
+///
+/// This panics if the input fails to read, write, or survive the round trip.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::Cursor;
+/// use noodles_fasta as fasta;
+/// use noodles_util::alignment::{self, Format};
+///
+/// let data = Cursor::new(b"@HD\tVN:1.6
+/// *\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS\tNH:i:1
+/// ");
+///
+/// alignment::assert_round_trip(data, Format::Sam, fasta::Repository::default());
+/// ```
+pub fn assert_round_trip<R>(
+    inner: R,
+    format: Format,
+    reference_sequence_repository: fasta::Repository,
+) where
+    R: Read + Seek + 'static,
+{
+    if let Err(e) = check_round_trip(inner, format, reference_sequence_repository) {
+        panic!("{:?} round trip failed: {}", format, e);
+    }
+}
+
+fn compare_records(
+    expected_header: &sam::Header,
+    expected: &dyn AlignmentRecord,
+    actual_header: &sam::Header,
+    actual: &dyn AlignmentRecord,
+) -> Result<(), FieldMismatch> {
+    compare("read name", expected.read_name(), actual.read_name())?;
+    compare("flags", expected.flags(), actual.flags())?;
+    compare(
+        "reference sequence",
+        reference_sequence_name(expected.reference_sequence(expected_header.reference_sequences())),
+        reference_sequence_name(actual.reference_sequence(actual_header.reference_sequences())),
+    )?;
+    compare(
+        "alignment start",
+        expected.alignment_start(),
+        actual.alignment_start(),
+    )?;
+    compare(
+        "mapping quality",
+        expected.mapping_quality(),
+        actual.mapping_quality(),
+    )?;
+    compare("CIGAR", expected.cigar(), actual.cigar())?;
+    compare(
+        "mate reference sequence",
+        reference_sequence_name(
+            expected.mate_reference_sequence(expected_header.reference_sequences()),
+        ),
+        reference_sequence_name(
+            actual.mate_reference_sequence(actual_header.reference_sequences()),
+        ),
+    )?;
+    compare(
+        "mate alignment start",
+        expected.mate_alignment_start(),
+        actual.mate_alignment_start(),
+    )?;
+    compare(
+        "template length",
+        expected.template_length(),
+        actual.template_length(),
+    )?;
+    compare("sequence", expected.sequence(), actual.sequence())?;
+    compare(
+        "quality scores",
+        expected.quality_scores(),
+        actual.quality_scores(),
+    )?;
+
+    let expected_data = expected.data();
+    let actual_data = actual.data();
+
+    for field in expected_data.values() {
+        compare("data", Some(field), actual_data.get(field.tag()))?;
+    }
+
+    for field in actual_data.values() {
+        compare("data", expected_data.get(field.tag()), Some(field))?;
+    }
+
+    Ok(())
+}
+
+fn reference_sequence_name(
+    reference_sequence: Option<io::Result<&sam::header::ReferenceSequence>>,
+) -> Option<Result<String, String>> {
+    reference_sequence.map(|result| {
+        result
+            .map(|reference_sequence| reference_sequence.name().to_string())
+            .map_err(|e| e.to_string())
+    })
+}
+
+// An in-memory output that can be read after its writer is dropped.
+#[derive(Clone, Default)]
+struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuf {
+    fn into_inner(self) -> Vec<u8> {
+        self.0.take()
+    }
+}
+
+impl Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DATA: &str = "@HD\tVN:1.6
+@SQ\tSN:sq0\tLN:8
+r0\t99\tsq0\t1\t60\t4M\t=\t5\t8\tACGT\tNDLS\tNH:i:1\tCO:Z:noodles
+r1\t147\tsq0\t5\t60\t4M\t=\t1\t-8\tTGCA\tSLDN\tNH:i:1
+*\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+    #[test]
+    fn test_check_round_trip() -> io::Result<()> {
+        check_round_trip(
+            Cursor::new(DATA.as_bytes()),
+            Format::Sam,
+            fasta::Repository::default(),
+        )?;
+        check_round_trip(
+            Cursor::new(DATA.as_bytes()),
+            Format::Bam,
+            fasta::Repository::default(),
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_round_trip_with_cram() -> io::Result<()> {
+        let reference_sequence_repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTTGCA".to_vec()),
+        )]);
+
+        // CRAM requires reference sequence MD5 checksums.
+        let data = DATA.replacen(
+            "@SQ\tSN:sq0\tLN:8",
+            "@SQ\tSN:sq0\tLN:8\tM5:57e46c2220d7fb645888d27273b70392",
+            1,
+        );
+
+        check_round_trip(
+            Cursor::new(data.into_bytes()),
+            Format::Cram,
+            reference_sequence_repository,
+        )
+    }
+
+    #[test]
+    fn test_compare_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let expected = sam::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_data("NH:i:1\tCO:Z:noodles".parse()?)
+            .build();
+
+        let actual = sam::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_data("CO:Z:noodles\tNH:i:1".parse()?)
+            .build();
+
+        assert!(compare_records(&header, &expected, &header, &actual).is_ok());
+
+        let actual = sam::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_data("NH:i:1".parse()?)
+            .build();
+
+        assert!(matches!(
+            compare_records(&header, &expected, &header, &actual),
+            Err(("data", _, actual)) if actual == "None"
+        ));
+
+        let actual = sam::Record::builder()
+            .set_read_name("r1".parse()?)
+            .set_data("NH:i:1\tCO:Z:noodles".parse()?)
+            .build();
+
+        assert!(matches!(
+            compare_records(&header, &expected, &header, &actual),
+            Err(("read name", _, _))
+        ));
+
+        Ok(())
+    }
+}
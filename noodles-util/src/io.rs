@@ -0,0 +1,9 @@
+//! I/O utilities.
+
+mod checksum_writer;
+mod counting_reader;
+
+pub use self::{
+    checksum_writer::{Algorithm, Checksum, ChecksumWriter},
+    counting_reader::{ByteCount, CountingReader},
+};
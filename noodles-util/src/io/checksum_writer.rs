@@ -0,0 +1,233 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use md5::{Digest, Md5};
+
+/// A checksum algorithm.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Algorithm {
+    /// MD5.
+    Md5,
+    /// CRC32 (IEEE).
+    Crc32,
+}
+
+/// A checksum of written bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Checksum {
+    /// An MD5 digest.
+    Md5([u8; 16]),
+    /// A CRC32 (IEEE) checksum.
+    Crc32(u32),
+}
+
+impl fmt::Display for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Md5(digest) => {
+                for b in digest {
+                    write!(f, "{:02x}", b)?;
+                }
+
+                Ok(())
+            }
+            Self::Crc32(crc32) => write!(f, "{:08x}", crc32),
+        }
+    }
+}
+
+enum Hasher {
+    Md5(Md5),
+    Crc32(crc32fast::Hasher),
+}
+
+impl Hasher {
+    fn update(&mut self, buf: &[u8]) {
+        match self {
+            Self::Md5(hasher) => hasher.update(buf),
+            Self::Crc32(hasher) => hasher.update(buf),
+        }
+    }
+
+    fn finalize(self) -> Checksum {
+        match self {
+            Self::Md5(hasher) => Checksum::Md5(hasher.finalize().into()),
+            Self::Crc32(hasher) => Checksum::Crc32(hasher.finalize()),
+        }
+    }
+}
+
+impl From<Algorithm> for Hasher {
+    fn from(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Md5 => Self::Md5(Md5::new()),
+            Algorithm::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+        }
+    }
+}
+
+/// A writer that computes a checksum of the bytes written to the underlying writer.
+///
+/// This wraps the output stream of another writer, e.g., a file, to record a checksum of the
+/// output without a second pass.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_util::io::{Algorithm, ChecksumWriter};
+///
+/// let mut writer = ChecksumWriter::new(Vec::new(), Algorithm::Md5);
+/// writer.write_all(b"noodles")?;
+///
+/// let (buf, checksum) = writer.finish()?;
+///
+/// assert_eq!(buf, b"noodles");
+/// assert_eq!(checksum.to_string(), "d7eba311421bbc9d3ada44709dd61534");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct ChecksumWriter<W> {
+    inner: W,
+    hasher: Hasher,
+}
+
+impl<W> ChecksumWriter<W>
+where
+    W: Write,
+{
+    /// Creates a checksum writer using the given algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::io::{Algorithm, ChecksumWriter};
+    /// let writer = ChecksumWriter::new(Vec::new(), Algorithm::Crc32);
+    /// ```
+    pub fn new(inner: W, algorithm: Algorithm) -> Self {
+        Self {
+            inner,
+            hasher: Hasher::from(algorithm),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::io::{Algorithm, ChecksumWriter};
+    /// let writer = ChecksumWriter::new(Vec::new(), Algorithm::Crc32);
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Flushes the underlying writer and returns it with the checksum of all written bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_util::io::{Algorithm, Checksum, ChecksumWriter};
+    ///
+    /// let mut writer = ChecksumWriter::new(Vec::new(), Algorithm::Crc32);
+    /// writer.write_all(b"noodles")?;
+    ///
+    /// let (_, checksum) = writer.finish()?;
+    /// assert_eq!(checksum, Checksum::Crc32(crc32fast::hash(b"noodles")));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<(W, Checksum)> {
+        self.inner.flush()?;
+        Ok((self.inner, self.hasher.finalize()))
+    }
+}
+
+impl<W> Write for ChecksumWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam as sam;
+
+    use super::*;
+
+    // A writer that accepts at most 3 bytes per write.
+    struct ShortWriter(Vec<u8>);
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let n = buf.len().min(3);
+            self.0.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header: sam::Header = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n".parse()?;
+        let record: sam::Record = "r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS".parse()?;
+
+        for algorithm in [Algorithm::Md5, Algorithm::Crc32] {
+            let mut writer = sam::Writer::new(ChecksumWriter::new(Vec::new(), algorithm));
+            writer.write_header(&header)?;
+            writer.write_record(&record)?;
+
+            let (buf, actual) = writer.into_inner().finish()?;
+
+            let expected = match algorithm {
+                Algorithm::Md5 => Checksum::Md5(Md5::digest(&buf).into()),
+                Algorithm::Crc32 => Checksum::Crc32(crc32fast::hash(&buf)),
+            };
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_partial_writes() -> io::Result<()> {
+        let mut writer = ChecksumWriter::new(ShortWriter(Vec::new()), Algorithm::Crc32);
+        writer.write_all(b"noodles")?;
+
+        let (inner, checksum) = writer.finish()?;
+
+        assert_eq!(inner.0, b"noodles");
+        assert_eq!(checksum, Checksum::Crc32(crc32fast::hash(b"noodles")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Checksum::Crc32(0xff).to_string(), "000000ff");
+        assert_eq!(
+            Checksum::Md5([
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+                0x0e, 0x0f,
+            ])
+            .to_string(),
+            "000102030405060708090a0b0c0d0e0f"
+        );
+    }
+}
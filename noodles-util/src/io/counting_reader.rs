@@ -0,0 +1,192 @@
+use std::{
+    io::{self, BufRead, Read},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// A shared handle to the number of bytes read by a [`CountingReader`].
+///
+/// This can be read while the reader is borrowed, e.g., by a record iterator.
+#[derive(Clone, Debug, Default)]
+pub struct ByteCount(Arc<AtomicU64>);
+
+impl ByteCount {
+    /// Returns the number of bytes read.
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn add(&self, n: usize) {
+        self.0.fetch_add(n as u64, Ordering::Relaxed);
+    }
+}
+
+/// A reader that counts the bytes read from the underlying reader.
+///
+/// This wraps the input stream of another reader, e.g., a file, to report progress. The count is
+/// of the bytes read from the input, i.e., the compressed bytes of a compressed format.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read};
+/// use noodles_util::io::CountingReader;
+///
+/// let data = b"noodles";
+/// let mut reader = CountingReader::new(&data[..]);
+/// let byte_count = reader.byte_count();
+///
+/// let mut buf = [0; 4];
+/// reader.read_exact(&mut buf)?;
+///
+/// assert_eq!(byte_count.get(), 4);
+/// # Ok::<_, io::Error>(())
+/// ```
+///
+/// A byte count can be combined with [`noodles_core::RecordsExt::with_progress`] to report both
+/// the number of records and bytes read.
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bam as bam;
+/// use noodles_core::RecordsExt;
+/// use noodles_util::io::CountingReader;
+///
+/// let reader = File::open("sample.bam").map(CountingReader::new)?;
+/// let byte_count = reader.byte_count();
+///
+/// let mut reader = bam::Reader::new(reader);
+/// reader.read_header()?;
+/// reader.read_reference_sequences()?;
+///
+/// let records = reader.records().with_progress(100000, |n| {
+///     eprintln!("{} records, {} bytes", n, byte_count.get());
+/// });
+///
+/// for result in records {
+///     let _record = result?;
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct CountingReader<R> {
+    inner: R,
+    byte_count: ByteCount,
+}
+
+impl<R> CountingReader<R> {
+    /// Creates a counting reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::io::CountingReader;
+    /// let reader = CountingReader::new(&b""[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            byte_count: ByteCount::default(),
+        }
+    }
+
+    /// Returns a shared handle to the number of bytes read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::io::CountingReader;
+    /// let reader = CountingReader::new(&b""[..]);
+    /// assert_eq!(reader.byte_count().get(), 0);
+    /// ```
+    pub fn byte_count(&self) -> ByteCount {
+        self.byte_count.clone()
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::io::CountingReader;
+    /// let reader = CountingReader::new(&b""[..]);
+    /// assert!(reader.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps and returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::io::CountingReader;
+    /// let reader = CountingReader::new(&b""[..]);
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Read for CountingReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.byte_count.add(n);
+        Ok(n)
+    }
+}
+
+impl<R> BufRead for CountingReader<R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.byte_count.add(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let data = b"noodles";
+        let mut reader = CountingReader::new(&data[..]);
+        let byte_count = reader.byte_count();
+
+        let mut buf = [0; 3];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(byte_count.get(), 3);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(byte_count.get(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_consume() -> io::Result<()> {
+        let data = b"noodles\nutil\n";
+        let mut reader = CountingReader::new(&data[..]);
+        let byte_count = reader.byte_count();
+
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        assert_eq!(byte_count.get(), 8);
+
+        Ok(())
+    }
+}
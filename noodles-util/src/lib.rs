@@ -3,3 +3,8 @@
 //! **noodles-util** are utilities for working with noodles.
 
 pub mod alignment;
+pub mod io;
+#[cfg(feature = "test-util")]
+mod round_trip;
+#[cfg(feature = "test-util")]
+pub mod variant;
@@ -0,0 +1,87 @@
+//! Round-trip verification.
+
+use std::{error, fmt, io};
+
+/// A difference found when verifying a round trip.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Mismatch {
+    /// The header differs.
+    Header,
+    /// The number of records differs.
+    ///
+    /// The values are the (expected, actual) number of records.
+    RecordCount(usize, usize),
+    /// A record field differs.
+    Record {
+        /// The index of the record.
+        index: usize,
+        /// The name of the field that differs.
+        field: &'static str,
+        /// The source value.
+        expected: String,
+        /// The value after the round trip.
+        actual: String,
+    },
+}
+
+impl error::Error for Mismatch {}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Header => f.write_str("header mismatch"),
+            Self::RecordCount(expected, actual) => write!(
+                f,
+                "record count mismatch: expected {}, got {}",
+                expected, actual
+            ),
+            Self::Record {
+                index,
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "record {}: {} mismatch: expected {}, got {}",
+                index, field, expected, actual
+            ),
+        }
+    }
+}
+
+pub(crate) type FieldMismatch = (&'static str, String, String);
+
+pub(crate) fn compare<T>(field: &'static str, expected: T, actual: T) -> Result<(), FieldMismatch>
+where
+    T: fmt::Debug + PartialEq,
+{
+    if expected == actual {
+        Ok(())
+    } else {
+        Err((field, format!("{:?}", expected), format!("{:?}", actual)))
+    }
+}
+
+pub(crate) fn invalid_data(mismatch: Mismatch) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, mismatch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt_for_mismatch() {
+        let mismatch = Mismatch::Record {
+            index: 1,
+            field: "data",
+            expected: String::from("NH:i:1"),
+            actual: String::from("None"),
+        };
+
+        assert_eq!(
+            mismatch.to_string(),
+            "record 1: data mismatch: expected NH:i:1, got None"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Variant format utilities.
+
+mod format;
+mod round_trip;
+
+pub use {
+    self::{
+        format::Format,
+        round_trip::{assert_round_trip, check_round_trip},
+    },
+    crate::round_trip::Mismatch,
+};
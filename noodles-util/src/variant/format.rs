@@ -0,0 +1,8 @@
+/// A variant format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Variant Call Format (VCF).
+    Vcf,
+    /// Binary Call Format (BCF).
+    Bcf,
+}
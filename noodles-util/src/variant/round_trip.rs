@@ -0,0 +1,265 @@
+//! Variant format round-trip verification.
+
+use std::io::{self, BufReader, Read, Write};
+
+use noodles_bcf::{self as bcf, header::StringMaps};
+use noodles_vcf as vcf;
+
+use super::Format;
+use crate::round_trip::{compare, invalid_data, FieldMismatch, Mismatch};
+
+/// Reads variant records in the given format, writes them back in the same format, reads them
+/// back, and compares the results.
+///
+/// The header and records are compared semantically, i.e., by their fields rather than by their
+/// encoded form; e.g., the order of INFO fields is ignored. BCF records are compared after
+/// converting them to VCF records.
+///
+/// The first difference found is returned as an error of kind [`io::ErrorKind::InvalidData`]
+/// wrapping a [`Mismatch`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_util::variant::{self, Format};
+///
+/// let data = b"##fileformat=VCFv4.3
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq0\t1\t.\tA\t.\t.\tPASS\t.
+/// ";
+///
+/// variant::check_round_trip(&data[..], Format::Vcf)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn check_round_trip<R>(inner: R, format: Format) -> io::Result<()>
+where
+    R: Read,
+{
+    let (expected_header, expected_records) = read(inner, format)?;
+
+    let mut buf = Vec::new();
+    write(&mut buf, format, &expected_header, &expected_records)?;
+
+    let (actual_header, actual_records) = read(&buf[..], format)?;
+
+    if actual_header != expected_header {
+        return Err(invalid_data(Mismatch::Header));
+    }
+
+    if actual_records.len() != expected_records.len() {
+        return Err(invalid_data(Mismatch::RecordCount(
+            expected_records.len(),
+            actual_records.len(),
+        )));
+    }
+
+    for (index, (expected, actual)) in expected_records.iter().zip(&actual_records).enumerate() {
+        compare_records(expected, actual).map_err(|(field, expected, actual)| {
+            invalid_data(Mismatch::Record {
+                index,
+                field,
+                expected,
+                actual,
+            })
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Verifies a round trip, panicking on the first difference.
+///
+/// This is the same as [`check_round_trip`] but panics with a description of the first
+/// differing record and field.
+///
+/// # Panics
+///
+/// This panics if the input fails to read, write, or survive the round trip.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::variant::{self, Format};
+///
+/// let data = b"##fileformat=VCFv4.3
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq0\t1\t.\tA\t.\t.\tPASS\t.
+/// ";
+///
+/// variant::assert_round_trip(&data[..], Format::Vcf);
+/// ```
+pub fn assert_round_trip<R>(inner: R, format: Format)
+where
+    R: Read,
+{
+    if let Err(e) = check_round_trip(inner, format) {
+        panic!("{:?} round trip failed: {}", format, e);
+    }
+}
+
+fn read<R>(inner: R, format: Format) -> io::Result<(vcf::Header, Vec<vcf::Record>)>
+where
+    R: Read,
+{
+    match format {
+        Format::Vcf => {
+            let mut reader = vcf::Reader::new(BufReader::new(inner));
+
+            let header: vcf::Header = reader
+                .read_header()?
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let records = reader.records(&header).collect::<io::Result<_>>()?;
+
+            Ok((header, records))
+        }
+        Format::Bcf => {
+            let mut reader = bcf::Reader::new(inner);
+            reader.read_file_format()?;
+
+            let raw_header = reader.read_header()?;
+
+            let header: vcf::Header = raw_header
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let string_maps: StringMaps = raw_header
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let records = reader
+                .records()
+                .map(|result| {
+                    result.and_then(|record| record.try_into_vcf_record(&header, &string_maps))
+                })
+                .collect::<io::Result<_>>()?;
+
+            Ok((header, records))
+        }
+    }
+}
+
+fn write<W>(
+    inner: W,
+    format: Format,
+    header: &vcf::Header,
+    records: &[vcf::Record],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    match format {
+        Format::Vcf => {
+            let mut writer = vcf::Writer::new(inner);
+
+            writer.write_header(header)?;
+
+            for record in records {
+                writer.write_record(record)?;
+            }
+        }
+        Format::Bcf => {
+            let mut writer = bcf::Writer::new(inner);
+
+            writer.write_file_format()?;
+            writer.write_header(header)?;
+
+            let string_maps = StringMaps::try_from(header)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            for record in records {
+                writer.write_vcf_record(header, &string_maps, record)?;
+            }
+
+            writer.into_inner().finish()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn compare_records(expected: &vcf::Record, actual: &vcf::Record) -> Result<(), FieldMismatch> {
+    compare("CHROM", expected.chromosome(), actual.chromosome())?;
+    compare("POS", expected.position(), actual.position())?;
+    compare("ID", expected.ids(), actual.ids())?;
+    compare("REF", expected.reference_bases(), actual.reference_bases())?;
+    compare("ALT", expected.alternate_bases(), actual.alternate_bases())?;
+    compare("QUAL", expected.quality_score(), actual.quality_score())?;
+    compare("FILTER", expected.filters(), actual.filters())?;
+
+    let expected_info = expected.info();
+    let actual_info = actual.info();
+
+    for field in expected_info.values() {
+        compare("INFO", Some(field), actual_info.get(field.key()))?;
+    }
+
+    for field in actual_info.values() {
+        compare("INFO", expected_info.get(field.key()), Some(field))?;
+    }
+
+    compare("FORMAT", expected.format(), actual.format())?;
+    compare("genotypes", expected.genotypes(), actual.genotypes())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DATA: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FILTER=<ID=PASS,Description=\"All filters passed\">
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Genotype quality\">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description=\"Read depth\">
+##contig=<ID=sq0,length=8>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\tid0\tA\tC\t13\tPASS\tNS=1;DP=8\tGQ:DP\t13:8
+sq0\t3\t.\tG\t.\t.\tq10\tDP=2\tGQ\t5
+";
+
+    #[test]
+    fn test_check_round_trip() -> io::Result<()> {
+        check_round_trip(DATA, Format::Vcf)?;
+
+        let mut bcf_data = Vec::new();
+        let (header, records) = read(DATA, Format::Vcf)?;
+        write(&mut bcf_data, Format::Bcf, &header, &records)?;
+        check_round_trip(&bcf_data[..], Format::Bcf)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_info(vcf::header::Info::from(
+                vcf::header::info::Key::SamplesWithDataCount,
+            ))
+            .add_info(vcf::header::Info::from(vcf::header::info::Key::TotalDepth))
+            .build();
+
+        let expected = vcf::Record::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\tNS=1;DP=8", &header)?;
+        let actual = vcf::Record::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\tDP=8;NS=1", &header)?;
+        assert!(compare_records(&expected, &actual).is_ok());
+
+        let actual = vcf::Record::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\tNS=1", &header)?;
+        assert!(matches!(
+            compare_records(&expected, &actual),
+            Err(("INFO", _, actual)) if actual == "None"
+        ));
+
+        let actual = vcf::Record::try_from_str("sq0\t2\t.\tA\t.\t.\tPASS\tNS=1;DP=8", &header)?;
+        assert!(matches!(
+            compare_records(&expected, &actual),
+            Err(("POS", _, _))
+        ));
+
+        Ok(())
+    }
+}
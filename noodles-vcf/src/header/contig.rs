@@ -10,6 +10,12 @@ use crate::record::chromosome;
 const ID: &str = "ID";
 const LENGTH: &str = "length";
 const IDX: &str = "IDX";
+const ASSEMBLY: &str = "assembly";
+const MD5: &str = "md5";
+const SPECIES: &str = "species";
+const TAXONOMY: &str = "taxonomy";
+
+const MD5_LEN: usize = 16;
 
 /// A VCF header contig record (`contig`).
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -83,6 +89,77 @@ impl Contig {
         self.idx
     }
 
+    /// Returns the assembly (`assembly`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new("sq0");
+    /// assert!(contig.assembly().is_none());
+    /// ```
+    pub fn assembly(&self) -> Option<&str> {
+        self.get(ASSEMBLY)
+    }
+
+    /// Returns the MD5 checksum of the sequence (`md5`), if it is set.
+    ///
+    /// The raw value is kept as read and is only validated here. This returns an error if it is
+    /// not a 128-bit hexadecimal value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::{contig, record, Record, Contig};
+    ///
+    /// let record = Record::new(
+    ///     record::Key::Contig,
+    ///     record::Value::Struct(vec![
+    ///         (String::from("ID"), String::from("sq0")),
+    ///         (String::from("md5"), String::from("d7eba311421bbc9d3ada44709dd61534")),
+    ///     ]),
+    /// );
+    /// let contig = Contig::try_from(record)?;
+    ///
+    /// assert_eq!(
+    ///     contig.md5(),
+    ///     Some(Ok([
+    ///         0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d,
+    ///         0x3a, 0xda, 0x44, 0x70, 0x9d, 0xd6, 0x15, 0x34,
+    ///     ]))
+    /// );
+    /// # Ok::<(), contig::TryFromRecordError>(())
+    /// ```
+    pub fn md5(&self) -> Option<Result<[u8; MD5_LEN], ParseMd5Error>> {
+        self.get(MD5).map(|s| parse_md5(s).ok_or(ParseMd5Error))
+    }
+
+    /// Returns the species (`species`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new("sq0");
+    /// assert!(contig.species().is_none());
+    /// ```
+    pub fn species(&self) -> Option<&str> {
+        self.get(SPECIES)
+    }
+
+    /// Returns the taxonomy (`taxonomy`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new("sq0");
+    /// assert!(contig.taxonomy().is_none());
+    /// ```
+    pub fn taxonomy(&self) -> Option<&str> {
+        self.get(TAXONOMY)
+    }
+
     /// Returns the value of the field with the given key.
     ///
     /// # Examples
@@ -165,6 +242,18 @@ impl fmt::Display for TryFromRecordError {
     }
 }
 
+/// An error returned when a contig MD5 checksum (`md5`) fails to parse.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseMd5Error;
+
+impl error::Error for ParseMd5Error {}
+
+impl fmt::Display for ParseMd5Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid MD5 checksum (`{}`)", MD5)
+    }
+}
+
 impl TryFrom<Record> for Contig {
     type Error = TryFromRecordError;
 
@@ -217,6 +306,24 @@ fn parse_struct(fields: Vec<(String, String)>) -> Result<Contig, TryFromRecordEr
     })
 }
 
+fn parse_md5(s: &str) -> Option<[u8; MD5_LEN]> {
+    let s = s.as_bytes();
+
+    if s.len() != 2 * MD5_LEN {
+        return None;
+    }
+
+    let mut buf = [0; MD5_LEN];
+
+    for (b, pair) in buf.iter_mut().zip(s.chunks_exact(2)) {
+        let hi = char::from(pair[0]).to_digit(16)?;
+        let lo = char::from(pair[1]).to_digit(16)?;
+        *b = (hi << 4 | lo) as u8;
+    }
+
+    Some(buf)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -297,6 +404,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_record_for_contig_with_all_standard_fields() -> Result<(), TryFromRecordError>
+    {
+        let record = Record::new(
+            record::Key::Contig,
+            record::Value::Struct(vec![
+                (String::from("ID"), String::from("sq0")),
+                (String::from("length"), String::from("13")),
+                (String::from("assembly"), String::from("B36")),
+                (
+                    String::from("md5"),
+                    String::from("D7EBA311421bbc9d3ada44709dd61534"),
+                ),
+                (String::from("species"), String::from("Homo sapiens")),
+                (String::from("taxonomy"), String::from("x")),
+            ]),
+        );
+
+        let contig = Contig::try_from(record)?;
+
+        assert_eq!(contig.id(), "sq0");
+        assert_eq!(contig.len(), Some(13));
+        assert_eq!(contig.assembly(), Some("B36"));
+        assert_eq!(
+            contig.md5(),
+            Some(Ok([
+                0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d, 0x3a, 0xda, 0x44, 0x70, 0x9d, 0xd6,
+                0x15, 0x34,
+            ]))
+        );
+        assert_eq!(contig.species(), Some("Homo sapiens"));
+        assert_eq!(contig.taxonomy(), Some("x"));
+
+        let expected = r#"##contig=<ID=sq0,length=13,assembly="B36",md5="D7EBA311421bbc9d3ada44709dd61534",species="Homo sapiens",taxonomy="x">"#;
+        assert_eq!(contig.to_string(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_record_for_contig_with_an_invalid_md5() -> Result<(), TryFromRecordError> {
+        let record = Record::new(
+            record::Key::Contig,
+            record::Value::Struct(vec![
+                (String::from("ID"), String::from("sq0")),
+                (String::from("md5"), String::from("d7eba311")),
+            ]),
+        );
+
+        let contig = Contig::try_from(record)?;
+        assert_eq!(contig.get("md5"), Some("d7eba311"));
+        assert_eq!(contig.md5(), Some(Err(ParseMd5Error)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_md5() {
+        assert_eq!(
+            parse_md5("d7eba311421bbc9d3ada44709dd61534"),
+            Some([
+                0xd7, 0xeb, 0xa3, 0x11, 0x42, 0x1b, 0xbc, 0x9d, 0x3a, 0xda, 0x44, 0x70, 0x9d, 0xd6,
+                0x15, 0x34,
+            ])
+        );
+        assert_eq!(parse_md5(""), None);
+        assert_eq!(parse_md5("d7eba311421bbc9d3ada44709dd6153"), None);
+        assert_eq!(parse_md5("n7eba311421bbc9d3ada44709dd61534"), None);
+    }
+
     #[test]
     fn test_try_from_record_for_contig_with_an_invalid_record_key() {
         let record = Record::new(
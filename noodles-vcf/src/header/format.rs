@@ -15,6 +15,8 @@ const ID: &str = "ID";
 const NUMBER: &str = "Number";
 const TYPE: &str = "Type";
 const DESCRIPTION: &str = "Description";
+const SOURCE: &str = "Source";
+const VERSION: &str = "Version";
 const IDX: &str = "IDX";
 
 /// A VCF header genotype format record (`FORMAT`).
@@ -149,6 +151,34 @@ impl Format {
         &self.description
     }
 
+    /// Returns the source (`Source`) of the definition, if it is set.
+    ///
+    /// This is typically the name of the annotation source or tool that produced the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::{format::Key, Format};
+    /// let format = Format::from(Key::Genotype);
+    /// assert!(format.source().is_none());
+    /// ```
+    pub fn source(&self) -> Option<&str> {
+        self.fields.get(SOURCE).map(|s| s.as_str())
+    }
+
+    /// Returns the version (`Version`) of the source, if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::{format::Key, Format};
+    /// let format = Format::from(Key::Genotype);
+    /// assert!(format.version().is_none());
+    /// ```
+    pub fn version(&self) -> Option<&str> {
+        self.fields.get(VERSION).map(|s| s.as_str())
+    }
+
     /// Returns the index of the ID in the dictionary of strings.
     ///
     /// This is typically used in BCF.
@@ -166,7 +196,8 @@ impl Format {
 
     /// Returns the extra fields in the record.
     ///
-    /// This includes fields other than `ID`, `Number`, `Type`, `Description`, and `IDX`.
+    /// This includes fields other than `ID`, `Number`, `Type`, `Description`, and `IDX`, e.g.,
+    /// `Source` and `Version`.
     ///
     /// # Examples
     ///
@@ -386,6 +417,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fmt_with_source_and_version() -> Result<(), Box<dyn std::error::Error>> {
+        let expected = r#"##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype",Source="noodles",Version="0.1">"#;
+
+        let record: Record = expected.parse()?;
+        let format = Format::try_from(record)?;
+
+        assert_eq!(format.source(), Some("noodles"));
+        assert_eq!(format.version(), Some("0.1"));
+        assert_eq!(format.to_string(), expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_file_format() {
         let record = Record::new(
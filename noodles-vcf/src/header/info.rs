@@ -15,6 +15,8 @@ const ID: &str = "ID";
 const NUMBER: &str = "Number";
 const TYPE: &str = "Type";
 const DESCRIPTION: &str = "Description";
+const SOURCE: &str = "Source";
+const VERSION: &str = "Version";
 const IDX: &str = "IDX";
 
 /// A VCF header information record (`INFO`).
@@ -150,6 +152,34 @@ impl Info {
         &self.description
     }
 
+    /// Returns the source (`Source`) of the definition, if it is set.
+    ///
+    /// This is typically the name of the annotation source or tool that produced the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::{info::Key, Info};
+    /// let info = Info::from(Key::SamplesWithDataCount);
+    /// assert!(info.source().is_none());
+    /// ```
+    pub fn source(&self) -> Option<&str> {
+        self.fields.get(SOURCE).map(|s| s.as_str())
+    }
+
+    /// Returns the version (`Version`) of the source, if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::{info::Key, Info};
+    /// let info = Info::from(Key::SamplesWithDataCount);
+    /// assert!(info.version().is_none());
+    /// ```
+    pub fn version(&self) -> Option<&str> {
+        self.fields.get(VERSION).map(|s| s.as_str())
+    }
+
     /// Returns the index of the ID in the dictionary of strings.
     ///
     /// This is typically used in BCF.
@@ -167,7 +197,8 @@ impl Info {
 
     /// Returns the extra fields in the record.
     ///
-    /// This includes fields other than `ID`, `Number`, `Type`, `Description`, and `IDX`.
+    /// This includes fields other than `ID`, `Number`, `Type`, `Description`, and `IDX`, e.g.,
+    /// `Source` and `Version`.
     ///
     /// # Examples
     ///
@@ -390,6 +421,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fmt_with_source_and_version() -> Result<(), Box<dyn std::error::Error>> {
+        let expected = r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data",Source="dbsnp",Version="138">"#;
+
+        let record: Record = expected.parse()?;
+        let info = Info::try_from(record)?;
+
+        assert_eq!(info.source(), Some("dbsnp"));
+        assert_eq!(info.version(), Some("138"));
+        assert_eq!(info.to_string(), expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_record_file_format() {
         let record = Record::new(
@@ -305,6 +305,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_with_alternative_alleles_meta_and_assembly_round_trip(
+    ) -> Result<(), ParseError> {
+        let s = r#"##fileformat=VCFv4.3
+##ALT=<ID=DEL,Description="Deletion">
+##ALT=<ID=INS:ME,Description="Insertion of a mobile element">
+##assembly=file:///assemblies.fasta
+##META=<ID=Assay,Type=String,Number=.,Values=[WholeGenome, Exome]>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        let header = parse(s)?;
+
+        let ids: Vec<_> = header
+            .alternative_alleles()
+            .values()
+            .map(|alternative_allele| alternative_allele.id().to_string())
+            .collect();
+        assert_eq!(ids, ["DEL", "INS:ME"]);
+
+        assert_eq!(header.assembly(), Some("file:///assemblies.fasta"));
+        assert_eq!(header.meta()["Assay"].values(), ["WholeGenome", "Exome"]);
+
+        assert_eq!(header.to_string(), s);
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_without_file_format() {
         let s = r#"##ALT=<ID=DEL,Description="Deletion">
@@ -1,9 +1,10 @@
 //! VCF reader and iterators.
 
+mod lines;
 pub(crate) mod query;
 mod records;
 
-pub use self::{query::Query, records::Records};
+pub use self::{lines::Lines, query::Query, records::Records};
 
 use std::{
     io::{self, BufRead, Read, Seek},
@@ -48,6 +49,7 @@ use super::Header;
 #[derive(Debug)]
 pub struct Reader<R> {
     inner: R,
+    line_number: Option<u64>,
 }
 
 impl<R> Reader<R>
@@ -69,7 +71,10 @@ where
     /// let reader = vcf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            line_number: Some(0),
+        }
     }
 
     /// Returns a reference to the underlying reader.
@@ -142,7 +147,13 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<String> {
-        read_header(&mut self.inner)
+        let header = read_header(&mut self.inner)?;
+
+        if let Some(n) = self.line_number.as_mut() {
+            *n += header.lines().count() as u64;
+        }
+
+        Ok(header)
     }
 
     /// Reads a single raw VCF record.
@@ -180,7 +191,57 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
-        read_line(&mut self.inner, buf)
+        let len = read_line(&mut self.inner, buf)?;
+
+        if len > 0 {
+            if let Some(n) = self.line_number.as_mut() {
+                *n += 1;
+            }
+        }
+
+        Ok(len)
+    }
+
+    /// Returns an iterator over raw lines starting from the current stream position.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// Unlike [`Self::records`], lines are not parsed. This is useful for filtering records on
+    /// their raw text before paying the cost of parsing. A retained line can be parsed using
+    /// [`crate::Record::try_from_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// sq1\t2\t.\tC\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?.parse()?;
+    ///
+    /// let mut lines = reader.lines().filter(|result| {
+    ///     result
+    ///         .as_ref()
+    ///         .map(|line| line.starts_with("sq1\t"))
+    ///         .unwrap_or(true)
+    /// });
+    ///
+    /// let line = lines.next().transpose()?.expect("missing line");
+    /// assert_eq!(line, "sq1\t2\t.\tC\t.\t.\tPASS\t.");
+    ///
+    /// let record = vcf::Record::try_from_str(&line, &header)?;
+    /// assert_eq!(i32::from(record.position()), 2);
+    ///
+    /// assert!(lines.next().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn lines(&mut self) -> Lines<'_, R> {
+        Lines::new(self)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -210,6 +271,13 @@ where
     pub fn records<'r, 'h>(&'r mut self, header: &'h Header) -> Records<'r, 'h, R> {
         Records::new(self, header)
     }
+
+    // Returns the 1-based line number of the last line read.
+    //
+    // This is `None` if the position of the stream is unknown, i.e., after a seek.
+    pub(crate) fn line_number(&self) -> Option<u64> {
+        self.line_number
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -261,11 +329,16 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.line_number = None;
         self.inner.seek(pos)
     }
 
     /// Returns an iterator over records that intersects the given region.
     ///
+    /// To decompress upcoming blocks on worker threads while records are being read, wrap a BGZF
+    /// reader built with [`bgzf::reader::Builder::set_worker_count`], e.g.,
+    /// `vcf::Reader::new(bgzf::Reader::builder(file).set_worker_count(4).build())`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -498,6 +571,56 @@ sq0\t13
         Ok(())
     }
 
+    #[test]
+    fn test_lines() -> io::Result<()> {
+        let mut reader = Reader::new(DATA);
+        reader.read_header()?;
+
+        let lines: Vec<_> = reader.lines().collect::<io::Result<_>>()?;
+        assert_eq!(lines, [String::from("sq0\t8"), String::from("sq0\t13")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_crlf_line_endings() -> Result<(), Box<dyn std::error::Error>> {
+        fn read_records(data: &[u8]) -> Result<Vec<crate::Record>, Box<dyn std::error::Error>> {
+            let mut reader = Reader::new(data);
+            let header = reader.read_header()?.parse()?;
+            let records = reader.records(&header).collect::<io::Result<_>>()?;
+            Ok(records)
+        }
+
+        let data = b"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tPASS\tNS=1\tGT\t0|0
+sq0\t2\t.\tC\tG\t5\tPASS\t.\tGT\t0/1
+";
+
+        let crlf_data = std::str::from_utf8(data)?.replace('\n', "\r\n");
+        let crlf_data = crlf_data.as_bytes();
+
+        let mut reader = Reader::new(crlf_data);
+        let header: crate::Header = reader.read_header()?.parse()?;
+        assert_eq!(
+            header.sample_names().get_index(0).map(|s| s.as_str()),
+            Some("sample0")
+        );
+
+        let mut buf = String::new();
+        reader.read_record(&mut buf)?;
+        assert!(!buf.contains('\r'));
+
+        let actual = read_records(crlf_data)?;
+        let expected = read_records(data)?;
+        assert_eq!(actual, expected);
+        assert_eq!(actual[1].genotypes().to_string(), "GT\t0/1");
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
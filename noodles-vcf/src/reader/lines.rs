@@ -0,0 +1,39 @@
+use std::io::{self, BufRead};
+
+use super::Reader;
+
+/// An iterator over raw lines of a VCF reader.
+///
+/// Each line is returned as a [`String`] without being parsed. A retained line can subsequently
+/// be parsed as a [`crate::Record`] using [`crate::Record::try_from_str`].
+///
+/// This is created by calling [`Reader::lines`].
+pub struct Lines<'r, R> {
+    inner: &'r mut Reader<R>,
+}
+
+impl<'r, R> Lines<'r, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(inner: &'r mut Reader<R>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'r, R> Iterator for Lines<'r, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = String::new();
+
+        match self.inner.read_record(&mut buf) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(buf)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
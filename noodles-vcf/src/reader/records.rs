@@ -10,6 +10,7 @@ pub struct Records<'r, 'h, R> {
     inner: &'r mut Reader<R>,
     header: &'h Header,
     line_buf: String,
+    pad_missing_samples: bool,
 }
 
 impl<'r, 'h, R> Records<'r, 'h, R>
@@ -21,8 +22,42 @@ where
             inner,
             header,
             line_buf: String::new(),
+            pad_missing_samples: false,
         }
     }
+
+    /// Sets whether to fill missing sample columns with missing values.
+    ///
+    /// By default, a record with a different number of sample columns than the number of samples
+    /// in the header fails to parse. When enabled, a record with fewer sample columns is instead
+    /// filled with missing genotypes (`./.` or `.`, if the record has no genotype (`GT`) field).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|0
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?.parse()?;
+    ///
+    /// let mut records = reader.records(&header).set_pad_missing_samples(true);
+    ///
+    /// let record = records.next().transpose()?.expect("missing record");
+    /// assert_eq!(
+    ///     record.genotypes().genotypes(),
+    ///     Ok(vec![Some("0|0".parse()?), Some("./.".parse()?)])
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_pad_missing_samples(mut self, pad_missing_samples: bool) -> Self {
+        self.pad_missing_samples = pad_missing_samples;
+        self
+    }
 }
 
 impl<'r, 'h, R> Iterator for Records<'r, 'h, R>
@@ -36,11 +71,80 @@ where
 
         match self.inner.read_record(&mut self.line_buf) {
             Ok(0) => None,
-            Ok(_) => Some(
-                Record::try_from_str(&self.line_buf, self.header)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
-            ),
+            Ok(_) => {
+                let result = if self.pad_missing_samples {
+                    Record::try_from_str_with_missing_samples(&self.line_buf, self.header)
+                } else {
+                    Record::try_from_str(&self.line_buf, self.header)
+                };
+
+                Some(result.map_err(|e| match self.inner.line_number() {
+                    Some(n) => {
+                        io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", n, e))
+                    }
+                    None => io::Error::new(io::ErrorKind::InvalidData, e),
+                }))
+            }
             Err(e) => Some(Err(e)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DATA: &[u8] = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT:GQ\t0|0:13\t0/1:8
+sq0\t2\t.\tA\t.\t.\tPASS\t.\tGT:GQ\t0|0:13
+";
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?.parse()?;
+
+        let mut records = reader.records(&header);
+
+        assert!(records.next().transpose()?.is_some());
+
+        match records.next() {
+            Some(Err(e)) => {
+                assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                assert_eq!(
+                    e.to_string(),
+                    "line 4: invalid genotypes: expected 2 sample columns, found 1"
+                );
+            }
+            _ => panic!("expected error"),
+        }
+
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_pad_missing_samples() -> Result<(), Box<dyn std::error::Error>> {
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?.parse()?;
+
+        let records: Vec<_> = reader
+            .records(&header)
+            .set_pad_missing_samples(true)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 2);
+
+        let genotypes = records[1].genotypes();
+        assert_eq!(genotypes.len(), 2);
+        assert_eq!(
+            genotypes.genotypes(),
+            Ok(vec![Some("0|0".parse()?), Some("./.".parse()?)])
+        );
+        assert_eq!(genotypes.to_string(), "GT:GQ\t0|0:13\t./.");
+
+        Ok(())
+    }
+}
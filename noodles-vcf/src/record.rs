@@ -63,7 +63,15 @@ pub struct Record {
 impl Record {
     /// Parses a raw VCF record.
     pub fn try_from_str(s: &str, header: &Header) -> Result<Self, ParseError> {
-        parser::parse(s, header)
+        parser::parse(s, header, false)
+    }
+
+    // Parses a raw VCF record, filling missing trailing sample columns with missing values.
+    pub(crate) fn try_from_str_with_missing_samples(
+        s: &str,
+        header: &Header,
+    ) -> Result<Self, ParseError> {
+        parser::parse(s, header, true)
     }
 
     /// Returns a builder to create a record from each of its fields.
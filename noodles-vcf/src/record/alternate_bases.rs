@@ -10,7 +10,9 @@ use std::{
     str::FromStr,
 };
 
+use self::allele::Symbol;
 use super::MISSING_FIELD;
+use crate::header::AlternativeAlleles;
 
 const DELIMITER: char = ',';
 
@@ -18,6 +20,53 @@ const DELIMITER: char = ',';
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AlternateBases(Vec<Allele>);
 
+impl AlternateBases {
+    /// Returns the symbols of symbolic alleles that are not defined in the given header
+    /// alternative allele (`ALT`) records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{AlternativeAllele, AlternativeAlleles},
+    ///     record::{
+    ///         alternate_bases::allele::{
+    ///             symbol::{structural_variant::Type, StructuralVariant},
+    ///             Symbol,
+    ///         },
+    ///         AlternateBases,
+    ///     },
+    /// };
+    ///
+    /// let del = Symbol::StructuralVariant(StructuralVariant::from(Type::Deletion));
+    /// let alternative_alleles: AlternativeAlleles = [(
+    ///     del.clone(),
+    ///     AlternativeAllele::new(del, String::from("Deletion")),
+    /// )]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let alternate_bases: AlternateBases = "<DEL>,<DUP>,G".parse()?;
+    /// let undefined_symbols: Vec<_> = alternate_bases
+    ///     .undefined_symbols(&alternative_alleles)
+    ///     .map(|symbol| symbol.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(undefined_symbols, ["DUP"]);
+    /// # Ok::<_, vcf::record::alternate_bases::ParseError>(())
+    /// ```
+    pub fn undefined_symbols<'a>(
+        &'a self,
+        alternative_alleles: &'a AlternativeAlleles,
+    ) -> impl Iterator<Item = &'a Symbol> + 'a {
+        self.iter().filter_map(move |allele| match allele {
+            Allele::Symbol(symbol) if !alternative_alleles.contains_key(symbol) => Some(symbol),
+            _ => None,
+        })
+    }
+}
+
 impl Deref for AlternateBases {
     type Target = [Allele];
 
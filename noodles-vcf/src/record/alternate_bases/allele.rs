@@ -1,8 +1,9 @@
-//! VCF record alternate bases allele and symbol.
+//! VCF record alternate bases allele, breakend, and symbol.
 
+pub mod breakend;
 pub mod symbol;
 
-pub use self::symbol::Symbol;
+pub use self::{breakend::Breakend, symbol::Symbol};
 
 use std::{
     error,
@@ -20,7 +21,7 @@ pub enum Allele {
     /// A symbolic allele (e.g., `<DEL>`, `<CN:0>`, etc.).
     Symbol(Symbol),
     /// A breakend (e.g., `]sq0:5]A`, `G.`, etc.).
-    Breakend(String),
+    Breakend(Breakend),
     /// An overlapping deletion, i.e., a missing allele (`*`).
     OverlappingDeletion,
 }
@@ -36,7 +37,7 @@ impl fmt::Display for Allele {
                 Ok(())
             }
             Self::Symbol(symbol) => write!(f, "<{}>", symbol),
-            Self::Breakend(breakend) => f.write_str(breakend),
+            Self::Breakend(breakend) => write!(f, "{}", breakend),
             Self::OverlappingDeletion => f.write_str("*"),
         }
     }
@@ -51,6 +52,8 @@ pub enum ParseError {
     InvalidSymbol(symbol::ParseError),
     /// A base is invalid.
     InvalidBase(base::TryFromCharError),
+    /// The breakend is invalid.
+    InvalidBreakend(breakend::ParseError),
 }
 
 impl error::Error for ParseError {}
@@ -61,6 +64,7 @@ impl fmt::Display for ParseError {
             Self::Empty => f.write_str("empty input"),
             Self::InvalidSymbol(e) => write!(f, "invalid symbol: {}", e),
             Self::InvalidBase(e) => write!(f, "invalid base: {}", e),
+            Self::InvalidBreakend(e) => write!(f, "invalid breakend: {}", e),
         }
     }
 }
@@ -79,7 +83,9 @@ impl FromStr for Allele {
                         .map(Self::Symbol)
                         .map_err(ParseError::InvalidSymbol)
                 } else if is_breakend(s) {
-                    Ok(Self::Breakend(s.into()))
+                    s.parse()
+                        .map(Self::Breakend)
+                        .map_err(ParseError::InvalidBreakend)
                 } else {
                     s.chars()
                         .map(|c| c.to_ascii_uppercase())
@@ -102,7 +108,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_fmt() {
+    fn test_fmt() -> Result<(), breakend::ParseError> {
         let allele = Allele::Bases(vec![Base::G]);
         assert_eq!(allele.to_string(), "G");
 
@@ -120,27 +126,29 @@ mod tests {
         let allele = Allele::Symbol(Symbol::NonstructuralVariant(String::from("CN:0")));
         assert_eq!(allele.to_string(), "<CN:0>");
 
-        let allele = Allele::Breakend(String::from("]sq0:5]A"));
+        let allele = Allele::Breakend("]sq0:5]A".parse()?);
         assert_eq!(allele.to_string(), "]sq0:5]A");
 
-        let allele = Allele::Breakend(String::from("C[sq1:13["));
+        let allele = Allele::Breakend("C[sq1:13[".parse()?);
         assert_eq!(allele.to_string(), "C[sq1:13[");
 
-        let allele = Allele::Breakend(String::from("G."));
+        let allele = Allele::Breakend("G.".parse()?);
         assert_eq!(allele.to_string(), "G.");
 
-        let allele = Allele::Breakend(String::from("CT."));
+        let allele = Allele::Breakend("CT.".parse()?);
         assert_eq!(allele.to_string(), "CT.");
 
-        let allele = Allele::Breakend(String::from(".A"));
+        let allele = Allele::Breakend(".A".parse()?);
         assert_eq!(allele.to_string(), ".A");
 
-        let allele = Allele::Breakend(String::from(".GC"));
+        let allele = Allele::Breakend(".GC".parse()?);
         assert_eq!(allele.to_string(), ".GC");
+
+        Ok(())
     }
 
     #[test]
-    fn test_from_str() {
+    fn test_from_str() -> Result<(), breakend::ParseError> {
         assert_eq!("G".parse::<Allele>(), Ok(Allele::Bases(vec![Base::G])));
 
         assert_eq!(
@@ -171,32 +179,26 @@ mod tests {
 
         assert_eq!(
             "]sq0:5]A".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from("]sq0:5]A")))
+            Ok(Allele::Breakend("]sq0:5]A".parse()?))
         );
 
         assert_eq!(
             "C[sq1:13[".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from("C[sq1:13[")))
+            Ok(Allele::Breakend("C[sq1:13[".parse()?))
         );
 
-        assert_eq!(
-            "G.".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from("G.")))
-        );
+        assert_eq!("G.".parse::<Allele>(), Ok(Allele::Breakend("G.".parse()?)));
 
         assert_eq!(
             "CT.".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from("CT.")))
+            Ok(Allele::Breakend("CT.".parse()?))
         );
 
-        assert_eq!(
-            ".A".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from(".A")))
-        );
+        assert_eq!(".A".parse::<Allele>(), Ok(Allele::Breakend(".A".parse()?)));
 
         assert_eq!(
             ".GC".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from(".GC")))
+            Ok(Allele::Breakend(".GC".parse()?))
         );
 
         assert_eq!("".parse::<Allele>(), Err(ParseError::Empty));
@@ -208,5 +210,11 @@ mod tests {
             "Z".parse::<Allele>(),
             Err(ParseError::InvalidBase(_))
         ));
+        assert!(matches!(
+            "G[sq0:ND[".parse::<Allele>(),
+            Err(ParseError::InvalidBreakend(_))
+        ));
+
+        Ok(())
     }
 }
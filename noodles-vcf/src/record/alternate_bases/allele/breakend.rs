@@ -0,0 +1,472 @@
+//! VCF record alternate bases allele breakend.
+
+use std::{
+    error,
+    fmt::{self, Write},
+    str::FromStr,
+};
+
+use crate::record::{
+    position,
+    reference_bases::{base, Base},
+    Position,
+};
+
+const SINGLE_BREAKEND: char = '.';
+const RIGHT_BRACKET: char = '[';
+const LEFT_BRACKET: char = ']';
+
+/// A side of a breakend or its mate.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+}
+
+/// The mate of a paired breakend (e.g., `sq0:5` in `]sq0:5]A`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mate {
+    reference_sequence_name: String,
+    position: Position,
+    extension: Direction,
+}
+
+impl Mate {
+    /// Creates a breakend mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{alternate_bases::allele::breakend::{Direction, Mate}, Position};
+    /// let mate = Mate::new(String::from("sq0"), Position::try_from(5)?, Direction::Left);
+    /// # Ok::<_, noodles_vcf::record::position::TryFromIntError>(())
+    /// ```
+    pub fn new(reference_sequence_name: String, position: Position, extension: Direction) -> Self {
+        Self {
+            reference_sequence_name,
+            position,
+            extension,
+        }
+    }
+
+    /// Returns the reference sequence name of the mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{alternate_bases::allele::breakend::{Direction, Mate}, Position};
+    /// let mate = Mate::new(String::from("sq0"), Position::try_from(5)?, Direction::Left);
+    /// assert_eq!(mate.reference_sequence_name(), "sq0");
+    /// # Ok::<_, noodles_vcf::record::position::TryFromIntError>(())
+    /// ```
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the position of the mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{alternate_bases::allele::breakend::{Direction, Mate}, Position};
+    /// let position = Position::try_from(5)?;
+    /// let mate = Mate::new(String::from("sq0"), position, Direction::Left);
+    /// assert_eq!(mate.position(), position);
+    /// # Ok::<_, noodles_vcf::record::position::TryFromIntError>(())
+    /// ```
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the direction the joined sequence extends from the mate position.
+    ///
+    /// This is [`Direction::Right`] for `[p[` and [`Direction::Left`] for `]p]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{alternate_bases::allele::breakend::{Direction, Mate}, Position};
+    /// let mate = Mate::new(String::from("sq0"), Position::try_from(5)?, Direction::Left);
+    /// assert_eq!(mate.extension(), Direction::Left);
+    /// # Ok::<_, noodles_vcf::record::position::TryFromIntError>(())
+    /// ```
+    pub fn extension(&self) -> Direction {
+        self.extension
+    }
+}
+
+impl fmt::Display for Mate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bracket = match self.extension {
+            Direction::Left => LEFT_BRACKET,
+            Direction::Right => RIGHT_BRACKET,
+        };
+
+        write!(
+            f,
+            "{bracket}{}:{}{bracket}",
+            self.reference_sequence_name,
+            self.position,
+            bracket = bracket
+        )
+    }
+}
+
+/// A VCF record alternate bases allele breakend.
+///
+/// A breakend is either paired, e.g., `t[p[`, `t]p]`, `]p]t`, and `[p[t`, or single, e.g., `t.`
+/// and `.t`, where `t` are the replacement bases and `p` is the mate position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breakend {
+    bases: Vec<Base>,
+    join: Direction,
+    mate: Option<Mate>,
+}
+
+impl Breakend {
+    /// Creates a breakend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::breakend::{Breakend, Direction},
+    ///     reference_bases::Base,
+    /// };
+    ///
+    /// let breakend = Breakend::new(vec![Base::G], Direction::Right, None);
+    /// assert_eq!(breakend.to_string(), "G.");
+    /// ```
+    pub fn new(bases: Vec<Base>, join: Direction, mate: Option<Mate>) -> Self {
+        Self { bases, join, mate }
+    }
+
+    /// Returns the replacement bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{alternate_bases::allele::breakend::Breakend, reference_bases::Base};
+    /// let breakend: Breakend = "]sq0:5]AC".parse()?;
+    /// assert_eq!(breakend.bases(), [Base::A, Base::C]);
+    /// # Ok::<_, noodles_vcf::record::alternate_bases::allele::breakend::ParseError>(())
+    /// ```
+    pub fn bases(&self) -> &[Base] {
+        &self.bases
+    }
+
+    /// Returns the side of the replacement bases to which the adjacency is joined.
+    ///
+    /// This is [`Direction::Right`] for `t[p[`, `t]p]`, and `t.` and [`Direction::Left`] for
+    /// `]p]t`, `[p[t`, and `.t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::breakend::{Breakend, Direction};
+    /// let breakend: Breakend = "]sq0:5]A".parse()?;
+    /// assert_eq!(breakend.join(), Direction::Left);
+    /// # Ok::<_, noodles_vcf::record::alternate_bases::allele::breakend::ParseError>(())
+    /// ```
+    pub fn join(&self) -> Direction {
+        self.join
+    }
+
+    /// Returns the mate of a paired breakend.
+    ///
+    /// This is `None` for a single breakend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::breakend::{Breakend, Direction};
+    ///
+    /// let breakend: Breakend = "]sq0:5]A".parse()?;
+    /// let mate = breakend.mate().expect("missing mate");
+    /// assert_eq!(mate.reference_sequence_name(), "sq0");
+    /// assert_eq!(i32::from(mate.position()), 5);
+    /// assert_eq!(mate.extension(), Direction::Left);
+    ///
+    /// let breakend: Breakend = "G.".parse()?;
+    /// assert!(breakend.mate().is_none());
+    /// # Ok::<_, noodles_vcf::record::alternate_bases::allele::breakend::ParseError>(())
+    /// ```
+    pub fn mate(&self) -> Option<&Mate> {
+        self.mate.as_ref()
+    }
+}
+
+impl fmt::Display for Breakend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.join == Direction::Left {
+            match &self.mate {
+                Some(mate) => write!(f, "{}", mate)?,
+                None => f.write_char(SINGLE_BREAKEND)?,
+            }
+        }
+
+        for base in &self.bases {
+            f.write_char(char::from(*base))?;
+        }
+
+        if self.join == Direction::Right {
+            match &self.mate {
+                Some(mate) => write!(f, "{}", mate)?,
+                None => f.write_char(SINGLE_BREAKEND)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw VCF record alternate bases allele breakend fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+    /// A base is invalid.
+    InvalidBase(base::TryFromCharError),
+    /// The mate reference sequence name is missing.
+    MissingMateReferenceSequenceName,
+    /// The mate position is invalid.
+    InvalidMatePosition(position::ParseError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidBase(e) => write!(f, "invalid base: {}", e),
+            Self::MissingMateReferenceSequenceName => {
+                f.write_str("missing mate reference sequence name")
+            }
+            Self::InvalidMatePosition(e) => write!(f, "invalid mate position: {}", e),
+        }
+    }
+}
+
+impl FromStr for Breakend {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        match s.find(|c| c == RIGHT_BRACKET || c == LEFT_BRACKET) {
+            // `]p]t` or `[p[t`
+            Some(0) => {
+                let bracket = s.chars().next().ok_or(ParseError::Invalid)?;
+                let rest = &s[1..];
+                let i = rest.find(bracket).ok_or(ParseError::Invalid)?;
+                let mate = parse_mate(&rest[..i], bracket)?;
+                let bases = parse_bases(&rest[i + 1..])?;
+                Ok(Self::new(bases, Direction::Left, Some(mate)))
+            }
+            // `t[p[` or `t]p]`
+            Some(i) => {
+                let bases = parse_bases(&s[..i])?;
+                let bracket = s[i..].chars().next().ok_or(ParseError::Invalid)?;
+                let raw_mate = s[i + 1..]
+                    .strip_suffix(bracket)
+                    .ok_or(ParseError::Invalid)?;
+                let mate = parse_mate(raw_mate, bracket)?;
+                Ok(Self::new(bases, Direction::Right, Some(mate)))
+            }
+            None => {
+                if let Some(t) = s.strip_suffix(SINGLE_BREAKEND) {
+                    parse_bases(t).map(|bases| Self::new(bases, Direction::Right, None))
+                } else if let Some(t) = s.strip_prefix(SINGLE_BREAKEND) {
+                    parse_bases(t).map(|bases| Self::new(bases, Direction::Left, None))
+                } else {
+                    Err(ParseError::Invalid)
+                }
+            }
+        }
+    }
+}
+
+fn parse_bases(s: &str) -> Result<Vec<Base>, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Invalid);
+    }
+
+    s.chars()
+        .map(|c| c.to_ascii_uppercase())
+        .map(Base::try_from)
+        .collect::<Result<_, _>>()
+        .map_err(ParseError::InvalidBase)
+}
+
+fn parse_mate(s: &str, bracket: char) -> Result<Mate, ParseError> {
+    let (reference_sequence_name, raw_position) = s.rsplit_once(':').ok_or(ParseError::Invalid)?;
+
+    if reference_sequence_name.is_empty() {
+        return Err(ParseError::MissingMateReferenceSequenceName);
+    }
+
+    let position = raw_position
+        .parse()
+        .map_err(ParseError::InvalidMatePosition)?;
+
+    let extension = if bracket == RIGHT_BRACKET {
+        Direction::Right
+    } else {
+        Direction::Left
+    };
+
+    Ok(Mate::new(
+        reference_sequence_name.into(),
+        position,
+        extension,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_mate(extension: Direction) -> Mate {
+        Mate::new(
+            String::from("sq1"),
+            Position::try_from(13).unwrap(),
+            extension,
+        )
+    }
+
+    #[test]
+    fn test_fmt() {
+        let breakend = Breakend::new(
+            vec![Base::G],
+            Direction::Right,
+            Some(build_mate(Direction::Right)),
+        );
+        assert_eq!(breakend.to_string(), "G[sq1:13[");
+
+        let breakend = Breakend::new(
+            vec![Base::G],
+            Direction::Right,
+            Some(build_mate(Direction::Left)),
+        );
+        assert_eq!(breakend.to_string(), "G]sq1:13]");
+
+        let breakend = Breakend::new(
+            vec![Base::G],
+            Direction::Left,
+            Some(build_mate(Direction::Left)),
+        );
+        assert_eq!(breakend.to_string(), "]sq1:13]G");
+
+        let breakend = Breakend::new(
+            vec![Base::G],
+            Direction::Left,
+            Some(build_mate(Direction::Right)),
+        );
+        assert_eq!(breakend.to_string(), "[sq1:13[G");
+
+        let breakend = Breakend::new(vec![Base::C, Base::T], Direction::Right, None);
+        assert_eq!(breakend.to_string(), "CT.");
+
+        let breakend = Breakend::new(vec![Base::C, Base::T], Direction::Left, None);
+        assert_eq!(breakend.to_string(), ".CT");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "G[sq1:13[".parse(),
+            Ok(Breakend::new(
+                vec![Base::G],
+                Direction::Right,
+                Some(build_mate(Direction::Right)),
+            ))
+        );
+
+        assert_eq!(
+            "G]sq1:13]".parse(),
+            Ok(Breakend::new(
+                vec![Base::G],
+                Direction::Right,
+                Some(build_mate(Direction::Left)),
+            ))
+        );
+
+        assert_eq!(
+            "]sq1:13]G".parse(),
+            Ok(Breakend::new(
+                vec![Base::G],
+                Direction::Left,
+                Some(build_mate(Direction::Left)),
+            ))
+        );
+
+        assert_eq!(
+            "[sq1:13[G".parse(),
+            Ok(Breakend::new(
+                vec![Base::G],
+                Direction::Left,
+                Some(build_mate(Direction::Right)),
+            ))
+        );
+
+        assert_eq!(
+            "CAGTN[sq1:13[".parse(),
+            Ok(Breakend::new(
+                vec![Base::C, Base::A, Base::G, Base::T, Base::N],
+                Direction::Right,
+                Some(build_mate(Direction::Right)),
+            ))
+        );
+
+        assert_eq!(
+            "G.".parse(),
+            Ok(Breakend::new(vec![Base::G], Direction::Right, None))
+        );
+
+        assert_eq!(
+            ".G".parse(),
+            Ok(Breakend::new(vec![Base::G], Direction::Left, None))
+        );
+
+        assert_eq!(
+            "]<ctg1>:13]G".parse(),
+            Ok(Breakend::new(
+                vec![Base::G],
+                Direction::Left,
+                Some(Mate::new(
+                    String::from("<ctg1>"),
+                    Position::try_from(13).unwrap(),
+                    Direction::Left
+                )),
+            ))
+        );
+
+        assert_eq!("".parse::<Breakend>(), Err(ParseError::Empty));
+        assert_eq!("G".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!(".".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G[sq1:13]".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("]sq1:13G".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("[sq1:13[".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G[sq1[".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!(
+            "G[:13[".parse::<Breakend>(),
+            Err(ParseError::MissingMateReferenceSequenceName)
+        );
+        assert!(matches!(
+            "G[sq1:ND[".parse::<Breakend>(),
+            Err(ParseError::InvalidMatePosition(_))
+        ));
+        assert!(matches!(
+            "Z.".parse::<Breakend>(),
+            Err(ParseError::InvalidBase(_))
+        ));
+    }
+}
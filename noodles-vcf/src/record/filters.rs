@@ -40,6 +40,23 @@ impl fmt::Display for TryFromIteratorError {
 }
 
 impl Filters {
+    /// Returns whether the filters are a pass (`PASS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Filters;
+    ///
+    /// assert!(Filters::Pass.is_pass());
+    ///
+    /// let filters = Filters::try_from_iter(["q10"])?;
+    /// assert!(!filters.is_pass());
+    /// # Ok::<(), noodles_vcf::record::filters::TryFromIteratorError>(())
+    /// ```
+    pub fn is_pass(&self) -> bool {
+        matches!(self, Self::Pass)
+    }
+
     /// Performs a conversion from a string iterator to a set of filters.
     ///
     /// # Examples
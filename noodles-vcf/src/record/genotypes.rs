@@ -2,8 +2,13 @@
 
 pub mod genotype;
 pub mod keys;
+pub mod phase_block;
 
-pub use self::{genotype::Genotype, keys::Keys};
+pub use self::{
+    genotype::Genotype,
+    keys::Keys,
+    phase_block::{phase_blocks, PhaseBlock},
+};
 
 use std::{
     error,
@@ -53,7 +58,7 @@ impl Genotypes {
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn parse(s: &str, header: &Header) -> Result<Genotypes, ParseError> {
-        parse(s, header)
+        parse(s, header, false)
     }
 
     /// Creates VCF record genotypes.
@@ -125,6 +130,13 @@ impl Genotypes {
     ) -> Result<Vec<Option<field::value::Genotype>>, genotype::GenotypeError> {
         self.iter().map(|g| g.genotype().transpose()).collect()
     }
+
+    /// Returns the VCF record genotype phase sets.
+    ///
+    /// Each sample without a phase set (`PS`) value is `None`.
+    pub fn phase_sets(&self) -> Result<Vec<Option<i32>>, genotype::PhaseSetError> {
+        self.iter().map(|g| g.phase_set().transpose()).collect()
+    }
 }
 
 impl Deref for Genotypes {
@@ -168,6 +180,10 @@ pub enum ParseError {
     InvalidKeys(keys::ParseError),
     /// A genotype is invalid.
     InvalidGenotype(genotype::ParseError),
+    /// The number of genotypes does not match the number of samples in the header.
+    ///
+    /// The values are the (actual, expected) number of sample columns.
+    SampleCountMismatch(usize, usize),
 }
 
 impl error::Error for ParseError {}
@@ -179,6 +195,9 @@ impl fmt::Display for ParseError {
             Self::Invalid => f.write_str("invalid input"),
             Self::InvalidKeys(e) => write!(f, "invalid keys: {}", e),
             Self::InvalidGenotype(e) => write!(f, "invalid genotype: {}", e),
+            Self::SampleCountMismatch(actual, expected) => {
+                write!(f, "expected {} sample columns, found {}", expected, actual)
+            }
         }
     }
 }
@@ -187,11 +206,19 @@ impl FromStr for Genotypes {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse(s, &Header::default())
+        parse(s, &Header::default(), false)
     }
 }
 
-fn parse(s: &str, header: &Header) -> Result<Genotypes, ParseError> {
+// Parses raw genotypes.
+//
+// If the header has sample names, the number of genotypes must match the number of samples. When
+// `pad_missing_samples` is set, missing trailing samples are instead filled with missing values.
+pub(crate) fn parse(
+    s: &str,
+    header: &Header,
+    pad_missing_samples: bool,
+) -> Result<Genotypes, ParseError> {
     if s.is_empty() {
         return Err(ParseError::Empty);
     }
@@ -200,15 +227,48 @@ fn parse(s: &str, header: &Header) -> Result<Genotypes, ParseError> {
 
     let keys = format.parse().map_err(ParseError::InvalidKeys)?;
 
-    let genotypes = t
+    let mut genotypes: Vec<_> = t
         .split(FIELD_DELIMITER)
-        .map(|t| Genotype::parse(t, header.formats(), &keys))
+        .map(|t| genotype::parse(t, header.formats(), &keys, header.file_format()))
         .collect::<Result<_, _>>()
         .map_err(ParseError::InvalidGenotype)?;
 
+    let sample_count = header.sample_names().len();
+
+    if sample_count > 0 && genotypes.len() != sample_count {
+        if pad_missing_samples && genotypes.len() < sample_count {
+            let genotype = missing_genotype(&keys);
+            genotypes.resize(sample_count, genotype);
+        } else {
+            return Err(ParseError::SampleCountMismatch(
+                genotypes.len(),
+                sample_count,
+            ));
+        }
+    }
+
     Ok(Genotypes::new(keys, genotypes))
 }
 
+fn missing_genotype(keys: &Keys) -> Genotype {
+    use self::genotype::{field::Value, Field};
+    use crate::header::format::Key;
+
+    const MISSING_GENOTYPE: &str = "./.";
+
+    if keys.get_index(0) == Some(&Key::Genotype) {
+        let fields = vec![Field::new(
+            Key::Genotype,
+            Some(Value::String(MISSING_GENOTYPE.into())),
+        )];
+
+        // SAFETY: The fields have no duplicate keys.
+        Genotype::try_from(fields).unwrap()
+    } else {
+        Genotype::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +349,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_with_sample_count_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let header = crate::Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        assert_eq!(
+            Genotypes::parse("GT\t0|0\t0/1", &header),
+            Err(ParseError::SampleCountMismatch(2, 3))
+        );
+
+        assert_eq!(
+            Genotypes::parse("GT\t0|0\t0/1\t1/1\t./.", &header),
+            Err(ParseError::SampleCountMismatch(4, 3))
+        );
+
+        let actual = parse("GT\t0|0", &header, true)?;
+        assert_eq!(actual.to_string(), "GT\t0|0\t./.\t./.");
+
+        let actual = parse("GQ\t13", &header, true)?;
+        assert_eq!(actual.to_string(), "GQ\t13\t.\t.");
+
+        assert_eq!(
+            parse("GT\t0|0\t0/1\t1/1\t./.", &header, true),
+            Err(ParseError::SampleCountMismatch(4, 3))
+        );
+
+        Ok(())
+    }
 }
@@ -13,8 +13,8 @@ use indexmap::IndexMap;
 
 use super::Keys;
 use crate::{
-    header::{format::Key, Format, Formats},
-    record::MISSING_FIELD,
+    header::{format::Key, FileFormat, Format, Formats},
+    record::{filters, Filters, MISSING_FIELD},
 };
 
 const DELIMITER: char = ':';
@@ -68,6 +68,47 @@ impl fmt::Display for GenotypeError {
     }
 }
 
+/// An error returned when a genotype filter (`FT`) field value fails to parse.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FiltersError {
+    /// The filter field value is invalid.
+    InvalidValue(filters::ParseError),
+    /// The filter field value type is invalid.
+    ///
+    /// The `FT` field value must be a `String`.
+    InvalidValueType(field::Value),
+}
+
+impl error::Error for FiltersError {}
+
+impl fmt::Display for FiltersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValue(e) => write!(f, "invalid value: {}", e),
+            Self::InvalidValueType(value) => write!(f, "invalid String, got {:?}", value),
+        }
+    }
+}
+
+/// An error returned when a phase set (`PS`) field value is invalid.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PhaseSetError {
+    /// The phase set field value type is invalid.
+    ///
+    /// The `PS` field value must be an `Integer`.
+    InvalidValueType(field::Value),
+}
+
+impl error::Error for PhaseSetError {}
+
+impl fmt::Display for PhaseSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValueType(value) => write!(f, "invalid Integer, got {:?}", value),
+        }
+    }
+}
+
 impl Genotype {
     /// Parses a raw genotype for the given genotype keys.
     ///
@@ -97,26 +138,7 @@ impl Genotype {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn parse(s: &str, formats: &Formats, keys: &Keys) -> Result<Self, ParseError> {
-        if s.is_empty() {
-            return Err(ParseError::Empty);
-        } else if s == MISSING_FIELD {
-            return Ok(Self::default());
-        }
-
-        let mut fields = Vec::with_capacity(keys.len());
-
-        for (raw_field, key) in s.split(DELIMITER).zip(keys.iter()) {
-            let field = if let Some(format) = formats.get(key) {
-                Field::from_str_format(raw_field, format).map_err(ParseError::InvalidField)?
-            } else {
-                let format = Format::from(key.clone());
-                Field::from_str_format(raw_field, &format).map_err(ParseError::InvalidField)?
-            };
-
-            fields.push(field);
-        }
-
-        Self::try_from(fields).map_err(ParseError::Invalid)
+        parse(s, formats, keys, FileFormat::default())
     }
 
     /// Returns the VCF record genotypes genotype value.
@@ -152,6 +174,85 @@ impl Genotype {
                 _ => Err(GenotypeError::InvalidValueType(value.clone())),
             })
     }
+
+    /// Returns the VCF record genotype filters.
+    ///
+    /// This is a convenience method to return a parsed version of the filter (`FT`) field value.
+    /// A missing field or missing value (`.`) returns `None`. Use `[Self::get]` with
+    /// `[Key::Filter]` to get the raw value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Key, Format},
+    ///     record::{genotypes::Genotype, Filters},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Format::from(Key::Genotype))
+    ///     .add_format(Format::from(Key::Filter))
+    ///     .build();
+    ///
+    /// let keys = "GT:FT".parse()?;
+    ///
+    /// let genotype = Genotype::parse("0|0:PASS", header.formats(), &keys)?;
+    /// assert_eq!(genotype.filters(), Some(Ok(Filters::Pass)));
+    ///
+    /// let genotype = Genotype::parse("0|0:q10", header.formats(), &keys)?;
+    /// assert_eq!(genotype.filters(), Some(Ok("q10".parse()?)));
+    ///
+    /// let genotype = Genotype::parse("0|0:.", header.formats(), &keys)?;
+    /// assert!(genotype.filters().is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn filters(&self) -> Option<Result<Filters, FiltersError>> {
+        self.get(&Key::Filter)
+            .and_then(|f| f.value())
+            .map(|value| match value {
+                field::Value::String(s) => s.parse().map_err(FiltersError::InvalidValue),
+                _ => Err(FiltersError::InvalidValueType(value.clone())),
+            })
+    }
+
+    /// Returns the VCF record genotype phase set.
+    ///
+    /// This is a convenience method to return the phase set (`PS`) field value as an integer. A
+    /// missing field or missing value (`.`) returns `None`. Use `[Self::get]` with
+    /// `[Key::PhaseSet]` to get the raw value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Key, Format},
+    ///     record::genotypes::Genotype,
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Format::from(Key::Genotype))
+    ///     .add_format(Format::from(Key::PhaseSet))
+    ///     .build();
+    ///
+    /// let keys = "GT:PS".parse()?;
+    ///
+    /// let genotype = Genotype::parse("0|1:8", header.formats(), &keys)?;
+    /// assert_eq!(genotype.phase_set(), Some(Ok(8)));
+    ///
+    /// let genotype = Genotype::parse("0/1:.", header.formats(), &keys)?;
+    /// assert!(genotype.phase_set().is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn phase_set(&self) -> Option<Result<i32, PhaseSetError>> {
+        self.get(&Key::PhaseSet)
+            .and_then(|f| f.value())
+            .map(|value| match value {
+                field::Value::Integer(n) => Ok(*n),
+                _ => Err(PhaseSetError::InvalidValueType(value.clone())),
+            })
+    }
 }
 
 impl Deref for Genotype {
@@ -236,6 +337,34 @@ impl TryFrom<Vec<Field>> for Genotype {
     }
 }
 
+pub(crate) fn parse(
+    s: &str,
+    formats: &Formats,
+    keys: &Keys,
+    file_format: FileFormat,
+) -> Result<Genotype, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    } else if s == MISSING_FIELD {
+        return Ok(Genotype::default());
+    }
+
+    let mut fields = Vec::with_capacity(keys.len());
+
+    for (raw_field, key) in s.split(DELIMITER).zip(keys.iter()) {
+        let field = if let Some(format) = formats.get(key) {
+            field::parse(raw_field, format, file_format).map_err(ParseError::InvalidField)?
+        } else {
+            let format = Format::from(key.clone());
+            field::parse(raw_field, &format, file_format).map_err(ParseError::InvalidField)?
+        };
+
+        fields.push(field);
+    }
+
+    Genotype::try_from(fields).map_err(ParseError::Invalid)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,6 +474,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_phase_set() -> Result<(), TryFromFieldsError> {
+        let genotype = Genotype::try_from(vec![Field::new(
+            Key::PhaseSet,
+            Some(field::Value::Integer(8)),
+        )])?;
+        assert_eq!(genotype.phase_set(), Some(Ok(8)));
+
+        let genotype = Genotype::try_from(vec![Field::new(Key::PhaseSet, None)])?;
+        assert!(genotype.phase_set().is_none());
+
+        let genotype = Genotype::default();
+        assert!(genotype.phase_set().is_none());
+
+        let genotype = Genotype::try_from(vec![Field::new(
+            Key::PhaseSet,
+            Some(field::Value::String(String::from("ndls"))),
+        )])?;
+        assert_eq!(
+            genotype.phase_set(),
+            Some(Err(PhaseSetError::InvalidValueType(field::Value::String(
+                String::from("ndls")
+            ))))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_genotype() -> Result<(), TryFromFieldsError> {
         let genotype = Genotype::try_from(vec![Field::new(
@@ -369,4 +526,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_filters() -> Result<(), Box<dyn std::error::Error>> {
+        let header = crate::Header::builder()
+            .add_format(Format::from(Key::Genotype))
+            .add_format(Format::from(Key::Filter))
+            .build();
+
+        let keys = "GT:FT".parse()?;
+
+        let genotype = Genotype::parse("0|0:PASS", header.formats(), &keys)?;
+        assert_eq!(genotype.filters(), Some(Ok(Filters::Pass)));
+
+        let genotype = Genotype::parse("0|0:q10;s50", header.formats(), &keys)?;
+        assert_eq!(
+            genotype.filters(),
+            Some(Ok(Filters::try_from_iter(["q10", "s50"])?))
+        );
+
+        let genotype = Genotype::parse("0|0:.", header.formats(), &keys)?;
+        assert!(genotype.filters().is_none());
+
+        let genotype = Genotype::parse("0|0", header.formats(), &keys)?;
+        assert!(genotype.filters().is_none());
+
+        let genotype = Genotype::try_from(vec![Field::new(
+            Key::Filter,
+            Some(field::Value::Integer(0)),
+        )])?;
+
+        assert!(matches!(
+            genotype.filters(),
+            Some(Err(FiltersError::InvalidValueType(_)))
+        ));
+
+        Ok(())
+    }
 }
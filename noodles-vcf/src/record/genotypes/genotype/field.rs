@@ -6,7 +6,7 @@ pub use self::value::Value;
 
 use std::{error, fmt};
 
-use crate::header::{format::Key, Format};
+use crate::header::{format::Key, FileFormat, Format};
 
 const MISSING_VALUE: &str = ".";
 
@@ -53,15 +53,7 @@ impl Field {
     /// );
     /// ```
     pub fn from_str_format(s: &str, format: &Format) -> Result<Self, ParseError> {
-        let key = format.id().clone();
-
-        if s == MISSING_VALUE {
-            Ok(Self::new(key, None))
-        } else {
-            Value::from_str_format(s, format)
-                .map(|v| Self::new(key, Some(v)))
-                .map_err(ParseError::InvalidValue)
-        }
+        parse(s, format, FileFormat::default())
     }
 
     /// Creates a VCF record genotype field.
@@ -159,6 +151,22 @@ impl fmt::Display for Field {
     }
 }
 
+pub(crate) fn parse(
+    s: &str,
+    format: &Format,
+    file_format: FileFormat,
+) -> Result<Field, ParseError> {
+    let key = format.id().clone();
+
+    if s == MISSING_VALUE {
+        Ok(Field::new(key, None))
+    } else {
+        value::parse(s, format, file_format)
+            .map(|v| Field::new(key, Some(v)))
+            .map_err(ParseError::InvalidValue)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::header::{format::Key, Format};
@@ -7,8 +7,8 @@ pub use self::genotype::Genotype;
 use std::{error, fmt, num, str};
 
 use crate::{
-    header::{format::Type, Format, Number},
-    record::value::{self, percent_decode},
+    header::{format::Type, FileFormat, Format, Number},
+    record::value::{self, decode_string},
 };
 
 const DELIMITER: char = ',';
@@ -140,6 +140,9 @@ impl fmt::Display for ParseError {
 impl Value {
     /// Parses a raw genotype field value for the given key.
     ///
+    /// String values are percent-decoded, i.e., this uses the rules of VCF 4.3. Records parsed
+    /// with a header (e.g., [`crate::Record::try_from_str`]) use the file format of the header.
+    ///
     /// # Examples
     ///
     /// ```
@@ -152,41 +155,203 @@ impl Value {
     /// assert_eq!(Value::from_str_format("13", &format), Ok(Value::Integer(13)));
     /// ```
     pub fn from_str_format(s: &str, format: &Format) -> Result<Self, ParseError> {
-        match format.ty() {
-            Type::Integer => match format.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(
-                    format.number(),
-                    format.ty(),
-                )),
-                Number::Count(1) => parse_i32(s),
-                _ => parse_i32_array(s),
-            },
-            Type::Float => match format.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(
-                    format.number(),
-                    format.ty(),
-                )),
-                Number::Count(1) => parse_f32(s),
-                _ => parse_f32_array(s),
-            },
-            Type::Character => match format.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(
-                    format.number(),
-                    format.ty(),
-                )),
-                Number::Count(1) => parse_char(s),
-                _ => parse_char_array(s),
-            },
-            Type::String => match format.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(
-                    format.number(),
-                    format.ty(),
-                )),
-                Number::Count(1) => parse_string(s),
-                _ => parse_string_array(s),
-            },
+        parse(s, format, FileFormat::default())
+    }
+
+    /// Converts phred-scaled genotype likelihoods (`PL`) to log10-scaled genotype likelihoods
+    /// (`GL`).
+    ///
+    /// Each value is converted as `GL = -PL / 10`. Missing values are kept as missing.
+    ///
+    /// This returns `None` if the value is not an integer array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::Value;
+    ///
+    /// let pl = Value::IntegerArray(vec![Some(0), Some(10), None]);
+    /// let gl = Value::FloatArray(vec![Some(0.0), Some(-1.0), None]);
+    /// assert_eq!(pl.pl_to_gl(), Some(gl));
+    ///
+    /// assert!(Value::Float(0.0).pl_to_gl().is_none());
+    /// ```
+    pub fn pl_to_gl(&self) -> Option<Self> {
+        match self {
+            Self::IntegerArray(values) => Some(Self::FloatArray(
+                values.iter().map(|v| v.map(pl_to_gl)).collect(),
+            )),
+            _ => None,
         }
     }
+
+    /// Converts log10-scaled genotype likelihoods (`GL`) to phred-scaled genotype likelihoods
+    /// (`PL`).
+    ///
+    /// Each value is converted as `PL = min(round(-10 * GL), 255)`. Missing and non-finite values
+    /// are converted to missing values.
+    ///
+    /// PL values are capped at 255, as in bcftools, so that they fit in a BCF 8-bit integer. They
+    /// are not normalized to the most likely genotype.
+    ///
+    /// This returns `None` if the value is not a float array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::Value;
+    ///
+    /// let gl = Value::FloatArray(vec![Some(0.0), Some(-1.04), Some(-30.0), None]);
+    /// let pl = Value::IntegerArray(vec![Some(0), Some(10), Some(255), None]);
+    /// assert_eq!(gl.gl_to_pl(), Some(pl));
+    ///
+    /// assert!(Value::Integer(0).gl_to_pl().is_none());
+    /// ```
+    pub fn gl_to_pl(&self) -> Option<Self> {
+        match self {
+            Self::FloatArray(values) => Some(Self::IntegerArray(
+                values.iter().map(|v| v.and_then(gl_to_pl)).collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Converts phred-scaled genotype likelihoods (`PL`) to genotype posterior probabilities
+    /// (`GP`).
+    ///
+    /// This assumes a flat prior, i.e., the likelihoods are normalized to sum to 1. Missing
+    /// values are kept as missing and are excluded from the normalization. Capped PL values
+    /// (e.g., 255) are used as is.
+    ///
+    /// This returns `None` if the value is not an integer array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::Value;
+    ///
+    /// let pl = Value::IntegerArray(vec![Some(0), Some(255), Some(255)]);
+    ///
+    /// match pl.pl_to_gp() {
+    ///     Some(Value::FloatArray(gp)) => {
+    ///         let sum: f32 = gp.iter().flatten().sum();
+    ///         assert!((sum - 1.0).abs() < f32::EPSILON);
+    ///     }
+    ///     _ => panic!("invalid GP value"),
+    /// }
+    /// ```
+    pub fn pl_to_gp(&self) -> Option<Self> {
+        match self {
+            Self::IntegerArray(values) => {
+                let gls: Vec<_> = values.iter().map(|v| v.map(pl_to_gl)).collect();
+                Some(Self::FloatArray(normalize_gls(&gls)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts log10-scaled genotype likelihoods (`GL`) to genotype posterior probabilities
+    /// (`GP`).
+    ///
+    /// This assumes a flat prior, i.e., the likelihoods are normalized to sum to 1. Missing and
+    /// non-finite values are converted to missing values and are excluded from the normalization.
+    ///
+    /// This returns `None` if the value is not a float array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::Value;
+    ///
+    /// let gl = Value::FloatArray(vec![Some(0.0), Some(0.0), None]);
+    /// let gp = Value::FloatArray(vec![Some(0.5), Some(0.5), None]);
+    /// assert_eq!(gl.gl_to_gp(), Some(gp));
+    /// ```
+    pub fn gl_to_gp(&self) -> Option<Self> {
+        match self {
+            Self::FloatArray(values) => {
+                let gls: Vec<_> = values.iter().map(|v| v.filter(|n| n.is_finite())).collect();
+
+                Some(Self::FloatArray(normalize_gls(&gls)))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn parse(
+    s: &str,
+    format: &Format,
+    file_format: FileFormat,
+) -> Result<Value, ParseError> {
+    match format.ty() {
+        Type::Integer => match format.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(
+                format.number(),
+                format.ty(),
+            )),
+            Number::Count(1) => parse_i32(s),
+            _ => parse_i32_array(s),
+        },
+        Type::Float => match format.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(
+                format.number(),
+                format.ty(),
+            )),
+            Number::Count(1) => parse_f32(s),
+            _ => parse_f32_array(s),
+        },
+        Type::Character => match format.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(
+                format.number(),
+                format.ty(),
+            )),
+            Number::Count(1) => parse_char(s),
+            _ => parse_char_array(s),
+        },
+        Type::String => match format.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(
+                format.number(),
+                format.ty(),
+            )),
+            Number::Count(1) => parse_string(s, file_format),
+            _ => parse_string_array(s, file_format),
+        },
+    }
+}
+
+fn pl_to_gl(n: i32) -> f32 {
+    -(n as f32) / 10.0
+}
+
+const MAX_PL: i32 = 255;
+
+fn gl_to_pl(n: f32) -> Option<i32> {
+    if n.is_finite() {
+        Some(((-10.0 * n).round() as i32).min(MAX_PL))
+    } else {
+        None
+    }
+}
+
+// Converts log10-scaled likelihoods to probabilities that sum to 1.
+//
+// Likelihoods are shifted by the maximum likelihood before exponentiation to avoid underflow.
+fn normalize_gls(gls: &[Option<f32>]) -> Vec<Option<f32>> {
+    let max = gls
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    let ps: Vec<_> = gls
+        .iter()
+        .map(|gl| gl.map(|n| 10.0f32.powf(n - max)))
+        .collect();
+
+    let sum: f32 = ps.iter().flatten().sum();
+
+    ps.into_iter().map(|p| p.map(|n| n / sum)).collect()
 }
 
 fn parse_i32(s: &str) -> Result<Value, ParseError> {
@@ -258,19 +423,19 @@ fn parse_char_array(s: &str) -> Result<Value, ParseError> {
         .map(Value::CharacterArray)
 }
 
-fn parse_string(s: &str) -> Result<Value, ParseError> {
-    percent_decode(s)
+fn parse_string(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
+    decode_string(file_format, s)
         .map(|t| Value::String(t.into()))
         .map_err(ParseError::InvalidString)
 }
 
-fn parse_string_array(s: &str) -> Result<Value, ParseError> {
+fn parse_string_array(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| {
             if t == MISSING_VALUE {
                 Ok(None)
             } else {
-                percent_decode(t)
+                decode_string(file_format, t)
                     .map(|u| Some(u.into()))
                     .map_err(ParseError::InvalidString)
             }
@@ -337,6 +502,66 @@ mod tests {
         assert_eq!(value.to_string(), "noodles,.");
     }
 
+    #[test]
+    fn test_pl_to_gl_and_gl_to_pl() {
+        let pl = Value::IntegerArray(vec![Some(0), Some(3), Some(255), Some(1027), None]);
+
+        let gl = pl.pl_to_gl();
+        assert_eq!(
+            gl,
+            Some(Value::FloatArray(vec![
+                Some(0.0),
+                Some(-0.3),
+                Some(-25.5),
+                Some(-102.7),
+                None
+            ]))
+        );
+
+        assert_eq!(
+            gl.and_then(|value| value.gl_to_pl()),
+            Some(Value::IntegerArray(vec![
+                Some(0),
+                Some(3),
+                Some(255),
+                Some(255),
+                None
+            ]))
+        );
+
+        let gl = Value::FloatArray(vec![Some(f32::NEG_INFINITY), Some(f32::NAN)]);
+        assert_eq!(gl.gl_to_pl(), Some(Value::IntegerArray(vec![None, None])));
+
+        assert!(Value::Integer(0).pl_to_gl().is_none());
+        assert!(Value::Float(0.0).gl_to_pl().is_none());
+    }
+
+    #[test]
+    fn test_pl_to_gp() {
+        let pl = Value::IntegerArray(vec![Some(10), Some(0), Some(20), None]);
+
+        let gp = match pl.pl_to_gp() {
+            Some(Value::FloatArray(gp)) => gp,
+            _ => panic!("invalid GP value"),
+        };
+
+        let expected: [f32; 3] = [0.1 / 1.11, 1.0 / 1.11, 0.01 / 1.11];
+
+        for (actual, expected) in gp.iter().zip(expected.iter()) {
+            let actual = actual.expect("missing GP value");
+            assert!((actual - expected).abs() < 1e-6);
+        }
+
+        assert!(gp[3].is_none());
+
+        // All values underflow without shifting by the maximum likelihood.
+        let gl = Value::FloatArray(vec![Some(-500.0), Some(-500.0)]);
+        assert_eq!(
+            gl.gl_to_gp(),
+            Some(Value::FloatArray(vec![Some(0.5), Some(0.5)]))
+        );
+    }
+
     #[test]
     fn test_from_str_format_with_integer() -> Result<(), crate::header::format::key::ParseError> {
         let format = Format::new(
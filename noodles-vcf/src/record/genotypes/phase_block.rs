@@ -0,0 +1,190 @@
+//! VCF record genotypes phase blocks.
+
+use std::ops::Range;
+
+use super::genotype::PhaseSetError;
+use crate::Record;
+
+/// A phase block of a sample.
+///
+/// A phase block is a run of consecutive records on the same chromosome where the genotype of the
+/// sample has the same phase set (`PS`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PhaseBlock {
+    phase_set: i32,
+    records: Range<usize>,
+}
+
+impl PhaseBlock {
+    /// Creates a phase block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::PhaseBlock;
+    /// let phase_block = PhaseBlock::new(8, 0..2);
+    /// ```
+    pub fn new(phase_set: i32, records: Range<usize>) -> Self {
+        Self { phase_set, records }
+    }
+
+    /// Returns the phase set of the phase block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::PhaseBlock;
+    /// let phase_block = PhaseBlock::new(8, 0..2);
+    /// assert_eq!(phase_block.phase_set(), 8);
+    /// ```
+    pub fn phase_set(&self) -> i32 {
+        self.phase_set
+    }
+
+    /// Returns the range of record indices in the phase block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::PhaseBlock;
+    /// let phase_block = PhaseBlock::new(8, 0..2);
+    /// assert_eq!(phase_block.records(), 0..2);
+    /// ```
+    pub fn records(&self) -> Range<usize> {
+        self.records.clone()
+    }
+}
+
+/// Groups records into phase blocks for a sample.
+///
+/// The sample is given by its index in the genotypes of each record. A record where the sample
+/// does not have a phase set (`PS`) value ends the current phase block and does not start a new
+/// one. A change in chromosome also ends the current phase block.
+///
+/// # Errors
+///
+/// This returns an error if a phase set value of the sample is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{format::Key, Format},
+///     record::genotypes::{phase_blocks, PhaseBlock},
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_format(Format::from(Key::Genotype))
+///     .add_format(Format::from(Key::PhaseSet))
+///     .add_sample_name("sample0")
+///     .build();
+///
+/// let records = [
+///     "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT:PS\t0|1:1",
+///     "sq0\t5\t.\tA\tC\t.\tPASS\t.\tGT:PS\t1|0:1",
+///     "sq0\t8\t.\tA\tC\t.\tPASS\t.\tGT:PS\t0|1:8",
+/// ]
+/// .iter()
+/// .map(|s| vcf::Record::try_from_str(s, &header))
+/// .collect::<Result<Vec<_>, _>>()?;
+///
+/// let blocks = phase_blocks(&records, 0)?;
+///
+/// assert_eq!(blocks, [PhaseBlock::new(1, 0..2), PhaseBlock::new(8, 2..3)]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn phase_blocks(
+    records: &[Record],
+    sample_index: usize,
+) -> Result<Vec<PhaseBlock>, PhaseSetError> {
+    let mut blocks: Vec<PhaseBlock> = Vec::new();
+    let mut is_in_block = false;
+
+    for (i, record) in records.iter().enumerate() {
+        let phase_set = record
+            .genotypes()
+            .get(sample_index)
+            .and_then(|genotype| genotype.phase_set())
+            .transpose()?;
+
+        let phase_set = match phase_set {
+            Some(phase_set) => phase_set,
+            None => {
+                is_in_block = false;
+                continue;
+            }
+        };
+
+        if is_in_block {
+            if let Some(block) = blocks.last_mut() {
+                let prev = &records[block.records.end - 1];
+
+                if block.phase_set == phase_set && prev.chromosome() == record.chromosome() {
+                    block.records.end = i + 1;
+                    continue;
+                }
+            }
+        }
+
+        blocks.push(PhaseBlock::new(phase_set, i..i + 1));
+        is_in_block = true;
+    }
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::{format::Key, Format},
+        Header,
+    };
+
+    #[test]
+    fn test_phase_blocks() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_format(Format::from(Key::Genotype))
+            .add_format(Format::from(Key::PhaseSet))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let records = [
+            "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT:PS\t0|1:1\t0|1:1",
+            "sq0\t2\t.\tA\tC\t.\tPASS\t.\tGT:PS\t1|0:1\t0/1:.",
+            "sq0\t3\t.\tA\tC\t.\tPASS\t.\tGT:PS\t0/1:.\t1|0:1",
+            "sq0\t4\t.\tA\tC\t.\tPASS\t.\tGT:PS\t0|1:1\t1|0:1",
+            "sq0\t5\t.\tA\tC\t.\tPASS\t.\tGT:PS\t0|1:5\t0|1:5",
+            "sq1\t1\t.\tA\tC\t.\tPASS\t.\tGT:PS\t0|1:5\t0|1:5",
+        ]
+        .iter()
+        .map(|s| Record::try_from_str(s, &header))
+        .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            phase_blocks(&records, 0)?,
+            [
+                PhaseBlock::new(1, 0..2),
+                PhaseBlock::new(1, 3..4),
+                PhaseBlock::new(5, 4..5),
+                PhaseBlock::new(5, 5..6),
+            ]
+        );
+
+        assert_eq!(
+            phase_blocks(&records, 1)?,
+            [
+                PhaseBlock::new(1, 0..1),
+                PhaseBlock::new(1, 2..4),
+                PhaseBlock::new(5, 4..5),
+                PhaseBlock::new(5, 5..6),
+            ]
+        );
+
+        assert!(phase_blocks(&records, 2)?.is_empty());
+
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use std::{error, fmt, str::FromStr};
 use indexmap::IndexMap;
 
 use super::MISSING_FIELD;
-use crate::header::{self, info::Key};
+use crate::header::{self, info::Key, FileFormat};
 
 const DELIMITER: char = ';';
 
@@ -20,7 +20,7 @@ pub struct Info(IndexMap<Key, Field>);
 impl Info {
     /// Parses raw VCF record info.
     pub fn try_from_str(s: &str, infos: &header::Infos) -> Result<Self, ParseError> {
-        parse(s, infos)
+        parse(s, infos, FileFormat::default())
     }
 
     /// Returns the number of info fields.
@@ -316,14 +316,18 @@ impl FromStr for Info {
     }
 }
 
-fn parse(s: &str, infos: &header::Infos) -> Result<Info, ParseError> {
+pub(crate) fn parse(
+    s: &str,
+    infos: &header::Infos,
+    file_format: FileFormat,
+) -> Result<Info, ParseError> {
     match s {
         "" => Err(ParseError::Empty),
         MISSING_FIELD => Ok(Info::default()),
         _ => {
             let fields = s
                 .split(DELIMITER)
-                .map(|s| Field::try_from_str(s, infos))
+                .map(|s| field::parse(s, infos, file_format))
                 .collect::<Result<Vec<_>, _>>()
                 .map_err(ParseError::InvalidField)?;
 
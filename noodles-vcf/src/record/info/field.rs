@@ -9,7 +9,7 @@ use std::{error, fmt, str::FromStr};
 use crate::header::{
     self,
     info::{Key, Type},
-    Info, Infos,
+    FileFormat, Info, Infos,
 };
 
 const MISSING_VALUE: &str = ".";
@@ -25,7 +25,7 @@ pub struct Field {
 impl Field {
     /// Parses a raw VCF record info field.
     pub fn try_from_str(s: &str, infos: &Infos) -> Result<Self, ParseError> {
-        parse(s, infos)
+        parse(s, infos, FileFormat::default())
     }
 
     /// Creates a VCF record info field.
@@ -141,7 +141,7 @@ impl FromStr for Field {
     }
 }
 
-fn parse(s: &str, infos: &Infos) -> Result<Field, ParseError> {
+pub(crate) fn parse(s: &str, infos: &Infos, file_format: FileFormat) -> Result<Field, ParseError> {
     const MAX_COMPONENTS: usize = 2;
 
     let mut components = s.splitn(MAX_COMPONENTS, SEPARATOR);
@@ -152,16 +152,20 @@ fn parse(s: &str, infos: &Infos) -> Result<Field, ParseError> {
         .and_then(|t| t.parse().map_err(ParseError::InvalidKey))?;
 
     let value = if let Some(info) = infos.get(&key) {
-        parse_value(&mut components, info)?
+        parse_value(&mut components, info, file_format)?
     } else {
         let info = header::Info::from(key.clone());
-        parse_value(&mut components, &info)?
+        parse_value(&mut components, &info, file_format)?
     };
 
     Ok(Field::new(key, value))
 }
 
-fn parse_value<'a, I>(iter: &mut I, info: &Info) -> Result<Option<Value>, ParseError>
+fn parse_value<'a, I>(
+    iter: &mut I,
+    info: &Info,
+    file_format: FileFormat,
+) -> Result<Option<Value>, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
@@ -171,7 +175,7 @@ where
         if t == MISSING_VALUE {
             Ok(None)
         } else {
-            Value::from_str_info(t, info)
+            value::parse(t, info, file_format)
                 .map(Some)
                 .map_err(ParseError::InvalidValue)
         }
@@ -180,7 +184,7 @@ where
             if t == MISSING_VALUE {
                 Ok(None)
             } else {
-                Value::from_str_info(t, info)
+                value::parse(t, info, file_format)
                     .map(Some)
                     .map_err(ParseError::InvalidValue)
             }
@@ -191,7 +195,7 @@ where
         if t == MISSING_VALUE {
             Ok(None)
         } else {
-            Value::from_str_info(t, info)
+            value::parse(t, info, file_format)
                 .map(Some)
                 .map_err(ParseError::InvalidValue)
         }
@@ -234,12 +238,12 @@ mod tests {
             .build();
 
         assert_eq!(
-            parse("AC=.", header.infos()),
+            parse("AC=.", header.infos(), header.file_format()),
             Ok(Field::new(Key::AlleleCount, None))
         );
 
         assert_eq!(
-            parse("NS=2", header.infos()),
+            parse("NS=2", header.infos(), header.file_format()),
             Ok(Field::new(
                 Key::SamplesWithDataCount,
                 Some(Value::Integer(2))
@@ -247,17 +251,17 @@ mod tests {
         );
 
         assert_eq!(
-            parse("BQ=1.333", header.infos()),
+            parse("BQ=1.333", header.infos(), header.file_format()),
             Ok(Field::new(Key::BaseQuality, Some(Value::Float(1.333))))
         );
 
         assert_eq!(
-            parse("SOMATIC", header.infos()),
+            parse("SOMATIC", header.infos(), header.file_format()),
             Ok(Field::new(Key::IsSomaticMutation, Some(Value::Flag)))
         );
 
         assert_eq!(
-            parse("EVENT=INV0", header.infos()),
+            parse("EVENT=INV0", header.infos(), header.file_format()),
             Ok(Field::new(
                 Key::BreakendEventId,
                 Some(Value::String(String::from("INV0")))
@@ -266,13 +270,13 @@ mod tests {
 
         let key = "NDLS".parse()?;
         assert_eq!(
-            parse("NDLS=VCF", header.infos()),
+            parse("NDLS=VCF", header.infos(), header.file_format()),
             Ok(Field::new(key, Some(Value::String(String::from("VCF")))))
         );
 
         let key = "FLG".parse()?;
         assert_eq!(
-            parse("FLG", header.infos()),
+            parse("FLG", header.infos(), header.file_format()),
             Ok(Field::new(key, Some(Value::Flag)))
         );
 
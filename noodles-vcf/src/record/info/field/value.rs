@@ -4,8 +4,8 @@ use std::{error, fmt, num, str};
 
 use super::MISSING_VALUE;
 use crate::{
-    header::{info::Type, Info, Number},
-    record::value::{self, percent_decode},
+    header::{info::Type, FileFormat, Info, Number},
+    record::value::{self, decode_string},
 };
 
 const DELIMITER: char = ',';
@@ -142,6 +142,9 @@ impl fmt::Display for ParseError {
 impl Value {
     /// Parses a raw info field value with the given info header record.
     ///
+    /// String values are percent-decoded, i.e., this uses the rules of VCF 4.3. Records parsed
+    /// with a header (e.g., [`crate::Record::try_from_str`]) use the file format of the header.
+    ///
     /// # Examples
     ///
     /// ```
@@ -150,32 +153,36 @@ impl Value {
     /// assert_eq!(Value::from_str_info("1", &info), Ok(Value::Integer(1)));
     /// ```
     pub fn from_str_info(s: &str, info: &Info) -> Result<Self, ParseError> {
-        match info.ty() {
-            Type::Integer => match info.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
-                Number::Count(1) => parse_i32(s),
-                _ => parse_i32_array(s),
-            },
-            Type::Float => match info.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
-                Number::Count(1) => parse_f32(s),
-                _ => parse_f32_array(s),
-            },
-            Type::Flag => match info.number() {
-                Number::Count(0) => parse_flag(s),
-                _ => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
-            },
-            Type::Character => match info.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
-                Number::Count(1) => parse_char(s),
-                _ => parse_char_array(s),
-            },
-            Type::String => match info.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
-                Number::Count(1) => parse_string(s),
-                _ => parse_string_array(s),
-            },
-        }
+        parse(s, info, FileFormat::default())
+    }
+}
+
+pub(crate) fn parse(s: &str, info: &Info, file_format: FileFormat) -> Result<Value, ParseError> {
+    match info.ty() {
+        Type::Integer => match info.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
+            Number::Count(1) => parse_i32(s),
+            _ => parse_i32_array(s),
+        },
+        Type::Float => match info.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
+            Number::Count(1) => parse_f32(s),
+            _ => parse_f32_array(s),
+        },
+        Type::Flag => match info.number() {
+            Number::Count(0) => parse_flag(s),
+            _ => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
+        },
+        Type::Character => match info.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
+            Number::Count(1) => parse_char(s),
+            _ => parse_char_array(s),
+        },
+        Type::String => match info.number() {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(info.number(), info.ty())),
+            Number::Count(1) => parse_string(s, file_format),
+            _ => parse_string_array(s, file_format),
+        },
     }
 }
 
@@ -247,17 +254,17 @@ fn parse_char_array(s: &str) -> Result<Value, ParseError> {
         .map(Value::CharacterArray)
 }
 
-fn parse_string(s: &str) -> Result<Value, ParseError> {
-    percent_decode(s)
+fn parse_string(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
+    decode_string(file_format, s)
         .map(|t| Value::String(t.into()))
         .map_err(ParseError::InvalidString)
 }
 
-fn parse_string_array(s: &str) -> Result<Value, ParseError> {
+fn parse_string_array(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| match t {
             MISSING_VALUE => Ok(None),
-            _ => percent_decode(t)
+            _ => decode_string(file_format, t)
                 .map(|u| Some(u.into()))
                 .map_err(ParseError::InvalidString),
         })
@@ -2,7 +2,7 @@ use std::{error, fmt};
 
 use super::{
     alternate_bases, chromosome, filters, genotypes, ids, info, position, quality_score,
-    reference_bases, Field, Filters, Genotypes, Info, QualityScore, Record, FIELD_DELIMITER,
+    reference_bases, Field, Filters, Genotypes, QualityScore, Record, FIELD_DELIMITER,
     MISSING_FIELD,
 };
 use crate::Header;
@@ -51,7 +51,7 @@ impl fmt::Display for ParseError {
     }
 }
 
-pub fn parse(s: &str, header: &Header) -> Result<Record, ParseError> {
+pub fn parse(s: &str, header: &Header, pad_missing_samples: bool) -> Result<Record, ParseError> {
     const MAX_FIELDS: usize = 9;
 
     let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
@@ -74,11 +74,12 @@ pub fn parse(s: &str, header: &Header) -> Result<Record, ParseError> {
     let qual = parse_quality_score(&mut fields)?;
     let filter = parse_filters(&mut fields)?;
 
-    let info = parse_string(&mut fields, Field::Info)
-        .and_then(|s| Info::try_from_str(s, header.infos()).map_err(ParseError::InvalidInfo))?;
+    let info = parse_string(&mut fields, Field::Info).and_then(|s| {
+        info::parse(s, header.infos(), header.file_format()).map_err(ParseError::InvalidInfo)
+    })?;
 
     let genotypes = if let Some(s) = fields.next() {
-        Genotypes::parse(s, header).map_err(ParseError::InvalidGenotypes)?
+        genotypes::parse(s, header, pad_missing_samples).map_err(ParseError::InvalidGenotypes)?
     } else {
         Genotypes::default()
     };
@@ -183,4 +184,63 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_with_file_format() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::FileFormat,
+            record::{genotypes::genotype::field::Value as GenotypeFieldValue, info::field::Value},
+        };
+
+        const S: &str = "sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=a%3Bb\tGT:NOTE\t0|0:c%3Ad";
+
+        fn parse_header(file_format: &str) -> Result<Header, crate::header::ParseError> {
+            format!(
+                "##fileformat={}
+##INFO=<ID=NOTE,Number=1,Type=String,Description=\"Note\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=NOTE,Number=1,Type=String,Description=\"Note\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+",
+                file_format
+            )
+            .parse()
+        }
+
+        let header = parse_header("VCFv4.3")?;
+        assert_eq!(header.file_format(), FileFormat::new(4, 3));
+
+        let record = parse(S, &header, false)?;
+        let key = "NOTE".parse()?;
+        assert_eq!(
+            record.info().get(&key).and_then(|field| field.value()),
+            Some(&Value::String(String::from("a;b")))
+        );
+        let key: crate::header::format::Key = "NOTE".parse()?;
+        assert_eq!(
+            record.genotypes()[0]
+                .get(&key)
+                .and_then(|field| field.value()),
+            Some(&GenotypeFieldValue::String(String::from("c:d")))
+        );
+
+        let header = parse_header("VCFv4.2")?;
+        assert_eq!(header.file_format(), FileFormat::new(4, 2));
+
+        let record = parse(S, &header, false)?;
+        let key = "NOTE".parse()?;
+        assert_eq!(
+            record.info().get(&key).and_then(|field| field.value()),
+            Some(&Value::String(String::from("a%3Bb")))
+        );
+        let key: crate::header::format::Key = "NOTE".parse()?;
+        assert_eq!(
+            record.genotypes()[0]
+                .get(&key)
+                .and_then(|field| field.value()),
+            Some(&GenotypeFieldValue::String(String::from("c%3Ad")))
+        );
+
+        Ok(())
+    }
 }
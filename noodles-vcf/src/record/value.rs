@@ -2,6 +2,8 @@ use std::{borrow::Cow, num, str};
 
 use percent_encoding::percent_decode_str;
 
+use crate::header::FileFormat;
+
 /// Parses a single-precision floating-point.
 pub(crate) fn parse_f32(s: &str) -> Result<f32, num::ParseFloatError> {
     s.parse()
@@ -11,6 +13,22 @@ pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
     percent_decode_str(s).decode_utf8()
 }
 
+/// Decodes a raw string value using the rules of the given file format.
+///
+/// Percent-encoding of special characters was introduced in VCF 4.3 (§ 1.2 "Character
+/// encoding, non-printable characters and characters with special meaning"). Strings of earlier
+/// versions are returned as is.
+pub(crate) fn decode_string(
+    file_format: FileFormat,
+    s: &str,
+) -> Result<Cow<'_, str>, str::Utf8Error> {
+    if file_format < FileFormat::new(4, 3) {
+        Ok(Cow::from(s))
+    } else {
+        percent_decode(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +58,21 @@ mod tests {
         assert_eq!(percent_decode("noodles%3Dvcf")?, "noodles=vcf");
         Ok(())
     }
+
+    #[test]
+    fn test_decode_string() -> Result<(), str::Utf8Error> {
+        assert_eq!(
+            decode_string(FileFormat::new(4, 3), "noodles%3Dvcf")?,
+            "noodles=vcf"
+        );
+        assert_eq!(
+            decode_string(FileFormat::new(4, 4), "noodles%3Dvcf")?,
+            "noodles=vcf"
+        );
+        assert_eq!(
+            decode_string(FileFormat::new(4, 2), "noodles%3Dvcf")?,
+            "noodles%3Dvcf"
+        );
+        Ok(())
+    }
 }
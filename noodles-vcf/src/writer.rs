@@ -1,6 +1,24 @@
-use std::io::{self, Write};
+//! VCF writer.
 
-use super::{Header, Record};
+mod builder;
+mod line_ending;
+
+pub use self::{builder::Builder, line_ending::LineEnding};
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::Hash,
+    io::{self, Write},
+};
+
+use indexmap::IndexSet;
+
+use super::{
+    header::{format, info},
+    record::{FIELD_DELIMITER, MISSING_FIELD},
+    Header, Record,
+};
 
 /// A VCF writer.
 ///
@@ -38,12 +56,29 @@ use super::{Header, Record};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    field_order: Option<FieldOrder>,
+    line_ending: LineEnding,
+    trailing_newline: bool,
+    is_line_ending_pending: bool,
 }
 
 impl<W> Writer<W>
 where
     W: Write,
 {
+    /// Creates a VCF writer builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::Writer::builder(Vec::new());
+    /// let writer = builder.build();
+    /// ```
+    pub fn builder(inner: W) -> Builder<W> {
+        Builder::new(inner)
+    }
+
     /// Creates a VCF writer.
     ///
     /// # Examples
@@ -53,7 +88,7 @@ where
     /// let writer = vcf::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self::builder(inner).build()
     }
 
     /// Returns a reference to the underlying writer.
@@ -110,7 +145,15 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        write!(self.inner, "{}", header)
+        if let Some(field_order) = self.field_order.as_mut() {
+            field_order.update(header);
+        }
+
+        for line in header.to_string().lines() {
+            self.write_line(line)?;
+        }
+
+        Ok(())
     }
 
     /// Writes a VCF record.
@@ -131,10 +174,188 @@ where
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        writeln!(self.inner, "{}", record)
+        self.start_line()?;
+
+        write!(
+            self.inner,
+            "{}",
+            OrderedRecord::new(record, self.field_order.as_ref())
+        )?;
+
+        self.finish_line()
+    }
+
+    // Writes a line followed by a line ending.
+    fn write_line<T>(&mut self, value: T) -> io::Result<()>
+    where
+        T: fmt::Display,
+    {
+        self.start_line()?;
+        write!(self.inner, "{}", value)?;
+        self.finish_line()
+    }
+
+    // Writes the line ending of the previous line, if it was deferred.
+    fn start_line(&mut self) -> io::Result<()> {
+        if self.is_line_ending_pending {
+            self.inner.write_all(self.line_ending.as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    // Writes a line ending. If trailing newlines are disabled, the line ending is instead deferred
+    // until the next line is written.
+    fn finish_line(&mut self) -> io::Result<()> {
+        if self.trailing_newline {
+            self.inner.write_all(self.line_ending.as_ref())?;
+        } else {
+            self.is_line_ending_pending = true;
+        }
+
+        Ok(())
+    }
+}
+
+// A record that is displayed with its INFO and FORMAT fields in the given field order.
+//
+// This avoids cloning and reordering the record itself.
+struct OrderedRecord<'a> {
+    record: &'a Record,
+    field_order: Option<&'a FieldOrder>,
+}
+
+impl<'a> OrderedRecord<'a> {
+    fn new(record: &'a Record, field_order: Option<&'a FieldOrder>) -> Self {
+        Self {
+            record,
+            field_order,
+        }
+    }
+}
+
+impl fmt::Display for OrderedRecord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const INFO_DELIMITER: &str = ";";
+        const FORMAT_DELIMITER: &str = ":";
+
+        let (record, field_order) = match self.field_order {
+            Some(field_order) => (self.record, field_order),
+            None => return write!(f, "{}", self.record),
+        };
+
+        write!(
+            f,
+            "{chrom}\t{pos}\t{id}\t{ref}\t{alt}",
+            chrom = record.chromosome(),
+            pos = record.position(),
+            id = record.ids(),
+            r#ref = record.reference_bases(),
+            alt = record.alternate_bases(),
+        )?;
+
+        if let Some(quality_score) = record.quality_score() {
+            write!(f, "\t{}", quality_score)?;
+        } else {
+            write!(f, "\t{}", MISSING_FIELD)?;
+        }
+
+        if let Some(filters) = record.filters() {
+            write!(f, "\t{}", filters)?;
+        } else {
+            write!(f, "\t{}", MISSING_FIELD)?;
+        }
+
+        let mut info_fields: Vec<_> = record.info().values().collect();
+        info_fields.sort_by(|a, b| cmp_keys(&field_order.info_keys, a.key(), b.key()));
+
+        write!(f, "{}", FIELD_DELIMITER)?;
+        write_fields(f, info_fields, INFO_DELIMITER)?;
+
+        let genotypes = record.genotypes();
+
+        if genotypes.is_empty() {
+            return Ok(());
+        }
+
+        let mut keys: Vec<_> = genotypes.keys().iter().collect();
+        keys.sort_by(|a, b| cmp_format_keys(&field_order.format_keys, a, b));
+
+        write!(f, "{}", FIELD_DELIMITER)?;
+        write_fields(f, keys, FORMAT_DELIMITER)?;
+
+        for genotype in genotypes.iter() {
+            let mut fields: Vec<_> = genotype.values().collect();
+            fields.sort_by(|a, b| cmp_format_keys(&field_order.format_keys, a.key(), b.key()));
+
+            write!(f, "{}", FIELD_DELIMITER)?;
+            write_fields(f, fields, FORMAT_DELIMITER)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Writes delimited values or, if there are none, a missing field.
+fn write_fields<I, T>(f: &mut fmt::Formatter<'_>, values: I, delimiter: &str) -> fmt::Result
+where
+    I: IntoIterator<Item = T>,
+    T: fmt::Display,
+{
+    let mut values = values.into_iter().peekable();
+
+    if values.peek().is_none() {
+        return f.write_str(MISSING_FIELD);
+    }
+
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            f.write_str(delimiter)?;
+        }
+
+        write!(f, "{}", value)?;
+    }
+
+    Ok(())
+}
+
+// The order of INFO and FORMAT keys as declared in a header.
+#[derive(Debug, Default)]
+struct FieldOrder {
+    info_keys: IndexSet<info::Key>,
+    format_keys: IndexSet<format::Key>,
+}
+
+impl FieldOrder {
+    fn update(&mut self, header: &Header) {
+        self.info_keys = header.infos().keys().cloned().collect();
+        self.format_keys = header.formats().keys().cloned().collect();
     }
 }
 
+// Keys declared in the header come first, in declaration order, followed by undeclared keys,
+// sorted by their string representation.
+fn cmp_keys<K>(keys: &IndexSet<K>, a: &K, b: &K) -> Ordering
+where
+    K: Eq + Hash + fmt::Display,
+{
+    let rank = |key| keys.get_index_of(key).unwrap_or(usize::MAX);
+
+    rank(a)
+        .cmp(&rank(b))
+        .then_with(|| a.to_string().cmp(&b.to_string()))
+}
+
+// § 1.6.2 Genotype fields (2021-01-13): "The first sub-field must always be the genotype (GT) if
+// it is present."
+fn cmp_format_keys(keys: &IndexSet<format::Key>, a: &format::Key, b: &format::Key) -> Ordering {
+    let is_genotype = |key: &format::Key| *key == format::Key::Genotype;
+
+    is_genotype(b)
+        .cmp(&is_genotype(a))
+        .then_with(|| cmp_keys(keys, a, b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,6 +396,93 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_record_with_order_fields_by_header() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = "##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description=\"Read depth\">
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+";
+
+        let header: Header = raw_header.parse()?;
+
+        let write = |raw_record: &str| -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let record = Record::try_from_str(raw_record, &header)?;
+            let mut writer = Writer::builder(Vec::new())
+                .set_order_fields_by_header(true)
+                .build();
+            writer.write_header(&header)?;
+            writer.write_record(&record)?;
+            Ok(writer.into_inner())
+        };
+
+        let a = write("sq0\t1\t.\tA\t.\t.\tPASS\tzz=1;DP=8;NS=2;aa=2\tGT:DP:zz:GQ\t0|0:8:1:13")?;
+        let b = write("sq0\t1\t.\tA\t.\t.\tPASS\taa=2;NS=2;zz=1;DP=8\tGT:zz:GQ:DP\t0|0:1:13:8")?;
+
+        let mut expected = header.to_string().into_bytes();
+        expected.extend_from_slice(
+            b"sq0\t1\t.\tA\t.\t.\tPASS\tNS=2;DP=8;aa=2;zz=1\tGT:GQ:DP:zz\t0|0:13:8:1\n",
+        );
+
+        assert_eq!(a, expected);
+        assert_eq!(b, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_line_ending_and_trailing_newline(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let write = |line_ending, trailing_newline| -> io::Result<Vec<u8>> {
+            let mut writer = Writer::builder(Vec::new())
+                .set_line_ending(line_ending)
+                .set_trailing_newline(trailing_newline)
+                .build();
+
+            writer.write_header(&header)?;
+            writer.write_record(&record)?;
+            writer.write_record(&record)?;
+
+            Ok(writer.into_inner())
+        };
+
+        let expected = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\t.\t.
+sq0\t1\t.\tA\t.\t.\t.\t.
+";
+        assert_eq!(write(LineEnding::Lf, true)?, expected);
+        assert_eq!(
+            write(LineEnding::Lf, false)?,
+            &expected[..expected.len() - 1]
+        );
+
+        let expected = b"##fileformat=VCFv4.3\r
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\r
+sq0\t1\t.\tA\t.\t.\t.\t.\r
+sq0\t1\t.\tA\t.\t.\t.\t.\r
+";
+        assert_eq!(write(LineEnding::CrLf, true)?, expected);
+        assert_eq!(
+            write(LineEnding::CrLf, false)?,
+            &expected[..expected.len() - 2]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record_with_format() -> Result<(), Box<dyn std::error::Error>> {
         use crate::{
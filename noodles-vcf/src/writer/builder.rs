@@ -0,0 +1,102 @@
+use std::io::Write;
+
+use super::{FieldOrder, LineEnding, Writer};
+
+/// A VCF writer builder.
+#[derive(Debug)]
+pub struct Builder<W> {
+    inner: W,
+    order_fields_by_header: bool,
+    line_ending: LineEnding,
+    trailing_newline: bool,
+}
+
+impl<W> Builder<W>
+where
+    W: Write,
+{
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            order_fields_by_header: false,
+            line_ending: LineEnding::default(),
+            trailing_newline: true,
+        }
+    }
+
+    /// Sets whether to write INFO and FORMAT fields in the order they are declared in the header.
+    ///
+    /// When enabled, the order of INFO fields and FORMAT keys (and genotype fields) of each record
+    /// follows the order of the INFO and FORMAT header records of the header last written by
+    /// [`Writer::write_header`]. The genotype (`GT`) field is always written first. Fields that are
+    /// not declared in the header are written after declared fields, sorted by key.
+    ///
+    /// This makes the output independent of the insertion order of fields. By default, fields are
+    /// written in the order they are stored in the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::Writer::builder(Vec::new()).set_order_fields_by_header(true);
+    /// ```
+    pub fn set_order_fields_by_header(mut self, order_fields_by_header: bool) -> Self {
+        self.order_fields_by_header = order_fields_by_header;
+        self
+    }
+
+    /// Sets the line ending of the header and record lines.
+    ///
+    /// VCF lines are terminated by a line feed (`\n`), which is the default. Other line endings
+    /// are only meant for compatibility with tools that expect them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, writer::LineEnding};
+    /// let builder = vcf::Writer::builder(Vec::new()).set_line_ending(LineEnding::CrLf);
+    /// ```
+    pub fn set_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Sets whether the last line is terminated by a line ending.
+    ///
+    /// By default, every line, including the last, is terminated. When disabled, line endings
+    /// are written as separators between lines, i.e., before each line but the first, so the
+    /// output does not end with a line ending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::Writer::builder(Vec::new()).set_trailing_newline(false);
+    /// ```
+    pub fn set_trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Builds a VCF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::Writer::builder(Vec::new()).build();
+    /// ```
+    pub fn build(self) -> Writer<W> {
+        Writer {
+            inner: self.inner,
+            field_order: if self.order_fields_by_header {
+                Some(FieldOrder::default())
+            } else {
+                None
+            },
+            line_ending: self.line_ending,
+            trailing_newline: self.trailing_newline,
+            is_line_ending_pending: false,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+/// A VCF writer line ending.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LineEnding {
+    /// Line feed (`\n`).
+    Lf,
+    /// Carriage return and line feed (`\r\n`).
+    CrLf,
+}
+
+impl AsRef<[u8]> for LineEnding {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Lf => b"\n",
+            Self::CrLf => b"\r\n",
+        }
+    }
+}
+
+impl Default for LineEnding {
+    fn default() -> Self {
+        Self::Lf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_ref_u8_slice_for_line_ending() {
+        assert_eq!(LineEnding::Lf.as_ref(), b"\n");
+        assert_eq!(LineEnding::CrLf.as_ref(), b"\r\n");
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(LineEnding::default(), LineEnding::Lf);
+    }
+}